@@ -0,0 +1,172 @@
+use anyhow::{bail, Result};
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::clients::ipc::ipc_client::IPCClient;
+use goxlr_ipc::clients::web::web_client::WebClient;
+use goxlr_ipc::commands::{
+    Backup, DaemonCommand, DaemonRequest, DaemonResponse, DaemonStatus, GoXLRCommandResponse,
+    HealthReport, ProfileImport, ProfileImportPreview,
+};
+use goxlr_ipc::status::ProfileLoadReport;
+
+use crate::device::Device;
+
+/// A connection to the daemon, with a method for each of its commands. Device commands are sent
+/// through the `Device` returned by `device()`.
+pub struct GoXLRClient {
+    client: Box<dyn Client + Send>,
+}
+
+impl GoXLRClient {
+    /// Connects to the daemon's IPC socket at its default location
+    pub async fn connect() -> Result<Self> {
+        Ok(Self::new(IPCClient::connect().await?))
+    }
+
+    /// Connects to the daemon's IPC socket, whether the path or the pipe name is used depends on
+    /// what the platform supports.
+    pub async fn connect_to(socket_path: &str, pipe_name: &str) -> Result<Self> {
+        Ok(Self::new(
+            IPCClient::connect_to(socket_path, pipe_name).await?,
+        ))
+    }
+
+    /// Connects to the daemon's HTTP API, from its base address (eg. http://localhost:14564)
+    pub fn connect_http(address: &str) -> Result<Self> {
+        let url = format!("{}/api/command", address.trim_end_matches('/'));
+        Ok(Self::new(WebClient::connect(url)?))
+    }
+
+    fn new(client: impl Client + Send + 'static) -> Self {
+        Self {
+            client: Box::new(client),
+        }
+    }
+
+    /// Sends a request as-is, for anything not covered by the other methods
+    pub async fn request(&mut self, request: DaemonRequest) -> Result<DaemonResponse> {
+        self.client.request(request).await
+    }
+
+    /// Sends a request which Live Mode would otherwise refuse, once the user has confirmed it
+    pub async fn forced(&mut self, request: DaemonRequest) -> Result<DaemonResponse> {
+        self.request(DaemonRequest::Forced(Box::new(request))).await
+    }
+
+    /// The status from the last time it was fetched
+    pub fn status(&self) -> &DaemonStatus {
+        self.client.status()
+    }
+
+    pub fn device(&mut self, serial: &str) -> Device<'_> {
+        Device::new(self, serial)
+    }
+
+    pub async fn ping(&mut self) -> Result<()> {
+        self.expect_ok(DaemonRequest::Ping).await
+    }
+
+    pub async fn get_status(&mut self) -> Result<DaemonStatus> {
+        match self.request(DaemonRequest::GetStatus).await? {
+            DaemonResponse::Status(status) => Ok(status),
+            response => unexpected(response),
+        }
+    }
+
+    pub async fn get_log_tail(&mut self, lines: usize) -> Result<Vec<String>> {
+        match self.daemon(DaemonCommand::GetLogTail(lines)).await? {
+            DaemonResponse::LogTail(lines) => Ok(lines),
+            response => unexpected(response),
+        }
+    }
+
+    pub async fn get_backup(&mut self) -> Result<Backup> {
+        match self.daemon(DaemonCommand::GetBackup).await? {
+            DaemonResponse::Backup(backup) => Ok(backup),
+            response => unexpected(response),
+        }
+    }
+
+    pub async fn restore_backup(&mut self, backup: Backup) -> Result<()> {
+        self.expect_daemon_ok(DaemonCommand::RestoreBackup(backup))
+            .await
+    }
+
+    pub async fn list_backups(&mut self) -> Result<Vec<String>> {
+        match self.daemon(DaemonCommand::ListBackups).await? {
+            DaemonResponse::Backups(backups) => Ok(backups),
+            response => unexpected(response),
+        }
+    }
+
+    pub async fn restore_stored_backup(&mut self, name: &str) -> Result<()> {
+        let command = DaemonCommand::RestoreStoredBackup(name.to_string());
+        self.expect_daemon_ok(command).await
+    }
+
+    pub async fn get_health(&mut self) -> Result<HealthReport> {
+        match self.daemon(DaemonCommand::GetHealth).await? {
+            DaemonResponse::Health(health) => Ok(health),
+            response => unexpected(response),
+        }
+    }
+
+    pub async fn set_usb_traffic_log(&mut self, enabled: bool) -> Result<()> {
+        self.expect_daemon_ok(DaemonCommand::SetUsbTrafficLog(enabled))
+            .await
+    }
+
+    pub async fn set_live_mode(&mut self, enabled: bool) -> Result<()> {
+        self.expect_daemon_ok(DaemonCommand::SetLiveMode(enabled))
+            .await
+    }
+
+    /// Starts a check for a newer daemon, the result appears in the status's update
+    pub async fn check_for_update(&mut self) -> Result<()> {
+        self.expect_daemon_ok(DaemonCommand::CheckForUpdate).await
+    }
+
+    /// Starts downloading the newest daemon, which is applied when the daemon next restarts
+    pub async fn install_update(&mut self) -> Result<()> {
+        self.expect_daemon_ok(DaemonCommand::InstallUpdate).await
+    }
+
+    /// Fetches a profile shared by URL, and describes what's in it without applying it
+    pub async fn preview_profile_import(&mut self, url: &str) -> Result<ProfileImportPreview> {
+        let command = DaemonCommand::PreviewProfileImport(url.to_string());
+        match self.daemon(command).await? {
+            DaemonResponse::ProfileImport(preview) => Ok(preview),
+            response => unexpected(response),
+        }
+    }
+
+    /// Loads a profile shared by URL onto a device. Passing the digest from a preview ensures
+    /// the profile hasn't changed since it was looked at.
+    pub async fn import_profile(&mut self, import: ProfileImport) -> Result<ProfileLoadReport> {
+        match self.daemon(DaemonCommand::ImportProfile(import)).await? {
+            DaemonResponse::DeviceCommand(GoXLRCommandResponse::ProfileLoaded(report)) => {
+                Ok(report)
+            }
+            response => unexpected(response),
+        }
+    }
+
+    async fn daemon(&mut self, command: DaemonCommand) -> Result<DaemonResponse> {
+        self.request(DaemonRequest::Daemon(command)).await
+    }
+
+    async fn expect_daemon_ok(&mut self, command: DaemonCommand) -> Result<()> {
+        self.expect_ok(DaemonRequest::Daemon(command)).await
+    }
+
+    async fn expect_ok(&mut self, request: DaemonRequest) -> Result<()> {
+        match self.request(request).await? {
+            DaemonResponse::Ok => Ok(()),
+            response => unexpected(response),
+        }
+    }
+}
+
+pub(crate) fn unexpected<T>(response: impl std::fmt::Debug) -> Result<T> {
+    bail!("Unexpected Response from the Daemon: {:?}", response)
+}
@@ -0,0 +1,178 @@
+use anyhow::Result;
+
+use goxlr_ipc::commands::automation::AutomationCommand;
+use goxlr_ipc::commands::button_cycles::ButtonCycleCommand;
+use goxlr_ipc::commands::channels::ChannelCommands;
+use goxlr_ipc::commands::configuration::ConfigurationCommand;
+use goxlr_ipc::commands::controls::ControlCommand;
+use goxlr_ipc::commands::diagnostics::DiagnosticsCommand;
+use goxlr_ipc::commands::ducking::DuckingCommand;
+use goxlr_ipc::commands::effects::EffectsCommand;
+use goxlr_ipc::commands::lighting::LightingCommand;
+use goxlr_ipc::commands::mic::MicrophoneCommand;
+use goxlr_ipc::commands::mix_modes::MixModeCommand;
+use goxlr_ipc::commands::monitor_mix::MonitorMixCommand;
+use goxlr_ipc::commands::pages::PageCommand;
+use goxlr_ipc::commands::push_to_talk::PushToTalkCommand;
+use goxlr_ipc::commands::sampler::SamplerCommand;
+use goxlr_ipc::commands::scribbles::ScribbleCommand;
+use goxlr_ipc::commands::solo::SoloCommand;
+use goxlr_ipc::commands::{
+    DaemonRequest, DaemonResponse, DeviceCommand, DryRunReport, GoXLRCommand, GoXLRCommandResponse,
+};
+use goxlr_ipc::status::DeviceStatus;
+
+use crate::client::{unexpected, GoXLRClient};
+
+/// Sends commands to a single device, by serial. Errors from the device (including Live Mode
+/// refusing a command) are returned as errors, anything else is returned as the response.
+pub struct Device<'a> {
+    client: &'a mut GoXLRClient,
+    serial: String,
+}
+
+impl<'a> Device<'a> {
+    pub(crate) fn new(client: &'a mut GoXLRClient, serial: &str) -> Self {
+        Self {
+            client,
+            serial: serial.to_string(),
+        }
+    }
+
+    pub fn serial(&self) -> &str {
+        &self.serial
+    }
+
+    /// The device's status from the last time it was fetched
+    pub fn status(&self) -> Option<&DeviceStatus> {
+        self.client.status().devices.get(&self.serial)
+    }
+
+    pub async fn command(&mut self, command: GoXLRCommand) -> Result<GoXLRCommandResponse> {
+        let request = DaemonRequest::DeviceCommand(self.build(command));
+        Self::unwrap(self.client.request(request).await?)
+    }
+
+    /// Sends a command which Live Mode would otherwise refuse, once the user has confirmed it
+    pub async fn forced(&mut self, command: GoXLRCommand) -> Result<GoXLRCommandResponse> {
+        let request = DaemonRequest::DeviceCommand(self.build(command));
+        Self::unwrap(self.client.forced(request).await?)
+    }
+
+    /// Reports what a command would change on the device, without applying any of it
+    pub async fn dry_run(&mut self, command: GoXLRCommand) -> Result<DryRunReport> {
+        let request = DaemonRequest::DryRun(self.build(command));
+        match Self::unwrap(self.client.request(request).await?)? {
+            GoXLRCommandResponse::DryRun(report) => Ok(report),
+            response => unexpected(response),
+        }
+    }
+
+    pub async fn configuration(
+        &mut self,
+        command: ConfigurationCommand,
+    ) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Configuration(command)).await
+    }
+
+    pub async fn microphone(&mut self, command: MicrophoneCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Microphone(command)).await
+    }
+
+    pub async fn channels(&mut self, command: ChannelCommands) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Channels(command)).await
+    }
+
+    pub async fn pages(&mut self, command: PageCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Pages(command)).await
+    }
+
+    pub async fn effects(&mut self, command: EffectsCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Effects(command)).await
+    }
+
+    pub async fn diagnostics(
+        &mut self,
+        command: DiagnosticsCommand,
+    ) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Diagnostics(command)).await
+    }
+
+    pub async fn mix_modes(&mut self, command: MixModeCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::MixModes(command)).await
+    }
+
+    pub async fn ducking(&mut self, command: DuckingCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Ducking(command)).await
+    }
+
+    pub async fn push_to_talk(
+        &mut self,
+        command: PushToTalkCommand,
+    ) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::PushToTalk(command)).await
+    }
+
+    pub async fn monitor_mix(
+        &mut self,
+        command: MonitorMixCommand,
+    ) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::MonitorMix(command)).await
+    }
+
+    pub async fn lighting(&mut self, command: LightingCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Lighting(command)).await
+    }
+
+    pub async fn solo(&mut self, command: SoloCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Solo(command)).await
+    }
+
+    pub async fn scribbles(&mut self, command: ScribbleCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Scribbles(command)).await
+    }
+
+    pub async fn sampler(&mut self, command: SamplerCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Sampler(command)).await
+    }
+
+    pub async fn controls(&mut self, command: ControlCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Controls(command)).await
+    }
+
+    pub async fn automation(&mut self, command: AutomationCommand) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::Automation(command)).await
+    }
+
+    pub async fn button_cycles(
+        &mut self,
+        command: ButtonCycleCommand,
+    ) -> Result<GoXLRCommandResponse> {
+        self.command(GoXLRCommand::ButtonCycles(command)).await
+    }
+
+    /// Applies several commands together, if any of them fail validation none are applied
+    pub async fn batch(
+        &mut self,
+        commands: Vec<GoXLRCommand>,
+    ) -> Result<Vec<GoXLRCommandResponse>> {
+        match self.command(GoXLRCommand::Batch(commands)).await? {
+            GoXLRCommandResponse::Batch(responses) => Ok(responses),
+            response => unexpected(response),
+        }
+    }
+
+    fn build(&self, command: GoXLRCommand) -> DeviceCommand {
+        DeviceCommand {
+            serial: self.serial.clone(),
+            command,
+        }
+    }
+
+    fn unwrap(response: DaemonResponse) -> Result<GoXLRCommandResponse> {
+        match response {
+            DaemonResponse::DeviceCommand(response) => Ok(response),
+            response => unexpected(response),
+        }
+    }
+}
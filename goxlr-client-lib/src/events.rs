@@ -0,0 +1,191 @@
+use anyhow::{bail, Context, Result};
+use futures::{SinkExt, Stream, StreamExt};
+use interprocess::local_socket::tokio::LocalSocketStream;
+use interprocess::local_socket::NameTypeSupport;
+use json_patch::Patch;
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+use goxlr_ipc::clients::ipc::ipc_client::{DEFAULT_NAMED_PIPE, DEFAULT_SOCKET_PATH};
+use goxlr_ipc::clients::ipc::ipc_socket::Socket;
+use goxlr_ipc::commands::{
+    DaemonRequest, DaemonResponse, DaemonStatus, FaderMovement, WebsocketRequest, WebsocketResponse,
+};
+
+#[derive(Debug, Clone)]
+pub enum DaemonEvent {
+    /// The daemon's full status, sent when the stream starts, and whenever it's had to resync
+    Status(DaemonStatus),
+
+    /// A change to the status, along with the status once it's been applied
+    Patched(Patch, DaemonStatus),
+
+    /// A physical fader has moved, only sent once subscribed with `subscribe_fader_events()`
+    FaderMoved(FaderMovement),
+}
+
+/// Follows the daemon's status as it changes. The stream keeps its own copy of the status, and
+/// if a patch can't be applied to it (or the daemon reports the stream has fallen behind) the
+/// full status is fetched again, and sent as a new Status event.
+pub struct EventStream {
+    transport: Transport,
+
+    // The status as JSON, for patching. This is None until the first full status arrives.
+    state: Option<Value>,
+}
+
+impl EventStream {
+    /// Follows the daemon through its IPC socket, at its default location
+    pub async fn connect() -> Result<Self> {
+        Self::connect_to(DEFAULT_SOCKET_PATH, DEFAULT_NAMED_PIPE).await
+    }
+
+    pub async fn connect_to(socket_path: &str, pipe_name: &str) -> Result<Self> {
+        let connection = LocalSocketStream::connect(match NameTypeSupport::query() {
+            NameTypeSupport::OnlyPaths | NameTypeSupport::Both => socket_path,
+            NameTypeSupport::OnlyNamespaced => pipe_name,
+        })
+        .await?;
+
+        // Resuming without a position subscribes, and fetches the full status in one go
+        let mut transport = Transport::Ipc(Socket::new(connection));
+        transport.send(DaemonRequest::ResumeSession(None)).await?;
+        Ok(Self::new(transport))
+    }
+
+    /// Follows the daemon through its WebSocket, from its base address (eg. http://localhost:14564)
+    pub async fn connect_websocket(address: &str) -> Result<Self> {
+        if !address.starts_with("http") {
+            bail!("Address should start with http:// or https://");
+        }
+        let url = format!("{}/api/websocket", address.trim_end_matches('/'));
+        let url = url.replacen("http", "ws", 1);
+        let (socket, _) = connect_async(url).await?;
+
+        // WebSockets are subscribed as soon as they connect, so only the status is needed
+        let mut transport = Transport::WebSocket(Box::new(socket));
+        transport.send(DaemonRequest::GetStatus).await?;
+        Ok(Self::new(transport))
+    }
+
+    fn new(transport: Transport) -> Self {
+        Self {
+            transport,
+            state: None,
+        }
+    }
+
+    /// Adds physical fader movements to the events, these are only available over IPC
+    pub async fn subscribe_fader_events(&mut self) -> Result<()> {
+        if let Transport::WebSocket(_) = self.transport {
+            bail!("Fader Events are only available over IPC");
+        }
+        self.transport
+            .send(DaemonRequest::SubscribeFaderEvents)
+            .await
+    }
+
+    /// Waits for the next event, returns None once the daemon has closed the connection
+    pub async fn next(&mut self) -> Option<Result<DaemonEvent>> {
+        loop {
+            let response = match self.transport.read().await? {
+                Ok(response) => response,
+                Err(error) => return Some(Err(error)),
+            };
+
+            match self.handle(response).await {
+                Ok(Some(event)) => return Some(Ok(event)),
+                Ok(None) => continue,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+
+    /// Turns this into a Stream, for use with the combinators in `futures`
+    pub fn into_stream(self) -> impl Stream<Item = Result<DaemonEvent>> {
+        futures::stream::unfold(self, |mut events| async move {
+            events.next().await.map(|event| (event, events))
+        })
+    }
+
+    async fn handle(&mut self, response: DaemonResponse) -> Result<Option<DaemonEvent>> {
+        let status = match response {
+            DaemonResponse::Patch(patch) => return self.apply(patch).await,
+            DaemonResponse::FaderMoved(movement) => {
+                return Ok(Some(DaemonEvent::FaderMoved(movement)));
+            }
+            DaemonResponse::Session(session) => session.status,
+            DaemonResponse::Status(status) => status,
+            DaemonResponse::Err(error) => bail!("{}", error),
+            _ => return Ok(None),
+        };
+
+        self.state = Some(serde_json::to_value(&status)?);
+        Ok(Some(DaemonEvent::Status(status)))
+    }
+
+    async fn apply(&mut self, patch: Patch) -> Result<Option<DaemonEvent>> {
+        // Patches which arrive before the first status are already included in it
+        let Some(state) = self.state.as_mut() else {
+            return Ok(None);
+        };
+
+        let status = json_patch::patch(state, &patch)
+            .ok()
+            .and_then(|_| serde_json::from_value(state.clone()).ok());
+
+        let Some(status) = status else {
+            // Our status has drifted from the daemon's, throw it away and fetch it again
+            self.state = None;
+            self.transport.send(DaemonRequest::GetStatus).await?;
+            return Ok(None);
+        };
+        Ok(Some(DaemonEvent::Patched(patch, status)))
+    }
+}
+
+enum Transport {
+    Ipc(Socket<DaemonResponse, DaemonRequest>),
+    WebSocket(Box<WebSocketStream<MaybeTlsStream<TcpStream>>>),
+}
+
+impl Transport {
+    async fn send(&mut self, request: DaemonRequest) -> Result<()> {
+        match self {
+            Transport::Ipc(socket) => socket.send(request).await?,
+            Transport::WebSocket(socket) => {
+                // Responses are all handled the same, so the request id doesn't matter
+                let request = WebsocketRequest {
+                    id: 0,
+                    data: request,
+                };
+                let text = serde_json::to_string(&request)?;
+                socket.send(Message::Text(text)).await?
+            }
+        }
+        Ok(())
+    }
+
+    async fn read(&mut self) -> Option<Result<DaemonResponse>> {
+        match self {
+            Transport::Ipc(socket) => {
+                let response = socket.read().await?;
+                Some(response.context("Failed to parse a message from the GoXLR daemon process"))
+            }
+            Transport::WebSocket(socket) => loop {
+                let text = match socket.next().await? {
+                    Ok(Message::Text(text)) => text,
+                    Ok(Message::Close(_)) => return None,
+                    Ok(_) => continue,
+                    Err(error) => return Some(Err(error.into())),
+                };
+                let response = serde_json::from_str::<WebsocketResponse>(&text)
+                    .map(|response| response.data)
+                    .context("Failed to parse a message from the GoXLR daemon's WebSocket");
+                return Some(response);
+            },
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod client;
+pub mod device;
+pub mod events;
+
+pub use client::GoXLRClient;
+pub use device::Device;
+pub use events::{DaemonEvent, EventStream};
+
+// Re-exported, so tools get the same versions of the types the client was built against
+pub use goxlr_ipc as ipc;
+pub use goxlr_profile as profile;
+pub use goxlr_shared as shared;
+
+/// The address the daemon's HTTP server listens on by default
+pub static DEFAULT_HTTP_ADDRESS: &str = "http://localhost:14564";
@@ -1,13 +1,25 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use goxlr_ipc::clients::ipc::ipc_client::{DEFAULT_NAMED_PIPE, DEFAULT_SOCKET_PATH};
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::category::InputCategory;
+use goxlr_shared::channels::ducking::{DuckingInput, DuckingSensitivity};
 use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
 use goxlr_shared::channels::sub_mix::SubMixChannels;
 use goxlr_shared::channels::volume::VolumeChannels;
 use goxlr_shared::compressor::{CompressorAttackTime, CompressorRatio, CompressorReleaseTime};
+use goxlr_shared::effects::{EchoStyle, GenderStyle, PitchStyle, ReverbStyle};
 use goxlr_shared::eq_frequencies::{Frequencies, MiniFrequencies};
 use goxlr_shared::faders::Fader;
 use goxlr_shared::gate::GateTimes;
-use goxlr_shared::microphone::MicrophoneType;
-use goxlr_shared::mute::MuteState;
+use goxlr_shared::microphone::{MicPreset, MicrophoneType};
+use goxlr_shared::mute::{CoughTarget, MuteState};
+use goxlr_shared::routing::StereoMode;
+use goxlr_shared::sampler::{SampleBank, SampleButton};
+use goxlr_shared::scribbles::ScribbleWidget;
 
 #[derive(Parser, Debug)]
 #[command(about, version, author)]
@@ -20,12 +32,36 @@ pub struct Cli {
     #[arg(long)]
     pub status_json: bool,
 
+    /// How Status and query results are displayed
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// The path of the Daemon's IPC Unix Socket
+    #[arg(long, default_value = DEFAULT_SOCKET_PATH)]
+    pub ipc_socket_path: String,
+
+    /// The name of the Daemon's IPC Named Pipe (Windows)
+    #[arg(long, default_value = DEFAULT_NAMED_PIPE)]
+    pub ipc_pipe_name: String,
+
     #[command(subcommand)]
     pub(crate) command: Option<SubCommands>,
 }
 
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable, the layout may change between versions
+    Text,
+
+    /// JSON, with field names matching the IPC types, for use in scripts
+    Json,
+}
+
 #[derive(Debug, Subcommand)]
 pub enum SubCommands {
+    /// Display the Status of the device
+    Status,
+
     Microphone {
         #[command(subcommand)]
         command: MicrophoneCommands,
@@ -40,6 +76,428 @@ pub enum SubCommands {
         #[command(subcommand)]
         command: PageCommands,
     },
+
+    Effects {
+        #[command(subcommand)]
+        command: EffectsCommands,
+    },
+
+    MixModes {
+        #[command(subcommand)]
+        command: MixModeCommands,
+    },
+
+    Ducking {
+        #[command(subcommand)]
+        command: DuckingCommands,
+    },
+
+    PushToTalk {
+        #[command(subcommand)]
+        command: PushToTalkCommands,
+    },
+
+    MonitorMix {
+        #[command(subcommand)]
+        command: MonitorMixCommands,
+    },
+
+    Solo {
+        #[command(subcommand)]
+        command: SoloCommands,
+    },
+
+    /// Choose where samples are heard while they play
+    Sampler {
+        #[command(subcommand)]
+        command: SamplerCommands,
+    },
+
+    /// Show a Clock or Timer on a Fader's Scribble display
+    Scribbles {
+        #[command(subcommand)]
+        command: ScribbleCommands,
+    },
+
+    /// Manage the device from a Desired State file (TOML)
+    State {
+        #[command(subcommand)]
+        command: StateCommands,
+    },
+
+    /// List every control with its value, and adjust them by ID
+    Controls {
+        #[command(subcommand)]
+        command: ControlCommands,
+    },
+
+    /// Record volume and routing changes, and play them back later
+    Automation {
+        #[command(subcommand)]
+        command: AutomationCommands,
+    },
+    /// Show what's in a profile shared by URL, and optionally load it
+    Import {
+        url: String,
+
+        /// Load the profile once it's been shown, replacing the current one
+        #[arg(long)]
+        apply: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AutomationCommands {
+    /// Start recording changes to the volumes and routing
+    Record,
+
+    /// Stop recording, and store the recording under a name
+    Save { name: String },
+
+    /// Play a stored Automation
+    Play { name: String },
+
+    /// Stop the Automation which is playing
+    Stop,
+
+    /// List the stored Automations
+    List,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ControlCommands {
+    /// List every control, with its ID and current value
+    List,
+
+    /// Move a Range up a step, or turn a Toggle on
+    Up { id: String },
+
+    /// Move a Range down a step, or turn a Toggle off
+    Down { id: String },
+
+    /// Flip a Toggle
+    Toggle { id: String },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StateCommands {
+    /// Bring the device in line with the Desired State
+    Apply { file: PathBuf },
+
+    /// Show what would change if the Desired State was applied
+    Diff { file: PathBuf },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SamplerCommands {
+    /// Set the outputs a bank's samples are heard on, none uses the routing table
+    BankOutputs {
+        #[arg(value_enum)]
+        bank: SampleBank,
+
+        #[arg(value_enum)]
+        outputs: Vec<OutputChannels>,
+    },
+
+    /// Set the outputs a single sample is heard on, none uses its bank's outputs
+    ButtonOutputs {
+        #[arg(value_enum)]
+        bank: SampleBank,
+
+        #[arg(value_enum)]
+        button: SampleButton,
+
+        #[arg(value_enum)]
+        outputs: Vec<OutputChannels>,
+    },
+
+    /// Assign a WAV file to a button, no file clears it
+    File {
+        #[arg(value_enum)]
+        bank: SampleBank,
+
+        #[arg(value_enum)]
+        button: SampleButton,
+
+        file: Option<PathBuf>,
+    },
+
+    /// Set the WAV files played after a button's sample, no files clears it
+    Playlist {
+        #[arg(value_enum)]
+        bank: SampleBank,
+
+        #[arg(value_enum)]
+        button: SampleButton,
+
+        files: Vec<PathBuf>,
+    },
+
+    /// Have the daemon play a button's sample, followed by its playlist
+    Play {
+        #[arg(value_enum)]
+        bank: SampleBank,
+
+        #[arg(value_enum)]
+        button: SampleButton,
+    },
+
+    /// Stop the sample the daemon is playing, and clear the queue
+    Stop,
+
+    /// Add a WAV file to the end of the queue which is playing
+    Enqueue { file: PathBuf },
+
+    /// Remove a file from the queue, by its position
+    Dequeue { index: usize },
+
+    /// Move on to the next sample in the queue
+    Skip,
+
+    /// Tell the daemon a sample has started playing
+    Playing {
+        #[arg(value_enum)]
+        bank: SampleBank,
+
+        #[arg(value_enum)]
+        button: SampleButton,
+    },
+
+    /// Tell the daemon the sample has stopped playing
+    Stopped,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ScribbleCommands {
+    /// Show a Widget in place of the channel name, showing a Timer restarts it
+    Show {
+        #[arg(value_enum)]
+        fader: Fader,
+
+        #[arg(value_enum)]
+        widget: ScribbleWidget,
+    },
+
+    /// Remove the Widget, restoring the channel name
+    Clear {
+        #[arg(value_enum)]
+        fader: Fader,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SoloCommands {
+    /// Solo an Input in the Headphones, or release the Solo if no Input is given
+    Set {
+        #[arg(value_enum)]
+        input: Option<InputChannels>,
+    },
+
+    /// Set the button which toggles Solo, or none to not use a button
+    Button {
+        #[arg(value_enum)]
+        button: Option<Buttons>,
+    },
+
+    /// Set the Input which the Solo button toggles
+    Input {
+        #[arg(value_enum)]
+        input: InputChannels,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MonitorMixCommands {
+    Enabled {
+        enabled: bool,
+    },
+
+    /// Set the Headphone level for an Input, from 0 to 32 (full volume)
+    Level {
+        #[arg(value_enum)]
+        input: InputChannels,
+        level: u8,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PushToTalkCommands {
+    Enabled {
+        enabled: bool,
+    },
+
+    /// Set the button to hold for Push to Talk, or none to only use a hotkey
+    Button {
+        #[arg(value_enum)]
+        button: Option<Buttons>,
+    },
+
+    /// Set the Outputs which only receive the Microphone while talking
+    Outputs {
+        #[arg(value_enum)]
+        outputs: Vec<OutputChannels>,
+    },
+
+    /// How long (in ms) to keep the Microphone open after release
+    ReleaseTail {
+        milliseconds: u64,
+    },
+
+    /// Press or Release Push to Talk (for use by hotkeys)
+    Pressed {
+        pressed: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DuckingCommands {
+    Enabled {
+        enabled: bool,
+    },
+
+    /// Enable or Disable an Input as a trigger for Ducking
+    InputSource {
+        #[arg(value_enum)]
+        input: DuckingInput,
+        enabled: bool,
+    },
+
+    /// Set where an Input's level is measured from, by OS capture device name. The Chat Mic and
+    /// Line In need one of these before they'll trigger.
+    InputCaptureDevice {
+        #[arg(value_enum)]
+        input: DuckingInput,
+        name: String,
+    },
+
+    /// Set whether a route is lowered while ducked
+    OutputRoute {
+        #[arg(value_enum)]
+        input: InputChannels,
+        #[arg(value_enum)]
+        output: OutputChannels,
+        enabled: bool,
+    },
+
+    /// Set whether a route is lowered while ducked for every Input in a category
+    CategoryRoute {
+        #[arg(value_enum)]
+        category: InputCategory,
+        #[arg(value_enum)]
+        output: OutputChannels,
+        enabled: bool,
+    },
+
+    /// How long (in ms) a trigger needs to be active before ducking
+    AttackTime {
+        milliseconds: u64,
+    },
+
+    /// How long (in ms) all triggers need to be inactive before unducking
+    ReleaseTime {
+        milliseconds: u64,
+    },
+
+    /// Set the level (in dB) an Input other than the Microphone needs to reach to trigger
+    TriggerLevel {
+        #[arg(allow_negative_numbers = true)]
+        level: f64,
+    },
+
+    /// Set the level (in dB) a single Input needs to reach to trigger, no level uses the above
+    InputTriggerLevel {
+        #[arg(value_enum)]
+        input: DuckingInput,
+        #[arg(allow_negative_numbers = true)]
+        level: Option<f64>,
+    },
+
+    /// Move the Microphone's trigger away from its gate threshold (-24 to 24 dB)
+    MicTriggerOffset {
+        #[arg(allow_negative_numbers = true)]
+        offset: i8,
+    },
+
+    /// Set the trigger levels, attack and release times from a preset
+    Sensitivity {
+        #[arg(value_enum)]
+        sensitivity: DuckingSensitivity,
+    },
+
+    /// Set the button which steps through the sensitivity presets, no button disables it
+    SensitivityButton {
+        #[arg(value_enum)]
+        button: Option<Buttons>,
+    },
+
+    /// Scale the duck depth by how loud the Music is
+    AdaptiveEnabled {
+        enabled: bool,
+    },
+
+    /// Set where the Music's level is measured from, by OS capture device name
+    MusicCaptureDevice {
+        name: String,
+    },
+
+    /// Set the Music levels (in dB) considered quiet and loud
+    AdaptiveLevels {
+        #[arg(allow_negative_numbers = true)]
+        quiet: f64,
+        #[arg(allow_negative_numbers = true)]
+        loud: f64,
+    },
+
+    /// Set the route volumes (0 - 32) ducked to when the Music is quiet, and when it's loud
+    AdaptiveVolumes {
+        quiet: u8,
+        loud: u8,
+    },
+
+    /// Tell the Ducker whether a sample is currently playing
+    SamplePlaying {
+        playing: bool,
+    },
+
+    /// Set the button which fades the ducked routes down while held, no button disables it
+    FadeButton {
+        #[arg(value_enum)]
+        button: Option<Buttons>,
+    },
+
+    /// Unduck straight away when the Microphone is muted, rather than waiting for the release
+    FastUnduckOnMute {
+        enabled: bool,
+    },
+
+    /// Display the Ducking activity since the statistics were last reset
+    Stats,
+
+    /// Reset the Ducking activity statistics
+    ResetStats,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MixModeCommands {
+    /// Save the current volumes, routing, ducking and lighting as a Mix Mode
+    Save { name: String },
+
+    /// Activate a Mix Mode
+    Activate { name: String },
+
+    /// Delete a Mix Mode
+    Delete { name: String },
+
+    /// Bind a Mix Mode to an Effect Select button, or clear the binding if none provided
+    SetButton {
+        name: String,
+
+        #[arg(value_enum)]
+        button: Option<Buttons>,
+    },
+
+    /// Set how long routing changes are cross-faded over when switching modes (0 to disable)
+    SetCrossfade { milliseconds: u16 },
 }
 
 #[derive(Debug, Subcommand)]
@@ -67,16 +525,81 @@ pub enum ChannelCommands {
         #[command(subcommand)]
         command: SubMixCommands,
     },
+
+    /// Trim an Input before it's routed, from 0 to 32 (untouched)
+    Trim {
+        #[arg(value_enum)]
+        channel: InputChannels,
+
+        trim: u8,
+    },
+
+    /// Set how the left and right sides of an Input are sent to its outputs
+    Stereo {
+        #[arg(value_enum)]
+        channel: InputChannels,
+
+        #[arg(value_enum)]
+        mode: StereoMode,
+    },
+
+    /// Move an Input into a category, or out of its category if none is given
+    Category {
+        #[arg(value_enum)]
+        channel: InputChannels,
+
+        #[arg(value_enum)]
+        category: Option<InputCategory>,
+    },
+
+    /// Set the highest the Headphones volume can be set (0 - 255), or remove the limit
+    HeadphoneLimit { limit: Option<u8> },
+
+    /// Temporarily lift the Headphone limit, or restore it
+    HeadphoneLimitOverride { overridden: bool },
+
+    /// Set the loudest any route to an Output can be, from 0 to 32 (no ceiling)
+    OutputCeiling {
+        #[arg(value_enum)]
+        output: OutputChannels,
+
+        ceiling: u8,
+    },
+
+    /// Set where the Cough button mutes to, Chat or the Stream only, or its configured actions
+    CoughTarget {
+        #[arg(value_enum)]
+        target: CoughTarget,
+    },
 }
 
 #[derive(Debug, Subcommand)]
 pub enum VolumeCommands {
-    Volume { volume: u8 },
+    Volume {
+        volume: u8,
+    },
+
+    /// Set the volume as a fraction of full volume, from 0.0 to 1.0
+    PreciseVolume {
+        volume: f32,
+    },
 }
 
 #[derive(Debug, Subcommand)]
 pub enum FaderCommands {
-    Mute { state: MuteState },
+    Mute {
+        state: MuteState,
+    },
+
+    /// Set the display name of the channel, or reset it to default if none provided
+    Name {
+        name: Option<String>,
+    },
+
+    /// How long (in ms) to fade the volume when muting or unmuting to all, 0 to disable
+    MuteFade {
+        fade_ms: u16,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -96,6 +619,32 @@ pub enum PageCommands {
         fader: Fader,
         channel: FaderChannels,
     },
+
+    /// Sets how a Fader's position is turned into a volume
+    FaderCurve {
+        fader: Fader,
+
+        #[command(subcommand)]
+        curve: FaderCurveCommands,
+    },
+
+    /// Sets the Dead Zones (0 - 255) at the bottom and top of a Fader
+    FaderDeadZones {
+        fader: Fader,
+        bottom: u8,
+        top: u8,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum FaderCurveCommands {
+    Linear,
+    Logarithmic,
+
+    /// Interpolates between points, given as position:volume (0 - 255) in order of position
+    Custom {
+        points: Vec<String>,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -119,6 +668,12 @@ pub enum MicrophoneCommands {
         #[command(subcommand)]
         command: MicrophoneGateCommands,
     },
+
+    /// Apply a bundled starting point over the current Mic settings
+    Preset {
+        #[arg(value_enum)]
+        preset: MicPreset,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -217,3 +772,143 @@ pub enum MicrophoneEqFullCommands {
         gain: i8,
     },
 }
+
+#[derive(Debug, Subcommand)]
+pub enum EffectsCommands {
+    Reverb {
+        #[command(subcommand)]
+        command: ReverbCommands,
+    },
+
+    Echo {
+        #[command(subcommand)]
+        command: EchoCommands,
+    },
+
+    Pitch {
+        #[command(subcommand)]
+        command: PitchCommands,
+    },
+
+    Gender {
+        #[command(subcommand)]
+        command: GenderCommands,
+    },
+
+    HardTune {
+        #[command(subcommand)]
+        command: HardTuneCommands,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ReverbCommands {
+    Style {
+        #[arg(value_enum)]
+        style: ReverbStyle,
+    },
+    Amount {
+        amount: u8,
+    },
+    Decay {
+        decay: u16,
+    },
+    EarlyLevel {
+        #[arg(allow_negative_numbers = true)]
+        level: i8,
+    },
+    PreDelay {
+        delay: u8,
+    },
+    LowColour {
+        #[arg(allow_negative_numbers = true)]
+        colour: i8,
+    },
+    HighColour {
+        #[arg(allow_negative_numbers = true)]
+        colour: i8,
+    },
+    HighFactor {
+        #[arg(allow_negative_numbers = true)]
+        factor: i8,
+    },
+    Diffuse {
+        #[arg(allow_negative_numbers = true)]
+        diffuse: i8,
+    },
+    ModSpeed {
+        #[arg(allow_negative_numbers = true)]
+        speed: i8,
+    },
+    ModDepth {
+        #[arg(allow_negative_numbers = true)]
+        depth: i8,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum EchoCommands {
+    Style {
+        #[arg(value_enum)]
+        style: EchoStyle,
+    },
+    Amount {
+        amount: u8,
+    },
+    Feedback {
+        feedback: u8,
+    },
+    Tempo {
+        tempo: u16,
+    },
+    DelayLeft {
+        delay: u16,
+    },
+    DelayRight {
+        delay: u16,
+    },
+    FeedbackLeft {
+        feedback: u8,
+    },
+    FeedbackRight {
+        feedback: u8,
+    },
+    FeedbackXfbLToR {
+        feedback: u8,
+    },
+    FeedbackXfbRToL {
+        feedback: u8,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PitchCommands {
+    Style {
+        #[arg(value_enum)]
+        style: PitchStyle,
+    },
+    Amount {
+        #[arg(allow_negative_numbers = true)]
+        amount: i8,
+    },
+    Character {
+        character: u8,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum GenderCommands {
+    Style {
+        #[arg(value_enum)]
+        style: GenderStyle,
+    },
+    Amount {
+        #[arg(allow_negative_numbers = true)]
+        amount: i8,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum HardTuneCommands {
+    Enabled { enabled: bool },
+}
@@ -4,12 +4,27 @@ use clap::Parser;
 use goxlr_ipc::client::Client;
 use goxlr_ipc::clients::ipc::ipc_client::IPCClient;
 
-use crate::cli::{Cli, SubCommands};
+use crate::cli::{Cli, OutputFormat, SubCommands};
+use crate::output::print_output;
+use crate::processors::automation::handle_automation;
 use crate::processors::channel::handle_channels;
+use crate::processors::controls::handle_controls;
+use crate::processors::ducking::handle_ducking;
+use crate::processors::effects::handle_effects;
+use crate::processors::import::handle_import;
 use crate::processors::microphone::handle_microphone;
+use crate::processors::mix_modes::handle_mix_modes;
+use crate::processors::monitor_mix::handle_monitor_mix;
 use crate::processors::pages::handle_pages;
+use crate::processors::push_to_talk::handle_push_to_talk;
+use crate::processors::sampler::handle_sampler;
+use crate::processors::scribbles::handle_scribbles;
+use crate::processors::solo::handle_solo;
+use crate::processors::state::handle_state;
+use crate::processors::status::handle_status;
 
 mod cli;
+mod output;
 mod processors;
 
 #[tokio::main]
@@ -17,11 +32,12 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     let mut client: Box<dyn Client>;
-    client = Box::new(IPCClient::connect().await?);
+    let (socket_path, pipe_name) = (&cli.ipc_socket_path, &cli.ipc_pipe_name);
+    client = Box::new(IPCClient::connect_to(socket_path, pipe_name).await?);
     client.poll_status().await?;
 
     if cli.status_json {
-        println!("{:#?}", client.status());
+        print_output(OutputFormat::Json, client.status())?;
     }
 
     let serial;
@@ -53,6 +69,45 @@ async fn main() -> Result<()> {
             SubCommands::Pages { command } => {
                 handle_pages(serial, client, command).await?;
             }
+            SubCommands::Effects { command } => {
+                handle_effects(serial, client, command).await?;
+            }
+            SubCommands::MixModes { command } => {
+                handle_mix_modes(serial, client, command).await?;
+            }
+            SubCommands::Status => {
+                handle_status(serial, client, cli.format).await?;
+            }
+            SubCommands::Ducking { command } => {
+                handle_ducking(serial, client, command, cli.format).await?;
+            }
+            SubCommands::PushToTalk { command } => {
+                handle_push_to_talk(serial, client, command).await?;
+            }
+            SubCommands::MonitorMix { command } => {
+                handle_monitor_mix(serial, client, command).await?;
+            }
+            SubCommands::Solo { command } => {
+                handle_solo(serial, client, command).await?;
+            }
+            SubCommands::Sampler { command } => {
+                handle_sampler(serial, client, command).await?;
+            }
+            SubCommands::Scribbles { command } => {
+                handle_scribbles(serial, client, command).await?;
+            }
+            SubCommands::State { command } => {
+                handle_state(serial, client, command, cli.format).await?;
+            }
+            SubCommands::Controls { command } => {
+                handle_controls(serial, client, command, cli.format).await?;
+            }
+            SubCommands::Automation { command } => {
+                handle_automation(serial, client, command, cli.format).await?;
+            }
+            SubCommands::Import { url, apply } => {
+                handle_import(serial, client, url, apply, cli.format).await?;
+            }
         }
     }
 
@@ -0,0 +1,16 @@
+use std::fmt::Debug;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
+
+/// Writes a result to stdout. In JSON the field names come straight from the IPC types, so are
+/// stable enough for scripts to build on.
+pub fn print_output<T: Serialize + Debug>(format: OutputFormat, value: &T) -> Result<()> {
+    match format {
+        OutputFormat::Text => println!("{:#?}", value),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(value)?),
+    }
+    Ok(())
+}
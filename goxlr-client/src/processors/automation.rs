@@ -0,0 +1,35 @@
+use anyhow::{bail, Result};
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::automation::AutomationCommand;
+use goxlr_ipc::commands::{
+    DaemonRequest, DaemonResponse, DeviceCommand, GoXLRCommand, GoXLRCommandResponse,
+};
+
+use crate::cli::{AutomationCommands, OutputFormat};
+use crate::output::print_output;
+
+pub async fn handle_automation(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: AutomationCommands,
+    format: OutputFormat,
+) -> Result<()> {
+    let command = match command {
+        AutomationCommands::Record => AutomationCommand::StartRecording,
+        AutomationCommands::Save { name } => AutomationCommand::StopRecording(name),
+        AutomationCommands::Play { name } => AutomationCommand::Play(name),
+        AutomationCommands::Stop => AutomationCommand::StopPlayback,
+        AutomationCommands::List => AutomationCommand::List,
+    };
+
+    let command = GoXLRCommand::Automation(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    match client.request(command).await? {
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::Automations(names)) => {
+            print_output(format, &names)
+        }
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::Ok) => Ok(()),
+        response => bail!("Unexpected Response: {:?}", response),
+    }
+}
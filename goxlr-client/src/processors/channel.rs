@@ -2,7 +2,10 @@ use anyhow::Result;
 
 use goxlr_ipc::client::Client;
 use goxlr_ipc::commands::channels::ChannelCommands as IPCChannelCommand;
-use goxlr_ipc::commands::channels::{ChannelVolume, MuteCommand};
+use goxlr_ipc::commands::channels::{
+    ChannelCategory, ChannelMuteFade, ChannelName, ChannelOutputCeiling, ChannelPreciseVolume,
+    ChannelStereo, ChannelTrim, ChannelVolume, MuteCommand,
+};
 use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
 
 use crate::cli::{ChannelCommands, FaderCommands, VolumeCommands};
@@ -20,6 +23,14 @@ pub async fn handle_channels(
                 let command = GoXLRCommand::Channels(command);
                 let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
 
+                client.send(command).await?;
+            }
+            VolumeCommands::PreciseVolume { volume } => {
+                let command = ChannelPreciseVolume { channel, volume };
+                let command = IPCChannelCommand::PreciseVolume(command);
+                let command = GoXLRCommand::Channels(command);
+                let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
                 client.send(command).await?;
             }
         },
@@ -30,10 +41,79 @@ pub async fn handle_channels(
                 let command = GoXLRCommand::Channels(command);
                 let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
 
+                client.send(command).await?;
+            }
+            FaderCommands::Name { name } => {
+                let command = ChannelName { channel, name };
+                let command = IPCChannelCommand::Name(command);
+                let command = GoXLRCommand::Channels(command);
+                let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+                client.send(command).await?;
+            }
+            FaderCommands::MuteFade { fade_ms } => {
+                let command = ChannelMuteFade { channel, fade_ms };
+                let command = IPCChannelCommand::MuteFade(command);
+                let command = GoXLRCommand::Channels(command);
+                let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
                 client.send(command).await?;
             }
         },
         ChannelCommands::SubMix { .. } => {}
+        ChannelCommands::Trim { channel, trim } => {
+            let command = ChannelTrim { channel, trim };
+            let command = IPCChannelCommand::Trim(command);
+            let command = GoXLRCommand::Channels(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+            client.send(command).await?;
+        }
+        ChannelCommands::Stereo { channel, mode } => {
+            let command = ChannelStereo { channel, mode };
+            let command = IPCChannelCommand::Stereo(command);
+            let command = GoXLRCommand::Channels(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+            client.send(command).await?;
+        }
+        ChannelCommands::Category { channel, category } => {
+            let command = ChannelCategory { channel, category };
+            let command = IPCChannelCommand::Category(command);
+            let command = GoXLRCommand::Channels(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+            client.send(command).await?;
+        }
+        ChannelCommands::HeadphoneLimit { limit } => {
+            let command = IPCChannelCommand::HeadphoneLimit(limit);
+            let command = GoXLRCommand::Channels(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+            client.send(command).await?;
+        }
+        ChannelCommands::HeadphoneLimitOverride { overridden } => {
+            let command = IPCChannelCommand::HeadphoneLimitOverride(overridden);
+            let command = GoXLRCommand::Channels(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+            client.send(command).await?;
+        }
+        ChannelCommands::OutputCeiling { output, ceiling } => {
+            let command = ChannelOutputCeiling { output, ceiling };
+            let command = IPCChannelCommand::OutputCeiling(command);
+            let command = GoXLRCommand::Channels(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+            client.send(command).await?;
+        }
+        ChannelCommands::CoughTarget { target } => {
+            let command = IPCChannelCommand::CoughTarget(target);
+            let command = GoXLRCommand::Channels(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+            client.send(command).await?;
+        }
     }
 
     Ok(())
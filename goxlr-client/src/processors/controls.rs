@@ -0,0 +1,53 @@
+use anyhow::{bail, Result};
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::controls::{
+    Control, ControlAction, ControlAdjust, ControlCommand, ControlValue,
+};
+use goxlr_ipc::commands::{
+    DaemonRequest, DaemonResponse, DeviceCommand, GoXLRCommand, GoXLRCommandResponse,
+};
+
+use crate::cli::{ControlCommands, OutputFormat};
+use crate::output::print_output;
+
+pub async fn handle_controls(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: ControlCommands,
+    format: OutputFormat,
+) -> Result<()> {
+    let adjust = |id, action| ControlCommand::Adjust(ControlAdjust { id, action });
+    let command = match command {
+        ControlCommands::List => ControlCommand::List,
+        ControlCommands::Up { id } => adjust(id, ControlAction::Increment),
+        ControlCommands::Down { id } => adjust(id, ControlAction::Decrement),
+        ControlCommands::Toggle { id } => adjust(id, ControlAction::Toggle),
+    };
+
+    let command = GoXLRCommand::Controls(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    match client.request(command).await? {
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::Controls(controls)) => {
+            match format {
+                OutputFormat::Text => print_controls(&controls),
+                OutputFormat::Json => print_output(format, &controls)?,
+            }
+            Ok(())
+        }
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::Ok) => Ok(()),
+        response => bail!("Unexpected Response: {:?}", response),
+    }
+}
+
+// One control per line, read in order this is easy to follow with a screen reader
+fn print_controls(controls: &[Control]) {
+    for control in controls {
+        let value = match control.value {
+            ControlValue::Range(range) => format!("{} of {}", range.value, range.max),
+            ControlValue::Toggle(true) => String::from("On"),
+            ControlValue::Toggle(false) => String::from("Off"),
+        };
+        println!("{}: {} ({})", control.label, value, control.id);
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::{bail, Result};
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::ducking::{DuckingCommand, DuckingLevelSource};
+use goxlr_ipc::commands::{
+    DaemonRequest, DaemonResponse, DeviceCommand, GoXLRCommand, GoXLRCommandResponse,
+};
+
+use crate::cli::{DuckingCommands, OutputFormat};
+use crate::output::print_output;
+
+pub async fn handle_ducking(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: DuckingCommands,
+    format: OutputFormat,
+) -> Result<()> {
+    let query = matches!(command, DuckingCommands::Stats);
+    let command = match command {
+        DuckingCommands::Enabled { enabled } => DuckingCommand::Enabled(enabled),
+        DuckingCommands::InputSource { input, enabled } => {
+            DuckingCommand::InputSource(input, enabled)
+        }
+        DuckingCommands::InputCaptureDevice { input, name } => {
+            DuckingCommand::LevelSource(input, DuckingLevelSource::CaptureDevice(name))
+        }
+        DuckingCommands::OutputRoute {
+            input,
+            output,
+            enabled,
+        } => DuckingCommand::OutputRoute(input, output, enabled),
+        DuckingCommands::CategoryRoute {
+            category,
+            output,
+            enabled,
+        } => DuckingCommand::CategoryRoute(category, output, enabled),
+        DuckingCommands::AttackTime { milliseconds } => DuckingCommand::AttackTime(milliseconds),
+        DuckingCommands::ReleaseTime { milliseconds } => DuckingCommand::ReleaseTime(milliseconds),
+        DuckingCommands::TriggerLevel { level } => DuckingCommand::TriggerLevel(level),
+        DuckingCommands::InputTriggerLevel { input, level } => {
+            DuckingCommand::InputTriggerLevel(input, level)
+        }
+        DuckingCommands::MicTriggerOffset { offset } => DuckingCommand::MicTriggerOffset(offset),
+        DuckingCommands::Sensitivity { sensitivity } => DuckingCommand::Sensitivity(sensitivity),
+        DuckingCommands::SensitivityButton { button } => DuckingCommand::SensitivityButton(button),
+        DuckingCommands::AdaptiveEnabled { enabled } => DuckingCommand::AdaptiveEnabled(enabled),
+        DuckingCommands::MusicCaptureDevice { name } => {
+            DuckingCommand::MusicLevelSource(DuckingLevelSource::CaptureDevice(name))
+        }
+        DuckingCommands::AdaptiveLevels { quiet, loud } => {
+            DuckingCommand::AdaptiveLevels(quiet, loud)
+        }
+        DuckingCommands::AdaptiveVolumes { quiet, loud } => {
+            DuckingCommand::AdaptiveVolumes(quiet, loud)
+        }
+        DuckingCommands::SamplePlaying { playing } => DuckingCommand::SamplePlaying(playing),
+        DuckingCommands::FadeButton { button } => DuckingCommand::FadeButton(button),
+        DuckingCommands::FastUnduckOnMute { enabled } => DuckingCommand::FastUnduckOnMute(enabled),
+        DuckingCommands::Stats => DuckingCommand::GetStats,
+        DuckingCommands::ResetStats => DuckingCommand::ResetStats,
+    };
+
+    let command = GoXLRCommand::Ducking(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    if !query {
+        return client.send(command).await;
+    }
+
+    match client.request(command).await? {
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::DuckingStats(report)) => {
+            print_output(format, &report)
+        }
+        response => bail!("Unexpected Response: {:?}", response),
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::Result;
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::effects::echo::EchoCommand;
+use goxlr_ipc::commands::effects::gender::GenderCommand;
+use goxlr_ipc::commands::effects::hard_tune::HardTuneCommand;
+use goxlr_ipc::commands::effects::pitch::PitchCommand;
+use goxlr_ipc::commands::effects::reverb::ReverbCommand;
+use goxlr_ipc::commands::effects::EffectsCommand;
+use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
+
+use crate::cli::{
+    EchoCommands, EffectsCommands, GenderCommands, HardTuneCommands, PitchCommands, ReverbCommands,
+};
+
+pub async fn handle_effects(
+    serial: String,
+    client: Box<dyn Client>,
+    command: EffectsCommands,
+) -> Result<()> {
+    match command {
+        EffectsCommands::Reverb { command } => {
+            handle_reverb_command(serial, client, command).await?;
+        }
+        EffectsCommands::Echo { command } => {
+            handle_echo_command(serial, client, command).await?;
+        }
+        EffectsCommands::Pitch { command } => {
+            handle_pitch_command(serial, client, command).await?;
+        }
+        EffectsCommands::Gender { command } => {
+            handle_gender_command(serial, client, command).await?;
+        }
+        EffectsCommands::HardTune { command } => {
+            handle_hard_tune_command(serial, client, command).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_reverb_command(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: ReverbCommands,
+) -> Result<()> {
+    let command = match command {
+        ReverbCommands::Style { style } => ReverbCommand::SetStyle(style),
+        ReverbCommands::Amount { amount } => ReverbCommand::SetAmount(amount),
+        ReverbCommands::Decay { decay } => ReverbCommand::SetDecay(decay),
+        ReverbCommands::EarlyLevel { level } => ReverbCommand::SetEarlyLevel(level),
+        ReverbCommands::PreDelay { delay } => ReverbCommand::SetPreDelay(delay),
+        ReverbCommands::LowColour { colour } => ReverbCommand::SetLowColour(colour),
+        ReverbCommands::HighColour { colour } => ReverbCommand::SetHighColour(colour),
+        ReverbCommands::HighFactor { factor } => ReverbCommand::SetHighFactor(factor),
+        ReverbCommands::Diffuse { diffuse } => ReverbCommand::SetDiffuse(diffuse),
+        ReverbCommands::ModSpeed { speed } => ReverbCommand::SetModSpeed(speed),
+        ReverbCommands::ModDepth { depth } => ReverbCommand::SetModDepth(depth),
+    };
+
+    let command = EffectsCommand::Reverb(command);
+    let command = GoXLRCommand::Effects(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+    client.send(command).await?;
+    Ok(())
+}
+
+pub async fn handle_echo_command(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: EchoCommands,
+) -> Result<()> {
+    let command = match command {
+        EchoCommands::Style { style } => EchoCommand::SetStyle(style),
+        EchoCommands::Amount { amount } => EchoCommand::SetAmount(amount),
+        EchoCommands::Feedback { feedback } => EchoCommand::SetFeedback(feedback),
+        EchoCommands::Tempo { tempo } => EchoCommand::SetTempo(tempo),
+        EchoCommands::DelayLeft { delay } => EchoCommand::SetDelayLeft(delay),
+        EchoCommands::DelayRight { delay } => EchoCommand::SetDelayRight(delay),
+        EchoCommands::FeedbackLeft { feedback } => EchoCommand::SetFeedbackLeft(feedback),
+        EchoCommands::FeedbackRight { feedback } => EchoCommand::SetFeedbackRight(feedback),
+        EchoCommands::FeedbackXfbLToR { feedback } => EchoCommand::SetFeedbackXFBLtoR(feedback),
+        EchoCommands::FeedbackXfbRToL { feedback } => EchoCommand::SetFeedbackXFBRtoL(feedback),
+    };
+
+    let command = EffectsCommand::Echo(command);
+    let command = GoXLRCommand::Effects(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+    client.send(command).await?;
+    Ok(())
+}
+
+pub async fn handle_pitch_command(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: PitchCommands,
+) -> Result<()> {
+    let command = match command {
+        PitchCommands::Style { style } => PitchCommand::SetStyle(style),
+        PitchCommands::Amount { amount } => PitchCommand::SetAmount(amount),
+        PitchCommands::Character { character } => PitchCommand::SetCharacter(character),
+    };
+
+    let command = EffectsCommand::Pitch(command);
+    let command = GoXLRCommand::Effects(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+    client.send(command).await?;
+    Ok(())
+}
+
+pub async fn handle_gender_command(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: GenderCommands,
+) -> Result<()> {
+    let command = match command {
+        GenderCommands::Style { style } => GenderCommand::SetStyle(style),
+        GenderCommands::Amount { amount } => GenderCommand::SetAmount(amount),
+    };
+
+    let command = EffectsCommand::Gender(command);
+    let command = GoXLRCommand::Effects(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+    client.send(command).await?;
+    Ok(())
+}
+
+pub async fn handle_hard_tune_command(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: HardTuneCommands,
+) -> Result<()> {
+    let command = match command {
+        HardTuneCommands::Enabled { enabled } => HardTuneCommand::SetEnabled(enabled),
+    };
+
+    let command = EffectsCommand::HardTune(command);
+    let command = GoXLRCommand::Effects(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+    client.send(command).await?;
+    Ok(())
+}
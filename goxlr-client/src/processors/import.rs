@@ -0,0 +1,42 @@
+use anyhow::{bail, Result};
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::{
+    DaemonCommand, DaemonRequest, DaemonResponse, GoXLRCommandResponse, ProfileImport,
+};
+
+use crate::cli::OutputFormat;
+use crate::output::print_output;
+
+pub async fn handle_import(
+    serial: String,
+    mut client: Box<dyn Client>,
+    url: String,
+    apply: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let request = DaemonRequest::Daemon(DaemonCommand::PreviewProfileImport(url.clone()));
+    let preview = match client.request(request).await? {
+        DaemonResponse::ProfileImport(preview) => preview,
+        response => bail!("Unexpected Response: {:?}", response),
+    };
+    print_output(format, &preview)?;
+
+    if !apply {
+        return Ok(());
+    }
+
+    // The digest makes sure what's loaded is what was just shown
+    let import = ProfileImport {
+        serial,
+        url,
+        digest: Some(preview.digest),
+    };
+    let request = DaemonRequest::Daemon(DaemonCommand::ImportProfile(import));
+    match client.request(request).await? {
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::ProfileLoaded(report)) => {
+            print_output(format, &report)
+        }
+        response => bail!("Unexpected Response: {:?}", response),
+    }
+}
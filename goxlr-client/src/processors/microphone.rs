@@ -19,7 +19,7 @@ use crate::cli::{
 
 pub async fn handle_microphone(
     serial: String,
-    client: Box<dyn Client>,
+    mut client: Box<dyn Client>,
     command: MicrophoneCommands,
 ) -> Result<()> {
     match command {
@@ -35,6 +35,13 @@ pub async fn handle_microphone(
         MicrophoneCommands::Equaliser { command } => {
             handle_mic_eq_command(serial, client, command).await?;
         }
+        MicrophoneCommands::Preset { preset } => {
+            let command = MicrophoneCommand::ApplyPreset(preset);
+            let command = GoXLRCommand::Microphone(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+            client.send(command).await?;
+        }
     }
     Ok(())
 }
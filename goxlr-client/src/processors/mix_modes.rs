@@ -0,0 +1,27 @@
+use anyhow::Result;
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::mix_modes::MixModeCommand;
+use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
+
+use crate::cli::MixModeCommands;
+
+pub async fn handle_mix_modes(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: MixModeCommands,
+) -> Result<()> {
+    let command = match command {
+        MixModeCommands::Save { name } => MixModeCommand::Save(name),
+        MixModeCommands::Activate { name } => MixModeCommand::Activate(name),
+        MixModeCommands::Delete { name } => MixModeCommand::Delete(name),
+        MixModeCommands::SetButton { name, button } => MixModeCommand::SetButton(name, button),
+        MixModeCommands::SetCrossfade { milliseconds } => {
+            MixModeCommand::SetCrossfade(milliseconds)
+        }
+    };
+
+    let command = GoXLRCommand::MixModes(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    client.send(command).await
+}
@@ -1,3 +1,16 @@
+pub(crate) mod automation;
 pub(crate) mod channel;
+pub(crate) mod controls;
+pub(crate) mod ducking;
+pub(crate) mod effects;
+pub(crate) mod import;
 pub(crate) mod microphone;
+pub(crate) mod mix_modes;
+pub(crate) mod monitor_mix;
 pub(crate) mod pages;
+pub(crate) mod push_to_talk;
+pub(crate) mod sampler;
+pub(crate) mod scribbles;
+pub(crate) mod solo;
+pub(crate) mod state;
+pub(crate) mod status;
@@ -0,0 +1,22 @@
+use anyhow::Result;
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::monitor_mix::MonitorMixCommand;
+use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
+
+use crate::cli::MonitorMixCommands;
+
+pub async fn handle_monitor_mix(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: MonitorMixCommands,
+) -> Result<()> {
+    let command = match command {
+        MonitorMixCommands::Enabled { enabled } => MonitorMixCommand::Enabled(enabled),
+        MonitorMixCommands::Level { input, level } => MonitorMixCommand::Level(input, level),
+    };
+
+    let command = GoXLRCommand::MonitorMix(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    client.send(command).await
+}
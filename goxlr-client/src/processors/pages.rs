@@ -1,8 +1,9 @@
-use crate::cli::PageCommands;
-use anyhow::Result;
+use crate::cli::{FaderCurveCommands, PageCommands};
+use anyhow::{Context, Result};
 use goxlr_ipc::client::Client;
 use goxlr_ipc::commands::pages::{PageCommand, SetFader};
 use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
+use goxlr_profile::FaderCurve;
 
 pub async fn handle_pages(
     serial: String,
@@ -42,7 +43,34 @@ pub async fn handle_pages(
             let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
             client.send(command).await?;
         }
+        PageCommands::FaderCurve { fader, curve } => {
+            let curve = match curve {
+                FaderCurveCommands::Linear => FaderCurve::Linear,
+                FaderCurveCommands::Logarithmic => FaderCurve::Logarithmic,
+                FaderCurveCommands::Custom { points } => {
+                    let points = points.iter().map(|point| parse_point(point));
+                    FaderCurve::Custom(points.collect::<Result<_>>()?)
+                }
+            };
+            let command = PageCommand::FaderCurve(fader, curve);
+            let command = GoXLRCommand::Pages(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+            client.send(command).await?;
+        }
+        PageCommands::FaderDeadZones { fader, bottom, top } => {
+            let command = PageCommand::FaderDeadZones(fader, bottom, top);
+            let command = GoXLRCommand::Pages(command);
+            let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+            client.send(command).await?;
+        }
     }
 
     Ok(())
 }
+
+fn parse_point(point: &str) -> Result<(u8, u8)> {
+    let (position, volume) = point
+        .split_once(':')
+        .with_context(|| format!("Expected position:volume, got {}", point))?;
+    Ok((position.trim().parse()?, volume.trim().parse()?))
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::push_to_talk::PushToTalkCommand;
+use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
+
+use crate::cli::PushToTalkCommands;
+
+pub async fn handle_push_to_talk(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: PushToTalkCommands,
+) -> Result<()> {
+    let command = match command {
+        PushToTalkCommands::Enabled { enabled } => PushToTalkCommand::Enabled(enabled),
+        PushToTalkCommands::Button { button } => PushToTalkCommand::Button(button),
+        PushToTalkCommands::Outputs { outputs } => PushToTalkCommand::Outputs(outputs),
+        PushToTalkCommands::ReleaseTail { milliseconds } => {
+            PushToTalkCommand::ReleaseTail(milliseconds)
+        }
+        PushToTalkCommands::Pressed { pressed } => PushToTalkCommand::Pressed(pressed),
+    };
+
+    let command = GoXLRCommand::PushToTalk(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    client.send(command).await
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::sampler::SamplerCommand;
+use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
+use goxlr_shared::channels::output::OutputChannels;
+
+use crate::cli::SamplerCommands;
+
+pub async fn handle_sampler(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: SamplerCommands,
+) -> Result<()> {
+    let command = match command {
+        SamplerCommands::BankOutputs { bank, outputs } => {
+            SamplerCommand::BankOutputs(bank, get_outputs(outputs))
+        }
+        SamplerCommands::ButtonOutputs {
+            bank,
+            button,
+            outputs,
+        } => SamplerCommand::ButtonOutputs(bank, button, get_outputs(outputs)),
+        SamplerCommands::File { bank, button, file } => SamplerCommand::File(bank, button, file),
+        SamplerCommands::Playlist {
+            bank,
+            button,
+            files,
+        } => SamplerCommand::Playlist(bank, button, files),
+        SamplerCommands::Play { bank, button } => SamplerCommand::Play(bank, button),
+        SamplerCommands::Stop => SamplerCommand::Stop,
+        SamplerCommands::Enqueue { file } => SamplerCommand::Enqueue(file),
+        SamplerCommands::Dequeue { index } => SamplerCommand::Dequeue(index),
+        SamplerCommands::Skip => SamplerCommand::Skip,
+        SamplerCommands::Playing { bank, button } => SamplerCommand::Playing(Some((bank, button))),
+        SamplerCommands::Stopped => SamplerCommand::Playing(None),
+    };
+
+    let command = GoXLRCommand::Sampler(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    client.send(command).await
+}
+
+// No outputs on the command line means the setting should be cleared
+fn get_outputs(outputs: Vec<OutputChannels>) -> Option<Vec<OutputChannels>> {
+    Some(outputs).filter(|outputs| !outputs.is_empty())
+}
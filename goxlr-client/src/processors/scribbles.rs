@@ -0,0 +1,22 @@
+use anyhow::Result;
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::scribbles::ScribbleCommand;
+use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
+
+use crate::cli::ScribbleCommands;
+
+pub async fn handle_scribbles(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: ScribbleCommands,
+) -> Result<()> {
+    let command = match command {
+        ScribbleCommands::Show { fader, widget } => ScribbleCommand::ShowWidget(fader, widget),
+        ScribbleCommands::Clear { fader } => ScribbleCommand::ClearWidget(fader),
+    };
+
+    let command = GoXLRCommand::Scribbles(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    client.send(command).await
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::solo::SoloCommand;
+use goxlr_ipc::commands::{DaemonRequest, DeviceCommand, GoXLRCommand};
+
+use crate::cli::SoloCommands;
+
+pub async fn handle_solo(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: SoloCommands,
+) -> Result<()> {
+    let command = match command {
+        SoloCommands::Set { input } => SoloCommand::Set(input),
+        SoloCommands::Button { button } => SoloCommand::Button(button),
+        SoloCommands::Input { input } => SoloCommand::Input(input),
+    };
+
+    let command = GoXLRCommand::Solo(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+    client.send(command).await
+}
@@ -0,0 +1,42 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use goxlr_ipc::client::Client;
+use goxlr_ipc::commands::configuration::{ConfigurationCommand, DesiredState};
+use goxlr_ipc::commands::{
+    DaemonRequest, DaemonResponse, DeviceCommand, GoXLRCommand, GoXLRCommandResponse,
+};
+
+use crate::cli::{OutputFormat, StateCommands};
+use crate::output::print_output;
+
+pub async fn handle_state(
+    serial: String,
+    mut client: Box<dyn Client>,
+    command: StateCommands,
+    format: OutputFormat,
+) -> Result<()> {
+    let command = match command {
+        StateCommands::Apply { file } => ConfigurationCommand::ApplyState(load_state(&file)?),
+        StateCommands::Diff { file } => ConfigurationCommand::DiffState(load_state(&file)?),
+    };
+
+    let command = GoXLRCommand::Configuration(command);
+    let command = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+    // Both Apply and Diff respond with the differences, either what changed, or what would
+    match client.request(command).await? {
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::StateDiff(differences)) => {
+            print_output(format, &differences)
+        }
+        response => bail!("Unexpected Response: {:?}", response),
+    }
+}
+
+fn load_state(file: &Path) -> Result<DesiredState> {
+    let content = fs::read_to_string(file)
+        .with_context(|| format!("Unable to read Desired State: {}", file.display()))?;
+    toml::from_str(&content).context("Unable to parse Desired State")
+}
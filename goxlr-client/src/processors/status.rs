@@ -0,0 +1,16 @@
+use anyhow::{Context, Result};
+
+use goxlr_ipc::client::Client;
+
+use crate::cli::OutputFormat;
+use crate::output::print_output;
+
+pub async fn handle_status(
+    serial: String,
+    client: Box<dyn Client>,
+    format: OutputFormat,
+) -> Result<()> {
+    let status = client.status().devices.get(&serial);
+    let status = status.with_context(|| format!("Device {} not found", serial))?;
+    print_output(format, status)
+}
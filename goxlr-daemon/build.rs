@@ -3,6 +3,9 @@ use std::path::Path;
 use std::process::Command;
 
 fn main() {
+    #[cfg(feature = "grpc")]
+    tonic_build::compile_protos("proto/goxlr.proto").expect("Failed to build the gRPC API");
+
     // This could probably use some work, but for now it does the job
     let mut command = if cfg!(target_os = "windows") {
         Command::new("cmd")
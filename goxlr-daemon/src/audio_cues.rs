@@ -0,0 +1,201 @@
+use std::f32::consts::PI;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, FromSample, SampleFormat, SizedSample, Stream, StreamConfig};
+use log::{debug, warn};
+use tokio::task;
+
+use crate::device::goxlr::components::sample_player::SampleSource;
+use crate::settings::{AudioCueSettings, CueSound};
+
+// Cues are meant to be short, anything longer is cut off rather than playing over the stream
+const MAX_LENGTH: Duration = Duration::from_secs(10);
+
+// How often the playback thread checks whether the cue has finished
+const FINISH_POLL: Duration = Duration::from_millis(50);
+
+// The built in chime's notes, and how loud they are (around -12dBFS)
+const NOTE_LENGTH: f32 = 0.12;
+const CHIME_LEVEL: f32 = 0.25;
+
+/// Events which can be heard as an audio cue
+#[derive(Debug, Copy, Clone)]
+pub enum AudioCue {
+    Ready,
+    Connected,
+    ProfileLoaded,
+    MicMuted,
+    MicUnmuted,
+}
+
+impl AudioCue {
+    fn get_sound(self, settings: &AudioCueSettings) -> Option<&CueSound> {
+        match self {
+            AudioCue::Ready => settings.ready.as_ref(),
+            AudioCue::Connected => settings.connected.as_ref(),
+            AudioCue::ProfileLoaded => settings.profile.as_ref(),
+            AudioCue::MicMuted | AudioCue::MicUnmuted => settings.mic_mute.as_ref(),
+        }
+    }
+
+    /// The frequencies of the notes played by the built in chime. Mutes fall, and unmutes rise,
+    /// so the two can be told apart without looking.
+    fn get_notes(self) -> &'static [f32] {
+        match self {
+            AudioCue::Ready => &[523.25, 659.25, 783.99],
+            AudioCue::Connected => &[659.25, 783.99],
+            AudioCue::ProfileLoaded => &[783.99, 783.99],
+            AudioCue::MicMuted => &[659.25, 440.],
+            AudioCue::MicUnmuted => &[440., 659.25],
+        }
+    }
+}
+
+/// Plays an audio cue, if it's enabled. Cues play in the background, and a cue which can't be
+/// played is only logged, as nothing should fail because a sound couldn't be made.
+pub fn play_cue(settings: &AudioCueSettings, cue: AudioCue) {
+    let Some(sound) = cue.get_sound(settings).cloned() else {
+        return;
+    };
+
+    debug!("Playing Audio Cue: {:?}", cue);
+    let device = settings.device.clone();
+    task::spawn_blocking(move || {
+        if let Err(error) = play(cue, &sound, device.as_deref()) {
+            warn!("Unable to play Audio Cue {:?}: {}", cue, error);
+        }
+    });
+}
+
+fn play(cue: AudioCue, sound: &CueSound, device: Option<&str>) -> Result<()> {
+    let finished = Arc::new(AtomicBool::new(false));
+
+    // cpal streams can't be moved between threads on all platforms, so the stream stays here
+    let stream = build_stream(cue, sound, device, finished.clone())?;
+
+    let started = Instant::now();
+    while !finished.load(Ordering::Relaxed) && started.elapsed() < MAX_LENGTH {
+        thread::sleep(FINISH_POLL);
+    }
+    drop(stream);
+    Ok(())
+}
+
+fn find_device(name: Option<&str>) -> Result<Device> {
+    let host = cpal::default_host();
+    let Some(name) = name else {
+        return host
+            .default_output_device()
+            .context("No Default Playback Device");
+    };
+
+    let mut devices = host.output_devices()?;
+    devices
+        .find(|device| device.name().map(|n| n == name).unwrap_or(false))
+        .with_context(|| format!("Unable to find Playback Device: {}", name))
+}
+
+fn build_stream(
+    cue: AudioCue,
+    sound: &CueSound,
+    device: Option<&str>,
+    finished: Arc<AtomicBool>,
+) -> Result<Stream> {
+    let device = find_device(device)?;
+    let output_config = device.default_output_config()?;
+    let config = output_config.config();
+
+    let rate = config.sample_rate.0;
+    let source = match sound {
+        CueSound::Chime => CueSource::Chime(Chime::new(cue.get_notes(), rate, finished)),
+        CueSound::File(file) => CueSource::File(SampleSource::open(file, rate, finished)?),
+    };
+
+    let stream = match output_config.sample_format() {
+        SampleFormat::F32 => build_playback::<f32>(&device, &config, source),
+        SampleFormat::I16 => build_playback::<i16>(&device, &config, source),
+        SampleFormat::I32 => build_playback::<i32>(&device, &config, source),
+        SampleFormat::U16 => build_playback::<u16>(&device, &config, source),
+        format => bail!("Unsupported Playback Sample Format: {:?}", format),
+    }?;
+    stream.play()?;
+    Ok(stream)
+}
+
+fn build_playback<T>(
+    device: &Device,
+    config: &StreamConfig,
+    mut source: CueSource,
+) -> Result<Stream>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels as usize;
+    let stream = device.build_output_stream(
+        config,
+        move |data: &mut [T], _| {
+            for frame in data.chunks_mut(channels) {
+                source.next_frame(frame);
+            }
+        },
+        |error| debug!("Audio Cue Stream Error: {}", error),
+        None,
+    )?;
+    Ok(stream)
+}
+
+enum CueSource {
+    Chime(Chime),
+    File(SampleSource),
+}
+
+impl CueSource {
+    fn next_frame<T>(&mut self, frame: &mut [T])
+    where
+        T: SizedSample + FromSample<f32>,
+    {
+        match self {
+            CueSource::Chime(chime) => frame.fill(T::from_sample(chime.next_sample())),
+            CueSource::File(file) => file.next_frame(frame),
+        }
+    }
+}
+
+/// A few short sine notes, each faded in and out so they don't click
+struct Chime {
+    notes: &'static [f32],
+    rate: f32,
+    position: usize,
+    finished: Arc<AtomicBool>,
+}
+
+impl Chime {
+    fn new(notes: &'static [f32], rate: u32, finished: Arc<AtomicBool>) -> Self {
+        Self {
+            notes,
+            rate: rate as f32,
+            position: 0,
+            finished,
+        }
+    }
+
+    fn next_sample(&mut self) -> f32 {
+        let time = self.position as f32 / self.rate;
+        let note = (time / NOTE_LENGTH) as usize;
+        let Some(frequency) = self.notes.get(note) else {
+            self.finished.store(true, Ordering::Relaxed);
+            return 0.;
+        };
+        self.position += 1;
+
+        // A sine shaped envelope across the note
+        let offset = time - note as f32 * NOTE_LENGTH;
+        let envelope = (PI * offset / NOTE_LENGTH).sin();
+        (2. * PI * frequency * time).sin() * envelope * CHIME_LEVEL
+    }
+}
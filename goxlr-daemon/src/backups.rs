@@ -0,0 +1,72 @@
+/*
+   Snapshots of every device's configuration, taken automatically before anything which replaces
+   it wholesale, so that a bad restore (or Desired State) can be undone.
+*/
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use log::{debug, warn};
+
+use goxlr_ipc::commands::Backup;
+
+/// Writes a Backup to the directory, named by when it was taken and why, then removes the oldest
+/// backups beyond `keep`. Returns the name the backup was stored under.
+pub fn store_backup(
+    directory: &Path,
+    backup: &Backup,
+    reason: &str,
+    keep: usize,
+) -> Result<String> {
+    fs::create_dir_all(directory).context("Unable to create the Backup directory")?;
+
+    let name = format!("{}-{}", Local::now().format("%Y%m%d-%H%M%S-%3f"), reason);
+    let json = serde_json::to_string_pretty(backup)?;
+    fs::write(get_backup_path(directory, &name), json).context("Unable to write the Backup")?;
+
+    for old in list_backups(directory)?.iter().skip(keep) {
+        debug!("Removing old Backup {}", old);
+        if let Err(error) = fs::remove_file(get_backup_path(directory, old)) {
+            warn!("Unable to remove old Backup {}: {}", old, error);
+        }
+    }
+    Ok(name)
+}
+
+/// The names of the stored backups, newest first
+pub fn list_backups(directory: &Path) -> Result<Vec<String>> {
+    if !directory.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut names = vec![];
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+            names.push(name.to_string());
+        }
+    }
+
+    // Names start with a fixed width timestamp, so they sort by age
+    names.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(names)
+}
+
+pub fn read_backup(directory: &Path, name: &str) -> Result<Backup> {
+    // Only names we've listed can be read, so a name can't reach outside the directory
+    if !list_backups(directory)?.iter().any(|backup| backup == name) {
+        bail!("Backup {} not found", name);
+    }
+
+    let json = fs::read_to_string(get_backup_path(directory, name))?;
+    serde_json::from_str(&json).with_context(|| format!("Backup {} is invalid", name))
+}
+
+fn get_backup_path(directory: &Path, name: &str) -> PathBuf {
+    directory.join(format!("{}.json", name))
+}
@@ -0,0 +1,396 @@
+use std::env;
+use std::num::ParseIntError;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Parser;
+use directories::ProjectDirs;
+
+use goxlr_ipc::clients::ipc::ipc_client::{DEFAULT_NAMED_PIPE, DEFAULT_SOCKET_PATH};
+use goxlr_ipc::status::ReleaseChannel;
+use goxlr_shared::locale::Locale;
+
+use crate::schema::SchemaFormat;
+use crate::settings::{
+    AudioCueSettings, CueSound, DeviceSettings, IPCSettings, LoadInputBehaviour, LogSettings,
+    NotificationSettings, ProfileBackend, ScheduledOverride, UpdateSettings,
+};
+
+#[derive(Parser, Debug)]
+#[command(about, version, author)]
+pub struct Cli {
+    /// The path of the IPC Unix Socket
+    #[arg(long, default_value = DEFAULT_SOCKET_PATH)]
+    pub ipc_socket_path: String,
+
+    /// The name of the IPC Named Pipe (Windows)
+    #[arg(long, default_value = DEFAULT_NAMED_PIPE)]
+    pub ipc_pipe_name: String,
+
+    /// The permissions to apply to the IPC Socket, in octal (eg. 660)
+    #[arg(long, value_parser = parse_octal)]
+    pub ipc_socket_mode: Option<u32>,
+
+    /// The User ID to set as the owner of the IPC Socket
+    #[arg(long)]
+    pub ipc_socket_uid: Option<u32>,
+
+    /// The Group ID to set as the owner of the IPC Socket
+    #[arg(long)]
+    pub ipc_socket_gid: Option<u32>,
+
+    /// How many commands per second each HTTP / WebSocket client can send (0 for unlimited)
+    #[arg(long, default_value_t = 20)]
+    pub http_rate_limit: u32,
+
+    /// How many commands an HTTP / WebSocket client can send in a burst before being limited
+    #[arg(long, default_value_t = 50)]
+    pub http_rate_burst: u32,
+
+    /// Serve the gRPC API on this port (on localhost)
+    #[cfg(feature = "grpc")]
+    #[arg(long)]
+    pub grpc_port: Option<u16>,
+
+    /// Where to store the Device State Cache between runs (defaults to the temp directory)
+    #[arg(long)]
+    pub state_cache_dir: Option<PathBuf>,
+
+    /// Don't persist the Device State between runs, always fully configure the device on start
+    #[arg(long)]
+    pub disable_state_cache: bool,
+
+    /// Apply routing changes immediately during profile loads, rather than once at the end
+    #[arg(long)]
+    pub disable_routing_staging: bool,
+
+    /// Never send colours to the device, for setups where the lighting isn't needed
+    #[arg(long)]
+    pub disable_lighting: bool,
+
+    /// Send colour changes as they're made, rather than collecting them once per tick
+    #[arg(long)]
+    pub disable_colour_buffering: bool,
+
+    /// The language used for channel names drawn on the scribble displays
+    #[arg(long, value_enum, default_value_t = Locale::English)]
+    pub locale: Locale,
+
+    /// How buttons pressed and faders moved while a profile is loading are handled
+    #[arg(long, value_enum, default_value_t = LoadInputBehaviour::Queue)]
+    pub load_input: LoadInputBehaviour,
+
+    /// The highest the Headphones volume can be set (0 - 255), whether by a fader, a profile or
+    /// a client. This can be changed, or temporarily overridden, while the daemon is running
+    #[arg(long)]
+    pub headphone_limit: Option<u8>,
+
+    /// The OS playback device for the GoXLR's Sample channel, if it can't be found by name
+    #[arg(long)]
+    pub sample_device: Option<String>,
+
+    /// The OS playback device samples are played through on the GoXLR Mini, which has no
+    /// Sample channel (defaults to the system's default output)
+    #[arg(long)]
+    pub sample_fallback_device: Option<String>,
+
+    /// Show a desktop notification when the Microphone is muted or unmuted
+    #[arg(long)]
+    pub notify_mic_mute: bool,
+
+    /// Show a desktop notification when a profile is loaded
+    #[arg(long)]
+    pub notify_profile: bool,
+
+    /// Show a desktop notification when a device is removed
+    #[arg(long)]
+    pub notify_disconnect: bool,
+
+    /// The OS playback device audio cues are played through (defaults to the system's default
+    /// output)
+    #[arg(long)]
+    pub cue_device: Option<String>,
+
+    /// Play a sound when the daemon has started, either 'chime' or the path to a WAV file
+    #[arg(long)]
+    pub cue_ready: Option<CueSound>,
+
+    /// Play a sound when a device is connected, either 'chime' or the path to a WAV file
+    #[arg(long)]
+    pub cue_connected: Option<CueSound>,
+
+    /// Play a sound when a profile is loaded, either 'chime' or the path to a WAV file
+    #[arg(long)]
+    pub cue_profile: Option<CueSound>,
+
+    /// Play a sound when the Microphone is muted or unmuted, either 'chime' or the path to a
+    /// WAV file
+    #[arg(long)]
+    pub cue_mic_mute: Option<CueSound>,
+
+    /// How often (in ms) devices handle timed work such as ducking and the gate (5 to 50)
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u64).range(5..=50))]
+    pub tick_interval: u64,
+
+    /// How often (in ms) to poll the Microphone level while it's needed (eg. for ducking)
+    #[arg(long, default_value_t = 20)]
+    pub mic_level_interval: u64,
+
+    /// Keep a record of this many recent device events, which can be dumped for bug reports
+    #[arg(long, default_value_t = 0)]
+    pub flight_recorder_size: usize,
+
+    /// Reset a device if it stops responding for this many seconds (0 to disable)
+    #[arg(long, default_value_t = 10)]
+    pub watchdog_timeout: u64,
+
+    /// Reconnect a device after this many USB commands fail in a row (0 to disable)
+    #[arg(long, default_value_t = 5)]
+    pub usb_failure_limit: u32,
+
+    /// The longest to wait (in seconds) between attempts to reconnect a failing device
+    #[arg(long, default_value_t = 60)]
+    pub reconnect_max_delay: u64,
+
+    /// Log every USB command and response from startup, for protocol debugging
+    #[arg(long)]
+    pub usb_traffic_log: bool,
+
+    /// Where the USB traffic is logged (defaults to the temp directory)
+    #[arg(long)]
+    pub usb_traffic_file: Option<PathBuf>,
+
+    /// Append a record of every configuration change to a file in this directory
+    #[arg(long)]
+    pub audit_log_dir: Option<PathBuf>,
+
+    /// Where to back up the configuration before it's replaced (defaults to the data directory)
+    #[arg(long)]
+    pub backup_dir: Option<PathBuf>,
+
+    /// Don't back up the configuration before commands which replace or remove parts of it
+    #[arg(long)]
+    pub disable_auto_backup: bool,
+
+    /// How many automatic backups to keep
+    #[arg(long, default_value_t = 10)]
+    pub backup_keep: usize,
+
+    /// How device profiles are stored between runs
+    #[arg(long, value_enum, default_value_t = ProfileBackend::Json)]
+    pub profile_backend: ProfileBackend,
+
+    /// Where device profiles are stored (defaults to the user's data directory)
+    #[arg(long)]
+    pub profile_dir: Option<PathBuf>,
+
+    /// Where recorded Automations are stored (defaults to the temp directory)
+    #[arg(long)]
+    pub automation_dir: Option<PathBuf>,
+
+    /// Follow a release channel (stable or beta) for daemon updates, disabled if not set
+    #[arg(long)]
+    pub update_channel: Option<ReleaseChannel>,
+
+    /// How many hours between checks for a new release (0 to only check when asked)
+    #[arg(long, default_value_t = 24)]
+    pub update_check_hours: u64,
+
+    /// Download new releases as soon as they're found, ready to apply when the daemon stops
+    #[arg(long)]
+    pub update_auto_download: bool,
+
+    /// Where downloaded updates are kept until they're applied (defaults to the user's data
+    /// directory), this shouldn't be writable by anyone else
+    #[arg(long)]
+    pub update_dir: Option<PathBuf>,
+
+    /// Override the gate or ducking between two times of day, can be given more than once, eg.
+    /// '22:00-07:00,gate-threshold=-30,duck-trigger=-35'. Other names are duck-mic-offset and
+    /// duck-release, the first window which covers the current time is used.
+    #[arg(long)]
+    pub schedule: Vec<ScheduledOverride>,
+
+    /// Where to write the log files (defaults to the temp directory)
+    #[arg(long)]
+    pub log_dir: Option<PathBuf>,
+
+    /// Only log to the terminal, don't write log files
+    #[arg(long)]
+    pub disable_file_logging: bool,
+
+    /// The size (in MB) a log file can reach before it's rotated
+    #[arg(long, default_value_t = 5)]
+    pub log_max_size: u64,
+
+    /// How many hours a log file is written to before it's rotated (0 to only rotate on size)
+    #[arg(long, default_value_t = 24)]
+    pub log_rotate_hours: u64,
+
+    /// How many rotated log files to keep
+    #[arg(long, default_value_t = 5)]
+    pub log_keep: usize,
+
+    /// Replay a recorded session (such as a Flight Recorder dump) against a Mock Device
+    #[arg(long)]
+    pub replay: Option<PathBuf>,
+
+    /// Load a profile against a Mock Device, print the routing, colours and ducking it results
+    /// in, then exit
+    #[arg(long)]
+    pub simulate: Option<PathBuf>,
+
+    /// A JSON list of Microphone levels ({"at": ms, "level": dB}) to play while simulating,
+    /// producing a ducking timeline
+    #[arg(long, requires = "simulate")]
+    pub mic_trace: Option<PathBuf>,
+
+    /// Print the JSON Schema for a file format, then exit
+    #[arg(long, value_enum)]
+    pub export_schema: Option<SchemaFormat>,
+}
+
+impl Cli {
+    pub fn ipc_settings(&self) -> IPCSettings {
+        IPCSettings {
+            socket_path: self.ipc_socket_path.clone(),
+            pipe_name: self.ipc_pipe_name.clone(),
+            socket_mode: self.ipc_socket_mode,
+            socket_uid: self.ipc_socket_uid,
+            socket_gid: self.ipc_socket_gid,
+        }
+    }
+
+    pub fn device_settings(&self) -> DeviceSettings {
+        DeviceSettings {
+            state_cache_dir: self.state_cache_dir(),
+            stage_routing: !self.disable_routing_staging,
+            lighting: !self.disable_lighting,
+            buffer_colours: !self.disable_colour_buffering,
+            locale: self.locale,
+            load_input: self.load_input,
+            headphone_limit: self.headphone_limit,
+            sample_device: self.sample_device.clone(),
+            sample_fallback_device: self.sample_fallback_device.clone(),
+            notifications: NotificationSettings {
+                mic_mute: self.notify_mic_mute,
+                profile: self.notify_profile,
+                disconnect: self.notify_disconnect,
+            },
+            audio_cues: AudioCueSettings {
+                device: self.cue_device.clone(),
+                ready: self.cue_ready.clone(),
+                connected: self.cue_connected.clone(),
+                profile: self.cue_profile.clone(),
+                mic_mute: self.cue_mic_mute.clone(),
+            },
+            tick_interval: self.tick_interval,
+            mic_level_interval: self.mic_level_interval,
+            flight_recorder_size: self.flight_recorder_size,
+            watchdog_timeout: self.watchdog_timeout,
+            usb_failure_limit: self.usb_failure_limit,
+            reconnect_max_delay: self.reconnect_max_delay,
+            usb_traffic_log: self.usb_traffic_log,
+            usb_traffic_file: self.usb_traffic_file(),
+            audit_log_dir: self.audit_log_dir.clone(),
+            backup_dir: self.backup_dir(),
+            backup_keep: self.backup_keep,
+            profile_backend: self.profile_backend(),
+            profile_dir: self.profile_dir(),
+            automation_dir: self.automation_dir(),
+            updates: self.update_settings(),
+            schedule: self.schedule.clone(),
+        }
+    }
+
+    pub fn update_settings(&self) -> UpdateSettings {
+        // A replay or simulation isn't the daemon a user is running, so shouldn't replace it
+        let simulated = self.replay.is_some() || self.simulate.is_some();
+
+        let default = || get_data_dir().join("updates");
+        UpdateSettings {
+            channel: self.update_channel.filter(|_| !simulated),
+            check_hours: self.update_check_hours,
+            auto_download: self.update_auto_download,
+            directory: self.update_dir.clone().unwrap_or_else(default),
+        }
+    }
+
+    pub fn log_settings(&self) -> LogSettings {
+        let max_age = match self.log_rotate_hours {
+            0 => None,
+            hours => Some(Duration::from_secs(hours * 60 * 60)),
+        };
+
+        LogSettings {
+            directory: self.log_dir(),
+            max_size: self.log_max_size * 1024 * 1024,
+            max_age,
+            keep: self.log_keep,
+        }
+    }
+
+    fn state_cache_dir(&self) -> Option<PathBuf> {
+        // A replay or simulation needs to start from the same state every time
+        if self.disable_state_cache || self.replay.is_some() || self.simulate.is_some() {
+            return None;
+        }
+
+        let default = || env::temp_dir().join("goxlr-daemon");
+        Some(self.state_cache_dir.clone().unwrap_or_else(default))
+    }
+
+    fn backup_dir(&self) -> Option<PathBuf> {
+        if self.disable_auto_backup || self.simulate.is_some() {
+            return None;
+        }
+
+        let default = || get_data_dir().join("backups");
+        Some(self.backup_dir.clone().unwrap_or_else(default))
+    }
+
+    fn profile_backend(&self) -> ProfileBackend {
+        // A simulation shouldn't leave its profile behind in the store
+        match self.simulate {
+            Some(_) => ProfileBackend::Defaults,
+            None => self.profile_backend,
+        }
+    }
+
+    fn profile_dir(&self) -> PathBuf {
+        let default = || get_data_dir().join("profiles");
+        self.profile_dir.clone().unwrap_or_else(default)
+    }
+
+    fn usb_traffic_file(&self) -> PathBuf {
+        let default = || env::temp_dir().join("goxlr-daemon").join("usb-traffic.log");
+        self.usb_traffic_file.clone().unwrap_or_else(default)
+    }
+
+    fn automation_dir(&self) -> PathBuf {
+        let default = || env::temp_dir().join("goxlr-daemon").join("automation");
+        self.automation_dir.clone().unwrap_or_else(default)
+    }
+
+    fn log_dir(&self) -> Option<PathBuf> {
+        if self.disable_file_logging {
+            return None;
+        }
+
+        let default = || env::temp_dir().join("goxlr-daemon").join("logs");
+        Some(self.log_dir.clone().unwrap_or_else(default))
+    }
+}
+
+fn parse_octal(value: &str) -> Result<u32, ParseIntError> {
+    u32::from_str_radix(value, 8)
+}
+
+/// Where anything which needs to survive a reboot is kept by default, the temp directory is only
+/// used if the platform doesn't have a data directory for the user.
+fn get_data_dir() -> PathBuf {
+    match ProjectDirs::from("org", "GoXLR-on-Linux", "goxlr-daemon") {
+        Some(dirs) => dirs.data_dir().to_path_buf(),
+        None => env::temp_dir().join("goxlr-daemon"),
+    }
+}
@@ -0,0 +1,51 @@
+/*
+   When a device can't be claimed there's usually something the user can do about it, these
+   hints explain what that is for each platform.
+*/
+
+use goxlr_shared::device::ClaimFailure;
+
+/// Returns a short explanation of how to resolve a claim failure on this platform
+pub fn remediation_hint(failure: ClaimFailure) -> String {
+    let hint = match failure {
+        ClaimFailure::PermissionDenied => PERMISSION_HINT,
+        ClaimFailure::InUse => IN_USE_HINT,
+        ClaimFailure::DriverMissing => DRIVER_HINT,
+        ClaimFailure::Disconnected => {
+            "The device was disconnected while being set up, check the USB cable and port"
+        }
+        ClaimFailure::Unknown => "Try reconnecting the device, or restarting the daemon",
+    };
+    hint.to_string()
+}
+
+#[cfg(target_os = "linux")]
+const PERMISSION_HINT: &str = "Your user doesn't have access to the device, install the GoXLR \
+    udev rules (50-goxlr.rules) into /etc/udev/rules.d/ then reconnect the device";
+
+#[cfg(target_os = "macos")]
+const PERMISSION_HINT: &str = "macOS refused access to the device, make sure the daemon has \
+    been granted permission to access USB devices";
+
+#[cfg(target_os = "windows")]
+const PERMISSION_HINT: &str = "Windows refused access to the device, try running the daemon as \
+    the same user that installed the GoXLR Driver";
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+const PERMISSION_HINT: &str = "The OS refused access to the device, check your USB permissions";
+
+#[cfg(target_os = "windows")]
+const IN_USE_HINT: &str = "The device is being used by another application, close the official \
+    GoXLR App (and any other GoXLR Utility) and try again";
+
+#[cfg(not(target_os = "windows"))]
+const IN_USE_HINT: &str = "The device is being used by another application, make sure no other \
+    GoXLR Utility or daemon is running";
+
+#[cfg(target_os = "windows")]
+const DRIVER_HINT: &str = "The GoXLR Driver couldn't be found, install the official GoXLR \
+    Driver from TC-Helicon";
+
+#[cfg(not(target_os = "windows"))]
+const DRIVER_HINT: &str = "The USB device can't be accessed by the current driver, make sure \
+    libusb is installed and nothing has replaced the device's driver";
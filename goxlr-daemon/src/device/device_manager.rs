@@ -2,33 +2,64 @@
    The primary device manager, this is responsible for most of the general workings of the daemon
 */
 
-use std::collections::HashMap;
-use std::time::{Duration, SystemTime};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use anyhow::bail;
 use json_patch::diff;
 use log::{debug, error, info, warn};
+use semver::Version;
 use tokio::sync::broadcast::Sender;
 use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
 use tokio::{join, select, task, time};
 
-use goxlr_ipc::commands::{DaemonResponse, DaemonStatus, GoXLRCommand, GoXLRCommandResponse};
-use goxlr_ipc::status::{Configuration, DeviceStatus};
+use goxlr_ipc::commands::channels::ChannelCommands;
+use goxlr_ipc::commands::configuration::ConfigurationCommand;
+use goxlr_ipc::commands::mix_modes::MixModeCommand;
+use goxlr_ipc::commands::pages::PageCommand;
+use goxlr_ipc::commands::{
+    Backup, BackupSettings, DaemonCommand, DaemonResponse, DaemonStatus, FaderMovement,
+    GoXLRCommand, GoXLRCommandResponse, HealthReport,
+};
+use goxlr_ipc::status::{
+    Configuration, DeviceIncident, DeviceLifecycle, DeviceStatus, IncidentType, LifecycleStatus,
+    ProfileLoadReport, ReconnectStatus, RuntimeStatus, UnclaimedDevice, UpdateState, UpdateStatus,
+};
 use goxlr_shared::device::DeviceInfo;
+use goxlr_usb::error::DeviceClaimError;
 use goxlr_usb::runners::pnp::PnPDeviceMessage;
 use goxlr_usb::runners::pnp::{start_pnp_runner, PnPConfiguration};
+use goxlr_usb::traffic::{start_traffic_log, stop_traffic_log};
 use goxlr_usb::USBLocation;
 
-use crate::device::device_manager::ManagerMessage::{Execute, GetConfig, GetDevice};
+use crate::audio_cues::{play_cue, AudioCue};
+use crate::backups::{list_backups, read_backup, store_backup};
+use crate::device::claim::remediation_hint;
+use crate::device::device_manager::ManagerMessage::{
+    DryRun, Execute, GetConfig, GetDevice, GetLoadReport, GetRuntimeStatus,
+};
 use crate::device::goxlr::device::start_goxlr;
 use crate::device::goxlr::device_config::GoXLRDeviceConfiguration;
 use crate::device::messaging::DeviceMessage;
+use crate::logging::read_log_tail;
+use crate::notifications::{send_notification, Notification};
 use crate::servers::http_server::PatchEvent;
+use crate::settings::DeviceSettings;
 use crate::stop::Stop;
+use crate::storage::defaults::DefaultProfileStore;
+use crate::storage::{open_profile_store, ProfileStore};
+use crate::updates::{check_for_update, download_update, is_update_staged, Release};
 
 struct DeviceManager {
     last_status: DaemonStatus,
     patch_broadcast: Sender<PatchEvent>,
 
+    /// Handed to each device, for sending physical fader movements to subscribed clients
+    fader_broadcast: Sender<FaderMovement>,
+
     /// Used for Devices sending messages back to the Manager
     device_receiver: mpsc::Receiver<RunnerMessage>,
     device_sender: mpsc::Sender<RunnerMessage>,
@@ -46,21 +77,99 @@ struct DeviceManager {
     /// Currently registered device serials
     serials: HashMap<String, USBLocation>,
 
+    /// Where each device is in its lifecycle, kept across recoveries
+    lifecycles: HashMap<USBLocation, LifecycleStatus>,
+
     /// Shutdown Signaller
     shutdown: Stop,
 
     /// Simple bool to help track shutdown
     stopping: bool,
+
+    /// Settings to pass to each device
+    device_settings: DeviceSettings,
+
+    /// Where each device loads and stores its profiles
+    profile_store: Arc<dyn ProfileStore>,
+
+    /// Recent device problems, reported in the DaemonStatus
+    incidents: Vec<DeviceIncident>,
+
+    /// Devices which couldn't be claimed on their last attempt, and why
+    unclaimed: HashMap<USBLocation, UnclaimedDevice>,
+
+    /// Where the daemon is writing its log files, if it is
+    log_directory: Option<PathBuf>,
+
+    /// While streaming, commands which could disrupt the stream are refused unless Forced
+    live_mode: bool,
+
+    /// Where the daemon is in finding, and installing, its next update
+    update: UpdateStatus,
+
+    /// The newest release found by the last check, and the version that's been staged
+    release: Option<Release>,
+    staged_version: Option<Version>,
+
+    /// Used by update checks and downloads, which run in the background, to report back
+    release_receiver: mpsc::Receiver<UpdateEvent>,
+    release_sender: mpsc::Sender<UpdateEvent>,
 }
 
 impl DeviceManager {
-    pub fn new(shutdown: Stop, broadcast_tx: Sender<PatchEvent>) -> Self {
+    pub fn new(
+        shutdown: Stop,
+        broadcast_tx: Sender<PatchEvent>,
+        fader_tx: Sender<FaderMovement>,
+        device_settings: DeviceSettings,
+        log_directory: Option<PathBuf>,
+    ) -> Self {
         let (device_sender, device_receiver) = mpsc::channel(128);
         let (update_sender, update_receiver) = mpsc::channel(1);
+        let (release_sender, release_receiver) = mpsc::channel(1);
+
+        let backend = device_settings.profile_backend;
+        let profile_store = match open_profile_store(backend, &device_settings.profile_dir) {
+            Ok(store) => store,
+            Err(error) => {
+                error!("Profile Storage Unavailable, using Defaults: {}", error);
+                Arc::new(DefaultProfileStore)
+            }
+        };
+
+        if device_settings.usb_traffic_log {
+            if let Err(error) = start_traffic_log(&device_settings.usb_traffic_file) {
+                warn!("Unable to start the USB Traffic Log: {}", error);
+            }
+        }
+
+        // Clients use this to notice the daemon has restarted, and their state is gone
+        let session = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_millis() as u64)
+            .unwrap_or_default();
+
+        // An update staged by a previous run will still be applied when this one stops
+        let updates = &device_settings.updates;
+        let staged = updates.channel.is_some() && is_update_staged(&updates.directory);
+        let update = UpdateStatus {
+            channel: updates.channel,
+            current_version: env!("CARGO_PKG_VERSION").to_string(),
+            state: if staged {
+                UpdateState::Staged
+            } else {
+                UpdateState::Idle
+            },
+            ..Default::default()
+        };
 
         Self {
-            last_status: DaemonStatus::default(),
+            last_status: DaemonStatus {
+                session,
+                ..Default::default()
+            },
             patch_broadcast: broadcast_tx,
+            fader_broadcast: fader_tx,
 
             device_receiver,
             device_sender,
@@ -71,13 +180,26 @@ impl DeviceManager {
             devices: Default::default(),
             states: HashMap::default(),
             serials: HashMap::default(),
+            lifecycles: HashMap::default(),
             shutdown,
             stopping: false,
+            device_settings,
+            profile_store,
+            incidents: vec![],
+            unclaimed: HashMap::default(),
+            log_directory,
+            live_mode: false,
+            update,
+            release: None,
+            staged_version: None,
+            release_receiver,
+            release_sender,
         }
     }
 
     pub async fn run(&mut self, mut message_receiver: mpsc::Receiver<DeviceMessage>) {
         info!("[DeviceManager] Starting Device Manager..");
+        play_cue(&self.device_settings.audio_cues, AudioCue::Ready);
         let (pnp_send, pnp_recv) = oneshot::channel();
         let (device_send, mut device_recv) = mpsc::channel(32);
 
@@ -91,6 +213,11 @@ impl DeviceManager {
         // Ticker for handling error states..
         let mut ticker = time::interval(Duration::from_millis(500));
 
+        // Ticker for checking for updates, the first check is made straight away
+        let check_hours = self.device_settings.updates.check_hours;
+        let mut update_ticker = time::interval(Duration::from_secs(check_hours.max(1) * 60 * 60));
+        let auto_check = self.update.channel.is_some() && check_hours > 0;
+
         loop {
             select! {
                 Some(message) = message_receiver.recv() => {
@@ -103,7 +230,9 @@ impl DeviceManager {
                         PnPDeviceMessage::Attached(device) => {
                             debug!("[DeviceManager] Received Device: {:?}", device);
                             self.devices.push(device.clone());
+                            self.set_lifecycle(&device, DeviceLifecycle::Discovered);
                             self.add_device(device.clone()).await;
+                            self.update_status().await;
                         }
                         PnPDeviceMessage::Removed(device) => {
                             self.devices.retain(|d| d != &device);
@@ -121,11 +250,30 @@ impl DeviceManager {
                         RunnerMessage::Error(device) => {
                             self.handle_error(device);
                         },
+                        RunnerMessage::Hung(device, elapsed) => {
+                            self.handle_hung(device, elapsed).await;
+                        }
+                        RunnerMessage::Failing(device, failures) => {
+                            self.handle_failing(device, failures).await;
+                        }
+                        RunnerMessage::ClaimFailed(device, error) => {
+                            self.handle_claim_failed(device, error).await;
+                        }
                     }
                 },
                 Some(()) = self.update_receiver.recv() => {
                     self.update_status().await;
                 }
+                Some(event) = self.release_receiver.recv() => {
+                    self.handle_update_event(event);
+                    self.update_status().await;
+                }
+                _ = update_ticker.tick(), if auto_check => {
+                    if let Err(error) = self.check_for_update() {
+                        debug!("[DeviceManager] Skipping Update Check: {}", error);
+                    }
+                    self.update_status().await;
+                }
                 _ = self.shutdown.recv() => {
                     let _ = pnp_send.send(());
                     self.stopping = true;
@@ -147,9 +295,10 @@ impl DeviceManager {
                         RunnerMessage::UpdateState(device, state) => {
                             self.update_state(device, state).await;
                         }
-                        RunnerMessage::Error(device) => {
+                        RunnerMessage::Error(device) | RunnerMessage::Hung(device, _) => {
                             self.handle_error(device);
                         },
+                        RunnerMessage::ClaimFailed(..) | RunnerMessage::Failing(..) => {}
                     }
                     if self.devices_stopped() {
                         break;
@@ -181,19 +330,32 @@ impl DeviceManager {
             update_sender: self.update_sender.clone(),
             manager_sender: self.device_sender.clone(),
             manager_recv,
+            fader_events: self.fader_broadcast.clone(),
+            settings: self.device_settings.clone(),
+            profile_store: self.profile_store.clone(),
         };
 
+        self.set_lifecycle(&location, DeviceLifecycle::Initialising);
+        let task = task::spawn(start_goxlr(config, self.shutdown.clone()));
         let state = DeviceState {
             stop,
             state: RunnerState::Starting,
             messenger: manager_send,
+            task,
+            running: None,
         };
 
         self.states.insert(location, state);
-        task::spawn(start_goxlr(config, self.shutdown.clone()));
     }
 
     async fn remove_device(&mut self, device: USBLocation) {
+        self.unclaimed.remove(&device);
+        self.set_lifecycle(&device, DeviceLifecycle::Disconnected);
+
+        let serial = self.lifecycles.get(&device).and_then(|l| l.serial.clone());
+        let notification = Notification::Disconnected(serial);
+        send_notification(&self.device_settings.notifications, notification);
+
         if let Some(status) = &mut self.states.get_mut(&device) {
             if let RunnerState::Running(_) = &status.state {
                 // We're running, trigger a stop and set us to stopping..
@@ -204,13 +366,18 @@ impl DeviceManager {
                 self.serials.retain(|_, dev| *dev != device);
 
                 // Return here, and wait for the Stopper to handle the stop.
+                self.update_status().await;
                 return;
             }
         }
 
+        // Let clients see the disconnect before the device disappears from the status
+        self.update_status().await;
+
         // If we're not already running, we should just nuke knowledge of the device..
         self.serials.retain(|_, dev| *dev != device);
         self.states.retain(|dev, _| *dev != device);
+        self.lifecycles.remove(&device);
 
         debug!("Updating DaemonStatus due to device removal");
         self.update_status().await;
@@ -218,12 +385,18 @@ impl DeviceManager {
 
     async fn check_devices(&mut self) {
         let mut refresh = vec![];
+        let mut stable = vec![];
 
-        // We need to see if any of our devices are in an error state, if so, reset them..
+        // We need to see if any of our devices are in an error state, if so, reset them once
+        // they've waited out their backoff..
+        let max_delay = Duration::from_secs(self.device_settings.reconnect_max_delay);
         for (location, state) in &mut self.states {
-            if let RunnerState::Error(time) = state.state {
-                if let Ok(elapsed) = time.elapsed() {
-                    if elapsed.as_secs() >= 2 {
+            let reconnect = self.lifecycles.get(location).and_then(|l| l.reconnect);
+            match state.state {
+                RunnerState::Error(time) => {
+                    let attempts = reconnect.map(|r| r.attempts).unwrap_or_default();
+                    let delay = get_reconnect_delay(attempts, max_delay);
+                    if time.elapsed().is_ok_and(|elapsed| elapsed >= delay) {
                         debug!(
                             "[DeviceManager]{} Attempting Recovery on Device..",
                             location
@@ -231,14 +404,43 @@ impl DeviceManager {
                         refresh.push(location.clone());
                     }
                 }
+                RunnerState::Running(_) if reconnect.is_some() => {
+                    // Once a device has stayed up for a while, start the backoff again
+                    if state
+                        .running
+                        .is_some_and(|time| time.elapsed() >= RECONNECT_STABLE)
+                    {
+                        stable.push(location.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for device in &stable {
+            info!("[DeviceManager]{} Device Stable", device);
+            if let Some(lifecycle) = self.lifecycles.get_mut(device) {
+                lifecycle.reconnect = None;
             }
         }
 
         // Refresh any devices that are in an error state..
+        let changed = !refresh.is_empty() || !stable.is_empty();
         for device in refresh {
             debug!("Handling Device..");
+            if let Some(lifecycle) = self.lifecycles.get_mut(&device) {
+                let attempts = lifecycle.reconnect.map(|r| r.attempts).unwrap_or_default();
+                lifecycle.reconnect = Some(ReconnectStatus {
+                    attempts: attempts + 1,
+                    next_attempt: None,
+                });
+            }
             self.add_device(device).await;
         }
+
+        if changed {
+            self.update_status().await;
+        }
     }
 
     async fn update_state(&mut self, device: USBLocation, state: RunnerState) {
@@ -248,6 +450,13 @@ impl DeviceManager {
                 device, serial
             );
             self.serials.insert(serial.to_owned(), device.clone());
+            self.unclaimed.remove(&device);
+
+            self.set_lifecycle(&device, DeviceLifecycle::Running);
+            play_cue(&self.device_settings.audio_cues, AudioCue::Connected);
+            if let Some(lifecycle) = self.lifecycles.get_mut(&device) {
+                lifecycle.serial = Some(serial.to_owned());
+            }
 
             debug!("Device Active, Updating DaemonStatus state..");
             self.update_status().await;
@@ -263,6 +472,7 @@ impl DeviceManager {
                 // If we're in a 'Stopping' state, we're prepping for removal..
                 if current.state == RunnerState::Stopping || self.stopping {
                     self.states.remove(&device);
+                    self.lifecycles.remove(&device);
                 } else {
                     // We've stopped, but we're not supposed to, that's an error.
                     debug!(
@@ -270,12 +480,17 @@ impl DeviceManager {
                         device
                     );
                     current.state = RunnerState::Error(SystemTime::now());
+                    self.set_lifecycle(&device, DeviceLifecycle::Degraded);
+                    self.schedule_reconnect(&device);
                 }
 
                 self.update_status().await;
                 return;
             }
 
+            if let RunnerState::Running(_) = state {
+                current.running = Some(Instant::now());
+            }
             current.state = state;
             return;
         }
@@ -298,6 +513,149 @@ impl DeviceManager {
 
         // If we're tracking a serial for this device, we need to remove it from the list..
         self.serials.retain(|_, dev| *dev != device);
+        self.set_lifecycle(&device, DeviceLifecycle::Degraded);
+        self.schedule_reconnect(&device);
+    }
+
+    /// Works out when a Degraded device will next be reconnected, backing off further with each
+    /// attempt so a device which keeps failing isn't hammered.
+    fn schedule_reconnect(&mut self, device: &USBLocation) {
+        let Some(lifecycle) = self.lifecycles.get_mut(device) else {
+            return;
+        };
+        if lifecycle.state != DeviceLifecycle::Degraded {
+            return;
+        }
+
+        let attempts = lifecycle.reconnect.map(|r| r.attempts).unwrap_or_default();
+        let max_delay = Duration::from_secs(self.device_settings.reconnect_max_delay);
+        let delay = get_reconnect_delay(attempts, max_delay);
+        let next_attempt = (SystemTime::now() + delay)
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .ok();
+
+        debug!(
+            "[DeviceManager]{} Reconnecting in {:?} (Attempt {})",
+            device,
+            delay,
+            attempts + 1
+        );
+        lifecycle.reconnect = Some(ReconnectStatus {
+            attempts,
+            next_attempt,
+        });
+    }
+
+    /// Moves a device to a new lifecycle state, ignoring any transition which doesn't fit the
+    /// lifecycle (such as an error arriving from a device which is already shutting down).
+    fn set_lifecycle(&mut self, device: &USBLocation, state: DeviceLifecycle) {
+        let Some(lifecycle) = self.lifecycles.get_mut(device) else {
+            // Devices only enter the lifecycle when they're found, anything else is from a
+            // device which has already been removed
+            if state == DeviceLifecycle::Discovered {
+                info!("[DeviceManager]{} Lifecycle -> {:?}", device, state);
+                let lifecycle = LifecycleStatus {
+                    serial: None,
+                    state,
+                    reconnect: None,
+                };
+                self.lifecycles.insert(device.clone(), lifecycle);
+            }
+            return;
+        };
+
+        if lifecycle.state == state {
+            return;
+        }
+
+        if !is_valid_transition(lifecycle.state, state) {
+            debug!(
+                "[DeviceManager]{} Ignoring Lifecycle change {:?} -> {:?}",
+                device, lifecycle.state, state
+            );
+            return;
+        }
+
+        info!(
+            "[DeviceManager]{} Lifecycle {:?} -> {:?}",
+            device, lifecycle.state, state
+        );
+        lifecycle.state = state;
+    }
+
+    async fn handle_hung(&mut self, device: USBLocation, elapsed: Duration) {
+        // The event loop is stuck, so it's not going to respond to a stop, the only way to get
+        // it back is to kill the task and let the error handling bring the device back up.
+        if let Some(current) = self.states.get(&device) {
+            warn!("[DeviceManager]{} Device Hung, aborting runtime", device);
+            current.task.abort();
+        }
+
+        let serial = self.get_serial(&device);
+        self.handle_error(device);
+        self.add_incident(serial, IncidentType::EventLoopHung(elapsed.as_secs()));
+        self.update_status().await;
+    }
+
+    async fn handle_failing(&mut self, device: USBLocation, failures: u32) {
+        // The event loop is still working, so it can be stopped normally, as it's not expecting
+        // to stop it'll be picked up as an error and reconnected.
+        if let Some(current) = self.states.get_mut(&device) {
+            warn!("[DeviceManager]{} USB Failing, tearing down device", device);
+            current.stop.trigger();
+        }
+
+        let serial = self.get_serial(&device);
+        self.add_incident(serial, IncidentType::UsbFailures(failures));
+        self.update_status().await;
+    }
+
+    fn get_serial(&self, device: &USBLocation) -> Option<String> {
+        self.serials
+            .iter()
+            .find(|(_, location)| *location == device)
+            .map(|(serial, _)| serial.clone())
+    }
+
+    async fn handle_claim_failed(&mut self, device: USBLocation, error: DeviceClaimError) {
+        // The device will keep being retried, so only shout about it when the reason changes
+        let previous = self
+            .unclaimed
+            .get(&device)
+            .map(|unclaimed| unclaimed.failure);
+        if previous == Some(error.failure) {
+            return;
+        }
+
+        let hint = remediation_hint(error.failure);
+        warn!("[DeviceManager]{} {}", device, error);
+        warn!("[DeviceManager]{} {}", device, hint);
+
+        let unclaimed = UnclaimedDevice {
+            location: device.to_string(),
+            failure: error.failure,
+            message: error.message,
+            hint,
+        };
+        self.unclaimed.insert(device, unclaimed);
+        self.update_status().await;
+    }
+
+    fn add_incident(&mut self, serial: Option<String>, incident: IncidentType) {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        if self.incidents.len() >= MAX_INCIDENTS {
+            self.incidents.remove(0);
+        }
+        self.incidents.push(DeviceIncident {
+            serial,
+            time,
+            incident,
+        });
     }
 
     fn devices_stopped(&self) -> bool {
@@ -312,7 +670,20 @@ impl DeviceManager {
     }
 
     async fn update_status(&mut self) {
-        let mut status = DaemonStatus::default();
+        let mut status = DaemonStatus {
+            session: self.last_status.session,
+            sequence: self.last_status.sequence,
+            incidents: self.incidents.clone(),
+            unclaimed: self.unclaimed.values().cloned().collect(),
+            lifecycle: self
+                .lifecycles
+                .iter()
+                .map(|(location, lifecycle)| (location.to_string(), lifecycle.clone()))
+                .collect(),
+            live_mode: self.live_mode,
+            update: self.update.clone(),
+            ..Default::default()
+        };
 
         for (serial, usb) in &self.serials {
             if let Some(device) = self.states.get(usb) {
@@ -326,6 +697,24 @@ impl DeviceManager {
 
                 let profile = cmd_rx.await.ok();
 
+                let (cmd_tx, cmd_rx) = oneshot::channel();
+                let result = device.messenger.send(GetLoadReport(cmd_tx)).await;
+                if let Err(e) = result {
+                    warn!("Unable to Fetch Load Report: {}", e);
+                    continue;
+                }
+
+                let load_report = cmd_rx.await.unwrap_or_default();
+
+                let (cmd_tx, cmd_rx) = oneshot::channel();
+                let result = device.messenger.send(GetRuntimeStatus(cmd_tx)).await;
+                if let Err(e) = result {
+                    warn!("Unable to Fetch Runtime Status: {}", e);
+                    continue;
+                }
+
+                let runtime = cmd_rx.await.unwrap_or_default();
+
                 let (cmd_tx, cmd_rx) = oneshot::channel();
                 let result = device.messenger.send(GetDevice(cmd_tx)).await;
                 if let Err(e) = result {
@@ -346,6 +735,8 @@ impl DeviceManager {
                         hardware: device.unwrap(),
                         serial: serial.clone(),
                         config: profile.unwrap(),
+                        load_report,
+                        runtime,
                     },
                 );
             }
@@ -356,6 +747,11 @@ impl DeviceManager {
 
         let patch = diff(&previous, &new);
         if !patch.0.is_empty() {
+            // Something's changed, so move the sequence on, this is carried in the patch
+            status.sequence += 1;
+            let new = serde_json::to_value(&status).unwrap();
+            let patch = diff(&previous, &new);
+
             // Broadcast Patch..
             let _ = self.patch_broadcast.send(PatchEvent { data: patch });
         }
@@ -363,20 +759,353 @@ impl DeviceManager {
         self.last_status = status;
     }
 
-    async fn handle_command(&self, command: DeviceMessage) -> bool {
+    async fn handle_daemon_command(
+        &mut self,
+        command: DaemonCommand,
+        force: bool,
+    ) -> DaemonResponse {
+        if self.live_mode && !force {
+            if let Some(reason) = get_daemon_live_mode_block(&command) {
+                return DaemonResponse::LiveModeBlocked(reason.to_string());
+            }
+        }
+
+        match command {
+            DaemonCommand::GetLogTail(lines) => {
+                let Some(directory) = &self.log_directory else {
+                    return DaemonResponse::Err("File Logging is not enabled".to_string());
+                };
+
+                match read_log_tail(directory, lines) {
+                    Ok(lines) => DaemonResponse::LogTail(lines),
+                    Err(error) => DaemonResponse::Err(error.to_string()),
+                }
+            }
+            DaemonCommand::GetBackup => DaemonResponse::Backup(self.create_backup()),
+            DaemonCommand::RestoreBackup(backup) => self.restore_with_backup(backup).await,
+            DaemonCommand::ListBackups => {
+                let Some(directory) = &self.device_settings.backup_dir else {
+                    return DaemonResponse::Err("Automatic Backups are not enabled".to_string());
+                };
+
+                match list_backups(directory) {
+                    Ok(names) => DaemonResponse::Backups(names),
+                    Err(error) => DaemonResponse::Err(error.to_string()),
+                }
+            }
+            DaemonCommand::RestoreStoredBackup(name) => {
+                let Some(directory) = &self.device_settings.backup_dir else {
+                    return DaemonResponse::Err("Automatic Backups are not enabled".to_string());
+                };
+
+                match read_backup(directory, &name) {
+                    Ok(backup) => self.restore_with_backup(backup).await,
+                    Err(error) => DaemonResponse::Err(error.to_string()),
+                }
+            }
+            DaemonCommand::GetHealth => DaemonResponse::Health(self.get_health()),
+            DaemonCommand::SetUsbTrafficLog(enabled) => {
+                if !enabled {
+                    stop_traffic_log();
+                    return DaemonResponse::Ok;
+                }
+
+                match start_traffic_log(&self.device_settings.usb_traffic_file) {
+                    Ok(()) => DaemonResponse::Ok,
+                    Err(error) => DaemonResponse::Err(error.to_string()),
+                }
+            }
+            DaemonCommand::SetLiveMode(enabled) => {
+                info!("Live Mode {}", if enabled { "Enabled" } else { "Disabled" });
+                self.live_mode = enabled;
+                DaemonResponse::Ok
+            }
+            DaemonCommand::CheckForUpdate => match self.check_for_update() {
+                Ok(()) => DaemonResponse::Ok,
+                Err(error) => DaemonResponse::Err(error.to_string()),
+            },
+            DaemonCommand::InstallUpdate => match self.install_update() {
+                Ok(()) => DaemonResponse::Ok,
+                Err(error) => DaemonResponse::Err(error.to_string()),
+            },
+        }
+    }
+
+    /// Starts checking the release channel in the background, the result is reported back as
+    /// an UpdateEvent
+    fn check_for_update(&mut self) -> anyhow::Result<()> {
+        let Some(channel) = self.update.channel else {
+            bail!("Updates are not enabled");
+        };
+        if self.is_updating() {
+            bail!("An Update Check or Download is already running");
+        }
+
+        self.update.state = UpdateState::Checking;
+        let sender = self.release_sender.clone();
+        task::spawn(async move {
+            let result = check_for_update(channel).await;
+            let _ = sender.send(UpdateEvent::Checked(result)).await;
+        });
+        Ok(())
+    }
+
+    /// Starts downloading the newest release in the background, to be applied on restart
+    fn install_update(&mut self) -> anyhow::Result<()> {
+        if self.update.channel.is_none() {
+            bail!("Updates are not enabled");
+        }
+        if self.is_updating() {
+            bail!("An Update Check or Download is already running");
+        }
+        let Some(release) = self.release.clone() else {
+            bail!("No Update is available, check for one first");
+        };
+
+        info!("Downloading Update {}", release.version);
+        self.update.state = UpdateState::Downloading;
+        let directory = self.device_settings.updates.directory.clone();
+        let sender = self.release_sender.clone();
+        task::spawn(async move {
+            let result = download_update(&release, &directory).await;
+            let _ = sender
+                .send(UpdateEvent::Downloaded(release.version, result))
+                .await;
+        });
+        Ok(())
+    }
+
+    fn is_updating(&self) -> bool {
+        let state = &self.update.state;
+        matches!(state, UpdateState::Checking | UpdateState::Downloading)
+    }
+
+    fn handle_update_event(&mut self, event: UpdateEvent) {
+        match event {
+            UpdateEvent::Checked(result) => {
+                let checked = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|time| time.as_secs())
+                    .unwrap_or_default();
+                self.update.last_checked = Some(checked);
+                self.update.state = self.get_resting_update_state();
+
+                match result {
+                    Ok(release) => {
+                        if let Some(release) = &release {
+                            info!("Update {} is available", release.version);
+                        }
+                        self.update.available = release.as_ref().map(Release::get_info);
+                        self.release = release;
+                    }
+                    Err(error) => {
+                        warn!("Unable to check for Updates: {:#}", error);
+                        self.update.state = UpdateState::Failed(format!("{:#}", error));
+                        return;
+                    }
+                }
+
+                // Download anything newer than what's already staged, if we've been asked to
+                let auto_download = self.device_settings.updates.auto_download;
+                if let Some(release) = &self.release {
+                    if auto_download && self.staged_version.as_ref() != Some(&release.version) {
+                        if let Err(error) = self.install_update() {
+                            warn!("Unable to download the Update: {}", error);
+                        }
+                    }
+                }
+            }
+            UpdateEvent::Downloaded(version, result) => match result {
+                Ok(()) => {
+                    self.staged_version = Some(version);
+                    self.update.state = UpdateState::Staged;
+                }
+                Err(error) => {
+                    warn!("Unable to download Update {}: {:#}", version, error);
+                    self.update.state = UpdateState::Failed(format!("{:#}", error));
+                }
+            },
+        }
+    }
+
+    /// The update state once nothing is running, which depends on whether anything is staged
+    fn get_resting_update_state(&self) -> UpdateState {
+        match is_update_staged(&self.device_settings.updates.directory) {
+            true => UpdateState::Staged,
+            false => UpdateState::Idle,
+        }
+    }
+
+    fn get_health(&self) -> HealthReport {
+        let mut running = 0;
+        let mut pending = BTreeMap::new();
+        for (location, lifecycle) in &self.lifecycles {
+            match lifecycle.state {
+                DeviceLifecycle::Running => running += 1,
+
+                // This device is on its way out, it shouldn't hold anything else up
+                DeviceLifecycle::Disconnected => {}
+                state => {
+                    let name = lifecycle.serial.clone();
+                    pending.insert(name.unwrap_or_else(|| location.to_string()), state);
+                }
+            }
+        }
+
+        HealthReport {
+            live: true,
+            ready: running > 0 && pending.is_empty(),
+            running,
+            pending,
+        }
+    }
+
+    /// Stores a Backup of every device's configuration, if automatic backups are enabled
+    fn auto_backup(&self, reason: &str) -> anyhow::Result<()> {
+        let Some(directory) = &self.device_settings.backup_dir else {
+            return Ok(());
+        };
+        if self.last_status.devices.is_empty() {
+            return Ok(());
+        }
+
+        let keep = self.device_settings.backup_keep;
+        let name = store_backup(directory, &self.create_backup(), reason, keep)?;
+        info!("Stored Backup {} before {}", name, reason);
+        Ok(())
+    }
+
+    // Restoring a Backup replaces everything, so it gets backed up first (even when restoring
+    // one of our own), and isn't restored at all if that fails.
+    async fn restore_with_backup(&mut self, backup: Backup) -> DaemonResponse {
+        if let Err(error) = self.auto_backup("restore") {
+            let error = format!("Unable to Backup the current configuration: {}", error);
+            return DaemonResponse::Err(error);
+        }
+        self.restore_backup(backup).await
+    }
+
+    fn create_backup(&self) -> Backup {
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        let devices = self.last_status.devices.iter();
+        let devices = devices.map(|(serial, status)| (serial.clone(), status.config.clone()));
+
+        let limits = self.last_status.devices.iter();
+        let limits = limits.map(|(serial, status)| (serial, &status.runtime));
+        let limits = limits.filter(|(_, runtime)| !runtime.headphone_limit_overridden);
+        let limits = limits.map(|(serial, runtime)| (serial.clone(), runtime.headphone_limit));
+
+        Backup {
+            version: BACKUP_VERSION,
+            created,
+            devices: devices.collect(),
+            settings: BackupSettings {
+                live_mode: Some(self.live_mode),
+                headphone_limits: limits.collect(),
+            },
+        }
+    }
+
+    async fn restore_backup(&mut self, backup: Backup) -> DaemonResponse {
+        if backup.version > BACKUP_VERSION {
+            let error = format!("Backup version {} is newer than supported", backup.version);
+            return DaemonResponse::Err(error);
+        }
+
+        let settings = backup.settings;
+        if let Some(live_mode) = settings.live_mode {
+            self.live_mode = live_mode;
+        }
+
+        let mut errors = vec![];
+        for (serial, config) in backup.devices {
+            let device = self
+                .serials
+                .get(&serial)
+                .and_then(|usb| self.states.get(usb));
+            let Some(device) = device else {
+                warn!("Device {} isn't attached, skipping its Backup", serial);
+                continue;
+            };
+
+            let command = ConfigurationCommand::Restore(Box::new(config));
+            let mut commands = vec![GoXLRCommand::Configuration(command)];
+            if let Some(limit) = settings.headphone_limits.get(&serial) {
+                let command = ChannelCommands::HeadphoneLimit(*limit);
+                commands.push(GoXLRCommand::Channels(command));
+            }
+
+            let mut error = None;
+            for command in commands {
+                let (cmd_tx, cmd_rx) = oneshot::channel();
+                if let Err(e) = device.messenger.send(Execute(command, cmd_tx)).await {
+                    error = Some(e.to_string());
+                    break;
+                }
+
+                match cmd_rx.await {
+                    Ok(GoXLRCommandResponse::Error(e)) => error = Some(e),
+                    Ok(_) => continue,
+                    Err(e) => error = Some(e.to_string()),
+                }
+                break;
+            }
+
+            match error {
+                Some(error) => errors.push(format!("{}: {}", serial, error)),
+                None => info!("Restored Backup to {}", serial),
+            }
+        }
+
+        if !errors.is_empty() {
+            let error = format!("Unable to restore Backup to {}", errors.join(", "));
+            return DaemonResponse::Err(error);
+        }
+        DaemonResponse::Ok
+    }
+
+    fn get_lifecycle_for_serial(&self, serial: &str) -> Option<DeviceLifecycle> {
+        self.lifecycles
+            .values()
+            .find(|lifecycle| lifecycle.serial.as_deref() == Some(serial))
+            .map(|lifecycle| lifecycle.state)
+    }
+
+    async fn handle_command(&mut self, command: DeviceMessage) -> bool {
         let mut update = false;
 
         match command {
             DeviceMessage::GetStatus(tx) => {
                 let _ = tx.send(self.last_status.clone());
             }
-            DeviceMessage::RunDaemon(_command, tx) => {
-                let _ = tx.send(DaemonResponse::Ok);
+            DeviceMessage::RunDaemon(command, force, tx) => {
+                let _ = tx.send(self.handle_daemon_command(command, force).await);
                 update = true;
             }
-            DeviceMessage::RunDevice(serial, command, tx) => {
+            DeviceMessage::RunDevice(serial, command, force, tx) => {
                 if let Some(usb) = self.serials.get(&*serial) {
                     if let Some(device) = self.states.get(usb) {
+                        if self.live_mode && !force {
+                            if let Some(reason) = get_live_mode_block(&command) {
+                                let reason = reason.to_string();
+                                let _ = tx.send(GoXLRCommandResponse::LiveModeBlocked(reason));
+                                return false;
+                            }
+                        }
+
+                        if let Some(reason) = get_backup_reason(&command) {
+                            if let Err(error) = self.auto_backup(reason) {
+                                let error =
+                                    format!("Unable to Backup before {}: {}", reason, error);
+                                let _ = tx.send(GoXLRCommandResponse::Error(error));
+                                return false;
+                            }
+                        }
+
                         let (cmd_tx, cmd_rx) = oneshot::channel();
 
                         let result = device.messenger.send(Execute(command, cmd_tx)).await;
@@ -395,23 +1124,137 @@ impl DeviceManager {
                             }
                         }
                     }
+                } else if let Some(state) = self.get_lifecycle_for_serial(&serial) {
+                    let error = format!(
+                        "Device {} is {:?}, commands can only be run while it's Running",
+                        serial, state
+                    );
+                    let _ = tx.send(GoXLRCommandResponse::Error(error));
                 } else {
                     let error = format!("Device {} not found", serial);
                     let _ = tx.send(GoXLRCommandResponse::Error(error));
                 }
                 update = true;
             }
+            DeviceMessage::DryRunDevice(serial, command, tx) => {
+                // Nothing gets applied, so neither Live Mode nor a Backup need to be involved
+                let device = self
+                    .serials
+                    .get(&*serial)
+                    .and_then(|usb| self.states.get(usb));
+                let Some(device) = device else {
+                    let error = format!("Device {} not found", serial);
+                    let _ = tx.send(GoXLRCommandResponse::Error(error));
+                    return false;
+                };
+
+                let (cmd_tx, cmd_rx) = oneshot::channel();
+                if let Err(e) = device.messenger.send(DryRun(command, cmd_tx)).await {
+                    let _ = tx.send(GoXLRCommandResponse::Error(e.to_string()));
+                    return false;
+                }
+
+                let response = match cmd_rx.await {
+                    Ok(result) => result,
+                    Err(error) => GoXLRCommandResponse::Error(error.to_string()),
+                };
+                let _ = tx.send(response);
+            }
         }
         update
     }
 }
 
+fn is_valid_transition(from: DeviceLifecycle, to: DeviceLifecycle) -> bool {
+    use DeviceLifecycle::*;
+
+    match from {
+        Discovered => matches!(to, Initialising | Degraded | Disconnected),
+        Initialising => matches!(to, Running | Degraded | Disconnected),
+        Running => matches!(to, Degraded | Disconnected),
+        Degraded => matches!(to, Initialising | Disconnected),
+
+        // The same port can have a device plugged back in before the old one finishes stopping
+        Disconnected => matches!(to, Discovered),
+    }
+}
+
+fn get_reconnect_delay(attempts: u32, max_delay: Duration) -> Duration {
+    let factor = 2_u32.saturating_pow(attempts);
+    RECONNECT_DELAY.saturating_mul(factor).min(max_delay)
+}
+
+/// Commands which replace or remove parts of a device's configuration, and what to call the
+/// Backup taken before they run
+fn get_backup_reason(command: &GoXLRCommand) -> Option<&'static str> {
+    match command {
+        GoXLRCommand::Configuration(command) => match command {
+            ConfigurationCommand::Restore(_) => Some("restore"),
+            ConfigurationCommand::ApplyState(_) => Some("apply-state"),
+            ConfigurationCommand::LoadProfile(_) => Some("import"),
+            ConfigurationCommand::RemoveRoutingRule(_) => Some("remove-routing-rule"),
+            _ => None,
+        },
+        GoXLRCommand::MixModes(MixModeCommand::Delete(_)) => Some("delete-mix-mode"),
+        GoXLRCommand::Pages(PageCommand::RemovePage(_)) => Some("remove-page"),
+        GoXLRCommand::Batch(commands) => commands.iter().find_map(get_backup_reason),
+        _ => None,
+    }
+}
+
+/// Device commands which could disrupt a stream, and why they're refused while in Live Mode
+fn get_live_mode_block(command: &GoXLRCommand) -> Option<&'static str> {
+    match command {
+        GoXLRCommand::Configuration(command) => match command {
+            ConfigurationCommand::ReloadProfile => Some("Reloading the Profile"),
+            ConfigurationCommand::Restore(_) => Some("Restoring a Configuration"),
+            ConfigurationCommand::ApplyState(_) => Some("Applying a Desired State"),
+            ConfigurationCommand::LoadProfile(_) => Some("Loading a Profile"),
+            _ => None,
+        },
+        GoXLRCommand::MixModes(MixModeCommand::Activate(_)) => Some("Activating a Mix Mode"),
+        GoXLRCommand::Batch(commands) => commands.iter().find_map(get_live_mode_block),
+        _ => None,
+    }
+}
+
+/// As above, for commands sent to the daemon itself
+fn get_daemon_live_mode_block(command: &DaemonCommand) -> Option<&'static str> {
+    match command {
+        DaemonCommand::RestoreBackup(_) | DaemonCommand::RestoreStoredBackup(_) => {
+            Some("Restoring a Backup")
+        }
+        _ => None,
+    }
+}
+
+// How many incidents to keep in the status before dropping the oldest
+const MAX_INCIDENTS: usize = 16;
+
+// How long to wait before the first reconnect, this doubles with each failed attempt
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+// How long a device needs to stay up before its reconnect backoff is reset
+const RECONNECT_STABLE: Duration = Duration::from_secs(30);
+
+// The Backup format this daemon writes, and the newest it can restore
+const BACKUP_VERSION: u32 = 2;
+
 pub async fn start_device_manager(
     message_receiver: mpsc::Receiver<DeviceMessage>,
     shutdown: Stop,
     broadcast_tx: Sender<PatchEvent>,
+    fader_tx: Sender<FaderMovement>,
+    device_settings: DeviceSettings,
+    log_directory: Option<PathBuf>,
 ) {
-    let mut manager = DeviceManager::new(shutdown, broadcast_tx);
+    let mut manager = DeviceManager::new(
+        shutdown,
+        broadcast_tx,
+        fader_tx,
+        device_settings,
+        log_directory,
+    );
     manager.run(message_receiver).await;
 }
 
@@ -419,19 +1262,35 @@ pub async fn start_device_manager(
 pub enum ManagerMessage {
     GetConfig(oneshot::Sender<Configuration>),
     GetDevice(oneshot::Sender<DeviceInfo>),
+    GetLoadReport(oneshot::Sender<ProfileLoadReport>),
+    GetRuntimeStatus(oneshot::Sender<RuntimeStatus>),
     Execute(GoXLRCommand, oneshot::Sender<GoXLRCommandResponse>),
+    DryRun(GoXLRCommand, oneshot::Sender<GoXLRCommandResponse>),
 }
 
 struct DeviceState {
     stop: Stop,
     state: RunnerState,
     messenger: mpsc::Sender<ManagerMessage>,
+    task: JoinHandle<()>,
+
+    // When the device last entered the Running state
+    running: Option<Instant>,
+}
+
+/// The results of update work the manager has started in the background
+enum UpdateEvent {
+    Checked(anyhow::Result<Option<Release>>),
+    Downloaded(Version, anyhow::Result<()>),
 }
 
 #[derive(Debug)]
 pub enum RunnerMessage {
     UpdateState(USBLocation, RunnerState),
     Error(USBLocation),
+    Hung(USBLocation, Duration),
+    Failing(USBLocation, u32),
+    ClaimFailed(USBLocation, DeviceClaimError),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
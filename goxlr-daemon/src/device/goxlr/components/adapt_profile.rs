@@ -0,0 +1,164 @@
+use anyhow::{Context, Result};
+use log::{debug, warn};
+use strum::IntoEnumIterator;
+
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::device::DeviceType;
+
+use crate::device::goxlr::components::hard_mute::HardMuteCrate;
+use crate::device::goxlr::device::GoXLR;
+
+/// Profiles aren't tied to a specific type of device, so before a profile is loaded it needs to
+/// be checked against the attached hardware. Anything the device can't handle is either ignored
+/// or dropped from the profile, and a warning is added to the load report. Timings are also
+/// checked against the tick interval, as anything which doesn't fit it will be rounded up.
+pub(crate) trait AdaptProfile {
+    fn adapt_profile(&mut self) -> Result<()>;
+}
+
+impl AdaptProfile for GoXLR {
+    fn adapt_profile(&mut self) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        let device_type = device.device_type;
+
+        debug!("Adapting Profile for {:?} device", device_type);
+        self.load_report = Default::default();
+
+        // The Full device supports everything that can be defined in a profile, so only the Mini
+        // needs adjusting.
+        if device_type == DeviceType::Mini {
+            self.adapt_scribbles();
+            self.adapt_effects();
+            self.adapt_sampler();
+        }
+        self.check_timings();
+        self.check_button_cycles();
+
+        // A restored or imported profile has its own idea of the Hard Mute, which isn't the
+        // device's
+        self.keep_hard_mute();
+
+        Ok(())
+    }
+}
+
+trait AdaptProfileLocal {
+    fn adapt_scribbles(&mut self);
+    fn adapt_effects(&mut self);
+    fn adapt_sampler(&mut self);
+    fn check_timings(&mut self);
+    fn check_button_cycles(&mut self);
+
+    fn add_skipped(&mut self, warning: &str);
+}
+
+impl AdaptProfileLocal for GoXLR {
+    fn adapt_scribbles(&mut self) {
+        // The Mini has no screens, fader.rs already skips them, so we simply let the user know.
+        let has_scribbles = FaderChannels::iter().any(|channel| {
+            let display = &self.profile.channels.configs[channel].display;
+            let screen = &display.screen_display;
+            screen.text.is_some() || screen.image.is_some() || screen.label.is_some()
+        });
+
+        if has_scribbles {
+            self.add_skipped("Scribbles are not available on the GoXLR Mini, ignoring");
+        }
+    }
+
+    fn adapt_effects(&mut self) {
+        // Effects are skipped by load_effects on the Mini, but they're kept in the profile so
+        // they're still present if it's later loaded on a Full device.
+        let effects = self.profile.effects;
+        let has_effects = effects.reverb.amount > 0
+            || effects.echo.amount > 0
+            || effects.pitch.amount != 0
+            || effects.gender.amount != 0
+            || effects.hard_tune.enabled;
+
+        if has_effects {
+            self.add_skipped("Effects are not available on the GoXLR Mini, ignoring");
+        }
+    }
+
+    fn adapt_sampler(&mut self) {
+        // There's no Sampler on the Mini, so any routes to it need to be removed, otherwise the
+        // routing table will be rejected.
+        let mut dropped = false;
+        for input in InputChannels::iter() {
+            if self.profile.routing[input][OutputChannels::Sampler] {
+                self.profile.routing[input][OutputChannels::Sampler] = false;
+                dropped = true;
+            }
+        }
+
+        if dropped {
+            self.add_skipped("The GoXLR Mini has no Sampler, removing Sampler routing");
+        }
+    }
+
+    fn check_timings(&mut self) {
+        let ducking = &self.profile.ducking;
+        let gate = &self.mic_profile.gate;
+
+        let mut timings = vec![
+            ("Ducking Attack", ducking.attack_time),
+            ("Ducking Release", ducking.release_time),
+            ("Gate Attack", u16::from(gate.attack) as u64),
+            ("Gate Release", u16::from(gate.release) as u64),
+        ];
+        let steps = [
+            ("Ducking Step", &ducking.transition.ducking),
+            ("Unducking Step", &ducking.transition.unducking),
+        ];
+        for (name, steps) in steps {
+            timings.extend(steps.iter().map(|step| (name, step.wait_time)));
+        }
+
+        // Timers move on a tick at a time, so they only finish on a multiple of the interval
+        let tick = self.timer_interval;
+        for (name, time) in timings {
+            if time % tick == 0 {
+                continue;
+            }
+
+            let actual = time.div_ceil(tick) * tick;
+            let warning = format!(
+                "{} of {}ms will take {}ms at a {}ms tick interval",
+                name, time, actual, tick
+            );
+            warn!("Profile Load: {}", warning);
+            if !self.load_report.timings.contains(&warning) {
+                self.load_report.timings.push(warning);
+            }
+        }
+    }
+
+    fn check_button_cycles(&mut self) {
+        for button in Buttons::iter() {
+            let Some(cycle) = &mut self.profile.button_cycles[button] else {
+                continue;
+            };
+
+            // A cycle with nothing to step through can't do anything, so it's dropped
+            if cycle.steps.is_empty() {
+                self.profile.button_cycles[button] = None;
+                let warning = format!("The Button Cycle on {:?} has no steps, removing", button);
+                self.add_skipped(&warning);
+                continue;
+            }
+
+            if cycle.current >= cycle.steps.len() {
+                cycle.current = 0;
+            }
+        }
+    }
+
+    fn add_skipped(&mut self, warning: &str) {
+        warn!("Profile Load: {}", warning);
+        self.load_report.skipped.push(warning.to_string());
+    }
+}
@@ -0,0 +1,141 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use log::{debug, info, warn};
+
+use goxlr_ipc::commands::lighting::{FlashPattern, FlashTarget};
+use goxlr_shared::colours::{Colour, ColourScheme, TwoColourTargets};
+
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::device::GoXLR;
+
+// Flashes are for grabbing attention, not for replacing the lighting
+const MAX_DURATION: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub(crate) struct AlertFlashes {
+    active: Vec<ActiveFlash>,
+}
+
+struct ActiveFlash {
+    target: FlashTarget,
+    colour: Colour,
+    pattern: FlashPattern,
+    started: Instant,
+    duration: Duration,
+
+    // Whether the colour is currently being shown, for blinking patterns
+    lit: bool,
+}
+
+impl ActiveFlash {
+    fn is_lit(&self) -> bool {
+        let interval = match self.pattern {
+            FlashPattern::Solid => return true,
+            FlashPattern::Blink => 500,
+            FlashPattern::FastBlink => 150,
+        };
+        (self.started.elapsed().as_millis() / interval) % 2 == 0
+    }
+}
+
+/// Alerts sit above every lighting layer, but only cover the lights they're flashing, so the
+/// rest of the device carries on as normal. Once a flash ends, the light simply shows whatever
+/// the layers below hold.
+pub(crate) trait AlertFlash {
+    async fn flash_button(
+        &mut self,
+        target: FlashTarget,
+        colour: Colour,
+        duration: u64,
+        pattern: FlashPattern,
+    ) -> Result<()>;
+}
+
+impl AlertFlash for GoXLR {
+    async fn flash_button(
+        &mut self,
+        target: FlashTarget,
+        colour: Colour,
+        duration: u64,
+        pattern: FlashPattern,
+    ) -> Result<()> {
+        if !self.config.settings.lighting {
+            bail!("Lighting has been disabled");
+        }
+
+        let duration = Duration::from_millis(duration);
+        if duration.is_zero() || duration > MAX_DURATION {
+            bail!("Flashes must last between 1ms and {:?}", MAX_DURATION);
+        }
+
+        info!("Flashing {:?} {:?} for {:?}", target, pattern, duration);
+        self.alert_flashes
+            .active
+            .retain(|flash| flash.target != target);
+        self.alert_flashes.active.push(ActiveFlash {
+            target,
+            colour,
+            pattern,
+            started: Instant::now(),
+            duration,
+            lit: true,
+        });
+        self.apply_colours().await
+    }
+}
+
+pub(crate) trait AlertFlashCrate {
+    /// Places any lit flashes over the top of a composed scheme
+    fn apply_alert_flashes(&self, scheme: &mut ColourScheme);
+
+    /// Called on every tick, moves blinking flashes along, and removes those which have ended
+    async fn handle_alert_flashes(&mut self);
+}
+
+impl AlertFlashCrate for GoXLR {
+    fn apply_alert_flashes(&self, scheme: &mut ColourScheme) {
+        for flash in self.alert_flashes.active.iter().filter(|flash| flash.lit) {
+            let colour = flash.colour;
+            match flash.target {
+                FlashTarget::Button(button) => {
+                    // Both colours are set, so the button shows the flash whatever its state
+                    let target = scheme.get_two_colour_target(TwoColourTargets::from(button));
+                    target.colour1 = colour;
+                    target.colour2 = colour;
+                }
+                FlashTarget::Strip(fader) => {
+                    let target = scheme.get_fader_target(fader);
+                    target.colour1 = colour;
+                    target.colour2 = colour;
+                }
+            }
+        }
+    }
+
+    async fn handle_alert_flashes(&mut self) {
+        if self.alert_flashes.active.is_empty() {
+            return;
+        }
+
+        let mut changed = false;
+        self.alert_flashes.active.retain_mut(|flash| {
+            if flash.started.elapsed() >= flash.duration {
+                debug!("Flash on {:?} Ended", flash.target);
+                changed = true;
+                return false;
+            }
+
+            let lit = flash.is_lit();
+            changed |= lit != flash.lit;
+            flash.lit = lit;
+            true
+        });
+
+        if changed {
+            if let Err(error) = self.apply_colours().await {
+                warn!("Unable to update Alert Flashes: {}", error);
+            }
+        }
+    }
+}
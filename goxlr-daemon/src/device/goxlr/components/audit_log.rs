@@ -0,0 +1,144 @@
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use json_patch::{diff, PatchOperation};
+use log::warn;
+use serde_json::{json, Value};
+
+use goxlr_ipc::commands::diagnostics::{AuditEntry, AuditFilter, AuditOrigin};
+
+use crate::device::goxlr::device::GoXLR;
+
+// How many entries to keep in memory for querying, the file (if enabled) keeps everything.
+const MAX_ENTRIES: usize = 1000;
+
+// Repeated changes from the same trigger within this time are folded into a single entry, so
+// dragging a fader doesn't push everything else out of the log.
+const COALESCE_MS: u64 = 2000;
+
+/// A record of what changed the configuration, and when. Each change is also appended to a file
+/// if an audit directory is configured.
+#[derive(Debug, Default)]
+pub(crate) struct AuditLog {
+    entries: VecDeque<AuditEntry>,
+}
+
+pub(crate) trait AuditLogging {
+    /// Takes a copy of the configuration, to compare against once a change has been handled
+    fn audit_snapshot(&self) -> Option<Value>;
+
+    /// Records anything that's changed since the snapshot was taken
+    fn audit_changes(&mut self, origin: AuditOrigin, trigger: String, before: Option<Value>);
+    fn get_audit_log(&self, filter: AuditFilter) -> Vec<AuditEntry>;
+}
+
+impl AuditLogging for GoXLR {
+    fn audit_snapshot(&self) -> Option<Value> {
+        // Matches the layout of the Configuration in the DeviceStatus
+        Some(json!({
+            "device": serde_json::to_value(&self.profile).ok()?,
+            "mic_profile": serde_json::to_value(&self.mic_profile).ok()?,
+        }))
+    }
+
+    fn audit_changes(&mut self, origin: AuditOrigin, trigger: String, before: Option<Value>) {
+        let (Some(before), Some(after)) = (before, self.audit_snapshot()) else {
+            return;
+        };
+
+        let patch = diff(&before, &after);
+        if patch.0.is_empty() {
+            return;
+        }
+
+        let changes: Vec<String> = patch.0.iter().map(get_path).collect();
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_millis() as u64)
+            .unwrap_or_default();
+
+        if let Some(last) = self.audit_log.entries.back_mut() {
+            let recent = timestamp.saturating_sub(last.timestamp) < COALESCE_MS;
+            if recent && last.trigger == trigger && last.changes == changes {
+                last.timestamp = timestamp;
+                return;
+            }
+        }
+
+        let entry = AuditEntry {
+            timestamp,
+            origin,
+            trigger,
+            changes,
+        };
+
+        if let Some(path) = self.get_audit_path() {
+            if let Err(error) = append_entry(path, &entry) {
+                warn!("Unable to write to the Audit Log: {}", error);
+            }
+        }
+
+        if self.audit_log.entries.len() >= MAX_ENTRIES {
+            self.audit_log.entries.pop_front();
+        }
+        self.audit_log.entries.push_back(entry);
+    }
+
+    fn get_audit_log(&self, filter: AuditFilter) -> Vec<AuditEntry> {
+        let mut entries: Vec<AuditEntry> = self
+            .audit_log
+            .entries
+            .iter()
+            .filter(|entry| filter.origin.map_or(true, |origin| entry.origin == origin))
+            .filter(|entry| filter.since.map_or(true, |since| entry.timestamp >= since))
+            .filter(|entry| match &filter.setting {
+                Some(setting) => entry.changes.iter().any(|c| c.starts_with(setting)),
+                None => true,
+            })
+            .cloned()
+            .collect();
+
+        if let Some(limit) = filter.limit {
+            let skip = entries.len().saturating_sub(limit);
+            entries.drain(..skip);
+        }
+        entries
+    }
+}
+
+trait AuditLoggingLocal {
+    fn get_audit_path(&self) -> Option<PathBuf>;
+}
+
+impl AuditLoggingLocal for GoXLR {
+    fn get_audit_path(&self) -> Option<PathBuf> {
+        let directory = self.config.settings.audit_log_dir.as_ref()?;
+        let device = self.device.as_ref()?;
+        Some(directory.join(format!("{}-audit.log", device.serial)))
+    }
+}
+
+pub(crate) fn get_path(operation: &PatchOperation) -> String {
+    match operation {
+        PatchOperation::Add(op) => op.path.clone(),
+        PatchOperation::Remove(op) => op.path.clone(),
+        PatchOperation::Replace(op) => op.path.clone(),
+        PatchOperation::Move(op) => op.path.clone(),
+        PatchOperation::Copy(op) => op.path.clone(),
+        PatchOperation::Test(op) => op.path.clone(),
+    }
+}
+
+fn append_entry(path: PathBuf, entry: &AuditEntry) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    Ok(())
+}
@@ -0,0 +1,309 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{bail, Context, Result};
+use enum_map::EnumMap;
+use log::{debug, info, warn};
+use strum::IntoEnumIterator;
+
+use goxlr_ipc::commands::automation::{Automation, AutomationChange, AutomationEvent};
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
+
+use crate::device::goxlr::components::adapt_profile::AdaptProfile;
+use crate::device::goxlr::components::channel::Channels;
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::components::profile_storage::ProfileStorageCrate;
+use crate::device::goxlr::device::GoXLR;
+
+// The newest file format we can play
+const AUTOMATION_VERSION: u32 = 1;
+
+// Changes are sampled every tick, this keeps a forgotten recording from growing forever
+const MAX_EVENTS: usize = 20000;
+
+type Routing = EnumMap<InputChannels, EnumMap<OutputChannels, bool>>;
+
+#[derive(Default)]
+pub(crate) struct AutomationState {
+    recording: Option<Recording>,
+    playing: Option<Playback>,
+}
+
+struct Recording {
+    started: Instant,
+    events: Vec<AutomationEvent>,
+
+    // What was last recorded, changes are found by comparing the profile against these
+    volumes: EnumMap<VolumeChannels, u8>,
+    routing: Routing,
+}
+
+struct Playback {
+    name: String,
+    started: Instant,
+    events: VecDeque<AutomationEvent>,
+}
+
+/// Records the volumes and routing as they change, and plays them back later on the same
+/// timeline, so a sequence (such as music fading in as a stream starts) can be performed once
+/// and repeated. Recording compares the profile each tick, so changes from the faders, clients
+/// and Mix Modes are all captured the same way.
+pub(crate) trait Automations {
+    fn start_automation_recording(&mut self) -> Result<()>;
+    fn stop_automation_recording(&mut self, name: String) -> Result<()>;
+    fn play_automation(&mut self, name: String) -> Result<()>;
+    fn stop_automation_playback(&mut self);
+    fn list_automations(&self) -> Result<Vec<String>>;
+}
+
+impl Automations for GoXLR {
+    fn start_automation_recording(&mut self) -> Result<()> {
+        if self.automation.recording.is_some() {
+            bail!("An Automation is already being recorded");
+        }
+
+        // Everything starts as it currently is, so a playback always begins from the same place
+        let volumes = self.profile.channels.volumes;
+        let routing = self.profile.routing;
+        let mut events = vec![];
+        for (channel, volume) in volumes {
+            let change = AutomationChange::Volume(channel, volume);
+            events.push(AutomationEvent { at: 0, change });
+        }
+        for (input, outputs) in routing {
+            for (output, enabled) in outputs {
+                let change = AutomationChange::Route(input, output, enabled);
+                events.push(AutomationEvent { at: 0, change });
+            }
+        }
+
+        info!("Recording Automation..");
+        self.automation.recording = Some(Recording {
+            started: Instant::now(),
+            events,
+            volumes,
+            routing,
+        });
+        Ok(())
+    }
+
+    fn stop_automation_recording(&mut self, name: String) -> Result<()> {
+        check_name(&name)?;
+
+        // Catch anything which has changed since the last tick
+        self.record_automation_changes();
+        let recording = self.automation.recording.take();
+        let recording = recording.context("No Automation is being recorded")?;
+
+        let automation = Automation {
+            version: AUTOMATION_VERSION,
+            events: recording.events,
+        };
+
+        let directory = &self.config.settings.automation_dir;
+        fs::create_dir_all(directory).context("Unable to create the Automation directory")?;
+
+        let path = get_automation_path(directory, &name);
+        let temp = path.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_string_pretty(&automation)?)?;
+        fs::rename(&temp, &path)?;
+
+        info!(
+            "Stored Automation {} ({} events)",
+            name,
+            automation.events.len()
+        );
+        Ok(())
+    }
+
+    fn play_automation(&mut self, name: String) -> Result<()> {
+        check_name(&name)?;
+
+        let path = get_automation_path(&self.config.settings.automation_dir, &name);
+        let json =
+            fs::read_to_string(&path).with_context(|| format!("Automation {} not found", name))?;
+        let automation: Automation = serde_json::from_str(&json)
+            .with_context(|| format!("Automation {} is invalid", name))?;
+
+        if automation.version > AUTOMATION_VERSION {
+            bail!("Automation {} is from a newer version of the daemon", name);
+        }
+
+        // Files can be written by hand, so don't rely on them being in order
+        let mut events = automation.events;
+        events.sort_by_key(|event| event.at);
+
+        info!("Playing Automation {}", name);
+        self.automation.playing = Some(Playback {
+            name,
+            started: Instant::now(),
+            events: events.into(),
+        });
+        Ok(())
+    }
+
+    fn stop_automation_playback(&mut self) {
+        if let Some(playback) = self.automation.playing.take() {
+            info!("Stopped Automation {}", playback.name);
+        }
+    }
+
+    fn list_automations(&self) -> Result<Vec<String>> {
+        let directory = &self.config.settings.automation_dir;
+        if !directory.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut names = vec![];
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+        names.sort_unstable();
+        Ok(names)
+    }
+}
+
+pub(crate) trait AutomationsCrate {
+    /// Called on every tick, records what's changed, and plays anything which is due
+    async fn handle_automation(&mut self);
+}
+
+impl AutomationsCrate for GoXLR {
+    async fn handle_automation(&mut self) {
+        self.record_automation_changes();
+
+        if self.automation.playing.is_some() {
+            if let Err(error) = self.run_automation_playback().await {
+                warn!("Unable to play Automation, stopping: {}", error);
+                self.automation.playing = None;
+            }
+        }
+    }
+}
+
+trait AutomationsLocal {
+    fn record_automation_changes(&mut self);
+    async fn run_automation_playback(&mut self) -> Result<()>;
+}
+
+impl AutomationsLocal for GoXLR {
+    fn record_automation_changes(&mut self) {
+        let volumes = self.profile.channels.volumes;
+        let routing = self.profile.routing;
+        let Some(recording) = &mut self.automation.recording else {
+            return;
+        };
+
+        if recording.events.len() >= MAX_EVENTS {
+            return;
+        }
+
+        let at = recording.started.elapsed().as_millis() as u64;
+        let mut changes = vec![];
+        for channel in VolumeChannels::iter() {
+            if recording.volumes[channel] != volumes[channel] {
+                changes.push(AutomationChange::Volume(channel, volumes[channel]));
+            }
+        }
+        for input in InputChannels::iter() {
+            for output in OutputChannels::iter() {
+                let enabled = routing[input][output];
+                if recording.routing[input][output] != enabled {
+                    changes.push(AutomationChange::Route(input, output, enabled));
+                }
+            }
+        }
+
+        recording.volumes = volumes;
+        recording.routing = routing;
+        recording.events.extend(
+            changes
+                .into_iter()
+                .map(|change| AutomationEvent { at, change }),
+        );
+
+        if recording.events.len() >= MAX_EVENTS {
+            warn!("Automation Recording is full, further changes won't be recorded");
+        }
+    }
+
+    async fn run_automation_playback(&mut self) -> Result<()> {
+        let Some(playback) = &mut self.automation.playing else {
+            return Ok(());
+        };
+
+        let elapsed = playback.started.elapsed().as_millis() as u64;
+        let mut due = vec![];
+        while let Some(event) = playback.events.pop_front() {
+            if event.at > elapsed {
+                playback.events.push_front(event);
+                break;
+            }
+            due.push(event.change);
+        }
+
+        if playback.events.is_empty() {
+            info!("Automation {} Finished", playback.name);
+            self.automation.playing = None;
+        }
+
+        let (mut changed, mut reload) = (false, false);
+        for change in due {
+            match change {
+                AutomationChange::Volume(channel, volume) => {
+                    if self.profile.channels.volumes[channel] != volume {
+                        debug!("Automation setting {:?} Volume to {}", channel, volume);
+                        self.set_channel_volume(channel, volume).await?;
+                        changed = true;
+                    }
+                }
+                AutomationChange::Route(input, output, enabled) => {
+                    if self.profile.routing[input][output] != enabled {
+                        debug!(
+                            "Automation Routing {:?} to {:?}: {}",
+                            input, output, enabled
+                        );
+                        self.profile.routing[input][output] = enabled;
+                        reload = true;
+                    }
+                }
+            }
+        }
+
+        // Routing changes are applied by reloading the profile, the same as a Mix Mode, so any
+        // mute states are correctly put back over the new routing.
+        if reload {
+            self.adapt_profile()?;
+            self.load_profile().await?;
+        }
+
+        if changed || reload {
+            self.mark_profile_changed();
+            self.send_device_update().await;
+        }
+        Ok(())
+    }
+}
+
+fn check_name(name: &str) -> Result<()> {
+    // Names become file names, so keep them to something that can't leave the directory
+    let valid = |c: char| c.is_alphanumeric() || c == '-' || c == '_' || c == ' ';
+    if name.is_empty() || !name.chars().all(valid) {
+        bail!("Automation names can only contain letters, numbers, spaces, - and _");
+    }
+    Ok(())
+}
+
+fn get_automation_path(directory: &Path, name: &str) -> PathBuf {
+    directory.join(format!("{}.json", name))
+}
@@ -0,0 +1,115 @@
+use anyhow::{anyhow, bail, Result};
+use log::{debug, warn};
+
+use goxlr_ipc::commands::{GoXLRCommand, GoXLRCommandResponse};
+
+use crate::device::goxlr::components::dry_run::{get_dry_run_block, DryRun, DryRunCrate};
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::IPCCommandHandler;
+
+/// Applies a set of commands as one. The batch is Dry Run first, so a command failing validation
+/// stops the batch before anything is applied. The device loop handles the batch as a single
+/// command, so there's only one status update and profile save once it's done.
+pub(crate) trait BatchCommands {
+    async fn run_batch(&mut self, commands: Vec<GoXLRCommand>) -> Result<GoXLRCommandResponse>;
+}
+
+impl BatchCommands for GoXLR {
+    async fn run_batch(&mut self, commands: Vec<GoXLRCommand>) -> Result<GoXLRCommandResponse> {
+        if let Some(reason) = commands.iter().find_map(get_dry_run_block) {
+            bail!("{} can't be part of a Batch", reason);
+        }
+
+        debug!("Validating Batch of {} Commands", commands.len());
+        self.dry_run_ipc_command(GoXLRCommand::Batch(commands.clone()))
+            .await?;
+
+        let snapshot = self.take_snapshot();
+        let mut responses = vec![];
+        for (index, command) in commands.into_iter().enumerate() {
+            match self.handle_single_command(command).await {
+                Ok(response) => responses.push(response),
+                Err(error) => {
+                    // Validation passed, so this is most likely the device going away, put the
+                    // earlier commands back so the device isn't left half way through the batch
+                    warn!("Batch Command {} failed, rolling back", index + 1);
+                    self.restore_snapshot(snapshot);
+                    if let Err(error) = self.load_profile().await {
+                        warn!("Unable to roll back Batch: {}", error);
+                    }
+                    return Err(anyhow!("Command {} failed: {}", index + 1, error));
+                }
+            }
+        }
+        Ok(GoXLRCommandResponse::Batch(responses))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use clap::Parser;
+    use tokio::sync::{broadcast, mpsc};
+
+    use goxlr_ipc::commands::mic::MicrophoneCommand;
+    use goxlr_ipc::commands::GoXLRCommand;
+    use goxlr_shared::microphone::MicEffectKeys;
+    use goxlr_usb::events::commands::{BasicResultCommand, CommandSender};
+    use goxlr_usb::mock::MockDevice;
+
+    use crate::cli::Cli;
+    use crate::device::goxlr::device::GoXLR;
+    use crate::device::goxlr::device_config::GoXLRDeviceConfiguration;
+    use crate::device::goxlr::ipc::handler::IPCCommandHandler;
+    use crate::stop::Stop;
+    use crate::storage::defaults::DefaultProfileStore;
+
+    #[tokio::test]
+    async fn batched_hard_mute_is_sent() {
+        let (manager_sender, _manager_recv) = mpsc::channel(1);
+        let (_device_sender, manager_recv) = mpsc::channel(1);
+        let config = GoXLRDeviceConfiguration {
+            stop: Stop::new(),
+            device: MockDevice::attach(0, "BATCH").location(),
+            update_sender: mpsc::channel(1).0,
+            manager_sender,
+            manager_recv,
+            fader_events: broadcast::channel(1).0,
+            settings: Cli::parse_from(["goxlr-daemon"]).device_settings(),
+            profile_store: Arc::new(DefaultProfileStore),
+        };
+
+        // Accept everything sent to the 'device', and keep hold of it
+        let (command_sender, mut command_recv) = mpsc::channel(32);
+        let usb = tokio::spawn(async move {
+            let mut sent = vec![];
+            while let Some(message) = command_recv.recv().await {
+                if let CommandSender::BasicResultCommand(command, result) = message {
+                    sent.push(command);
+                    let _ = result.send(Ok(()));
+                }
+            }
+            sent
+        });
+
+        let mut device = GoXLR::new(config, Stop::new());
+        device.command_sender = Some(command_sender);
+
+        let batch = vec![GoXLRCommand::Microphone(MicrophoneCommand::HardMute(true))];
+        let result = device.handle_ipc_command(GoXLRCommand::Batch(batch)).await;
+        assert!(result.is_ok(), "Batch Failed: {:?}", result.err());
+        drop(device);
+
+        // The validation pass mustn't leave the Hard Mute looking engaged, or it's never sent
+        let sent = usb.await.unwrap();
+        let muted = sent.iter().any(|command| match command {
+            BasicResultCommand::SetMicEffects(effects) => {
+                effects.get(&MicEffectKeys::MicInputMute) == Some(&1)
+            }
+            _ => false,
+        });
+        assert!(muted, "Mic Mute wasn't sent: {:?}", sent);
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::{bail, Context, Result};
+use log::debug;
+use strum::IntoEnumIterator;
+
+use goxlr_profile::{ButtonCycle, ButtonCycleAction};
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::colours::TwoColourTargets;
+use goxlr_shared::states::State;
+
+use crate::device::goxlr::components::adapt_profile::AdaptProfile;
+use crate::device::goxlr::components::buttons::ButtonHandlers;
+use crate::device::goxlr::components::channel::Channels;
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::components::mute_handler::MuteHandler;
+use crate::device::goxlr::device::GoXLR;
+
+/// A Button Cycle replaces a button's behaviour with a list of steps, each press applies the
+/// next step's actions and shows its colour, so a single button can (for example) step Music
+/// through full volume, half volume and muted.
+pub(crate) trait ButtonCycles {
+    async fn set_button_cycle(&mut self, button: Buttons, cycle: Option<ButtonCycle>)
+        -> Result<()>;
+    async fn next_button_cycle_step(&mut self, button: Buttons) -> Result<()>;
+}
+
+impl ButtonCycles for GoXLR {
+    async fn set_button_cycle(
+        &mut self,
+        button: Buttons,
+        cycle: Option<ButtonCycle>,
+    ) -> Result<()> {
+        if let Some(cycle) = &cycle {
+            if cycle.steps.is_empty() {
+                bail!("A Button Cycle needs at least one step");
+            }
+            if cycle.current >= cycle.steps.len() {
+                bail!("Current step {} doesn't exist", cycle.current);
+            }
+        }
+
+        debug!("Setting Button Cycle for {:?}: {:?}", button, cycle);
+        let removed = cycle.is_none() && self.is_button_cycle(button);
+        self.profile.button_cycles[button] = cycle;
+
+        // The cycle replaced the button's own state and colours, a reload puts those back
+        if removed {
+            return self.load_profile().await;
+        }
+
+        self.set_button_cycle_states();
+        self.load_button_cycle_colours();
+
+        self.apply_button_states().await?;
+        self.apply_colours().await
+    }
+
+    async fn next_button_cycle_step(&mut self, button: Buttons) -> Result<()> {
+        let cycle = self.profile.button_cycles[button].as_mut();
+        let cycle = cycle.with_context(|| format!("No Button Cycle on {:?}", button))?;
+        if cycle.steps.is_empty() {
+            bail!("The Button Cycle on {:?} has no steps", button);
+        }
+
+        cycle.current = (cycle.current + 1) % cycle.steps.len();
+        let step = cycle.steps[cycle.current].clone();
+        debug!("Button Cycle {:?} moving to step {}", button, cycle.current);
+
+        let mut reload = false;
+        for action in step.actions {
+            match action {
+                ButtonCycleAction::Volume(channel, volume) => {
+                    self.set_channel_volume(channel, volume).await?;
+                }
+                ButtonCycleAction::Mute(channel, state) => {
+                    self.set_mute_state(channel, state).await?;
+                }
+                ButtonCycleAction::Route(input, output, enabled) => {
+                    if self.profile.routing[input][output] != enabled {
+                        self.profile.routing[input][output] = enabled;
+                        reload = true;
+                    }
+                }
+            }
+        }
+
+        // As with Mix Modes, routing changes reload the profile so mutes are put back on top
+        if reload {
+            self.adapt_profile()?;
+            return self.load_profile().await;
+        }
+
+        self.load_button_cycle_colours();
+        self.apply_colours().await
+    }
+}
+
+pub(crate) trait ButtonCyclesCrate {
+    fn is_button_cycle(&self, button: Buttons) -> bool;
+
+    /// Lights every cycling button, called while building the button states
+    fn set_button_cycle_states(&mut self);
+
+    /// Places each cycling button's current step colour into the colour scheme
+    fn load_button_cycle_colours(&mut self);
+}
+
+impl ButtonCyclesCrate for GoXLR {
+    fn is_button_cycle(&self, button: Buttons) -> bool {
+        self.profile.button_cycles[button].is_some()
+    }
+
+    fn set_button_cycle_states(&mut self) {
+        for button in Buttons::iter() {
+            if self.is_button_cycle(button) {
+                self.button_states.set_state(button, State::Colour1);
+            }
+        }
+    }
+
+    fn load_button_cycle_colours(&mut self) {
+        for button in Buttons::iter() {
+            let Some(cycle) = &self.profile.button_cycles[button] else {
+                continue;
+            };
+            let Some(step) = cycle.steps.get(cycle.current) else {
+                continue;
+            };
+
+            let target = TwoColourTargets::from(button);
+            let colours = self.colour_scheme.get_two_colour_target(target);
+            colours.colour1 = step.colour;
+            colours.colour2 = step.colour;
+        }
+    }
+}
@@ -1,11 +1,16 @@
 use anyhow::{Context, Result};
 use goxlr_shared::channels::CanFrom;
 use log::debug;
+use strum::IntoEnumIterator;
 
+use crate::device::goxlr::components::fader::DeviceFader;
+use crate::device::goxlr::components::headphone_limit::HeadphoneLimitCrate;
 use crate::device::goxlr::components::submix::SubMix;
+use goxlr_shared::channels::fader::FaderChannels;
 use goxlr_shared::channels::sub_mix::SubMixChannels;
 use goxlr_shared::channels::volume::VolumeChannels;
 use goxlr_shared::device::GoXLRFeature;
+use goxlr_shared::volume::Volume;
 use goxlr_usb::events::commands::BasicResultCommand;
 
 use crate::device::goxlr::device::GoXLR;
@@ -14,6 +19,14 @@ pub(crate) trait Channels {
     /// Sets and applies a Channel Volume in the Profile
     async fn set_channel_volume(&mut self, source: VolumeChannels, volume: u8) -> Result<()>;
 
+    /// Sets a Channel Volume at a higher resolution than the device's scale, the profile holds
+    /// the nearest value the device can use.
+    async fn set_channel_volume_precise(
+        &mut self,
+        source: VolumeChannels,
+        volume: Volume,
+    ) -> Result<()>;
+
     /// Applies a volume as set in the Profile
     async fn apply_channel_volume(&mut self, source: VolumeChannels) -> Result<()>;
 
@@ -24,10 +37,22 @@ pub(crate) trait Channels {
 impl Channels for GoXLR {
     async fn set_channel_volume(&mut self, source: VolumeChannels, volume: u8) -> Result<()> {
         self.profile.channels.volumes[source] = volume;
+        self.precise_volumes[source] = Volume::from_hardware(volume);
+        self.apply_channel_volume(source).await
+    }
+
+    async fn set_channel_volume_precise(
+        &mut self,
+        source: VolumeChannels,
+        volume: Volume,
+    ) -> Result<()> {
+        self.profile.channels.volumes[source] = volume.to_hardware();
+        self.precise_volumes[source] = volume;
         self.apply_channel_volume(source).await
     }
 
     async fn apply_channel_volume(&mut self, source: VolumeChannels) -> Result<()> {
+        self.enforce_headphone_limit(source);
         let volume = self.profile.channels.volumes[source];
 
         debug!("Setting Volume for {:?} from to {:?}", source, volume);
@@ -38,7 +63,7 @@ impl Channels for GoXLR {
             self.sync_sub_mix_volume(source.into()).await?;
         }
 
-        Ok(())
+        self.refresh_linked_colours(source).await
     }
 
     async fn sync_mix_volume(&mut self, source: VolumeChannels) -> Result<()> {
@@ -66,4 +91,36 @@ impl Channels for GoXLR {
     }
 }
 
-pub(crate) trait ChannelsCrate {}
+pub(crate) trait ChannelsCrate {
+    /// The Channel Volume at full resolution, for anything which builds on it (such as fades)
+    fn get_channel_volume(&self, source: VolumeChannels) -> Volume;
+
+    /// Redraws the channel's fader if its lighting is linked to the volume
+    async fn refresh_linked_colours(&mut self, source: VolumeChannels) -> Result<()>;
+}
+
+impl ChannelsCrate for GoXLR {
+    fn get_channel_volume(&self, source: VolumeChannels) -> Volume {
+        // If the volume has been changed on the device's scale since (for example, by a fader
+        // or a profile load), the precise volume no longer applies.
+        let volume = self.profile.channels.volumes[source];
+        let precise = self.precise_volumes[source];
+        match precise.to_hardware() == volume {
+            true => precise,
+            false => Volume::from_hardware(volume),
+        }
+    }
+
+    async fn refresh_linked_colours(&mut self, source: VolumeChannels) -> Result<()> {
+        if !self.profile.lighting.channel_linked {
+            return Ok(());
+        }
+
+        let channel =
+            FaderChannels::iter().find(|&channel| VolumeChannels::from(channel) == source);
+        match channel {
+            Some(channel) => self.refresh_fader_colours(channel).await,
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use log::debug;
+
+use goxlr_shared::colours::ColourScheme;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::components::lighting::LightingCrate;
+use crate::device::goxlr::device::GoXLR;
+
+/// The colour scheme is double-buffered, components write into `colour_scheme` (the back
+/// buffer) or a lighting layer as they change, and only a committed scheme is ever sent to the
+/// device. When buffering is enabled, the commit happens once per tick, so several components
+/// updating the colours at once are always seen by the device as a single, complete change.
+#[derive(Clone, Default)]
+pub(crate) struct ColourBuffer {
+    // The scheme the device is currently showing, None if it isn't known
+    front: Option<ColourScheme>,
+
+    // Whether the back buffer has changes waiting to be committed
+    pending: bool,
+}
+
+pub(crate) trait ColourBufferCrate {
+    /// Marks the back buffer as ready to be sent on the next commit
+    fn queue_colours(&mut self);
+
+    /// Sends the back buffer to the device, if there's anything waiting and it's changed
+    async fn commit_colours(&mut self) -> Result<()>;
+}
+
+impl ColourBufferCrate for GoXLR {
+    fn queue_colours(&mut self) {
+        self.colour_buffer.pending = true;
+    }
+
+    async fn commit_colours(&mut self) -> Result<()> {
+        if !self.colour_buffer.pending {
+            return Ok(());
+        }
+        self.colour_buffer.pending = false;
+
+        if !self.config.settings.lighting {
+            return Ok(());
+        }
+
+        let scheme = self.get_display_scheme();
+        if self.colour_buffer.front == Some(scheme) {
+            return Ok(());
+        }
+
+        debug!("Applying Colour Scheme..");
+        self.send_no_result(BasicResultCommand::SetColour(scheme))
+            .await?;
+        self.colour_buffer.front = Some(scheme);
+        Ok(())
+    }
+}
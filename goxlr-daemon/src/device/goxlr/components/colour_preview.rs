@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use log::{debug, info, warn};
+
+use goxlr_shared::colours::ColourScheme;
+
+use crate::device::goxlr::components::lighting_layers::{LightingLayer, LightingLayersCrate};
+use crate::device::goxlr::device::GoXLR;
+
+// A preview is for trying colours out, if the client forgets about it the profile comes back
+const MAX_DURATION: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub(crate) struct ColourPreview {
+    until: Option<Instant>,
+}
+
+/// Shows a colour scheme on the device without touching the profile, such as while the user is
+/// dragging around a colour picker. Each preview replaces the last and restarts the timer, once
+/// it runs out (or the preview is cancelled) the profile's colours are revealed again.
+pub(crate) trait ColourPreviewing {
+    async fn preview_colours(&mut self, scheme: ColourScheme, duration: u64) -> Result<()>;
+    async fn cancel_colour_preview(&mut self) -> Result<()>;
+}
+
+impl ColourPreviewing for GoXLR {
+    async fn preview_colours(&mut self, scheme: ColourScheme, duration: u64) -> Result<()> {
+        if !self.config.settings.lighting {
+            bail!("Lighting has been disabled");
+        }
+
+        let duration = Duration::from_millis(duration);
+        if duration.is_zero() || duration > MAX_DURATION {
+            bail!("Previews must last between 1ms and {:?}", MAX_DURATION);
+        }
+
+        if self.colour_preview.until.is_none() {
+            info!("Previewing Colours for {:?}", duration);
+        }
+        self.colour_preview.until = Some(Instant::now() + duration);
+        self.set_lighting_layer(LightingLayer::Preview, Some(scheme))
+            .await
+    }
+
+    async fn cancel_colour_preview(&mut self) -> Result<()> {
+        if self.colour_preview.until.take().is_none() {
+            return Ok(());
+        }
+
+        info!("Colour Preview Cancelled");
+        self.set_lighting_layer(LightingLayer::Preview, None).await
+    }
+}
+
+pub(crate) trait ColourPreviewCrate {
+    /// Called on every tick, ends the preview once its time is up
+    async fn handle_colour_preview(&mut self);
+}
+
+impl ColourPreviewCrate for GoXLR {
+    async fn handle_colour_preview(&mut self) {
+        let Some(until) = self.colour_preview.until else {
+            return;
+        };
+        if Instant::now() < until {
+            return;
+        }
+
+        debug!("Colour Preview Ended");
+        self.colour_preview.until = None;
+        if let Err(error) = self.set_lighting_layer(LightingLayer::Preview, None).await {
+            warn!("Unable to restore Colours after Preview: {}", error);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use anyhow::{bail, Context, Result};
+use log::debug;
+use strum::IntoEnumIterator;
+
+use goxlr_ipc::commands::controls::{Control, ControlAction, ControlRange, ControlValue};
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
+use goxlr_shared::mute::MuteState;
+
+use crate::device::goxlr::components::channel::Channels;
+use crate::device::goxlr::components::monitor_mix::MonitorMix;
+use crate::device::goxlr::components::mute_handler::MuteHandler;
+use crate::device::goxlr::components::push_to_talk::PushToTalk;
+use crate::device::goxlr::device::GoXLR;
+
+// Volumes are presented as a percentage, moved in steps a listener can easily follow
+const VOLUME_STEP: u8 = 5;
+
+/// Everything that can be adjusted through the enumeration, the ID of each is built from this,
+/// so a control's ID stays the same between daemon runs.
+#[derive(Debug, Copy, Clone)]
+enum ControlTarget {
+    Volume(VolumeChannels),
+    Mute(FaderChannels),
+    PushToTalk,
+    MonitorMix,
+}
+
+impl ControlTarget {
+    fn all() -> Vec<Self> {
+        let volumes = VolumeChannels::iter().map(Self::Volume);
+        let mutes = FaderChannels::iter().map(Self::Mute);
+        let toggles = [Self::PushToTalk, Self::MonitorMix];
+        volumes.chain(mutes).chain(toggles).collect()
+    }
+
+    fn id(&self) -> String {
+        match self {
+            Self::Volume(channel) => format!("volume.{:?}", channel),
+            Self::Mute(channel) => format!("mute.{:?}", channel),
+            Self::PushToTalk => String::from("push_to_talk"),
+            Self::MonitorMix => String::from("monitor_mix"),
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Volume(channel) => format!("{:?} Volume", channel),
+            Self::Mute(channel) => format!("{:?} Mute", channel),
+            Self::PushToTalk => String::from("Push to Talk"),
+            Self::MonitorMix => String::from("Monitor Mix"),
+        }
+    }
+}
+
+/// Lists every control with its current value, and adjusts them by ID with simple relative
+/// actions, so screen reader friendly clients and basic remotes can drive the whole device
+/// without a pointer, or any knowledge of the command structure.
+pub(crate) trait ControlEnumeration {
+    fn get_controls(&self) -> Vec<Control>;
+    async fn adjust_control(&mut self, id: &str, action: ControlAction) -> Result<()>;
+}
+
+impl ControlEnumeration for GoXLR {
+    fn get_controls(&self) -> Vec<Control> {
+        ControlTarget::all()
+            .into_iter()
+            .map(|target| Control {
+                id: target.id(),
+                label: target.label(),
+                value: self.get_control_value(target),
+            })
+            .collect()
+    }
+
+    async fn adjust_control(&mut self, id: &str, action: ControlAction) -> Result<()> {
+        let target = ControlTarget::all()
+            .into_iter()
+            .find(|target| target.id() == id)
+            .with_context(|| format!("Unknown Control: {}", id))?;
+
+        debug!("Adjusting Control {} with {:?}", id, action);
+        match self.get_control_value(target) {
+            ControlValue::Range(range) => {
+                let value = match action {
+                    ControlAction::Increment => range.value.saturating_add(range.step),
+                    ControlAction::Decrement => range.value.saturating_sub(range.step),
+                    ControlAction::Toggle => bail!("{} is a Range, it can't be toggled", id),
+                };
+                let value = value.clamp(range.min, range.max);
+                self.set_control_range(target, value).await
+            }
+            ControlValue::Toggle(enabled) => {
+                let enabled = match action {
+                    ControlAction::Increment => true,
+                    ControlAction::Decrement => false,
+                    ControlAction::Toggle => !enabled,
+                };
+                self.set_control_toggle(target, enabled).await
+            }
+        }
+    }
+}
+
+trait ControlEnumerationLocal {
+    fn get_control_value(&self, target: ControlTarget) -> ControlValue;
+    async fn set_control_range(&mut self, target: ControlTarget, value: u8) -> Result<()>;
+    async fn set_control_toggle(&mut self, target: ControlTarget, enabled: bool) -> Result<()>;
+}
+
+impl ControlEnumerationLocal for GoXLR {
+    fn get_control_value(&self, target: ControlTarget) -> ControlValue {
+        match target {
+            ControlTarget::Volume(channel) => {
+                let volume = self.profile.channels.volumes[channel] as f32;
+                ControlValue::Range(ControlRange {
+                    value: (volume / 255. * 100.).round() as u8,
+                    min: 0,
+                    max: 100,
+                    step: VOLUME_STEP,
+                })
+            }
+            ControlTarget::Mute(channel) => {
+                let state = self.profile.channels.configs[channel].mute_state;
+                ControlValue::Toggle(state != MuteState::Unmuted)
+            }
+            ControlTarget::PushToTalk => ControlValue::Toggle(self.profile.push_to_talk.enabled),
+            ControlTarget::MonitorMix => ControlValue::Toggle(self.profile.monitor_mix.enabled),
+        }
+    }
+
+    async fn set_control_range(&mut self, target: ControlTarget, value: u8) -> Result<()> {
+        match target {
+            ControlTarget::Volume(channel) => {
+                let volume = (value as f32 / 100. * 255.).round() as u8;
+                self.set_channel_volume(channel, volume).await
+            }
+            _ => bail!("{} is not a Range", target.id()),
+        }
+    }
+
+    async fn set_control_toggle(&mut self, target: ControlTarget, enabled: bool) -> Result<()> {
+        match target {
+            ControlTarget::Mute(channel) => {
+                let state = match enabled {
+                    true => MuteState::Pressed,
+                    false => MuteState::Unmuted,
+                };
+                self.set_mute_state(channel, state).await
+            }
+            ControlTarget::PushToTalk => self.set_push_to_talk_enabled(enabled).await,
+            ControlTarget::MonitorMix => self.set_monitor_mix_enabled(enabled).await,
+            _ => bail!("{} is not a Toggle", target.id()),
+        }
+    }
+}
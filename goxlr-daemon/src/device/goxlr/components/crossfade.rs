@@ -0,0 +1,245 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use enum_map::EnumMap;
+use log::{debug, warn};
+use strum::IntoEnumIterator;
+
+use goxlr_ipc::commands::diagnostics::RouteController;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::RoutingOutput;
+use goxlr_shared::routing::RouteValue;
+
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::components::route_arbiter::RouteArbiterCrate;
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+type Table = EnumMap<InputChannels, EnumMap<RoutingOutput, RouteValue>>;
+type Routes = Vec<(InputChannels, RoutingOutput)>;
+
+// The maximum value of a route, as used by the ducker
+const ROUTE_MAX: u16 = 32;
+
+// How many volume changes to make in each direction, regardless of duration
+const FADE_STEPS: u16 = 16;
+
+#[derive(Default)]
+pub(crate) struct CrossfadeState {
+    running: Option<RunningCrossfade>,
+}
+
+struct RunningCrossfade {
+    removed: Routes,
+    added: Routes,
+    to: Table,
+
+    // Whether the removed routes have finished fading out, and the added ones are fading in
+    flipped: bool,
+    position: u16,
+    step: Duration,
+    last_step: Instant,
+}
+
+/// Rather than hard switching between two routing tables, fades out routes which are being
+/// removed, then fades in the routes which are being added. This uses the same route values as
+/// the ducker, so any route not changing is left untouched. The fade is stepped along by the
+/// tick, so the device carries on handling everything else while it runs.
+pub(crate) trait RoutingCrossfade {
+    /// Loads the profile, cross-fading from the current routing to the routing it ends up
+    /// with, if a cross-fade time is set.
+    async fn load_profile_with_crossfade(&mut self) -> Result<()>;
+
+    async fn handle_crossfade(&mut self);
+}
+
+impl RoutingCrossfade for GoXLR {
+    async fn load_profile_with_crossfade(&mut self) -> Result<()> {
+        let crossfade = self.profile.mix_modes.crossfade_ms;
+        if crossfade == 0 {
+            return self.load_profile().await;
+        }
+
+        // Hold back the routing during the load, so we know where it's going to end up and can
+        // fade between the two, rather than having the load switch it.
+        let from = self.get_routing_table();
+        self.stage_routing();
+        let loaded = self.load_profile().await;
+
+        let to = self.get_routing_table();
+        let duration = Duration::from_millis(crossfade as u64);
+        let started = self.start_crossfade(from, to, duration).await;
+        if started.is_err() {
+            self.cancel_crossfade();
+        }
+        loaded.and(started)
+    }
+
+    async fn handle_crossfade(&mut self) {
+        if self.crossfade.running.is_none() {
+            return;
+        }
+
+        if let Err(error) = self.step_crossfade().await {
+            warn!("Unable to Cross-fade Routing: {}", error);
+            self.crossfade.running = None;
+        }
+
+        // Whether or not we made it to the end, the routes are no longer being faded.
+        if self.crossfade.running.is_none() {
+            self.release_routes(RouteController::Crossfade);
+        }
+    }
+}
+
+pub(crate) trait RoutingCrossfadeCrate {
+    /// Stops a running cross-fade where it is, for when something else is about to set the
+    /// whole routing table.
+    fn cancel_crossfade(&mut self);
+}
+
+impl RoutingCrossfadeCrate for GoXLR {
+    fn cancel_crossfade(&mut self) {
+        if self.crossfade.running.take().is_some() {
+            debug!("Cancelling Cross-fade");
+            self.release_routes(RouteController::Crossfade);
+        }
+    }
+}
+
+trait RoutingCrossfadeLocal {
+    fn get_routing_table(&self) -> Table;
+    async fn start_crossfade(&mut self, from: Table, to: Table, duration: Duration) -> Result<()>;
+    async fn step_crossfade(&mut self) -> Result<()>;
+    async fn set_routing_table(&mut self, table: Table) -> Result<()>;
+    async fn set_route_values(&mut self, routes: &Routes, value: RouteValue) -> Result<()>;
+}
+
+impl RoutingCrossfadeLocal for GoXLR {
+    fn get_routing_table(&self) -> Table {
+        EnumMap::from_fn(|input| self.get_routing_input_row(input))
+    }
+
+    async fn start_crossfade(&mut self, from: Table, to: Table, duration: Duration) -> Result<()> {
+        let mut removed = vec![];
+        let mut added = vec![];
+        for input in InputChannels::iter() {
+            for output in RoutingOutput::iter() {
+                match (from[input][output], to[input][output]) {
+                    (RouteValue::Off, RouteValue::Off) => {}
+                    (_, RouteValue::Off) => removed.push((input, output)),
+                    (RouteValue::Off, _) => added.push((input, output)),
+                    _ => {}
+                }
+            }
+        }
+
+        debug!(
+            "Cross-fading {} removed, {} added routes",
+            removed.len(),
+            added.len()
+        );
+
+        // Start from where we were, if the new table was built while routing was staged, this
+        // releases the stage without sending any of the new routes.
+        self.set_routing_table(from).await?;
+        self.commit_routing().await?;
+
+        self.crossfade.running = Some(RunningCrossfade {
+            removed,
+            added,
+            to,
+            flipped: false,
+            position: 0,
+            step: duration / 2 / FADE_STEPS as u32,
+            last_step: Instant::now(),
+        });
+        Ok(())
+    }
+
+    async fn step_crossfade(&mut self) -> Result<()> {
+        let Some(mut fade) = self.crossfade.running.take() else {
+            return Ok(());
+        };
+
+        let passed = if fade.step.is_zero() {
+            FADE_STEPS
+        } else {
+            (fade.last_step.elapsed().as_millis() / fade.step.as_millis().max(1)) as u16
+        };
+        if passed == 0 {
+            self.crossfade.running = Some(fade);
+            return Ok(());
+        }
+        fade.position = (fade.position + passed).min(FADE_STEPS);
+        fade.last_step += fade.step * passed as u32;
+
+        if !fade.flipped {
+            // Fade out anything being removed..
+            let value = fade_value(FADE_STEPS - fade.position);
+            self.set_route_values(&fade.removed, value).await?;
+
+            if fade.position == FADE_STEPS {
+                // Flip to the new table, with the new routes silent..
+                let mut flipped = fade.to;
+                for &(input, output) in &fade.added {
+                    flipped[input][output] = RouteValue::Value(0);
+                }
+                self.set_routing_table(flipped).await?;
+                fade.flipped = true;
+                fade.position = 0;
+            }
+            self.crossfade.running = Some(fade);
+            return Ok(());
+        }
+
+        // Then fade them in.
+        self.set_route_values(&fade.added, fade_value(fade.position))
+            .await?;
+        if fade.position < FADE_STEPS {
+            self.crossfade.running = Some(fade);
+            return Ok(());
+        }
+
+        // Finally, make sure we land exactly on the target
+        debug!("Cross-fade complete");
+        self.set_routing_table(fade.to).await
+    }
+
+    async fn set_routing_table(&mut self, table: Table) -> Result<()> {
+        for input in InputChannels::iter() {
+            let mut changed = false;
+            for output in RoutingOutput::iter() {
+                changed |= self.set_route_as(
+                    RouteController::Crossfade,
+                    input,
+                    output,
+                    table[input][output],
+                )?;
+            }
+            if changed {
+                self.apply_routing_for_channel(input).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn set_route_values(&mut self, routes: &Routes, value: RouteValue) -> Result<()> {
+        let mut changed: EnumMap<InputChannels, bool> = Default::default();
+        for &(input, output) in routes {
+            changed[input] |=
+                self.set_route_as(RouteController::Crossfade, input, output, value)?;
+        }
+
+        for (input, changed) in changed {
+            if changed {
+                self.apply_routing_for_channel(input).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn fade_value(position: u16) -> RouteValue {
+    RouteValue::Value((ROUTE_MAX * position / FADE_STEPS) as u8)
+}
@@ -0,0 +1,168 @@
+use std::fmt::Debug;
+
+use anyhow::{bail, Context, Result};
+use log::info;
+use strum::IntoEnumIterator;
+
+use goxlr_ipc::commands::configuration::{DesiredState, StateDifference};
+use goxlr_profile::Profile;
+use goxlr_shared::channels::ducking::DuckingInput;
+use goxlr_shared::device::DeviceType;
+
+use crate::device::goxlr::components::adapt_profile::AdaptProfile;
+use crate::device::goxlr::components::crossfade::RoutingCrossfade;
+use crate::device::goxlr::device::GoXLR;
+
+/// Reconciles the device against a Desired State, allowing a setup to be described in a file
+/// and kept in version control. The state is written over the profile, then the profile is
+/// reloaded, the same as activating a Mix Mode (including its routing cross-fade).
+pub(crate) trait DesiredStates {
+    fn diff_state(&self, state: &DesiredState) -> Result<Vec<StateDifference>>;
+    async fn apply_state(&mut self, state: DesiredState) -> Result<Vec<StateDifference>>;
+}
+
+impl DesiredStates for GoXLR {
+    fn diff_state(&self, state: &DesiredState) -> Result<Vec<StateDifference>> {
+        let differences = self.get_differences(state)?;
+        for difference in &differences {
+            info!(
+                "{} differs: {} (desired {})",
+                difference.setting, difference.current, difference.desired
+            );
+        }
+        Ok(differences)
+    }
+
+    async fn apply_state(&mut self, state: DesiredState) -> Result<Vec<StateDifference>> {
+        let differences = self.get_differences(&state)?;
+        if differences.is_empty() {
+            info!("Device already matches the Desired State");
+            return Ok(differences);
+        }
+
+        for difference in &differences {
+            info!(
+                "Applying {}: {} -> {}",
+                difference.setting, difference.current, difference.desired
+            );
+        }
+
+        write_state(&mut self.profile, &state);
+        self.adapt_profile()?;
+        self.load_profile_with_crossfade().await?;
+        Ok(differences)
+    }
+}
+
+trait DesiredStatesLocal {
+    fn get_differences(&self, state: &DesiredState) -> Result<Vec<StateDifference>>;
+    fn validate_state(&self, state: &DesiredState) -> Result<()>;
+}
+
+impl DesiredStatesLocal for GoXLR {
+    fn get_differences(&self, state: &DesiredState) -> Result<Vec<StateDifference>> {
+        self.validate_state(state)?;
+
+        let mut desired = self.profile.clone();
+        write_state(&mut desired, state);
+        Ok(diff_profiles(&self.profile, &desired))
+    }
+
+    fn validate_state(&self, state: &DesiredState) -> Result<()> {
+        let Some(lighting) = &state.lighting else {
+            return Ok(());
+        };
+
+        if lighting
+            .brightness
+            .is_some_and(|brightness| brightness > 100)
+        {
+            bail!("Brightness must be a percentage");
+        }
+
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini && lighting.accent.is_some() {
+            bail!("The GoXLR Mini doesn't have an Accent light");
+        }
+        Ok(())
+    }
+}
+
+fn write_state(profile: &mut Profile, state: &DesiredState) {
+    if let Some(volumes) = &state.volumes {
+        for (&channel, &volume) in volumes {
+            profile.channels.volumes[channel] = volume;
+        }
+    }
+
+    if let Some(routing) = &state.routing {
+        for (&input, outputs) in routing {
+            for (&output, &enabled) in outputs {
+                profile.routing[input][output] = enabled;
+            }
+        }
+    }
+
+    if let Some(ducking) = &state.ducking {
+        if let Some(enabled) = ducking.enabled {
+            profile.ducking.enabled = enabled;
+        }
+        if let Some(inputs) = &ducking.inputs {
+            for input in DuckingInput::iter() {
+                profile.ducking.input_source[input] = inputs.contains(&input);
+            }
+        }
+    }
+
+    if let Some(lighting) = &state.lighting {
+        let current = &mut profile.lighting;
+        current.accent = lighting.accent.unwrap_or(current.accent);
+        current.global = lighting.global.unwrap_or(current.global);
+        current.brightness = lighting.brightness.unwrap_or(current.brightness);
+    }
+}
+
+/// Compares only the parts of the profile a Desired State can change
+fn diff_profiles(current: &Profile, desired: &Profile) -> Vec<StateDifference> {
+    let mut differences = vec![];
+    let mut compare = |setting: String, from: &dyn Debug, to: &dyn Debug| {
+        let (current, desired) = (format!("{:?}", from), format!("{:?}", to));
+        if current != desired {
+            differences.push(StateDifference {
+                setting,
+                current,
+                desired,
+            });
+        }
+    };
+
+    for (channel, volume) in current.channels.volumes {
+        let setting = format!("Volume {:?}", channel);
+        compare(setting, &volume, &desired.channels.volumes[channel]);
+    }
+
+    for (input, outputs) in current.routing {
+        for (output, enabled) in outputs {
+            let setting = format!("Route {:?} -> {:?}", input, output);
+            compare(setting, &enabled, &desired.routing[input][output]);
+        }
+    }
+
+    let (ducking, desired_ducking) = (&current.ducking, &desired.ducking);
+    let setting = String::from("Ducking Enabled");
+    compare(setting, &ducking.enabled, &desired_ducking.enabled);
+    for (input, enabled) in ducking.input_source {
+        let setting = format!("Ducking Input {:?}", input);
+        compare(setting, &enabled, &desired_ducking.input_source[input]);
+    }
+
+    let (lighting, desired_lighting) = (&current.lighting, &desired.lighting);
+    let setting = String::from("Accent Colour");
+    compare(setting, &lighting.accent, &desired_lighting.accent);
+    let setting = String::from("Global Colour");
+    compare(setting, &lighting.global, &desired_lighting.global);
+    let setting = String::from("Brightness");
+    compare(setting, &lighting.brightness, &desired_lighting.brightness);
+
+    differences
+}
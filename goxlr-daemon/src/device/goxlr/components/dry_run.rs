@@ -0,0 +1,334 @@
+use std::mem;
+use std::sync::{Mutex, PoisonError};
+
+use anyhow::{anyhow, bail, Result};
+use enum_map::EnumMap;
+use json_patch::diff;
+use serde_json::Value;
+
+use goxlr_ipc::commands::button_cycles::ButtonCycleCommand;
+use goxlr_ipc::commands::configuration::{ConfigurationCommand, StateDifference};
+use goxlr_ipc::commands::controls::ControlCommand;
+use goxlr_ipc::commands::ducking::DuckingCommand;
+use goxlr_ipc::commands::lighting::LightingCommand;
+use goxlr_ipc::commands::mix_modes::MixModeCommand;
+use goxlr_ipc::commands::sampler::SamplerCommand;
+use goxlr_ipc::commands::{DryRunReport, GoXLRCommand};
+use goxlr_profile::{MicProfile, Profile};
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
+use goxlr_shared::colours::ColourScheme;
+use goxlr_shared::faders::Fader;
+use goxlr_shared::mute::ChannelMuteState;
+use goxlr_shared::routing::RoutingTable;
+use goxlr_shared::states::ButtonDisplayStates;
+use goxlr_shared::volume::Volume;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::components::audit_log::{get_path, AuditLogging};
+use crate::device::goxlr::components::colour_buffer::ColourBuffer;
+use crate::device::goxlr::components::hard_mute::HardMuteState;
+use crate::device::goxlr::components::headphone_limit::HeadphoneLimitState;
+use crate::device::goxlr::components::lighting_layers::LightingLayers;
+use crate::device::goxlr::components::mute_handler::MuteFadeState;
+use crate::device::goxlr::components::push_to_talk::PushToTalkState;
+use crate::device::goxlr::components::route_arbiter::RouteArbiterState;
+use crate::device::goxlr::components::session_stats::SessionStats;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::IPCCommandHandler;
+
+/// While a command is being Dry Run, this holds the commands it would have sent to the device
+#[derive(Debug, Default)]
+pub(crate) struct DryRunState {
+    commands: Mutex<Option<Vec<String>>>,
+}
+
+// The parts of the device's state a command can change, put back once the Dry Run is finished
+pub(crate) struct DryRunSnapshot {
+    profile: Profile,
+    mic_profile: MicProfile,
+    colour_scheme: ColourScheme,
+    colour_buffer: ColourBuffer,
+    lighting_layers: LightingLayers,
+    button_states: ButtonDisplayStates,
+    routing_state: RoutingTable,
+    route_arbiter: RouteArbiterState,
+    mute_state: EnumMap<FaderChannels, Option<ChannelMuteState>>,
+    mute_fades: MuteFadeState,
+    precise_volumes: EnumMap<VolumeChannels, Volume>,
+    fader_state: EnumMap<Fader, Option<FaderChannels>>,
+    solo: Option<InputChannels>,
+    hard_mute: HardMuteState,
+    headphone_limit: HeadphoneLimitState,
+    push_to_talk: PushToTalkState,
+    session_stats: SessionStats,
+}
+
+pub(crate) trait DryRun {
+    /// Runs a command with everything it sends to the device captured rather than sent, then
+    /// puts the state back how it was. Validation errors are returned as they would be normally.
+    /// Each command in a Batch is run against the state left by the ones before it.
+    async fn dry_run_ipc_command(&mut self, command: GoXLRCommand) -> Result<DryRunReport>;
+}
+
+pub(crate) trait DryRunCrate {
+    /// Called before a command is sent to the device, returns true if a Dry Run has captured it,
+    /// in which case it mustn't be sent.
+    fn capture_dry_run(&self, command: &BasicResultCommand) -> bool;
+
+    /// Copies the parts of the state a command can change, so they can be put back afterwards
+    fn take_snapshot(&self) -> DryRunSnapshot;
+    fn restore_snapshot(&mut self, snapshot: DryRunSnapshot);
+}
+
+impl DryRun for GoXLR {
+    async fn dry_run_ipc_command(&mut self, command: GoXLRCommand) -> Result<DryRunReport> {
+        if let Some(reason) = get_dry_run_block(&command) {
+            bail!("{} can't be Dry Run", reason);
+        }
+
+        let (batch, commands) = match command {
+            GoXLRCommand::Batch(commands) => (true, commands),
+            command => (false, vec![command]),
+        };
+
+        let snapshot = self.take_snapshot();
+        let before = self.audit_snapshot();
+        self.set_capture(Some(vec![]));
+
+        let mut result = Ok(());
+        for (index, command) in commands.into_iter().enumerate() {
+            if let Err(error) = self.handle_single_command(command).await {
+                result = if batch {
+                    Err(anyhow!("Command {} failed: {}", index + 1, error))
+                } else {
+                    Err(error)
+                };
+                break;
+            }
+        }
+
+        let usb_commands = self.set_capture(None).unwrap_or_default();
+        let after = self.audit_snapshot();
+        self.restore_snapshot(snapshot);
+        result?;
+
+        Ok(DryRunReport {
+            usb_commands,
+            changes: get_changes(before, after),
+        })
+    }
+}
+
+impl DryRunCrate for GoXLR {
+    fn capture_dry_run(&self, command: &BasicResultCommand) -> bool {
+        // A poisoned lock still needs to be honoured, otherwise a Dry Run could become a real one
+        let mut capture = self
+            .dry_run
+            .commands
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(commands) = capture.as_mut() {
+            commands.push(format!("{:?}", command));
+            return true;
+        }
+        false
+    }
+
+    fn take_snapshot(&self) -> DryRunSnapshot {
+        DryRunSnapshot {
+            profile: self.profile.clone(),
+            mic_profile: self.mic_profile,
+            colour_scheme: self.colour_scheme,
+            colour_buffer: self.colour_buffer.clone(),
+            lighting_layers: self.lighting_layers.clone(),
+            button_states: self.button_states,
+            routing_state: self.routing_state.clone(),
+            route_arbiter: self.route_arbiter.clone(),
+            mute_state: self.mute_state,
+            mute_fades: self.mute_fades.clone(),
+            precise_volumes: self.precise_volumes,
+            fader_state: self.fader_state,
+            solo: self.solo,
+            hard_mute: self.hard_mute.clone(),
+            headphone_limit: self.headphone_limit.clone(),
+            push_to_talk: self.push_to_talk.clone(),
+            session_stats: self.session_stats.clone(),
+        }
+    }
+
+    fn restore_snapshot(&mut self, snapshot: DryRunSnapshot) {
+        self.profile = snapshot.profile;
+        self.mic_profile = snapshot.mic_profile;
+        self.colour_scheme = snapshot.colour_scheme;
+        self.colour_buffer = snapshot.colour_buffer;
+        self.lighting_layers = snapshot.lighting_layers;
+        self.button_states = snapshot.button_states;
+        self.routing_state = snapshot.routing_state;
+        self.route_arbiter = snapshot.route_arbiter;
+        self.mute_state = snapshot.mute_state;
+        self.mute_fades = snapshot.mute_fades;
+        self.precise_volumes = snapshot.precise_volumes;
+        self.fader_state = snapshot.fader_state;
+        self.solo = snapshot.solo;
+        self.hard_mute = snapshot.hard_mute;
+        self.headphone_limit = snapshot.headphone_limit;
+        self.push_to_talk = snapshot.push_to_talk;
+        self.session_stats = snapshot.session_stats;
+    }
+}
+
+trait DryRunLocal {
+    fn set_capture(&self, commands: Option<Vec<String>>) -> Option<Vec<String>>;
+}
+
+impl DryRunLocal for GoXLR {
+    fn set_capture(&self, commands: Option<Vec<String>>) -> Option<Vec<String>> {
+        let mut capture = self
+            .dry_run
+            .commands
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        mem::replace(&mut *capture, commands)
+    }
+}
+
+/// Only commands whose changes are all held in the snapshot can be Dry Run. Anything else starts
+/// something running, waits in the device loop, or changes state which can't be put back, so
+/// isn't on the list. New commands aren't on it either, until they've been checked.
+pub(crate) fn get_dry_run_block(command: &GoXLRCommand) -> Option<&'static str> {
+    match command {
+        GoXLRCommand::Configuration(command) => match command {
+            ConfigurationCommand::SubMixEnabled(_)
+            | ConfigurationCommand::ButtonHoldTime(_)
+            | ConfigurationCommand::ChangePageWithButtons(_)
+            | ConfigurationCommand::DiffState(_)
+            | ConfigurationCommand::AddRoutingRule(_)
+            | ConfigurationCommand::RemoveRoutingRule(_) => None,
+            ConfigurationCommand::SetVoiceApplications(_) => Some("Voice Routing"),
+            _ => Some("Profile Loading"),
+        },
+        GoXLRCommand::Channels(_) => None,
+        GoXLRCommand::Pages(_) => None,
+        GoXLRCommand::Microphone(_) => None,
+        GoXLRCommand::Effects(_) => None,
+        GoXLRCommand::Diagnostics(_) => Some("Diagnostics"),
+        GoXLRCommand::MixModes(command) => match command {
+            MixModeCommand::Activate(_) => Some("Mix Mode Activation"),
+            _ => None,
+        },
+        GoXLRCommand::Ducking(command) => match command {
+            DuckingCommand::GetStats => None,
+            _ => Some("Ducking"),
+        },
+        GoXLRCommand::PushToTalk(_) => None,
+        GoXLRCommand::MonitorMix(_) => None,
+        GoXLRCommand::Lighting(command) => match command {
+            LightingCommand::Accent(_)
+            | LightingCommand::Global(_)
+            | LightingCommand::Brightness(_)
+            | LightingCommand::IdleTimeout(_)
+            | LightingCommand::IdleBrightness(_)
+            | LightingCommand::ChannelLinked(_) => None,
+            _ => Some("Lighting Effects"),
+        },
+        GoXLRCommand::Solo(_) => None,
+        GoXLRCommand::Scribbles(_) => Some("Scribble Widgets"),
+        GoXLRCommand::Sampler(command) => match command {
+            SamplerCommand::BankOutputs(..)
+            | SamplerCommand::ButtonOutputs(..)
+            | SamplerCommand::File(..)
+            | SamplerCommand::Playlist(..) => None,
+            _ => Some("Sample Playback"),
+        },
+        GoXLRCommand::Controls(command) => match command {
+            ControlCommand::List => None,
+            _ => Some("Control Adjustment"),
+        },
+        GoXLRCommand::Automation(_) => Some("Automation"),
+        GoXLRCommand::ButtonCycles(command) => match command {
+            ButtonCycleCommand::Set(_, Some(_)) => None,
+            _ => Some("Button Cycle Changes"),
+        },
+        GoXLRCommand::Batch(commands) => commands.iter().find_map(get_dry_run_block),
+    }
+}
+
+fn get_changes(before: Option<Value>, after: Option<Value>) -> Vec<StateDifference> {
+    let (Some(before), Some(after)) = (before, after) else {
+        return vec![];
+    };
+
+    let value = |config: &Value, path: &str| match config.pointer(path) {
+        Some(value) => value.to_string(),
+        None => String::from("(none)"),
+    };
+
+    let patch = diff(&before, &after);
+    let paths = patch.0.iter().map(get_path);
+    paths
+        .map(|setting| StateDifference {
+            current: value(&before, &setting),
+            desired: value(&after, &setting),
+            setting,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use clap::Parser;
+    use tokio::sync::{broadcast, mpsc};
+
+    use goxlr_ipc::commands::channels::{ChannelCommands, MuteCommand};
+    use goxlr_ipc::commands::pages::PageCommand;
+    use goxlr_ipc::commands::GoXLRCommand;
+    use goxlr_shared::channels::fader::FaderChannels;
+    use goxlr_shared::mute::MuteState;
+    use goxlr_usb::mock::MockDevice;
+
+    use crate::cli::Cli;
+    use crate::device::goxlr::components::dry_run::DryRun;
+    use crate::device::goxlr::components::session_stats::SessionStatistics;
+    use crate::device::goxlr::device::GoXLR;
+    use crate::device::goxlr::device_config::GoXLRDeviceConfiguration;
+    use crate::stop::Stop;
+    use crate::storage::defaults::DefaultProfileStore;
+
+    #[tokio::test]
+    async fn dry_run_leaves_session_stats() {
+        let (manager_sender, _manager_recv) = mpsc::channel(1);
+        let (_device_sender, manager_recv) = mpsc::channel(1);
+        let config = GoXLRDeviceConfiguration {
+            stop: Stop::new(),
+            device: MockDevice::attach(0, "DRYRUN").location(),
+            update_sender: mpsc::channel(1).0,
+            manager_sender,
+            manager_recv,
+            fader_events: broadcast::channel(1).0,
+            settings: Cli::parse_from(["goxlr-daemon"]).device_settings(),
+            profile_store: Arc::new(DefaultProfileStore),
+        };
+        let mut device = GoXLR::new(config, Stop::new());
+
+        let mute = MuteCommand {
+            channel: FaderChannels::Music,
+            state: MuteState::Pressed,
+        };
+        let batch = vec![
+            GoXLRCommand::Pages(PageCommand::AddPage),
+            GoXLRCommand::Pages(PageCommand::LoadPage(1)),
+            GoXLRCommand::Channels(ChannelCommands::Mute(mute)),
+        ];
+        let result = device.dry_run_ipc_command(GoXLRCommand::Batch(batch)).await;
+        assert!(result.is_ok(), "Dry Run Failed: {:?}", result.err());
+
+        // Nothing actually happened, so nothing should have been counted
+        let stats = device.get_session_stats();
+        assert_eq!(stats.mutes, 0);
+        assert_eq!(stats.page_switches, 0);
+    }
+}
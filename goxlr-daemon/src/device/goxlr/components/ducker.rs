@@ -1,22 +1,81 @@
+use crate::device::goxlr::components::button_cycles::ButtonCyclesCrate;
+use crate::device::goxlr::components::flight_recorder::{FlightRecording, RecorderEvent};
+use crate::device::goxlr::components::hard_mute::HardMuteCrate;
+use crate::device::goxlr::components::level_source::{
+    CaptureSource, InjectedLevel, LevelSource, MicMeterSource,
+};
+use crate::device::goxlr::components::mute_handler::MuteHandlerCrate;
+use crate::device::goxlr::components::push_to_talk::PushToTalkCrate;
+use crate::device::goxlr::components::route_arbiter::RouteArbiterCrate;
 use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::components::schedule::ScheduleCrate;
+use crate::device::goxlr::components::solo::SoloCrate;
 use crate::device::goxlr::device::GoXLR;
 use anyhow::{bail, Result};
 use async_trait::async_trait;
-use goxlr_shared::channels::ducking::DuckingInput;
+use enum_map::EnumMap;
+use goxlr_ipc::commands::diagnostics::RouteController;
+use goxlr_ipc::commands::ducking::DuckingStatsReport;
+use goxlr_profile::{AdaptiveDucking, DuckingSettings};
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::ducking::{DuckingInput, DuckingSensitivity};
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::routing::RouteValue;
 use goxlr_usb::events::commands::CommandSender;
-use log::debug;
+use log::{debug, warn};
 use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use strum::IntoEnumIterator;
 use tokio::sync::oneshot;
 use goxlr_shared::gate::GateTimes;
 use goxlr_shared::mute::MuteState;
 
 const MIC_DB_MAX: f64 = -72.2;
+const ROUTE_MAX: u8 = 32;
+
+// What the Sample input reports while something's playing, comfortably above the trigger
+const SAMPLE_PLAYING_DB: f64 = 0.;
+
+// The Fade button is tracked by the calculator alongside the inputs, under this name
+const FADE_TRIGGER: &str = "Fade";
+
+// How far the sensitivity can move the Microphone's trigger from its gate threshold
+const MIC_OFFSET_MAX: i8 = 24;
+
+// The longest (in ms) the Ducker will wait before unducking
+const RELEASE_TIME_MAX: u64 = 5000;
 
 #[derive(Default)]
 pub(crate) struct AudioDucker {
     temp: TempDucking,
     ducking_calc: DuckingCalculator,
     noise_gate: SimulatedNoiseGate,
+
+    // The level sources for each input, if one hasn't been set the input's default is used
+    sources: EnumMap<DuckingInput, Option<Arc<dyn LevelSource>>>,
+
+    // The profile's capture devices which are currently open, so a load only reopens changes
+    capture_devices: EnumMap<DuckingInput, Option<String>>,
+
+    // Set by whatever is playing samples, this is the default source for the Sample input
+    sample_level: InjectedLevel,
+
+    // Where the Music is measured from when Adaptive Ducking is enabled
+    music_level: Option<Arc<dyn LevelSource>>,
+
+    // How much deeper (or shallower) than the transition the current duck is, None if unscaled
+    depth_scale: Option<f64>,
+
+    // Whether the Fade button is being held
+    fading: bool,
+
+    // Whether the Microphone was muted on the last check, so we can catch it being muted
+    mic_muted: bool,
+
+    stats: DuckingStats,
 }
 
 #[derive(Default)]
@@ -26,11 +85,57 @@ struct TempDucking {
 
     last_duck_time: u64,
     last_unduck_time: u64,
+
+    // The transition lengths the indexes were built against, if the profile changes under us
+    // these won't match, and the indexes can't be trusted.
+    ducking_len: usize,
+    unducking_len: usize,
+}
+
+impl AudioDucker {
+    /// Drops any transition in progress. If we're currently ducked (or unducked) the transition
+    /// is treated as complete, so the next change in state starts from the first step of the
+    /// new config rather than part way through the old one.
+    fn reset(&mut self, ducking_len: usize, unducking_len: usize) {
+        let calc = &self.ducking_calc;
+        self.temp = TempDucking {
+            ducking_index: if calc.in_ducking { ducking_len } else { 0 },
+            unducking_index: if calc.in_unducking { unducking_len } else { 0 },
+            ducking_len,
+            unducking_len,
+            ..Default::default()
+        };
+    }
 }
 
 #[async_trait]
 pub(crate) trait AudioDuckerTrait {
     fn is_ducker_active(&self) -> bool;
+    fn set_ducking_input_source(&mut self, input: DuckingInput, enabled: bool);
+    fn set_sample_playing(&mut self, playing: bool);
+    fn is_fade_button(&self, button: Buttons) -> bool;
+    fn set_fade_pressed(&mut self, pressed: bool);
+    fn is_sensitivity_button(&self, button: Buttons) -> bool;
+    async fn set_ducking_sensitivity(&mut self, sensitivity: DuckingSensitivity) -> Result<()>;
+    async fn next_ducking_sensitivity(&mut self) -> Result<()>;
+
+    /// Replaces an input's level source, the capture device is stored in the profile (if the
+    /// source is one) so it can be opened again when the profile is next loaded
+    fn set_level_source(
+        &mut self,
+        input: DuckingInput,
+        source: Option<Arc<dyn LevelSource>>,
+        device: Option<String>,
+    );
+
+    /// Opens the capture devices named in the profile, if they aren't already
+    async fn load_capture_devices(&mut self);
+    fn set_music_level_source(&mut self, source: Option<Arc<dyn LevelSource>>);
+    fn reset_ducker(&mut self);
+    async fn apply_ducking_settings(&mut self, settings: DuckingSettings) -> Result<()>;
+
+    fn get_ducking_stats(&self) -> DuckingStatsReport;
+    fn reset_ducking_stats(&mut self);
 
     async fn handle_ducking(&mut self);
 
@@ -50,30 +155,224 @@ impl AudioDuckerTrait for GoXLR {
             .any(|(_, &state)| state)
     }
 
+    fn set_ducking_input_source(&mut self, input: DuckingInput, enabled: bool) {
+        debug!("[Ducker] Setting {:?} Input Source to {}", input, enabled);
+        self.profile.ducking.input_source[input] = enabled;
+        self.reset_ducker();
+    }
+
+    fn set_sample_playing(&mut self, playing: bool) {
+        debug!("[Ducker] Sample Playing: {}", playing);
+        let level = if playing { Some(SAMPLE_PLAYING_DB) } else { None };
+        self.ducking.sample_level.set(level);
+    }
+
+    fn is_fade_button(&self, button: Buttons) -> bool {
+        self.profile.ducking.fade_button == Some(button)
+    }
+
+    fn set_fade_pressed(&mut self, pressed: bool) {
+        debug!("[Ducker] Fade Button Pressed: {}", pressed);
+        self.ducking.fading = pressed;
+    }
+
+    fn is_sensitivity_button(&self, button: Buttons) -> bool {
+        self.profile.ducking.sensitivity_button == Some(button)
+    }
+
+    async fn set_ducking_sensitivity(&mut self, sensitivity: DuckingSensitivity) -> Result<()> {
+        debug!("[Ducker] Applying {} Sensitivity", sensitivity);
+        let (trigger_db, mic_offset, attack, release) = match sensitivity {
+            DuckingSensitivity::Low => (-30., 6, 150, 300),
+            DuckingSensitivity::Medium => (-40., 0, 0, 500),
+            DuckingSensitivity::High => (-50., -6, 0, 1000),
+        };
+
+        let mut settings = self.profile.ducking.clone();
+        settings.trigger_db = trigger_db;
+        settings.mic_trigger_offset = mic_offset;
+        settings.attack_time = attack;
+        settings.release_time = release;
+        settings.sensitivity = Some(sensitivity);
+        self.apply_ducking_settings(settings).await
+    }
+
+    async fn next_ducking_sensitivity(&mut self) -> Result<()> {
+        // Once the values have been changed by hand, we start again from the bottom
+        let next = match self.profile.ducking.sensitivity {
+            Some(DuckingSensitivity::Low) => DuckingSensitivity::Medium,
+            Some(DuckingSensitivity::Medium) => DuckingSensitivity::High,
+            Some(DuckingSensitivity::High) | None => DuckingSensitivity::Low,
+        };
+        self.set_ducking_sensitivity(next).await
+    }
+
+    fn set_level_source(
+        &mut self,
+        input: DuckingInput,
+        source: Option<Arc<dyn LevelSource>>,
+        device: Option<String>,
+    ) {
+        debug!("[Ducker] Replacing {:?} Level Source", input);
+        self.ducking.sources[input] = source;
+        self.ducking.capture_devices[input] = device.clone();
+        self.profile.ducking.capture_devices[input] = device;
+    }
+
+    async fn load_capture_devices(&mut self) {
+        for input in DuckingInput::iter() {
+            let device = self.profile.ducking.capture_devices[input].clone();
+            if device == self.ducking.capture_devices[input] {
+                continue;
+            }
+
+            // A device which fails to open isn't retried until the profile names another, it
+            // would only fail again on every load
+            let source: Option<Arc<dyn LevelSource>> = match &device {
+                Some(name) => match CaptureSource::open(name.clone()).await {
+                    Ok(source) => Some(Arc::new(source)),
+                    Err(error) => {
+                        warn!("[Ducker] Unable to open {}: {}", name, error);
+                        None
+                    }
+                },
+                None => None,
+            };
+            self.ducking.sources[input] = source;
+            self.ducking.capture_devices[input] = device;
+        }
+    }
+
+    fn set_music_level_source(&mut self, source: Option<Arc<dyn LevelSource>>) {
+        debug!("[Ducker] Replacing Music Level Source");
+        self.ducking.music_level = source;
+    }
+
+    fn reset_ducker(&mut self) {
+        debug!("[Ducker] Resetting Transition State");
+        let transition = &self.profile.ducking.transition;
+        let (ducking, unducking) = (transition.ducking.len(), transition.unducking.len());
+        self.ducking.reset(ducking, unducking);
+    }
+
+    async fn apply_ducking_settings(&mut self, settings: DuckingSettings) -> Result<()> {
+        let transition = &settings.transition;
+        if transition.ducking.is_empty() || transition.unducking.is_empty() {
+            bail!("Ducking and Unducking transitions need at least one step");
+        }
+
+        let mut steps = transition.ducking.iter().chain(&transition.unducking);
+        if steps.any(|step| step.route_volume > ROUTE_MAX) {
+            bail!("Transition route volumes must be {} or less", ROUTE_MAX);
+        }
+
+        let adaptive = &settings.adaptive;
+        if adaptive.quiet_volume > ROUTE_MAX || adaptive.loud_volume > ROUTE_MAX {
+            bail!("Adaptive route volumes must be {} or less", ROUTE_MAX);
+        }
+        if adaptive.quiet_db >= adaptive.loud_db {
+            bail!("The Adaptive quiet level must be below the loud level");
+        }
+        if settings.trigger_db < MIC_DB_MAX || settings.trigger_db >= SAMPLE_PLAYING_DB {
+            bail!("The Trigger level must be between {} and 0dB", MIC_DB_MAX);
+        }
+        if settings.input_trigger_db[DuckingInput::Mic].is_some() {
+            bail!("The Microphone triggers from its gate, use the Mic trigger offset instead");
+        }
+        for (input, level) in settings.input_trigger_db {
+            if level.is_some_and(|level| !(MIC_DB_MAX..SAMPLE_PLAYING_DB).contains(&level)) {
+                bail!("{} Trigger must be between {} and 0dB", input, MIC_DB_MAX);
+            }
+        }
+        if !(-MIC_OFFSET_MAX..=MIC_OFFSET_MAX).contains(&settings.mic_trigger_offset) {
+            bail!("The Mic trigger offset must be within {}dB", MIC_OFFSET_MAX);
+        }
+        if settings.release_time > RELEASE_TIME_MAX {
+            bail!("The Release time must be {}ms or less", RELEASE_TIME_MAX);
+        }
+        if settings.fade_button.is_some() && settings.fade_button == settings.sensitivity_button {
+            bail!("The Fade and Sensitivity buttons must be different");
+        }
+        if settings.sensitivity_button != self.profile.ducking.sensitivity_button {
+            if let Some(button) = settings.sensitivity_button {
+                if self.is_push_to_talk_button(button)
+                    || self.is_solo_button(button)
+                    || self.is_button_cycle(button)
+                    || self.profile.hard_mute.buttons.contains(&button)
+                {
+                    bail!("{:?} is already being used by something else", button);
+                }
+            }
+        }
+
+        // The new settings may not touch the same routes (or may be disabled), so anything we've
+        // lowered needs to come back up under the old settings before they're replaced.
+        if self.is_lowered() {
+            debug!("[Ducker] Settings changed while ducked, unducking");
+            self.restore_now().await;
+        }
+
+        // A Fade button which has moved can't be released, so don't leave it held
+        if settings.fade_button != self.profile.ducking.fade_button {
+            self.ducking.fading = false;
+        }
+
+        self.profile.ducking = settings;
+        self.ducking.depth_scale = None;
+        self.reset_ducker();
+        Ok(())
+    }
+
+    fn get_ducking_stats(&self) -> DuckingStatsReport {
+        self.ducking.stats.report()
+    }
+
+    fn reset_ducking_stats(&mut self) {
+        self.ducking.stats = DuckingStats::default();
+    }
+
     async fn handle_ducking(&mut self) {
-        // Pre-check if ducking is enabled.
-        if !self.profile.ducking.enabled {
+        // Pre-check if ducking (or the Fade button) is enabled.
+        let enabled = self.profile.ducking.enabled;
+        let fade = self.profile.ducking.fade_button.is_some();
+        if !enabled && !fade {
             return;
         }
 
-        let mut should_duck = false;
+        // The Fade button is treated as one more trigger, so it uses the same transitions
+        let fading = fade && self.ducking.fading;
+        self.ducking
+            .ducking_calc
+            .handle_result(&FADE_TRIGGER.to_string(), fading);
+
+        // A Microphone which can't be heard on the Stream shouldn't hold the duck, when enabled
+        let fast_unduck = self.profile.ducking.fast_unduck_on_mute;
+        let mic_muted = self.is_muted_to(FaderChannels::Microphone, OutputChannels::StreamMix)
+            || self.is_hard_muted();
+        let newly_muted = mic_muted && !self.ducking.mic_muted;
+        self.ducking.mic_muted = mic_muted;
+
+        let mut should_duck = fade;
         for input_source in self.profile.ducking.input_source {
             let (input, state) = input_source;
 
-            if state {
+            if state && enabled {
                 should_duck = true;
-                match input {
-                    DuckingInput::Mic => {
-                        if let Ok(db) = self.grab_mic_db().await {
-                            let (name, ducking_state) = self.handle_mic_calculations(db);
-                            self.ducking
-                                .ducking_calc
-                                .handle_result(&name, ducking_state);
-                        }
-                    } // In case we would add os level DuckingInputs like Chat, we could make them run
-                      // in a different thread, add all the values that are running within those 20ms
-                      // and make an average of them to use in here, must be stored thread safe of course.
-                }
+                let source = self.get_level_source(input);
+                let triggered = match source.level_db().await {
+                    _ if fast_unduck && mic_muted && input == DuckingInput::Mic => false,
+                    Some(db) if source.is_microphone() => self.handle_mic_calculations(db),
+                    Some(db) => db >= self.get_input_trigger_db(input),
+                    None => false,
+                };
+                self.ducking
+                    .ducking_calc
+                    .handle_result(&input.to_string(), triggered);
+            } else {
+                // Make sure a disabled input can't keep the ducker held down
+                self.ducking
+                    .ducking_calc
+                    .handle_result(&input.to_string(), false);
             }
         }
 
@@ -82,6 +381,19 @@ impl AudioDuckerTrait for GoXLR {
             return;
         }
 
+        // If the Microphone was all that held the duck, skip the release time and start coming
+        // back up, the rest of the transition then runs from the timer as usual
+        let calc = &self.ducking.ducking_calc;
+        if fast_unduck && newly_muted && calc.is_empty && calc.in_duck_mode {
+            debug!("[Ducker] Microphone Muted, unducking now");
+            self.ducking.temp.last_unduck_time = self.get_ducking_release_time();
+            let (allowed, volume) = self.handle_first(false);
+            if allowed {
+                self.run_ducking(volume).await;
+            }
+            return;
+        }
+
         self.handle_ducking_calculations().await;
     }
 
@@ -106,6 +418,15 @@ impl AudioDuckerTrait for GoXLR {
             return;
         }
 
+        // If the transitions have changed without us being told, our indexes are stale
+        let transition = &self.profile.ducking.transition;
+        let temp = &self.ducking.temp;
+        if temp.ducking_len != transition.ducking.len()
+            || temp.unducking_len != transition.unducking.len()
+        {
+            self.reset_ducker();
+        }
+
         let calc = &self.ducking.ducking_calc;
 
         if calc.need_duck_time_reset() {
@@ -119,6 +440,7 @@ impl AudioDuckerTrait for GoXLR {
 
             let (allowed, volume) = self.handle_first(true);
             if allowed {
+                self.update_duck_depth().await;
                 self.run_ducking(volume).await;
             }
         } else if calc.need_other_duck(
@@ -162,13 +484,15 @@ impl AudioDuckerTrait for GoXLR {
 
     //noinspection t
     async fn run_ducking(&mut self, volume: u8) {
-        for (input, input_map) in self.profile.ducking.output_routing {
+        let volume = self.scale_duck_volume(volume);
+        for (input, input_map) in self.get_ducked_routes() {
             for (output, state) in input_map {
                 let mut changed = false;
                 if state {
-                    match self.set_route_value(input, output.into(), volume) {
-                        Ok(_) => {
-                            changed = true;
+                    let value = RouteValue::Value(volume);
+                    match self.set_route_as(RouteController::Ducker, input, output.into(), value) {
+                        Ok(set) => {
+                            changed = set;
                         }
                         Err(err) => {
                             debug!("[Ducker] Error setting route value: {}", err);
@@ -183,14 +507,26 @@ impl AudioDuckerTrait for GoXLR {
                 }
             }
         }
+
+        // Once we're back up, other features are free to take these routes again
+        if !self.is_lowered() {
+            self.release_routes(RouteController::Ducker);
+        }
     }
 }
 
 trait InternalAudioDucker {
+    fn is_lowered(&self) -> bool;
+    fn get_ducked_routes(&self) -> EnumMap<InputChannels, EnumMap<OutputChannels, bool>>;
+    async fn restore_now(&mut self);
     fn update_check_time(&mut self, duck: bool, time: u64) -> bool;
     fn handle_first(&mut self, duck: bool) -> (bool, u8);
     fn handle_other(&mut self, duck: bool) -> (bool, u8);
-    fn handle_mic_calculations(&mut self, db: f64) -> (String, bool);
+    fn get_level_source(&mut self, input: DuckingInput) -> Arc<dyn LevelSource>;
+    fn get_input_trigger_db(&self, input: DuckingInput) -> f64;
+    async fn update_duck_depth(&mut self);
+    fn scale_duck_volume(&self, volume: u8) -> u8;
+    fn handle_mic_calculations(&mut self, db: f64) -> bool;
     fn noise_gate(
         &mut self,
         db_input: f64,
@@ -202,6 +538,46 @@ trait InternalAudioDucker {
 }
 
 impl InternalAudioDucker for GoXLR {
+    fn get_ducked_routes(&self) -> EnumMap<InputChannels, EnumMap<OutputChannels, bool>> {
+        // Category routes are expanded here rather than when they're set, so an input picks up
+        // (or drops) its category's routes as soon as it's moved between categories.
+        let ducking = &self.profile.ducking;
+        let mut routes = ducking.output_routing;
+        for (input, category) in self.profile.channels.categories {
+            let Some(category) = category else {
+                continue;
+            };
+            for (output, enabled) in ducking.category_routing[category] {
+                routes[input][output] |= enabled;
+            }
+        }
+        routes
+    }
+
+    fn is_lowered(&self) -> bool {
+        let calc = &self.ducking.ducking_calc;
+        let temp = &self.ducking.temp;
+        let unducked = temp.unducking_index >= self.profile.ducking.transition.unducking.len();
+
+        calc.in_duck_mode || calc.in_ducking || (calc.in_unducking && !unducked)
+    }
+
+    async fn restore_now(&mut self) {
+        // Jump straight to the end of the unducking transition, waiting on each step here would
+        // hold up everything else the device is doing.
+        if let Some(step) = self.profile.ducking.transition.unducking.last() {
+            self.run_ducking(step.route_volume).await;
+        }
+
+        let calc = &mut self.ducking.ducking_calc;
+        calc.in_duck_mode = false;
+        calc.in_ducking = false;
+        calc.in_unducking = true;
+        self.ducking.stats.update(false);
+        self.record_event(RecorderEvent::Ducking(false));
+        self.release_routes(RouteController::Ducker);
+    }
+
     fn update_check_time(&mut self, duck: bool, time: u64) -> bool {
         let last_time = if duck {
             self.ducking.temp.last_duck_time
@@ -228,7 +604,7 @@ impl InternalAudioDucker for GoXLR {
         let at_time = if duck {
             self.profile.ducking.attack_time
         } else {
-            self.profile.ducking.release_time
+            self.get_ducking_release_time()
         };
 
         if !self.update_check_time(duck, at_time) {
@@ -238,73 +614,141 @@ impl InternalAudioDucker for GoXLR {
         self.ducking.ducking_calc.in_duck_mode = duck;
         self.ducking.ducking_calc.in_ducking = duck;
         self.ducking.ducking_calc.in_unducking = !duck;
+        self.ducking.stats.update(duck);
+        self.record_event(RecorderEvent::Ducking(duck));
 
-        let route_volume = if duck {
-            self.ducking.temp.ducking_index += 1;
+        let step = if duck {
+            self.ducking.temp.ducking_index = 1;
             self.ducking.temp.last_unduck_time = 0;
             self.ducking.temp.unducking_index = 0;
-            self.profile.ducking.transition.ducking[0].route_volume
+            self.profile.ducking.transition.ducking.first()
         } else {
-            self.ducking.temp.unducking_index += 1;
+            self.ducking.temp.unducking_index = 1;
             self.ducking.temp.last_duck_time = 0;
             self.ducking.temp.ducking_index = 0;
-            self.profile.ducking.transition.unducking[0].route_volume
+            self.profile.ducking.transition.unducking.first()
         };
 
-        (true, route_volume)
+        match step {
+            Some(step) => (true, step.route_volume),
+            None => (false, 0),
+        }
     }
 
     fn handle_other(&mut self, duck: bool) -> (bool, u8) {
         // Check if we waited enough in between the lowering.
 
-        let wait_time = if duck {
-            self.profile.ducking.transition.ducking[self.ducking.temp.ducking_index - 1].wait_time
+        let transition = &self.profile.ducking.transition;
+        let (steps, index) = if duck {
+            (&transition.ducking, self.ducking.temp.ducking_index)
         } else {
-            self.profile.ducking.transition.unducking[self.ducking.temp.unducking_index - 1]
-                .wait_time
+            (&transition.unducking, self.ducking.temp.unducking_index)
         };
 
+        // We should always have taken a first step before getting here, if either the previous
+        // or next step has gone away, the transition has changed under us.
+        let previous = index.checked_sub(1).and_then(|previous| steps.get(previous));
+        let (Some(previous), Some(next)) = (previous, steps.get(index)) else {
+            debug!("[Ducker] Transition index {} out of range, resetting", index);
+            self.reset_ducker();
+            return (false, 0);
+        };
+        let (wait_time, route_volume) = (previous.wait_time, next.route_volume);
+
         if !self.update_check_time(duck, wait_time) {
             return (false, 0);
         }
 
-        let route_volume = if duck {
-            let index = self.ducking.temp.ducking_index;
+        if duck {
             self.ducking.temp.ducking_index += 1;
             self.ducking.temp.last_duck_time = 0;
             self.ducking.temp.unducking_index = 0;
-            self.profile.ducking.transition.ducking[index].route_volume
         } else {
-            let index = self.ducking.temp.unducking_index;
             self.ducking.temp.unducking_index += 1;
             self.ducking.temp.last_unduck_time = 0;
             self.ducking.temp.ducking_index = 0;
-            self.profile.ducking.transition.unducking[index].route_volume
-        };
+        }
 
         (true, route_volume)
     }
 
-    fn handle_mic_calculations(&mut self, db: f64) -> (String, bool) {
+    fn get_level_source(&mut self, input: DuckingInput) -> Arc<dyn LevelSource> {
+        if let Some(source) = &self.ducking.sources[input] {
+            return source.clone();
+        }
+
+        let source: Arc<dyn LevelSource> = match input {
+            DuckingInput::Mic => Arc::new(MicMeterSource::new(self.mic_level.meter())),
+            DuckingInput::Sample => Arc::new(self.ducking.sample_level.clone()),
+
+            // There's nothing on the device to measure these from, until a source is set
+            DuckingInput::ChatMic | DuckingInput::LineIn => Arc::new(InjectedLevel::default()),
+        };
+        self.ducking.sources[input] = Some(source.clone());
+        source
+    }
+
+    fn get_input_trigger_db(&self, input: DuckingInput) -> f64 {
+        let level = self.profile.ducking.input_trigger_db[input];
+        level.unwrap_or(self.get_ducking_trigger_db())
+    }
+
+    async fn update_duck_depth(&mut self) {
+        // This is measured once as the duck starts, if it followed the Music while ducked it
+        // would be chasing its own effect should the source be measured after the GoXLR.
+        self.ducking.depth_scale = None;
+        if !self.profile.ducking.adaptive.enabled {
+            return;
+        }
+
+        let Some(source) = self.ducking.music_level.clone() else {
+            debug!("[Ducker] Adaptive Ducking enabled, but no Music Level Source is set");
+            return;
+        };
+        let Some(db) = source.level_db().await else {
+            return;
+        };
+
+        let steps = &self.profile.ducking.transition.ducking;
+        let deepest = steps.iter().map(|step| step.route_volume).min();
+        let deepest = deepest.unwrap_or(ROUTE_MAX).min(ROUTE_MAX);
+        if deepest == ROUTE_MAX {
+            // The transition doesn't lower anything, so there's nothing to scale
+            return;
+        }
+
+        let target = adaptive_volume(&self.profile.ducking.adaptive, db);
+        let scale = f64::from(ROUTE_MAX - target) / f64::from(ROUTE_MAX - deepest);
+        debug!("[Ducker] Music at {:.1}dB, ducking to {}", db, target);
+        self.ducking.depth_scale = Some(scale);
+    }
+
+    fn scale_duck_volume(&self, volume: u8) -> u8 {
+        let Some(scale) = self.ducking.depth_scale else {
+            return volume;
+        };
+
+        let depth = f64::from(ROUTE_MAX - volume.min(ROUTE_MAX)) * scale;
+        ROUTE_MAX - (depth.round() as u8).min(ROUTE_MAX)
+    }
+
+    fn handle_mic_calculations(&mut self, db: f64) -> bool {
         // TODO Noise Gate calculations!
 
         let new_db = self.noise_gate(
             db,
-            self.mic_profile.gate.threshold + 12,
+            self.get_gate_threshold() + 12,
             self.mic_profile.gate.attenuation,
-            self.mic_profile.gate.attack.to_u16(),
-            self.mic_profile.gate.release.to_u16(),
+            u16::from(self.mic_profile.gate.attack),
+            u16::from(self.mic_profile.gate.release),
         );
 
         // Threshold, Attenuation, Attack, Release
 
         //debug!("{}", &db);
 
-        if new_db >= self.mic_profile.gate.threshold as f64 {
-            (DuckingInput::Mic.to_string(), true)
-        } else {
-            (DuckingInput::Mic.to_string(), false)
-        }
+        let offset = self.get_mic_trigger_offset();
+        new_db >= f64::from(self.get_gate_threshold()) + f64::from(offset)
     }
 
     fn noise_gate(
@@ -363,6 +807,78 @@ impl InternalAudioDucker for GoXLR {
     }
 }
 
+/// Maps the Music's level to the route volume it should be ducked to, between the quiet and loud
+/// points the volume is interpolated linearly in dB.
+fn adaptive_volume(settings: &AdaptiveDucking, db: f64) -> u8 {
+    let range = settings.loud_db - settings.quiet_db;
+    let position = ((db - settings.quiet_db) / range).clamp(0., 1.);
+
+    let (quiet, loud) = (
+        f64::from(settings.quiet_volume),
+        f64::from(settings.loud_volume),
+    );
+    (quiet + (loud - quiet) * position).round() as u8
+}
+
+/// Keeps track of how often, and for how long, the ducker has been active
+struct DuckingStats {
+    since: Instant,
+    activations: u32,
+    total_ducked: Duration,
+    ducked_at: Option<Instant>,
+}
+
+impl Default for DuckingStats {
+    fn default() -> Self {
+        Self {
+            since: Instant::now(),
+            activations: 0,
+            total_ducked: Duration::ZERO,
+            ducked_at: None,
+        }
+    }
+}
+
+impl DuckingStats {
+    fn update(&mut self, ducked: bool) {
+        if ducked && self.ducked_at.is_none() {
+            self.activations += 1;
+            self.ducked_at = Some(Instant::now());
+        } else if !ducked {
+            if let Some(ducked_at) = self.ducked_at.take() {
+                self.total_ducked += ducked_at.elapsed();
+            }
+        }
+    }
+
+    fn report(&self) -> DuckingStatsReport {
+        // Include the current duck, if there is one..
+        let current = self.ducked_at.map(|at| at.elapsed()).unwrap_or_default();
+        let total_ducked = self.total_ducked + current;
+        let tracked = self.since.elapsed();
+
+        let hours = tracked.as_secs_f64() / 3600.;
+        let activations_per_hour = match hours > 0. {
+            true => self.activations as f64 / hours,
+            false => 0.,
+        };
+
+        let average_duck_ms = match self.activations {
+            0 => 0,
+            count => total_ducked.as_millis() as u64 / count as u64,
+        };
+
+        DuckingStatsReport {
+            tracked_ms: tracked.as_millis() as u64,
+            activations: self.activations,
+            activations_per_hour,
+            total_ducked_ms: total_ducked.as_millis() as u64,
+            average_duck_ms,
+            ducked: self.ducked_at.is_some(),
+        }
+    }
+}
+
 struct SimulatedNoiseGate {
     last_attack: u64,
     last_release: u64,
@@ -374,7 +890,7 @@ impl Default for SimulatedNoiseGate {
     fn default() -> Self {
         Self {
             last_attack: Default::default(),
-            last_release: GateTimes::Time2000ms.to_u16() as u64,
+            last_release: u16::from(GateTimes::Time2000ms) as u64,
             last_attack_db: Default::default(),
             was_above: Default::default()
         }
@@ -0,0 +1,193 @@
+use anyhow::{bail, Context, Result};
+use ritelinked::LinkedHashMap;
+
+use goxlr_shared::device::DeviceType;
+use goxlr_shared::effects::EchoStyle;
+use goxlr_shared::microphone::MicEffectKeys;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::device::GoXLR;
+
+pub trait Echo {
+    async fn set_echo_style(&mut self, style: EchoStyle) -> Result<()>;
+    async fn set_echo_amount(&mut self, amount: u8) -> Result<()>;
+    async fn set_echo_feedback(&mut self, feedback: u8) -> Result<()>;
+    async fn set_echo_tempo(&mut self, tempo: u16) -> Result<()>;
+    async fn set_echo_delay_left(&mut self, delay: u16) -> Result<()>;
+    async fn set_echo_delay_right(&mut self, delay: u16) -> Result<()>;
+    async fn set_echo_feedback_left(&mut self, feedback: u8) -> Result<()>;
+    async fn set_echo_feedback_right(&mut self, feedback: u8) -> Result<()>;
+    async fn set_echo_feedback_xfb_l_to_r(&mut self, feedback: u8) -> Result<()>;
+    async fn set_echo_feedback_xfb_r_to_l(&mut self, feedback: u8) -> Result<()>;
+}
+
+impl Echo for GoXLR {
+    async fn set_echo_style(&mut self, style: EchoStyle) -> Result<()> {
+        self.ensure_echo_supported()?;
+        self.profile.effects.echo.style = style;
+        self.apply_echo_value(MicEffectKeys::EchoFilterStyle).await
+    }
+
+    async fn set_echo_amount(&mut self, amount: u8) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if amount > 100 {
+            bail!("Echo Amount must be a percentage");
+        }
+
+        self.profile.effects.echo.amount = amount;
+        self.apply_echo_value(MicEffectKeys::EchoAmount).await
+    }
+
+    async fn set_echo_feedback(&mut self, feedback: u8) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if feedback > 100 {
+            bail!("Echo Feedback must be between 0 and 100");
+        }
+
+        self.profile.effects.echo.feedback = feedback;
+        self.apply_echo_value(MicEffectKeys::EchoFeedback).await
+    }
+
+    async fn set_echo_tempo(&mut self, tempo: u16) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if !(45..=300).contains(&tempo) {
+            bail!("Echo Tempo must be between 45 and 300bpm");
+        }
+
+        self.profile.effects.echo.tempo = tempo;
+        self.apply_echo_value(MicEffectKeys::EchoTempo).await
+    }
+
+    async fn set_echo_delay_left(&mut self, delay: u16) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if delay > 2500 {
+            bail!("Echo Delay must be between 0 and 2500ms");
+        }
+
+        self.profile.effects.echo.delay_left = delay;
+        self.apply_echo_value(MicEffectKeys::EchoDelayL).await
+    }
+
+    async fn set_echo_delay_right(&mut self, delay: u16) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if delay > 2500 {
+            bail!("Echo Delay must be between 0 and 2500ms");
+        }
+
+        self.profile.effects.echo.delay_right = delay;
+        self.apply_echo_value(MicEffectKeys::EchoDelayR).await
+    }
+
+    async fn set_echo_feedback_left(&mut self, feedback: u8) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if feedback > 100 {
+            bail!("Echo Feedback must be between 0 and 100");
+        }
+
+        self.profile.effects.echo.feedback_left = feedback;
+        self.apply_echo_value(MicEffectKeys::EchoFeedbackL).await
+    }
+
+    async fn set_echo_feedback_right(&mut self, feedback: u8) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if feedback > 100 {
+            bail!("Echo Feedback must be between 0 and 100");
+        }
+
+        self.profile.effects.echo.feedback_right = feedback;
+        self.apply_echo_value(MicEffectKeys::EchoFeedbackR).await
+    }
+
+    async fn set_echo_feedback_xfb_l_to_r(&mut self, feedback: u8) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if feedback > 100 {
+            bail!("Echo Cross Feedback must be between 0 and 100");
+        }
+
+        self.profile.effects.echo.feedback_xfb_l_to_r = feedback;
+        self.apply_echo_value(MicEffectKeys::EchoXFBLtoR).await
+    }
+
+    async fn set_echo_feedback_xfb_r_to_l(&mut self, feedback: u8) -> Result<()> {
+        self.ensure_echo_supported()?;
+
+        if feedback > 100 {
+            bail!("Echo Cross Feedback must be between 0 and 100");
+        }
+
+        self.profile.effects.echo.feedback_xfb_r_to_l = feedback;
+        self.apply_echo_value(MicEffectKeys::EchoXFBRtoL).await
+    }
+}
+
+pub(crate) trait EchoCrate {
+    fn get_echo_values(&self) -> LinkedHashMap<MicEffectKeys, i32>;
+}
+
+impl EchoCrate for GoXLR {
+    fn get_echo_values(&self) -> LinkedHashMap<MicEffectKeys, i32> {
+        let mut map = LinkedHashMap::new();
+        let echo = self.profile.effects.echo;
+
+        // Fill out all the Echo Values..
+        map.insert(MicEffectKeys::EchoAmount, self.get_echo_amount());
+        map.insert(MicEffectKeys::EchoFeedback, echo.feedback as i32);
+        map.insert(MicEffectKeys::EchoTempo, echo.tempo as i32);
+        map.insert(MicEffectKeys::EchoDelayL, echo.delay_left as i32);
+        map.insert(MicEffectKeys::EchoDelayR, echo.delay_right as i32);
+        map.insert(MicEffectKeys::EchoFeedbackL, echo.feedback_left as i32);
+        map.insert(MicEffectKeys::EchoFeedbackR, echo.feedback_right as i32);
+        map.insert(MicEffectKeys::EchoXFBLtoR, echo.feedback_xfb_l_to_r as i32);
+        map.insert(MicEffectKeys::EchoXFBRtoL, echo.feedback_xfb_r_to_l as i32);
+
+        // Source and Divisions aren't configurable, the style handles the timing.
+        map.insert(MicEffectKeys::EchoSource, 0);
+        map.insert(MicEffectKeys::EchoDivL, 0);
+        map.insert(MicEffectKeys::EchoDivR, 0);
+        map.insert(MicEffectKeys::EchoFilterStyle, echo.style as i32);
+
+        map
+    }
+}
+
+trait EchoLocal {
+    fn ensure_echo_supported(&self) -> Result<()>;
+
+    fn get_echo_amount(&self) -> i32;
+
+    async fn apply_echo_value(&self, key: MicEffectKeys) -> Result<()>;
+}
+
+impl EchoLocal for GoXLR {
+    fn ensure_echo_supported(&self) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            bail!("Effects are not available on the GoXLR Mini");
+        }
+        Ok(())
+    }
+
+    fn get_echo_amount(&self) -> i32 {
+        // The device expects the amount as -36 to 0, rather than a percentage
+        let percent = self.profile.effects.echo.amount as f32;
+        (percent * 0.36).round() as i32 - 36
+    }
+
+    async fn apply_echo_value(&self, key: MicEffectKeys) -> Result<()> {
+        let value = *self
+            .get_echo_values()
+            .get(&key)
+            .context("Invalid Echo Key")?;
+        let effect = LinkedHashMap::from_iter([(key, value)]);
+        let command = BasicResultCommand::SetMicEffects(effect);
+        self.send_no_result(command).await
+    }
+}
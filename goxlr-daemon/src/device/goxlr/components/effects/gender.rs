@@ -0,0 +1,104 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Context, Result};
+use ritelinked::LinkedHashMap;
+
+use goxlr_shared::device::DeviceType;
+use goxlr_shared::effects::GenderStyle;
+use goxlr_shared::microphone::MicEffectKeys;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::device::GoXLR;
+
+pub trait Gender {
+    async fn set_gender_style(&mut self, style: GenderStyle) -> Result<()>;
+    async fn set_gender_amount(&mut self, amount: i8) -> Result<()>;
+}
+
+impl Gender for GoXLR {
+    async fn set_gender_style(&mut self, style: GenderStyle) -> Result<()> {
+        self.ensure_gender_supported()?;
+        self.profile.effects.gender.style = style;
+
+        // Make sure the amount still fits inside the new range..
+        let range = self.get_gender_range();
+        let amount = self.profile.effects.gender.amount;
+        self.profile.effects.gender.amount = amount.clamp(*range.start(), *range.end());
+
+        self.apply_gender_amount().await
+    }
+
+    async fn set_gender_amount(&mut self, amount: i8) -> Result<()> {
+        self.ensure_gender_supported()?;
+
+        let range = self.get_gender_range();
+        if !range.contains(&amount) {
+            bail!(
+                "Gender Amount must be between {} and {}",
+                range.start(),
+                range.end()
+            );
+        }
+
+        self.profile.effects.gender.amount = amount;
+        self.apply_gender_amount().await
+    }
+}
+
+pub(crate) trait GenderCrate {
+    fn get_gender_values(&self) -> LinkedHashMap<MicEffectKeys, i32>;
+
+    /// Called when the Gender encoder has been physically turned on the device.
+    async fn set_gender_from_encoder(&mut self, value: i8) -> Result<()>;
+}
+
+impl GenderCrate for GoXLR {
+    fn get_gender_values(&self) -> LinkedHashMap<MicEffectKeys, i32> {
+        let mut map = LinkedHashMap::new();
+        let amount = self.profile.effects.gender.amount as i32;
+
+        map.insert(MicEffectKeys::GenderAmount, amount);
+        map
+    }
+
+    async fn set_gender_from_encoder(&mut self, value: i8) -> Result<()> {
+        // The encoder turns in the same steps as the amount, so only needs keeping in range
+        let range = self.get_gender_range();
+        self.profile.effects.gender.amount = value.clamp(*range.start(), *range.end());
+        self.apply_gender_amount().await
+    }
+}
+
+trait GenderLocal {
+    fn ensure_gender_supported(&self) -> Result<()>;
+
+    fn get_gender_range(&self) -> RangeInclusive<i8>;
+
+    async fn apply_gender_amount(&self) -> Result<()>;
+}
+
+impl GenderLocal for GoXLR {
+    fn ensure_gender_supported(&self) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            bail!("Effects are not available on the GoXLR Mini");
+        }
+        Ok(())
+    }
+
+    fn get_gender_range(&self) -> RangeInclusive<i8> {
+        match self.profile.effects.gender.style {
+            GenderStyle::Narrow => -12..=12,
+            GenderStyle::Medium => -25..=25,
+            GenderStyle::Wide => -50..=50,
+        }
+    }
+
+    async fn apply_gender_amount(&self) -> Result<()> {
+        let key = MicEffectKeys::GenderAmount;
+        let amount = self.profile.effects.gender.amount as i32;
+        let effect = LinkedHashMap::from_iter([(key, amount)]);
+        let command = BasicResultCommand::SetMicEffects(effect);
+        self.send_no_result(command).await
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::{bail, Context, Result};
+use ritelinked::LinkedHashMap;
+
+use goxlr_shared::device::DeviceType;
+use goxlr_shared::microphone::MicEffectKeys;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::components::effects::pitch::PitchCrate;
+use crate::device::goxlr::device::GoXLR;
+
+pub trait HardTune {
+    async fn set_hard_tune_enabled(&mut self, enabled: bool) -> Result<()>;
+}
+
+impl HardTune for GoXLR {
+    async fn set_hard_tune_enabled(&mut self, enabled: bool) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            bail!("Effects are not available on the GoXLR Mini");
+        }
+
+        if self.profile.effects.hard_tune.enabled == enabled {
+            return Ok(());
+        }
+
+        // The Pitch amount switches between semitones and octaves when Hard Tune is toggled, so
+        // convert it across before the encoder is reconfigured.
+        let amount = self.profile.effects.pitch.amount;
+        self.profile.effects.pitch.amount = match enabled {
+            true => amount / 12,
+            false => amount.saturating_mul(12),
+        };
+        self.profile.effects.hard_tune.enabled = enabled;
+
+        let key = MicEffectKeys::HardTuneEnabled;
+        let effect = LinkedHashMap::from_iter([(key, enabled as i32)]);
+        let command = BasicResultCommand::SetMicEffects(effect);
+        self.send_no_result(command).await?;
+
+        self.apply_pitch_encoder().await
+    }
+}
+
+pub(crate) trait HardTuneCrate {
+    fn get_hard_tune_values(&self) -> LinkedHashMap<MicEffectKeys, i32>;
+}
+
+impl HardTuneCrate for GoXLR {
+    fn get_hard_tune_values(&self) -> LinkedHashMap<MicEffectKeys, i32> {
+        let mut map = LinkedHashMap::new();
+        let enabled = self.profile.effects.hard_tune.enabled as i32;
+
+        map.insert(MicEffectKeys::HardTuneEnabled, enabled);
+        map
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::{Context, Result};
+use log::debug;
+use ritelinked::LinkedHashMap;
+
+use goxlr_shared::device::DeviceType;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::components::effects::echo::EchoCrate;
+use crate::device::goxlr::components::effects::gender::GenderCrate;
+use crate::device::goxlr::components::effects::hard_tune::HardTuneCrate;
+use crate::device::goxlr::components::effects::pitch::PitchCrate;
+use crate::device::goxlr::components::effects::reverb::ReverbCrate;
+use crate::device::goxlr::device::GoXLR;
+
+pub trait LoadEffects {
+    async fn load_effects(&mut self) -> Result<()>;
+}
+
+impl LoadEffects for GoXLR {
+    async fn load_effects(&mut self) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            debug!("Device is a Mini, skipping Effects..");
+            return Ok(());
+        }
+
+        debug!("Loading Effects..");
+        let mut effects = LinkedHashMap::new();
+
+        // Load the Reverb..
+        effects.extend(self.get_reverb_values());
+
+        // Load the Echo..
+        effects.extend(self.get_echo_values());
+
+        // Load the Pitch..
+        effects.extend(self.get_pitch_values());
+
+        // Load the Gender..
+        effects.extend(self.get_gender_values());
+
+        // Load Hard Tune..
+        effects.extend(self.get_hard_tune_values());
+
+        let command = BasicResultCommand::SetMicEffects(effects);
+        self.send_no_result(command).await?;
+
+        // The Pitch encoder range depends on the Hard Tune state, so configure it last
+        self.apply_pitch_encoder().await
+    }
+}
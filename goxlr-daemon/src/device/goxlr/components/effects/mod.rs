@@ -0,0 +1,6 @@
+pub mod echo;
+pub mod gender;
+pub mod hard_tune;
+pub mod load_effects;
+pub mod pitch;
+pub mod reverb;
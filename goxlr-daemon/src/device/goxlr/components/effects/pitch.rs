@@ -0,0 +1,186 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Context, Result};
+use ritelinked::LinkedHashMap;
+
+use goxlr_shared::device::DeviceType;
+use goxlr_shared::effects::PitchStyle;
+use goxlr_shared::encoders::Encoders;
+use goxlr_shared::microphone::MicEffectKeys;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::device::GoXLR;
+
+pub trait Pitch {
+    async fn set_pitch_style(&mut self, style: PitchStyle) -> Result<()>;
+    async fn set_pitch_amount(&mut self, amount: i8) -> Result<()>;
+    async fn set_pitch_character(&mut self, character: u8) -> Result<()>;
+}
+
+impl Pitch for GoXLR {
+    async fn set_pitch_style(&mut self, style: PitchStyle) -> Result<()> {
+        self.ensure_pitch_supported()?;
+        self.profile.effects.pitch.style = style;
+
+        // Changing the style changes the range, so the encoder needs reconfiguring
+        self.apply_pitch_encoder().await
+    }
+
+    async fn set_pitch_amount(&mut self, amount: i8) -> Result<()> {
+        self.ensure_pitch_supported()?;
+
+        let range = self.get_pitch_range();
+        if !range.contains(&amount) {
+            bail!(
+                "Pitch Amount must be between {} and {}",
+                range.start(),
+                range.end()
+            );
+        }
+
+        self.profile.effects.pitch.amount = amount;
+
+        let value = self.get_pitch_encoder_value();
+        let command = BasicResultCommand::SetEncoderValue(Encoders::Pitch, value);
+        self.send_no_result(command).await?;
+
+        self.apply_pitch_amount().await
+    }
+
+    async fn set_pitch_character(&mut self, character: u8) -> Result<()> {
+        self.ensure_pitch_supported()?;
+        if character > 100 {
+            bail!("Pitch Character must be a percentage");
+        }
+
+        self.profile.effects.pitch.character = character;
+
+        let key = MicEffectKeys::PitchCharacter;
+        let effect = LinkedHashMap::from_iter([(key, character as i32)]);
+        let command = BasicResultCommand::SetMicEffects(effect);
+        self.send_no_result(command).await
+    }
+}
+
+pub(crate) trait PitchCrate {
+    fn get_pitch_values(&self) -> LinkedHashMap<MicEffectKeys, i32>;
+
+    /// Configures the Pitch encoder for the current Style and Hard Tune state, clamping the
+    /// current amount to the new range if needed.
+    async fn apply_pitch_encoder(&mut self) -> Result<()>;
+
+    /// Called when the Pitch encoder has been physically turned on the device.
+    async fn set_pitch_from_encoder(&mut self, value: i8) -> Result<()>;
+}
+
+impl PitchCrate for GoXLR {
+    fn get_pitch_values(&self) -> LinkedHashMap<MicEffectKeys, i32> {
+        let mut map = LinkedHashMap::new();
+        let character = self.profile.effects.pitch.character as i32;
+
+        map.insert(MicEffectKeys::PitchAmount, self.get_pitch_amount());
+        map.insert(MicEffectKeys::PitchCharacter, character);
+
+        map
+    }
+
+    async fn apply_pitch_encoder(&mut self) -> Result<()> {
+        let range = self.get_pitch_range();
+        let amount = self.profile.effects.pitch.amount;
+        self.profile.effects.pitch.amount = amount.clamp(*range.start(), *range.end());
+
+        // When Hard Tune is enabled, the encoder is 'stepped' to a number of octaves
+        let (mode, steps) = match self.profile.effects.hard_tune.enabled {
+            true => match self.profile.effects.pitch.style {
+                PitchStyle::Narrow => (3, 1),
+                PitchStyle::Wide => (3, 2),
+            },
+            false => (1, 4),
+        };
+
+        let command = BasicResultCommand::SetEncoderMode(Encoders::Pitch, mode, steps);
+        self.send_no_result(command).await?;
+
+        let value = self.get_pitch_encoder_value();
+        let command = BasicResultCommand::SetEncoderValue(Encoders::Pitch, value);
+        self.send_no_result(command).await?;
+
+        self.apply_pitch_amount().await
+    }
+
+    async fn set_pitch_from_encoder(&mut self, value: i8) -> Result<()> {
+        let amount = match self.profile.effects.hard_tune.enabled {
+            true => value,
+            false => match self.profile.effects.pitch.style {
+                PitchStyle::Narrow => value / 2,
+                PitchStyle::Wide => value,
+            },
+        };
+
+        let range = self.get_pitch_range();
+        self.profile.effects.pitch.amount = amount.clamp(*range.start(), *range.end());
+        self.apply_pitch_amount().await
+    }
+}
+
+trait PitchLocal {
+    fn ensure_pitch_supported(&self) -> Result<()>;
+
+    fn get_pitch_range(&self) -> RangeInclusive<i8>;
+    fn get_pitch_amount(&self) -> i32;
+    fn get_pitch_encoder_value(&self) -> i8;
+
+    async fn apply_pitch_amount(&self) -> Result<()>;
+}
+
+impl PitchLocal for GoXLR {
+    fn ensure_pitch_supported(&self) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            bail!("Effects are not available on the GoXLR Mini");
+        }
+        Ok(())
+    }
+
+    fn get_pitch_range(&self) -> RangeInclusive<i8> {
+        // The hardware only permits whole octaves when Hard Tune is enabled
+        let style = self.profile.effects.pitch.style;
+        match self.profile.effects.hard_tune.enabled {
+            true => match style {
+                PitchStyle::Narrow => -1..=1,
+                PitchStyle::Wide => -2..=2,
+            },
+            false => match style {
+                PitchStyle::Narrow => -12..=12,
+                PitchStyle::Wide => -24..=24,
+            },
+        }
+    }
+
+    fn get_pitch_amount(&self) -> i32 {
+        // The device always expects semitones, so convert octaves when Hard Tune is on
+        let amount = self.profile.effects.pitch.amount as i32;
+        match self.profile.effects.hard_tune.enabled {
+            true => amount * 12,
+            false => amount,
+        }
+    }
+
+    fn get_pitch_encoder_value(&self) -> i8 {
+        let amount = self.profile.effects.pitch.amount;
+        match self.profile.effects.hard_tune.enabled {
+            true => amount,
+            false => match self.profile.effects.pitch.style {
+                PitchStyle::Narrow => amount * 2,
+                PitchStyle::Wide => amount,
+            },
+        }
+    }
+
+    async fn apply_pitch_amount(&self) -> Result<()> {
+        let key = MicEffectKeys::PitchAmount;
+        let effect = LinkedHashMap::from_iter([(key, self.get_pitch_amount())]);
+        let command = BasicResultCommand::SetMicEffects(effect);
+        self.send_no_result(command).await
+    }
+}
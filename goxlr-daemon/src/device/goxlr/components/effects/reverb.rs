@@ -0,0 +1,215 @@
+use anyhow::{bail, Context, Result};
+use ritelinked::LinkedHashMap;
+
+use goxlr_shared::device::DeviceType;
+use goxlr_shared::effects::ReverbStyle;
+use goxlr_shared::microphone::MicEffectKeys;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::device::GoXLR;
+
+pub trait Reverb {
+    async fn set_reverb_style(&mut self, style: ReverbStyle) -> Result<()>;
+    async fn set_reverb_amount(&mut self, amount: u8) -> Result<()>;
+    async fn set_reverb_decay(&mut self, decay: u16) -> Result<()>;
+    async fn set_reverb_early_level(&mut self, level: i8) -> Result<()>;
+    async fn set_reverb_pre_delay(&mut self, delay: u8) -> Result<()>;
+    async fn set_reverb_low_colour(&mut self, colour: i8) -> Result<()>;
+    async fn set_reverb_high_colour(&mut self, colour: i8) -> Result<()>;
+    async fn set_reverb_high_factor(&mut self, factor: i8) -> Result<()>;
+    async fn set_reverb_diffuse(&mut self, diffuse: i8) -> Result<()>;
+    async fn set_reverb_mod_speed(&mut self, speed: i8) -> Result<()>;
+    async fn set_reverb_mod_depth(&mut self, depth: i8) -> Result<()>;
+}
+
+impl Reverb for GoXLR {
+    async fn set_reverb_style(&mut self, style: ReverbStyle) -> Result<()> {
+        self.ensure_reverb_supported()?;
+        self.profile.effects.reverb.style = style;
+        self.apply_reverb_value(MicEffectKeys::ReverbType).await
+    }
+
+    async fn set_reverb_amount(&mut self, amount: u8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if amount > 100 {
+            bail!("Reverb Amount must be a percentage");
+        }
+
+        self.profile.effects.reverb.amount = amount;
+        self.apply_reverb_value(MicEffectKeys::ReverbAmount).await
+    }
+
+    async fn set_reverb_decay(&mut self, decay: u16) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if !(10..=10000).contains(&decay) {
+            bail!("Reverb Decay must be between 10 and 10000ms");
+        }
+
+        self.profile.effects.reverb.decay = decay;
+        self.apply_reverb_value(MicEffectKeys::ReverbDecay).await
+    }
+
+    async fn set_reverb_early_level(&mut self, level: i8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if !(-25..=0).contains(&level) {
+            bail!("Reverb Early Level must be between -25 and 0");
+        }
+
+        self.profile.effects.reverb.early_level = level;
+        self.apply_reverb_value(MicEffectKeys::ReverbEarlyLevel)
+            .await
+    }
+
+    async fn set_reverb_pre_delay(&mut self, delay: u8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if delay > 100 {
+            bail!("Reverb Pre-Delay must be between 0 and 100ms");
+        }
+
+        self.profile.effects.reverb.pre_delay = delay;
+        self.apply_reverb_value(MicEffectKeys::ReverbPredelay).await
+    }
+
+    async fn set_reverb_low_colour(&mut self, colour: i8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if !(-50..=50).contains(&colour) {
+            bail!("Reverb Low Colour must be between -50 and 50");
+        }
+
+        self.profile.effects.reverb.low_colour = colour;
+        self.apply_reverb_value(MicEffectKeys::ReverbLowColor).await
+    }
+
+    async fn set_reverb_high_colour(&mut self, colour: i8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if !(-50..=50).contains(&colour) {
+            bail!("Reverb High Colour must be between -50 and 50");
+        }
+
+        self.profile.effects.reverb.high_colour = colour;
+        self.apply_reverb_value(MicEffectKeys::ReverbHighColor)
+            .await
+    }
+
+    async fn set_reverb_high_factor(&mut self, factor: i8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if !(-25..=25).contains(&factor) {
+            bail!("Reverb High Factor must be between -25 and 25");
+        }
+
+        self.profile.effects.reverb.high_factor = factor;
+        self.apply_reverb_value(MicEffectKeys::ReverbHighFactor)
+            .await
+    }
+
+    async fn set_reverb_diffuse(&mut self, diffuse: i8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if !(-50..=50).contains(&diffuse) {
+            bail!("Reverb Diffuse must be between -50 and 50");
+        }
+
+        self.profile.effects.reverb.diffuse = diffuse;
+        self.apply_reverb_value(MicEffectKeys::ReverbDiffuse).await
+    }
+
+    async fn set_reverb_mod_speed(&mut self, speed: i8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if !(-25..=25).contains(&speed) {
+            bail!("Reverb Mod Speed must be between -25 and 25");
+        }
+
+        self.profile.effects.reverb.mod_speed = speed;
+        self.apply_reverb_value(MicEffectKeys::ReverbModSpeed).await
+    }
+
+    async fn set_reverb_mod_depth(&mut self, depth: i8) -> Result<()> {
+        self.ensure_reverb_supported()?;
+
+        if !(-25..=25).contains(&depth) {
+            bail!("Reverb Mod Depth must be between -25 and 25");
+        }
+
+        self.profile.effects.reverb.mod_depth = depth;
+        self.apply_reverb_value(MicEffectKeys::ReverbModDepth).await
+    }
+}
+
+pub(crate) trait ReverbCrate {
+    fn get_reverb_values(&self) -> LinkedHashMap<MicEffectKeys, i32>;
+}
+
+impl ReverbCrate for GoXLR {
+    fn get_reverb_values(&self) -> LinkedHashMap<MicEffectKeys, i32> {
+        let mut map = LinkedHashMap::new();
+        let reverb = self.profile.effects.reverb;
+
+        // Fill out all the Reverb Values..
+        map.insert(MicEffectKeys::ReverbAmount, self.get_reverb_amount());
+        map.insert(MicEffectKeys::ReverbDecay, self.get_reverb_decay());
+        map.insert(MicEffectKeys::ReverbEarlyLevel, reverb.early_level as i32);
+        map.insert(MicEffectKeys::ReverbTailLevel, 0);
+        map.insert(MicEffectKeys::ReverbPredelay, reverb.pre_delay as i32);
+        map.insert(MicEffectKeys::ReverbLowColor, reverb.low_colour as i32);
+        map.insert(MicEffectKeys::ReverbHighColor, reverb.high_colour as i32);
+        map.insert(MicEffectKeys::ReverbHighFactor, reverb.high_factor as i32);
+        map.insert(MicEffectKeys::ReverbDiffuse, reverb.diffuse as i32);
+        map.insert(MicEffectKeys::ReverbModSpeed, reverb.mod_speed as i32);
+        map.insert(MicEffectKeys::ReverbModDepth, reverb.mod_depth as i32);
+        map.insert(MicEffectKeys::ReverbType, reverb.style as i32);
+
+        map
+    }
+}
+
+trait ReverbLocal {
+    fn ensure_reverb_supported(&self) -> Result<()>;
+
+    fn get_reverb_amount(&self) -> i32;
+    fn get_reverb_decay(&self) -> i32;
+
+    async fn apply_reverb_value(&self, key: MicEffectKeys) -> Result<()>;
+}
+
+impl ReverbLocal for GoXLR {
+    fn ensure_reverb_supported(&self) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            bail!("Effects are not available on the GoXLR Mini");
+        }
+        Ok(())
+    }
+
+    fn get_reverb_amount(&self) -> i32 {
+        // The device expects the amount as -36 to 0, rather than a percentage
+        let percent = self.profile.effects.reverb.amount as f32;
+        (percent * 0.36).round() as i32 - 36
+    }
+
+    fn get_reverb_decay(&self) -> i32 {
+        // Decay is 10ms steps up to 1 second, then 100ms steps beyond that
+        let decay = self.profile.effects.reverb.decay as i32;
+        if decay <= 1000 {
+            return decay / 10;
+        }
+        100 + ((decay - 1000) / 100)
+    }
+
+    async fn apply_reverb_value(&self, key: MicEffectKeys) -> Result<()> {
+        let value = *self
+            .get_reverb_values()
+            .get(&key)
+            .context("Invalid Reverb Key")?;
+        let effect = LinkedHashMap::from_iter([(key, value)]);
+        let command = BasicResultCommand::SetMicEffects(effect);
+        self.send_no_result(command).await
+    }
+}
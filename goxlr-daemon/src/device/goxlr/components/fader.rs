@@ -8,24 +8,40 @@ use goxlr_shared::channels::fader::FaderChannels;
 use goxlr_shared::colours::Colour;
 use goxlr_shared::device::{DeviceType, GoXLRFeature};
 use goxlr_shared::faders::Fader;
+use goxlr_shared::locale::DisplayName;
 use goxlr_shared::mute::MuteState;
 use goxlr_shared::scribbles::Scribble;
 use goxlr_usb::events::commands::BasicResultCommand;
 
 use crate::device::goxlr::components::buttons::ButtonHandlers;
+use crate::device::goxlr::components::headphone_limit::HeadphoneLimitCrate;
 use crate::device::goxlr::components::load_profile::LoadProfile;
 use crate::device::goxlr::components::mute_handler::MuteHandler;
 use crate::device::goxlr::components::profile::Profile;
+use crate::device::goxlr::components::scribble_widgets::ScribbleWidgetsCrate;
+use crate::device::goxlr::components::session_stats::SessionStatisticsCrate;
 use crate::device::goxlr::device::GoXLR;
 
 const SUBMIX_MITIGATION: &[FaderChannels] = &[FaderChannels::Headphones, FaderChannels::LineOut];
 
+// With linked lighting, a fader at zero is still lit this much, so it isn't mistaken for muted
+const LINKED_MIN_INTENSITY: u32 = 10;
+
 /// This trait is responsible for the management of faders, everything from the top of the
 /// scribble display, to the bottom of the mute button. Any changes which are to occur to them
 /// should make their way through here.
 pub(crate) trait DeviceFader {
     async fn assign_fader(&mut self, fader: Fader, source: FaderChannels) -> Result<()>;
     async fn update_mute_state(&mut self, source: FaderChannels, state: MuteState) -> Result<()>;
+    async fn set_channel_name(&mut self, source: FaderChannels, name: Option<String>)
+        -> Result<()>;
+
+    /// Redraws the assigned channel's name, for after something else has used the scribble
+    async fn refresh_scribble(&mut self, fader: Fader) -> Result<()>;
+
+    /// Updates a channel's fader colours, for when something they follow (such as the volume
+    /// with linked lighting) has changed
+    async fn refresh_fader_colours(&mut self, source: FaderChannels) -> Result<()>;
 }
 
 impl DeviceFader for GoXLR {
@@ -65,6 +81,9 @@ impl DeviceFader for GoXLR {
         if SUBMIX_MITIGATION.contains(&source) {
             let device = self.device.as_ref().context("Device Not Found!")?;
             if device.features.contains(&GoXLRFeature::SubMix) {
+                // This runs during profile loads before the volumes are applied, so the
+                // Headphones need holding to their limit here too.
+                self.enforce_headphone_limit(source.into());
                 let volume = self.profile.channels.volumes[source.into()];
 
                 debug!("Mitigating, Setting Volume of {:?} to {:?}", source, volume);
@@ -98,14 +117,7 @@ impl DeviceFader for GoXLR {
         mute_colours.colour1 = style.mute_colours.active_colour;
         mute_colours.colour2 = style.mute_colours.inactive_colour;
 
-        let device = self.device.as_ref().context("Device Not Found!")?;
-        if device.device_type != DeviceType::Mini {
-            let text = format!("{:?}", source);
-            debug!("Setting Screen Text to {:?}", text);
-            let scribble = get_scribble(None, Some(text), None, false);
-            let command = BasicResultCommand::SetScribble(fader, scribble);
-            self.send_no_result(command).await?;
-        }
+        self.apply_scribble(fader, source).await?;
 
         // Get the button mute state for this channel..
         debug!("Loading Mute button state for {:?}", source);
@@ -118,6 +130,9 @@ impl DeviceFader for GoXLR {
     /// Called when the mute state gets updated (probably from mute_handler.rs) to update the
     /// button and fader state.
     async fn update_mute_state(&mut self, source: FaderChannels, state: MuteState) -> Result<()> {
+        let previous = self.profile.channels.configs[source].mute_state;
+        self.record_mute(previous, state);
+
         self.profile.channels.configs[source].mute_state = state;
         if let Some(button) = self.get_button_for_channel(source) {
             let state = self.get_mute_button_state(source);
@@ -126,6 +141,33 @@ impl DeviceFader for GoXLR {
         }
         self.set_fader_colours(source, true).await
     }
+
+    /// Sets the display name of a channel, and updates the scribble if it's currently on a fader
+    async fn set_channel_name(
+        &mut self,
+        source: FaderChannels,
+        name: Option<String>,
+    ) -> Result<()> {
+        // Treat an empty name as clearing it..
+        let name = name.filter(|name| !name.trim().is_empty());
+        self.profile.channels.configs[source].name = name;
+
+        if let Some(fader) = self.get_fader_for_channel(source) {
+            self.apply_scribble(fader, source).await?;
+        }
+        Ok(())
+    }
+
+    async fn refresh_scribble(&mut self, fader: Fader) -> Result<()> {
+        match self.fader_state[fader] {
+            Some(source) => self.apply_scribble(fader, source).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn refresh_fader_colours(&mut self, source: FaderChannels) -> Result<()> {
+        self.set_fader_colours(source, true).await
+    }
 }
 
 trait DeviceFaderLocal {
@@ -135,8 +177,14 @@ trait DeviceFaderLocal {
     /// Updates colours for a fader if they don't match provided colours (true on change)
     fn update_colours(&mut self, c1: Colour, c2: Colour, current: Fader) -> bool;
 
+    /// The top and bottom colours a channel's fader should currently show
+    fn get_fader_colours(&self, source: FaderChannels) -> (Colour, Colour);
+
     /// Applies fader colours based on config
     async fn set_fader_colours(&mut self, source: FaderChannels, apply: bool) -> Result<()>;
+
+    /// Draws the channel name onto the faders scribble display
+    async fn apply_scribble(&mut self, fader: Fader, source: FaderChannels) -> Result<()>;
 }
 
 impl DeviceFaderLocal for GoXLR {
@@ -158,27 +206,63 @@ impl DeviceFaderLocal for GoXLR {
         false
     }
 
-    async fn set_fader_colours(&mut self, source: FaderChannels, apply: bool) -> Result<()> {
-        // Now we check whether we should dim the fader..
-        if let Some(fader) = self.get_fader_for_channel(source) {
-            if self.is_muted_to_all(source) {
-                let dimmed = Colour::black();
+    fn get_fader_colours(&self, source: FaderChannels) -> (Colour, Colour) {
+        let display = &self.profile.channels.configs[source].display;
+        let top = display.fader_colours.top_colour;
+        let bottom = display.fader_colours.bottom_colour;
 
-                if self.update_colours(dimmed, dimmed, fader) && apply {
-                    self.apply_colours().await?;
-                }
-            } else {
-                // Get the original profile colours, and check if our map has them..
-                let channel = &self.profile.channels.configs[source].display;
+        if !self.profile.lighting.channel_linked {
+            // Dim the fader if nothing can hear the channel
+            return match self.is_muted_to_all(source) {
+                true => (Colour::black(), Colour::black()),
+                false => (top, bottom),
+            };
+        }
+
+        // Linked lighting shows the mute colour on any mute, and is dimmed to follow the volume
+        let (top, bottom) = match self.profile.channels.configs[source].mute_state {
+            MuteState::Unmuted => (top, bottom),
+            _ => {
+                let muted = display.mute_colours.active_colour;
+                (muted, muted)
+            }
+        };
 
-                let bottom = channel.fader_colours.bottom_colour;
-                let top = channel.fader_colours.top_colour;
+        let volume = self.profile.channels.volumes[source.into()] as u32;
+        let intensity = LINKED_MIN_INTENSITY + (100 - LINKED_MIN_INTENSITY) * volume / 255;
+        let intensity = intensity as u8;
+        (top.scale(intensity), bottom.scale(intensity))
+    }
 
-                if self.update_colours(top, bottom, fader) && apply {
-                    self.apply_colours().await?;
-                }
+    async fn set_fader_colours(&mut self, source: FaderChannels, apply: bool) -> Result<()> {
+        if let Some(fader) = self.get_fader_for_channel(source) {
+            let (top, bottom) = self.get_fader_colours(source);
+            if self.update_colours(top, bottom, fader) && apply {
+                self.apply_colours().await?;
             }
         }
         Ok(())
     }
+
+    async fn apply_scribble(&mut self, fader: Fader, source: FaderChannels) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            return Ok(());
+        }
+
+        // A widget is being shown, the name will be drawn when it's cleared
+        if self.has_scribble_widget(fader) {
+            return Ok(());
+        }
+
+        // Use the user defined name if one is set, otherwise fall back to the channel name
+        let name = self.profile.channels.configs[source].name.clone();
+        let locale = self.config.settings.locale;
+        let text = name.unwrap_or_else(|| source.display_name(locale).to_string());
+
+        debug!("Setting Screen Text to {:?}", text);
+        let scribble = get_scribble(None, Some(text), None, false);
+        let command = BasicResultCommand::SetScribble(fader, scribble);
+        self.send_no_result(command).await
+    }
 }
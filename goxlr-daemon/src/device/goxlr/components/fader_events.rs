@@ -0,0 +1,40 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use goxlr_ipc::commands::FaderMovement;
+use goxlr_shared::faders::Fader;
+
+use crate::device::goxlr::device::GoXLR;
+
+/// Shares physical fader movements with clients subscribed to Fader Events, so tools can follow
+/// the faders as they move (for example, mirroring them into a DAW) without diffing the status.
+pub(crate) trait FaderEventsCrate {
+    fn publish_fader_movement(&self, fader: Fader, old_value: Option<u8>, new_value: u8);
+}
+
+impl FaderEventsCrate for GoXLR {
+    fn publish_fader_movement(&self, fader: Fader, old_value: Option<u8>, new_value: u8) {
+        let sender = &self.config.fader_events;
+        if sender.receiver_count() == 0 {
+            return;
+        }
+
+        let Some(device) = &self.device else {
+            return;
+        };
+
+        let current = self.profile.pages.current;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_millis() as u64)
+            .unwrap_or_default();
+
+        let _ = sender.send(FaderMovement {
+            serial: device.serial.clone(),
+            fader,
+            channel: self.profile.pages.page_list[current].faders[fader],
+            old_value,
+            new_value,
+            timestamp,
+        });
+    }
+}
@@ -0,0 +1,171 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use enum_map::EnumMap;
+use log::debug;
+
+use goxlr_profile::FaderCurve;
+use goxlr_shared::faders::Fader;
+
+use crate::device::goxlr::components::channel::Channels;
+use crate::device::goxlr::components::headphone_limit::HeadphoneLimitCrate;
+use crate::device::goxlr::device::GoXLR;
+
+// How steep the Logarithmic curve is, higher values keep the volume lower for longer
+const LOG_BASE: f32 = 100.;
+
+// How long after we set a volume the device's report of it is treated as an echo
+const ECHO_WINDOW: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Default)]
+pub(crate) struct FaderResponseState {
+    // Where each fader was last physically seen, so a new curve can be applied straight away
+    positions: EnumMap<Fader, Option<u8>>,
+
+    // The last volume set for each fader because of its response, and when
+    sent: EnumMap<Fader, Option<(u8, Instant)>>,
+}
+
+/// Reshapes how the physical faders map to volumes. The device sets the volume from the fader
+/// itself, so when the response isn't linear the volume is corrected after every movement.
+pub(crate) trait FaderResponse {
+    async fn set_fader_curve(&mut self, fader: Fader, curve: FaderCurve) -> Result<()>;
+    async fn set_fader_dead_zones(&mut self, fader: Fader, bottom: u8, top: u8) -> Result<()>;
+}
+
+impl FaderResponse for GoXLR {
+    async fn set_fader_curve(&mut self, fader: Fader, curve: FaderCurve) -> Result<()> {
+        if let FaderCurve::Custom(points) = &curve {
+            if points.is_empty() {
+                bail!("A Custom Curve needs at least one point");
+            }
+            if points.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                bail!("Custom Curve points must be in order of position");
+            }
+        }
+
+        debug!("Setting Fader {:?} Curve to {:?}", fader, curve);
+        self.profile.fader_response[fader].curve = curve;
+        self.reapply_fader_response(fader).await
+    }
+
+    async fn set_fader_dead_zones(&mut self, fader: Fader, bottom: u8, top: u8) -> Result<()> {
+        if bottom as u16 + top as u16 >= 255 {
+            bail!("The Dead Zones cover the whole fader");
+        }
+
+        debug!("Setting Fader {:?} Dead Zones: {} / {}", fader, bottom, top);
+        let response = &mut self.profile.fader_response[fader];
+        response.bottom_dead_zone = bottom;
+        response.top_dead_zone = top;
+        self.reapply_fader_response(fader).await
+    }
+}
+
+pub(crate) trait FaderResponseCrate {
+    /// The device reports volumes it's sent as though the fader had moved there. Normally this
+    /// doesn't matter, but it would reshape a volume a second time, so returns true (once) when
+    /// a fader reports the volume its response set shortly before.
+    fn is_fader_response_echo(&mut self, fader: Fader, value: u8) -> bool;
+
+    /// Notes the volume about to be set for a fader's channel, so its echo can be ignored
+    fn record_fader_response_volume(&mut self, fader: Fader);
+
+    /// Where the fader was last physically seen, if it's been seen at all
+    fn get_fader_position(&self, fader: Fader) -> Option<u8>;
+
+    /// Records the fader's position, and returns the volume it should give
+    fn get_fader_volume(&mut self, fader: Fader, position: u8) -> u8;
+}
+
+impl FaderResponseCrate for GoXLR {
+    fn is_fader_response_echo(&mut self, fader: Fader, value: u8) -> bool {
+        let Some((volume, sent)) = self.fader_response.sent[fader] else {
+            return false;
+        };
+
+        if sent.elapsed() > ECHO_WINDOW {
+            self.fader_response.sent[fader] = None;
+            return false;
+        }
+
+        // A different volume is the fader genuinely moving, the echo may still be on its way
+        if volume != value {
+            return false;
+        }
+
+        self.fader_response.sent[fader] = None;
+        true
+    }
+
+    fn record_fader_response_volume(&mut self, fader: Fader) {
+        let current = self.profile.pages.current;
+        let channel = self.profile.pages.page_list[current].faders[fader];
+        let volume = self.profile.channels.volumes[channel.into()];
+        self.fader_response.sent[fader] = Some((volume, Instant::now()));
+    }
+
+    fn get_fader_position(&self, fader: Fader) -> Option<u8> {
+        self.fader_response.positions[fader]
+    }
+
+    fn get_fader_volume(&mut self, fader: Fader, position: u8) -> u8 {
+        self.fader_response.positions[fader] = Some(position);
+
+        let response = &self.profile.fader_response[fader];
+        let bottom = response.bottom_dead_zone as f32;
+        let top = 255. - response.top_dead_zone as f32;
+
+        // Where the fader is between the dead zones, from 0 to 1
+        let travel = ((position as f32 - bottom) / (top - bottom)).clamp(0., 1.);
+        let volume = match &response.curve {
+            FaderCurve::Linear => travel,
+            FaderCurve::Logarithmic => (LOG_BASE.powf(travel) - 1.) / (LOG_BASE - 1.),
+            FaderCurve::Custom(points) => get_custom_volume(points, travel * 255.) / 255.,
+        };
+        (volume * 255.).round() as u8
+    }
+}
+
+trait FaderResponseLocal {
+    async fn reapply_fader_response(&mut self, fader: Fader) -> Result<()>;
+}
+
+impl FaderResponseLocal for GoXLR {
+    async fn reapply_fader_response(&mut self, fader: Fader) -> Result<()> {
+        // If the fader hasn't moved yet, the new response applies when it does
+        let Some(position) = self.fader_response.positions[fader] else {
+            return Ok(());
+        };
+
+        let current = self.profile.pages.current;
+        let channel = self.profile.pages.page_list[current].faders[fader].into();
+
+        self.profile.channels.volumes[channel] = self.get_fader_volume(fader, position);
+        self.enforce_headphone_limit(channel);
+        self.record_fader_response_volume(fader);
+        self.apply_channel_volume(channel).await
+    }
+}
+
+fn get_custom_volume(points: &[(u8, u8)], position: f32) -> f32 {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return position;
+    };
+
+    if position <= first.0 as f32 {
+        return first.1 as f32;
+    }
+    if position >= last.0 as f32 {
+        return last.1 as f32;
+    }
+
+    for pair in points.windows(2) {
+        let ((start, from), (end, to)) = (pair[0], pair[1]);
+        if position <= end as f32 {
+            let progress = (position - start as f32) / (end - start) as f32;
+            return from as f32 + (to as f32 - from as f32) * progress;
+        }
+    }
+    last.1 as f32
+}
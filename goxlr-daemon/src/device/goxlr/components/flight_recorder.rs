@@ -0,0 +1,97 @@
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::encoders::Encoders;
+use goxlr_shared::faders::Fader;
+
+use crate::device::goxlr::device::GoXLR;
+
+/// Keeps the most recent events which have happened on a device, so that when something odd
+/// happens the lead up to it can be fetched and attached to a bug report. This is opt-in
+/// and does nothing unless a size has been configured.
+///
+/// Dumps can be replayed against a Mock Device with the daemon's --replay option. Mic levels are
+/// recorded each time they're sampled, so the size should allow for around 50 per second while
+/// ducking is enabled.
+#[derive(Debug, Default)]
+pub(crate) struct FlightRecorder {
+    capacity: usize,
+    events: VecDeque<RecordedEvent>,
+}
+
+#[derive(Debug, Serialize)]
+struct RecordedEvent {
+    /// Milliseconds since the Unix Epoch
+    timestamp: u128,
+    event: RecorderEvent,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) enum RecorderEvent {
+    ButtonDown(Buttons),
+    ButtonUp(Buttons),
+    VolumeChange(Fader, u8),
+    EncoderChange(Encoders, i8),
+    MicLevel(f64),
+    Command(String),
+    CommandError(String),
+    UsbError(String),
+    DeviceError,
+    Ducking(bool),
+}
+
+impl FlightRecorder {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    fn record(&mut self, event: RecorderEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_millis())
+            .unwrap_or_default();
+
+        if self.events.len() >= self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(RecordedEvent { timestamp, event });
+    }
+}
+
+pub(crate) trait FlightRecording {
+    fn record_event(&self, event: RecorderEvent);
+
+    /// The recorded events as JSON, in the format read by --replay
+    fn dump_flight_recorder(&self) -> Result<String>;
+}
+
+impl FlightRecording for GoXLR {
+    fn record_event(&self, event: RecorderEvent) {
+        if let Ok(mut recorder) = self.flight_recorder.lock() {
+            recorder.record(event);
+        }
+    }
+
+    fn dump_flight_recorder(&self) -> Result<String> {
+        match self.flight_recorder.lock() {
+            Ok(recorder) => {
+                if recorder.capacity == 0 {
+                    bail!("The Flight Recorder is not enabled");
+                }
+                Ok(serde_json::to_string_pretty(&recorder.events)?)
+            }
+            Err(_) => bail!("Unable to access the Flight Recorder"),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::time::{Duration, Instant};
+
+use enum_map::EnumMap;
+use log::{debug, info};
+use strum::IntoEnumIterator;
+use tokio::sync::watch;
+
+use goxlr_ipc::commands::diagnostics::{ChannelGainReport, GainReport};
+use goxlr_shared::channels::input::InputChannels;
+
+use crate::device::goxlr::device::GoXLR;
+
+// Levels at or above this are treated as clipping..
+const CLIP_THRESHOLD_DB: f64 = -1.;
+
+// Where we'd like the peaks to sit, and how far below that before suggesting more gain.
+const TARGET_PEAK_DB: f64 = -6.;
+const LOW_PEAK_DB: f64 = -18.;
+
+#[derive(Default)]
+pub(crate) struct GainAnalyser {
+    started: Option<Instant>,
+    duration: Duration,
+    running: bool,
+    levels: EnumMap<InputChannels, Option<ChannelLevels>>,
+
+    // Only held while running, so the level isn't sampled when we don't need it
+    mic_level: Option<watch::Receiver<Option<f64>>>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct ChannelLevels {
+    samples: u64,
+    total_db: f64,
+    peak_db: f64,
+    clip_events: u32,
+    clipping: bool,
+}
+
+impl Default for ChannelLevels {
+    fn default() -> Self {
+        Self {
+            samples: 0,
+            total_db: 0.,
+            peak_db: f64::MIN,
+            clip_events: 0,
+            clipping: false,
+        }
+    }
+}
+
+impl ChannelLevels {
+    fn add(&mut self, db: f64) {
+        self.samples += 1;
+        self.total_db += db;
+        self.peak_db = self.peak_db.max(db);
+
+        // Only count the start of each clip, rather than every sample during it
+        let clipping = db >= CLIP_THRESHOLD_DB;
+        if clipping && !self.clipping {
+            self.clip_events += 1;
+        }
+        self.clipping = clipping;
+    }
+
+    fn report(&self, channel: InputChannels) -> ChannelGainReport {
+        let suggested_adjustment_db = if self.clip_events > 0 || self.peak_db < LOW_PEAK_DB {
+            Some(TARGET_PEAK_DB - self.peak_db)
+        } else {
+            None
+        };
+
+        ChannelGainReport {
+            channel,
+            peak_db: self.peak_db,
+            average_db: self.total_db / self.samples as f64,
+            headroom_db: -self.peak_db,
+            clip_events: self.clip_events,
+            suggested_adjustment_db,
+        }
+    }
+}
+
+/// Monitors the channel levels over a period of time, to help users find a sensible gain setup.
+/// The device only provides levels for the Microphone, so other channels are reported as
+/// unmetered.
+pub(crate) trait GainAnalysis {
+    fn start_gain_analysis(&mut self, duration: Duration);
+    fn get_gain_report(&self) -> GainReport;
+
+    fn handle_gain_analysis(&mut self);
+}
+
+impl GainAnalysis for GoXLR {
+    fn start_gain_analysis(&mut self, duration: Duration) {
+        debug!("Starting Gain Analysis for {:?}", duration);
+        self.gain_analyser = GainAnalyser {
+            started: Some(Instant::now()),
+            duration,
+            running: true,
+            levels: Default::default(),
+            mic_level: Some(self.mic_level.subscribe()),
+        };
+    }
+
+    fn get_gain_report(&self) -> GainReport {
+        let analyser = &self.gain_analyser;
+        let duration = match analyser.started {
+            Some(started) => started.elapsed().min(analyser.duration),
+            None => Duration::ZERO,
+        };
+
+        let mut channels = vec![];
+        for channel in InputChannels::iter() {
+            if let Some(levels) = analyser.levels[channel] {
+                if levels.samples > 0 {
+                    channels.push(levels.report(channel));
+                }
+            }
+        }
+
+        GainReport {
+            running: analyser.running,
+            duration_ms: duration.as_millis() as u64,
+            channels,
+            unmetered: InputChannels::iter()
+                .filter(|channel| !is_metered(*channel))
+                .collect(),
+        }
+    }
+
+    fn handle_gain_analysis(&mut self) {
+        if !self.gain_analyser.running {
+            return;
+        }
+
+        if let Some(started) = self.gain_analyser.started {
+            if started.elapsed() >= self.gain_analyser.duration {
+                self.gain_analyser.running = false;
+                self.gain_analyser.mic_level = None;
+                info!("Gain Analysis Complete: {:#?}", self.get_gain_report());
+                return;
+            }
+        }
+
+        // Only count each sample once, the level may not be sampled on every tick..
+        let Some(receiver) = self.gain_analyser.mic_level.as_mut() else {
+            return;
+        };
+        if !receiver.has_changed().unwrap_or(false) {
+            return;
+        }
+
+        let level = *receiver.borrow_and_update();
+        if let Some(db) = level {
+            let levels = &mut self.gain_analyser.levels[InputChannels::Microphone];
+            levels.get_or_insert_with(Default::default).add(db);
+        }
+    }
+}
+
+fn is_metered(channel: InputChannels) -> bool {
+    channel == InputChannels::Microphone
+}
@@ -0,0 +1,256 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+use log::{info, warn};
+
+use goxlr_profile::HardMuteOrigin;
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::fader::FaderChannels;
+
+use crate::device::goxlr::components::button_cycles::ButtonCyclesCrate;
+use crate::device::goxlr::components::buttons::ButtonHandlers;
+use crate::device::goxlr::components::ducker::AudioDuckerTrait;
+use crate::device::goxlr::components::mute_handler::{MuteHandler, MuteHandlerCrate};
+use crate::device::goxlr::components::profile::Profile;
+use crate::device::goxlr::components::push_to_talk::PushToTalkCrate;
+use crate::device::goxlr::components::solo::SoloCrate;
+use crate::device::goxlr::device::{ButtonState, GoXLR};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HardMuteState {
+    engaged: Option<HardMuteOrigin>,
+}
+
+/// The Hard Mute holds the Microphone's input mute on at the device, underneath the routing and
+/// mute states. Anything which would normally unmute the Microphone (mute buttons, scripts,
+/// automations, Push to Talk) still changes its own state, but the Microphone stays silent
+/// until the Hard Mute is released by the gesture which engaged it.
+pub(crate) trait HardMute {
+    async fn set_hard_mute(&mut self, engaged: bool) -> Result<()>;
+    fn set_hard_mute_buttons(&mut self, buttons: Vec<Buttons>) -> Result<()>;
+}
+
+impl HardMute for GoXLR {
+    async fn set_hard_mute(&mut self, engaged: bool) -> Result<()> {
+        // Without a combination there's nothing on the device which could release it, so don't
+        // leave the Microphone stuck (this can happen when a profile without one is loaded)
+        let has_combo = !self.profile.hard_mute.buttons.is_empty();
+        if self.hard_mute.engaged == Some(HardMuteOrigin::Buttons) && has_combo {
+            if !engaged {
+                bail!("The Hard Mute was engaged on the device, and can only be released there");
+            }
+            return Ok(());
+        }
+
+        let origin = engaged.then_some(HardMuteOrigin::Command);
+        self.set_hard_mute_origin(origin).await
+    }
+
+    fn set_hard_mute_buttons(&mut self, buttons: Vec<Buttons>) -> Result<()> {
+        if self.hard_mute.engaged == Some(HardMuteOrigin::Buttons) {
+            bail!("The Hard Mute is engaged, release it before changing its buttons");
+        }
+
+        if buttons.len() == 1 {
+            bail!("The Hard Mute needs at least two buttons, so it can't be pressed by accident");
+        }
+
+        // These buttons never register as held, so the combination could never be completed
+        for button in &buttons {
+            if self.is_push_to_talk_button(*button)
+                || self.is_solo_button(*button)
+                || self.is_button_cycle(*button)
+                || self.is_fade_button(*button)
+                || self.is_sensitivity_button(*button)
+            {
+                bail!("{:?} is already being used by something else", button);
+            }
+        }
+
+        self.profile.hard_mute.buttons = buttons;
+        Ok(())
+    }
+}
+
+pub(crate) trait HardMuteCrate {
+    fn is_hard_muted(&self) -> bool;
+
+    /// Picks the Hard Mute back up from a stored profile, so a restart doesn't release it
+    fn restore_hard_mute(&mut self);
+
+    /// Writes the Hard Mute into the profile, so replacing the profile doesn't release it
+    fn keep_hard_mute(&mut self);
+
+    /// Called when a button goes down, returns true if it completed the Hard Mute combination,
+    /// in which case the press has been handled
+    async fn handle_hard_mute_combo(&mut self, button: Buttons) -> Result<bool>;
+}
+
+impl HardMuteCrate for GoXLR {
+    fn is_hard_muted(&self) -> bool {
+        self.hard_mute.engaged.is_some()
+    }
+
+    fn restore_hard_mute(&mut self) {
+        if let Some(origin) = self.profile.hard_mute.engaged {
+            info!("Hard Mute still Engaged by {:?}", origin);
+        }
+        self.hard_mute.engaged = self.profile.hard_mute.engaged;
+    }
+
+    fn keep_hard_mute(&mut self) {
+        self.profile.hard_mute.engaged = self.hard_mute.engaged;
+    }
+
+    async fn handle_hard_mute_combo(&mut self, button: Buttons) -> Result<bool> {
+        let combo = &self.profile.hard_mute.buttons;
+        if !combo.contains(&button) {
+            return Ok(false);
+        }
+
+        let others: Vec<Buttons> = combo.iter().copied().filter(|o| *o != button).collect();
+        let held = |other: &Buttons| self.button_down_states[*other].is_some();
+        if !others.iter().all(held) {
+            return Ok(false);
+        }
+
+        // The other buttons are already down, stop them doing anything when they're released
+        for other in others {
+            if let Some(state) = self.button_down_states[other].as_mut() {
+                state.skip_hold = true;
+                state.skip_release = true;
+            }
+        }
+
+        // And register this one, so its release is skipped too
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        self.button_down_states[button].replace(ButtonState {
+            press_time: now,
+            skip_hold: true,
+            skip_release: true,
+            hold_handled: false,
+        });
+
+        if self.hard_mute.engaged == Some(HardMuteOrigin::Command) {
+            warn!("The Hard Mute was engaged by a command, and can only be released by one");
+            return Ok(true);
+        }
+
+        let origin = match self.hard_mute.engaged {
+            Some(_) => None,
+            None => Some(HardMuteOrigin::Buttons),
+        };
+        self.set_hard_mute_origin(origin).await?;
+        Ok(true)
+    }
+}
+
+trait HardMuteLocal {
+    async fn set_hard_mute_origin(&mut self, origin: Option<HardMuteOrigin>) -> Result<()>;
+}
+
+impl HardMuteLocal for GoXLR {
+    async fn set_hard_mute_origin(&mut self, origin: Option<HardMuteOrigin>) -> Result<()> {
+        if self.hard_mute.engaged == origin {
+            return Ok(());
+        }
+
+        match origin {
+            Some(origin) => info!("Hard Mute Engaged by {:?}", origin),
+            None => info!("Hard Mute Released"),
+        }
+        self.hard_mute.engaged = origin;
+        self.keep_hard_mute();
+        self.sync_mic_mute_state().await?;
+
+        let mic = FaderChannels::Microphone;
+        if let Some(button) = self.get_button_for_channel(mic) {
+            let state = self.get_mute_button_state(mic);
+            self.button_states.set_state(button, state);
+            self.apply_button_states().await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use clap::Parser;
+    use tokio::sync::{broadcast, mpsc};
+
+    use goxlr_profile::HardMuteOrigin;
+    use goxlr_shared::buttons::Buttons;
+    use goxlr_usb::events::commands::CommandSender;
+    use goxlr_usb::mock::MockDevice;
+
+    use crate::cli::Cli;
+    use crate::device::goxlr::components::hard_mute::{HardMute, HardMuteCrate};
+    use crate::device::goxlr::device::GoXLR;
+    use crate::device::goxlr::device_config::GoXLRDeviceConfiguration;
+    use crate::stop::Stop;
+    use crate::storage::defaults::DefaultProfileStore;
+
+    fn create_device(serial: &str) -> GoXLR {
+        let (manager_sender, _manager_recv) = mpsc::channel(1);
+        let (_device_sender, manager_recv) = mpsc::channel(1);
+        let config = GoXLRDeviceConfiguration {
+            stop: Stop::new(),
+            device: MockDevice::attach(0, serial).location(),
+            update_sender: mpsc::channel(1).0,
+            manager_sender,
+            manager_recv,
+            fader_events: broadcast::channel(1).0,
+            settings: Cli::parse_from(["goxlr-daemon"]).device_settings(),
+            profile_store: Arc::new(DefaultProfileStore),
+        };
+
+        // Accept everything sent to the 'device'
+        let (command_sender, mut command_recv) = mpsc::channel(32);
+        tokio::spawn(async move {
+            while let Some(message) = command_recv.recv().await {
+                if let CommandSender::BasicResultCommand(_, result) = message {
+                    let _ = result.send(Ok(()));
+                }
+            }
+        });
+
+        let mut device = GoXLR::new(config, Stop::new());
+        device.command_sender = Some(command_sender);
+        device
+    }
+
+    #[tokio::test]
+    async fn combo_cannot_change_while_engaged_by_buttons() {
+        let mut device = create_device("HARDMUTE1");
+        let combo = vec![Buttons::SamplerTopLeft, Buttons::SamplerTopRight];
+        assert!(device.set_hard_mute_buttons(combo.clone()).is_ok());
+        device.hard_mute.engaged = Some(HardMuteOrigin::Buttons);
+
+        // Neither clearing nor replacing the combination should leave it without a release
+        assert!(device.set_hard_mute_buttons(vec![]).is_err());
+        let other = vec![Buttons::SamplerSelectA, Buttons::SamplerSelectB];
+        assert!(device.set_hard_mute_buttons(other).is_err());
+        assert_eq!(device.profile.hard_mute.buttons, combo);
+
+        // With the combination still there, only the device can release it
+        assert!(device.set_hard_mute(false).await.is_err());
+        assert!(device.is_hard_muted());
+    }
+
+    #[tokio::test]
+    async fn command_releases_without_a_combo() {
+        let mut device = create_device("HARDMUTE2");
+
+        // As if the Hard Mute had been kept over a load of a profile without a combination
+        device.hard_mute.engaged = Some(HardMuteOrigin::Buttons);
+        device.keep_hard_mute();
+        assert!(device.profile.hard_mute.buttons.is_empty());
+
+        let result = device.set_hard_mute(false).await;
+        assert!(result.is_ok(), "Release Failed: {:?}", result.err());
+        assert!(!device.is_hard_muted());
+        assert_eq!(device.profile.hard_mute.engaged, None);
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use log::{info, warn};
+
+use goxlr_shared::channels::volume::VolumeChannels;
+
+use crate::device::goxlr::components::channel::Channels;
+use crate::device::goxlr::device::GoXLR;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HeadphoneLimitState {
+    limit: Option<u8>,
+
+    // While overridden the limit is kept, but not enforced
+    overridden: bool,
+}
+
+impl HeadphoneLimitState {
+    pub fn new(limit: Option<u8>) -> Self {
+        Self {
+            limit,
+            overridden: false,
+        }
+    }
+}
+
+/// Caps the Headphones volume, so a script, profile or fader can't push it above a level the
+/// user has decided is safe. The limit belongs to the daemon rather than the profile, so loading
+/// a profile can't remove it, only the override can temporarily lift it.
+pub(crate) trait HeadphoneLimit {
+    async fn set_headphone_limit(&mut self, limit: Option<u8>) -> Result<()>;
+    async fn set_headphone_limit_override(&mut self, overridden: bool) -> Result<()>;
+}
+
+impl HeadphoneLimit for GoXLR {
+    async fn set_headphone_limit(&mut self, limit: Option<u8>) -> Result<()> {
+        info!("Setting Headphone Volume Limit to {:?}", limit);
+        self.headphone_limit.limit = limit;
+        self.apply_channel_volume(VolumeChannels::Headphones).await
+    }
+
+    async fn set_headphone_limit_override(&mut self, overridden: bool) -> Result<()> {
+        info!("Headphone Volume Limit Overridden: {}", overridden);
+        self.headphone_limit.overridden = overridden;
+        self.apply_channel_volume(VolumeChannels::Headphones).await
+    }
+}
+
+pub(crate) trait HeadphoneLimitCrate {
+    /// The limit currently being enforced, None if there isn't one (or it's overridden)
+    fn get_headphone_limit(&self) -> Option<u8>;
+    fn is_headphone_limit_overridden(&self) -> bool;
+
+    /// Brings the profile's volume for a channel down to the limit, returns true if it was over
+    fn enforce_headphone_limit(&mut self, source: VolumeChannels) -> bool;
+}
+
+impl HeadphoneLimitCrate for GoXLR {
+    fn get_headphone_limit(&self) -> Option<u8> {
+        let state = &self.headphone_limit;
+        state.limit.filter(|_| !state.overridden)
+    }
+
+    fn is_headphone_limit_overridden(&self) -> bool {
+        self.headphone_limit.overridden
+    }
+
+    fn enforce_headphone_limit(&mut self, source: VolumeChannels) -> bool {
+        if source != VolumeChannels::Headphones {
+            return false;
+        }
+
+        let Some(limit) = self.get_headphone_limit() else {
+            return false;
+        };
+
+        let volume = &mut self.profile.channels.volumes[source];
+        if *volume <= limit {
+            return false;
+        }
+
+        warn!(
+            "Headphone Volume {} is above the limit, using {}",
+            volume, limit
+        );
+        *volume = limit;
+        true
+    }
+}
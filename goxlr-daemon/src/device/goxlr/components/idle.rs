@@ -0,0 +1,103 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use log::{debug, info, warn};
+
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::device::GoXLR;
+
+pub(crate) struct IdleState {
+    last_activity: Instant,
+    idle: bool,
+}
+
+impl Default for IdleState {
+    fn default() -> Self {
+        Self {
+            last_activity: Instant::now(),
+            idle: false,
+        }
+    }
+}
+
+/// Tracks how long it's been since the device was last used, so the lighting can be dimmed
+/// while nobody's around. Only physical input counts, a client changing settings doesn't mean
+/// anyone is looking at the device.
+pub(crate) trait IdleDetection {
+    fn set_idle_timeout(&mut self, minutes: u16);
+    async fn set_idle_brightness(&mut self, brightness: u8) -> Result<()>;
+}
+
+impl IdleDetection for GoXLR {
+    fn set_idle_timeout(&mut self, minutes: u16) {
+        debug!("Setting Idle Timeout to {} minutes", minutes);
+        self.profile.lighting.idle.timeout = minutes;
+    }
+
+    async fn set_idle_brightness(&mut self, brightness: u8) -> Result<()> {
+        if brightness > 100 {
+            bail!("Brightness must be a percentage");
+        }
+
+        debug!("Setting Idle Brightness to {}%", brightness);
+        self.profile.lighting.idle.brightness = brightness;
+        if self.idle.idle {
+            self.apply_colours().await?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) trait IdleDetectionCrate {
+    fn is_idle(&self) -> bool;
+
+    /// Called on any physical input, waking the device if it's idle
+    async fn mark_activity(&mut self);
+
+    /// Called on every tick, dims the device once the timeout has passed
+    async fn handle_idle(&mut self);
+}
+
+impl IdleDetectionCrate for GoXLR {
+    fn is_idle(&self) -> bool {
+        self.idle.idle
+    }
+
+    async fn mark_activity(&mut self) {
+        self.idle.last_activity = Instant::now();
+        if self.idle.idle {
+            info!("Input Received, waking from Idle");
+            self.set_idle(false).await;
+        }
+    }
+
+    async fn handle_idle(&mut self) {
+        let timeout = self.profile.lighting.idle.timeout;
+        if timeout == 0 {
+            // Dimming may have been turned off while we were idle..
+            if self.idle.idle {
+                self.set_idle(false).await;
+            }
+            return;
+        }
+
+        let timeout = Duration::from_secs(timeout as u64 * 60);
+        if !self.idle.idle && self.idle.last_activity.elapsed() >= timeout {
+            info!("No Input for {:?}, dimming Lighting", timeout);
+            self.set_idle(true).await;
+        }
+    }
+}
+
+trait IdleDetectionLocal {
+    async fn set_idle(&mut self, idle: bool);
+}
+
+impl IdleDetectionLocal for GoXLR {
+    async fn set_idle(&mut self, idle: bool) {
+        self.idle.idle = idle;
+        if let Err(error) = self.apply_colours().await {
+            warn!("Unable to apply Idle Lighting: {}", error);
+        }
+    }
+}
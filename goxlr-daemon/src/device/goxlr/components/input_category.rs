@@ -0,0 +1,36 @@
+use anyhow::Result;
+use log::debug;
+
+use goxlr_shared::channels::category::InputCategory;
+use goxlr_shared::channels::input::InputChannels;
+
+use crate::device::goxlr::components::ducker::AudioDuckerTrait;
+use crate::device::goxlr::device::GoXLR;
+
+/// Categories group inputs by what they carry, so settings such as the ducker's category routes
+/// can target 'all Media' and follow channels as they're moved between categories.
+pub(crate) trait InputCategories {
+    async fn set_input_category(
+        &mut self,
+        input: InputChannels,
+        category: Option<InputCategory>,
+    ) -> Result<()>;
+}
+
+impl InputCategories for GoXLR {
+    async fn set_input_category(
+        &mut self,
+        input: InputChannels,
+        category: Option<InputCategory>,
+    ) -> Result<()> {
+        debug!("Setting Category for {:?} to {:?}", input, category);
+
+        // Re-applying the ducking settings brings anything currently lowered back up using the
+        // old categories, otherwise an input leaving a ducked category would stay lowered.
+        let settings = self.profile.ducking.clone();
+        self.apply_ducking_settings(settings).await?;
+
+        self.profile.channels.categories[input] = category;
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use log::{debug, info};
+
+use goxlr_ipc::commands::lighting::{FlashPattern, FlashTarget};
+use goxlr_ipc::status::{InputTestEvent, InputTestInput};
+use goxlr_shared::colours::Colour;
+
+use crate::device::goxlr::components::alert_flash::AlertFlash;
+use crate::device::goxlr::components::channel::Channels;
+use crate::device::goxlr::device::GoXLR;
+
+// Only the most recent are kept, a client watching the status sees each one as it arrives
+const MAX_EVENTS: usize = 32;
+
+// How long (in ms) a light is lit for when its control is used
+const FEEDBACK_TIME: u64 = 300;
+
+#[derive(Default)]
+pub(crate) struct InputTestState {
+    // None while Input Test Mode is disabled
+    events: Option<VecDeque<InputTestEvent>>,
+}
+
+/// Input Test Mode reports every button press, fader move and encoder turn, and lights the
+/// control up, without any of their normal behaviour, so the hardware (and any keybindings a
+/// client builds on the status) can be checked without changing anything.
+pub(crate) trait InputTest {
+    async fn set_input_test(&mut self, enabled: bool) -> Result<()>;
+}
+
+impl InputTest for GoXLR {
+    async fn set_input_test(&mut self, enabled: bool) -> Result<()> {
+        if enabled == self.input_test.events.is_some() {
+            return Ok(());
+        }
+
+        if enabled {
+            info!("Entering Input Test Mode");
+
+            // Anything held now would be released during the test, and never seen as released
+            self.button_down_states = Default::default();
+            self.input_test.events = Some(VecDeque::new());
+            return Ok(());
+        }
+
+        info!("Leaving Input Test Mode");
+        self.input_test.events = None;
+
+        // The faders change the device's volume themselves, put back what the profile has
+        let page = &self.profile.pages.page_list[self.profile.pages.current];
+        let channels: Vec<_> = page.faders.values().copied().collect();
+        for channel in channels {
+            self.apply_channel_volume(channel.into()).await?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) trait InputTestCrate {
+    fn is_input_testing(&self) -> bool;
+    fn get_input_test_events(&self) -> Option<Vec<InputTestEvent>>;
+
+    /// Records a control being used, in place of whatever it would normally do
+    async fn handle_input_test(&mut self, input: InputTestInput);
+}
+
+impl InputTestCrate for GoXLR {
+    fn is_input_testing(&self) -> bool {
+        self.input_test.events.is_some()
+    }
+
+    fn get_input_test_events(&self) -> Option<Vec<InputTestEvent>> {
+        let events = self.input_test.events.as_ref()?;
+        Some(events.iter().cloned().collect())
+    }
+
+    async fn handle_input_test(&mut self, input: InputTestInput) {
+        let Some(events) = &mut self.input_test.events else {
+            return;
+        };
+
+        debug!("Input Test: {:?}", input);
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        if events.len() >= MAX_EVENTS {
+            events.pop_front();
+        }
+        events.push_back(InputTestEvent { time, input });
+
+        // Encoders don't have a light of their own to show
+        let target = match input {
+            InputTestInput::ButtonDown(button) => FlashTarget::Button(button),
+            InputTestInput::Fader(fader, _) => FlashTarget::Strip(fader),
+            InputTestInput::ButtonUp(_) | InputTestInput::Encoder(_, _) => return,
+        };
+        self.show_input_feedback(target).await;
+    }
+}
+
+trait InputTestLocal {
+    async fn show_input_feedback(&mut self, target: FlashTarget);
+}
+
+impl InputTestLocal for GoXLR {
+    async fn show_input_feedback(&mut self, target: FlashTarget) {
+        let white = Colour {
+            red: 255,
+            green: 255,
+            blue: 255,
+        };
+
+        let pattern = FlashPattern::Solid;
+        if let Err(error) = self
+            .flash_button(target, white, FEEDBACK_TIME, pattern)
+            .await
+        {
+            debug!("Unable to show Input Test feedback: {}", error);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::{bail, Result};
+use enum_map::EnumMap;
+use log::debug;
+
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::RoutingOutput;
+use goxlr_shared::routing::RouteValue;
+
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+type Row = EnumMap<RoutingOutput, RouteValue>;
+
+// The maximum value of a route, a trim of 32 leaves the input untouched
+const ROUTE_MAX: u8 = 32;
+
+/// Only the Microphone has a hardware gain stage, so everything else is trimmed by lowering
+/// each of the input's routes by the same amount as they're sent to the device. This sits
+/// after the other routing layers, so an input is trimmed the same wherever it ends up. Routes
+/// can't go above full volume, so a trim can only bring louder inputs down to match quieter
+/// ones.
+pub(crate) trait InputTrim {
+    async fn set_input_trim(&mut self, input: InputChannels, trim: u8) -> Result<()>;
+}
+
+impl InputTrim for GoXLR {
+    async fn set_input_trim(&mut self, input: InputChannels, trim: u8) -> Result<()> {
+        if trim > ROUTE_MAX {
+            bail!("Trim must be {} or less", ROUTE_MAX);
+        }
+
+        debug!("Setting Input Trim for {:?} to {}", input, trim);
+        self.profile.channels.trims[input] = trim;
+        self.apply_routing_for_channel(input).await
+    }
+}
+
+pub(crate) trait InputTrimCrate {
+    /// Scales every route of a row about to be sent to the device by the input's trim
+    fn apply_input_trim(&self, input: InputChannels, row: Row) -> Row;
+}
+
+impl InputTrimCrate for GoXLR {
+    fn apply_input_trim(&self, input: InputChannels, mut row: Row) -> Row {
+        let trim = self.profile.channels.trims[input];
+        if trim >= ROUTE_MAX {
+            return row;
+        }
+
+        for value in row.values_mut() {
+            *value = match *value {
+                RouteValue::Off => RouteValue::Off,
+                RouteValue::On => RouteValue::Value(trim),
+                RouteValue::Value(value) => {
+                    let scaled = value as u16 * trim as u16 / ROUTE_MAX as u16;
+                    RouteValue::Value(scaled as u8)
+                }
+            };
+        }
+        row
+    }
+}
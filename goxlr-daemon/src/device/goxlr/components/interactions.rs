@@ -12,11 +12,24 @@ use goxlr_shared::encoders::Encoders;
 use goxlr_shared::faders::Fader;
 use goxlr_shared::mute::MuteState;
 use goxlr_shared::states::State;
+use goxlr_usb::events::commands::BasicResultCommand;
 
+use crate::device::goxlr::components::button_cycles::{ButtonCycles, ButtonCyclesCrate};
 use crate::device::goxlr::components::buttons::ButtonHandlers;
+use crate::device::goxlr::components::channel::{Channels, ChannelsCrate};
+use crate::device::goxlr::components::ducker::AudioDuckerTrait;
+use crate::device::goxlr::components::effects::gender::GenderCrate;
+use crate::device::goxlr::components::effects::pitch::PitchCrate;
+use crate::device::goxlr::components::fader_events::FaderEventsCrate;
+use crate::device::goxlr::components::fader_response::FaderResponseCrate;
+use crate::device::goxlr::components::hard_mute::HardMuteCrate;
+use crate::device::goxlr::components::headphone_limit::HeadphoneLimitCrate;
+use crate::device::goxlr::components::mix_modes::{MixModes, MixModesCrate};
 use crate::device::goxlr::components::mute_handler::MuteHandler;
 use crate::device::goxlr::components::pages::FaderPages;
 use crate::device::goxlr::components::profile::Profile;
+use crate::device::goxlr::components::push_to_talk::{PushToTalk, PushToTalkCrate};
+use crate::device::goxlr::components::solo::SoloCrate;
 use crate::device::goxlr::components::submix::SubMix;
 use crate::device::goxlr::device::{ButtonState, GoXLR};
 
@@ -36,6 +49,40 @@ impl Interactions for GoXLR {
         let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
 
         debug!("Button Down: {:?}", button);
+
+        // The Hard Mute combination takes priority over everything else the buttons do
+        if self.handle_hard_mute_combo(button).await? {
+            return Ok(());
+        }
+
+        // Push to Talk replaces whatever the button would normally do..
+        if self.is_push_to_talk_button(button) {
+            return self.set_push_to_talk_pressed(true).await;
+        }
+
+        // As does Solo, which toggles on release
+        if self.is_solo_button(button) {
+            return Ok(());
+        }
+
+        // And Button Cycles, which step on release
+        if self.is_button_cycle(button) {
+            return Ok(());
+        }
+
+        // The Fade button lowers the ducked routes for as long as it's held
+        if self.is_fade_button(button) {
+            self.set_fade_pressed(true);
+            self.button_states.set_state(button, State::Colour1);
+            return self.apply_button_states().await;
+        }
+
+        // The Sensitivity button steps through the presets on release
+        if self.is_sensitivity_button(button) {
+            self.button_states.set_state(button, State::Colour1);
+            return self.apply_button_states().await;
+        }
+
         let mut skip_hold = false;
         let skip_release = false;
 
@@ -87,6 +134,26 @@ impl Interactions for GoXLR {
 
     async fn on_button_up(&mut self, button: Buttons) -> Result<()> {
         debug!("Button Up: {:?}", button);
+        if self.is_push_to_talk_button(button) {
+            return self.set_push_to_talk_pressed(false).await;
+        }
+        if self.is_solo_button(button) {
+            return self.toggle_solo().await;
+        }
+        if self.is_button_cycle(button) {
+            return self.next_button_cycle_step(button).await;
+        }
+        if self.is_fade_button(button) {
+            self.set_fade_pressed(false);
+            self.button_states.set_state(button, State::DimmedColour1);
+            return self.apply_button_states().await;
+        }
+        if self.is_sensitivity_button(button) {
+            self.next_ducking_sensitivity().await?;
+            self.button_states.set_state(button, State::DimmedColour1);
+            return self.apply_button_states().await;
+        }
+
         if let Some(state) = self.button_down_states[button] {
             if state.skip_release {
                 debug!("Skipping Button Up behaviour by request for {:?}", button);
@@ -107,6 +174,16 @@ impl Interactions for GoXLR {
                     self.handle_cough_press(false).await?;
                 }
             }
+            Buttons::EffectSelect1
+            | Buttons::EffectSelect2
+            | Buttons::EffectSelect3
+            | Buttons::EffectSelect4
+            | Buttons::EffectSelect5
+            | Buttons::EffectSelect6 => {
+                if let Some(mode) = self.get_mix_mode_for_button(button) {
+                    self.activate_mix_mode(mode).await?;
+                }
+            }
             Buttons::Swear => {
                 // Button released, revert to inactive state.
                 let state = State::from(self.profile.swear.colours.inactive_behaviour);
@@ -153,22 +230,43 @@ impl Interactions for GoXLR {
         let current = self.profile.pages.current;
         let channel = self.profile.pages.page_list[current].faders[fader];
 
+        // The device reporting a volume we've set, rather than the fader moving
+        if self.is_fader_response_echo(fader, value) {
+            return Ok(());
+        }
+
         debug!("Fader Moved: {:?} to {:?}", channel, value);
-        self.profile.channels.volumes[channel.into()] = value;
+        let previous = self.get_fader_position(fader);
+        self.publish_fader_movement(fader, previous, value);
+
+        let volume = self.get_fader_volume(fader, value);
+        self.profile.channels.volumes[channel.into()] = volume;
+
+        // The device has changed the volume itself, so make sure the cache knows about it
+        self.record_cached(&BasicResultCommand::SetVolume(channel.into(), value));
+
+        // The fader's gone past the Headphone limit, or the fader's response gives a different
+        // volume to its position, so put the device at the volume it should be
+        if self.enforce_headphone_limit(channel.into()) || volume != value {
+            self.record_fader_response_volume(fader);
+            return self.apply_channel_volume(channel.into()).await;
+        }
 
         // IF SubMix is supported, sync the channel
         if SubMixChannels::can_from(channel) {
             self.sync_sub_mix_volume(channel.into()).await?;
         }
 
-        Ok(())
+        self.refresh_linked_colours(channel.into()).await
     }
 
     async fn on_encoder_change(&mut self, encoder: Encoders, value: i8) -> Result<()> {
         debug!("Encoder {:?} changed to {}", encoder, value);
+        self.record_cached(&BasicResultCommand::SetEncoderValue(encoder, value));
+
         match encoder {
-            Encoders::Pitch => {}
-            Encoders::Gender => {}
+            Encoders::Pitch => self.set_pitch_from_encoder(value).await?,
+            Encoders::Gender => self.set_gender_from_encoder(value).await?,
             Encoders::Reverb => {}
             Encoders::Echo => {}
         }
@@ -0,0 +1,329 @@
+use std::f32::consts::PI;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, FromSample, Sample, SampleFormat, SizedSample, Stream, StreamConfig};
+use enum_map::EnumMap;
+use log::{debug, info, warn};
+use strum::IntoEnumIterator;
+use tokio::task;
+use tokio::task::JoinHandle;
+
+use goxlr_ipc::commands::diagnostics::{LatencyReport, LatencyTest};
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
+use goxlr_shared::routing::RouteValue;
+
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+// How long to wait before playing the tone, this gives both streams time to settle.
+const LEAD_IN: Duration = Duration::from_millis(250);
+const TONE_LENGTH: Duration = Duration::from_millis(100);
+const TIMEOUT: Duration = Duration::from_secs(3);
+
+const TONE_FREQUENCY: f32 = 1000.;
+const TONE_AMPLITUDE: f32 = 0.5;
+const DETECTION_THRESHOLD: f32 = 0.1;
+
+type ToneStart = Arc<Mutex<Option<Instant>>>;
+
+#[derive(Default)]
+pub(crate) struct LatencyState {
+    running: Option<RunningTest>,
+    last: Option<LatencyReport>,
+}
+
+struct RunningTest {
+    test: LatencyTest,
+
+    // The routing to the Output before the test changed it, put back once it's done
+    previous: EnumMap<InputChannels, RouteValue>,
+    handle: JoinHandle<Result<(u32, Duration)>>,
+}
+
+/// Measures the round trip latency of a route, by playing a tone into an Input via the OS and
+/// listening for it on an Output. The test runs in the background for a few seconds, with the
+/// result collected by the tick once it's done.
+pub(crate) trait Latency {
+    async fn start_latency_test(&mut self, test: LatencyTest) -> Result<()>;
+    fn get_latency_report(&self) -> Result<LatencyReport>;
+
+    async fn handle_latency_test(&mut self);
+}
+
+impl Latency for GoXLR {
+    async fn start_latency_test(&mut self, test: LatencyTest) -> Result<()> {
+        if self.latency.running.is_some() {
+            bail!("A Latency Measurement is already running");
+        }
+
+        // Route only the tested Input to the Output, so nothing else can trigger the detection..
+        let output = RoutingOutput::from(test.output);
+        let previous: EnumMap<InputChannels, RouteValue> =
+            EnumMap::from_fn(|input| self.routing_state.get_routing(input, output));
+        let isolated = EnumMap::from_fn(|input| match input == test.input {
+            true => RouteValue::On,
+            false => RouteValue::Off,
+        });
+        if let Err(error) = self.set_output_routing(test.output, isolated).await {
+            self.restore_output_routing(test.output, previous).await;
+            return Err(error);
+        }
+
+        debug!(
+            "Measuring Latency from {:?} to {:?}",
+            test.input, test.output
+        );
+        let playback = test.playback_device.clone();
+        let capture = test.capture_device.clone();
+        let handle = task::spawn_blocking(move || run_latency_test(&playback, &capture));
+
+        self.latency.running = Some(RunningTest {
+            test,
+            previous,
+            handle,
+        });
+        Ok(())
+    }
+
+    fn get_latency_report(&self) -> Result<LatencyReport> {
+        if let Some(running) = &self.latency.running {
+            let mut report = get_empty_report(&running.test);
+            report.running = true;
+            return Ok(report);
+        }
+        self.latency
+            .last
+            .clone()
+            .context("No Latency Measurement has been run")
+    }
+
+    async fn handle_latency_test(&mut self) {
+        let Some(running) = &self.latency.running else {
+            return;
+        };
+        if !running.handle.is_finished() {
+            return;
+        }
+        let Some(running) = self.latency.running.take() else {
+            return;
+        };
+
+        // Put the routing back how we found it, regardless of the result..
+        let (test, previous) = (running.test, running.previous);
+        self.restore_output_routing(test.output, previous).await;
+
+        let mut report = get_empty_report(&test);
+        match running.handle.await {
+            Ok(Ok((sample_rate, latency))) => {
+                let latency_ms = latency.as_secs_f64() * 1000.;
+                info!(
+                    "Latency from {:?} to {:?}: {:.2}ms",
+                    test.input, test.output, latency_ms
+                );
+                report.sample_rate = Some(sample_rate);
+                report.latency_ms = Some(latency_ms);
+            }
+            Ok(Err(error)) => report.error = Some(error.to_string()),
+            Err(error) => report.error = Some(error.to_string()),
+        }
+        self.latency.last = Some(report);
+    }
+}
+
+trait LatencyLocal {
+    async fn set_output_routing(
+        &mut self,
+        output: OutputChannels,
+        values: EnumMap<InputChannels, RouteValue>,
+    ) -> Result<()>;
+    async fn restore_output_routing(
+        &mut self,
+        output: OutputChannels,
+        values: EnumMap<InputChannels, RouteValue>,
+    );
+}
+
+impl LatencyLocal for GoXLR {
+    async fn set_output_routing(
+        &mut self,
+        output: OutputChannels,
+        values: EnumMap<InputChannels, RouteValue>,
+    ) -> Result<()> {
+        let output = RoutingOutput::from(output);
+        for input in InputChannels::iter() {
+            if self.set_route(input, output, values[input])? {
+                self.apply_routing_for_channel(input).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn restore_output_routing(
+        &mut self,
+        output: OutputChannels,
+        values: EnumMap<InputChannels, RouteValue>,
+    ) {
+        // Every input is attempted, so one failure doesn't leave the rest as the test set them
+        let routing = RoutingOutput::from(output);
+        for input in InputChannels::iter() {
+            let result = match self.set_route(input, routing, values[input]) {
+                Ok(true) => self.apply_routing_for_channel(input).await,
+                Ok(false) => Ok(()),
+                Err(error) => Err(error),
+            };
+            if let Err(error) = result {
+                warn!("Unable to restore {:?} to {:?}: {}", input, output, error);
+            }
+        }
+    }
+}
+
+fn get_empty_report(test: &LatencyTest) -> LatencyReport {
+    LatencyReport {
+        input: test.input,
+        output: test.output,
+        running: false,
+        error: None,
+        sample_rate: None,
+        latency_ms: None,
+    }
+}
+
+/// Runs the test itself, cpal streams can't be moved between threads on all platforms so this
+/// needs to run on a dedicated thread.
+fn run_latency_test(playback: &str, capture: &str) -> Result<(u32, Duration)> {
+    let host = cpal::default_host();
+
+    let output = find_device(host.output_devices()?, playback)
+        .with_context(|| format!("Unable to find Playback Device: {}", playback))?;
+    let input = find_device(host.input_devices()?, capture)
+        .with_context(|| format!("Unable to find Capture Device: {}", capture))?;
+
+    let output_config = output.default_output_config()?;
+    let input_config = input.default_input_config()?;
+    let sample_rate = output_config.sample_rate().0;
+
+    let started: ToneStart = Default::default();
+    let (detected_tx, detected_rx) = channel();
+
+    // Start listening first, so we don't miss the start of the tone..
+    let config = input_config.config();
+    let capture_stream = match input_config.sample_format() {
+        SampleFormat::F32 => build_capture::<f32>(&input, &config, started.clone(), detected_tx),
+        SampleFormat::I16 => build_capture::<i16>(&input, &config, started.clone(), detected_tx),
+        SampleFormat::I32 => build_capture::<i32>(&input, &config, started.clone(), detected_tx),
+        SampleFormat::U16 => build_capture::<u16>(&input, &config, started.clone(), detected_tx),
+        format => bail!("Unsupported Capture Sample Format: {:?}", format),
+    }?;
+    capture_stream.play()?;
+
+    let config = output_config.config();
+    let playback_stream = match output_config.sample_format() {
+        SampleFormat::F32 => build_playback::<f32>(&output, &config, started.clone()),
+        SampleFormat::I16 => build_playback::<i16>(&output, &config, started.clone()),
+        SampleFormat::I32 => build_playback::<i32>(&output, &config, started.clone()),
+        SampleFormat::U16 => build_playback::<u16>(&output, &config, started.clone()),
+        format => bail!("Unsupported Playback Sample Format: {:?}", format),
+    }?;
+    playback_stream.play()?;
+
+    let Ok(detected) = detected_rx.recv_timeout(LEAD_IN + TIMEOUT) else {
+        bail!("Tone not detected, check that the channels aren't muted and the volume is up");
+    };
+
+    let started = started.lock().ok().and_then(|started| *started);
+    let started = started.context("Tone was detected before it was played")?;
+    Ok((sample_rate, detected.saturating_duration_since(started)))
+}
+
+fn find_device(mut devices: impl Iterator<Item = Device>, name: &str) -> Option<Device> {
+    devices.find(|device| device.name().map(|n| n == name).unwrap_or(false))
+}
+
+fn build_playback<T>(device: &Device, config: &StreamConfig, started: ToneStart) -> Result<Stream>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let rate = config.sample_rate.0 as f64;
+    let channels = config.channels as usize;
+
+    let lead_in = (LEAD_IN.as_secs_f64() * rate) as u64;
+    let tone_end = lead_in + (TONE_LENGTH.as_secs_f64() * rate) as u64;
+    let mut frame = 0;
+
+    let stream = device.build_output_stream(
+        config,
+        move |data: &mut [T], _| {
+            let now = Instant::now();
+            for (index, samples) in data.chunks_mut(channels).enumerate() {
+                let mut value = 0.;
+                if frame >= lead_in && frame < tone_end {
+                    if frame == lead_in {
+                        let offset = Duration::from_secs_f64(index as f64 / rate);
+                        if let Ok(mut started) = started.lock() {
+                            started.replace(now + offset);
+                        }
+                    }
+
+                    let position = (frame - lead_in) as f32 / rate as f32;
+                    value = (2. * PI * TONE_FREQUENCY * position).sin() * TONE_AMPLITUDE;
+                }
+
+                for sample in samples {
+                    *sample = T::from_sample(value);
+                }
+                frame += 1;
+            }
+        },
+        |error| debug!("Playback Stream Error: {}", error),
+        None,
+    )?;
+    Ok(stream)
+}
+
+fn build_capture<T>(
+    device: &Device,
+    config: &StreamConfig,
+    started: ToneStart,
+    detected: Sender<Instant>,
+) -> Result<Stream>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let rate = config.sample_rate.0 as f64;
+    let channels = config.channels as usize;
+    let mut found = false;
+
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[T], _| {
+            // Ignore anything captured before the tone has started..
+            let playing = started.lock().map(|s| s.is_some()).unwrap_or(false);
+            if found || !playing {
+                return;
+            }
+
+            let now = Instant::now();
+            let frames = data.len() / channels;
+            for (index, samples) in data.chunks(channels).enumerate() {
+                let value = samples.iter().map(|s| s.to_sample::<f32>().abs());
+                if value.fold(0., f32::max) > DETECTION_THRESHOLD {
+                    // The buffer was captured before now, so work back to when this frame was..
+                    let offset = Duration::from_secs_f64((frames - index) as f64 / rate);
+                    let _ = detected.send(now.checked_sub(offset).unwrap_or(now));
+                    found = true;
+                    return;
+                }
+            }
+        },
+        |error| debug!("Capture Stream Error: {}", error),
+        None,
+    )?;
+    Ok(stream)
+}
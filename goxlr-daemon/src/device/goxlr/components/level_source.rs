@@ -0,0 +1,188 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, FromSample, Sample, SampleFormat, SizedSample, Stream, StreamConfig};
+use log::{debug, warn};
+use tokio::sync::{oneshot, watch};
+
+// The level reported for a silent capture buffer, rather than negative infinity
+const SILENCE_DB: f64 = -96.;
+
+// If a capture stream hasn't delivered a buffer in this long, its last level is no longer current
+const CAPTURE_STALE_AFTER: Duration = Duration::from_millis(500);
+
+type Level = Arc<Mutex<Option<f64>>>;
+
+// The level of a capture stream, and when it was measured
+type CaptureLevel = Arc<Mutex<Option<(f64, Instant)>>>;
+
+/// Something the ducker can watch the level of, each DuckingInput is backed by one of these. New
+/// inputs only need a source registering, the ducker itself doesn't need to know what it is.
+#[async_trait]
+pub(crate) trait LevelSource: Send + Sync {
+    /// The current level, or None if there's nothing to measure
+    async fn level_db(&self) -> Option<f64>;
+
+    /// Microphone levels are passed through the simulated noise gate using the Mic Profile's
+    /// settings, rather than being compared directly against the trigger level.
+    fn is_microphone(&self) -> bool {
+        false
+    }
+}
+
+/// Reads the level most recently polled from the device's Microphone meter
+pub(crate) struct MicMeterSource {
+    meter: Arc<watch::Sender<Option<f64>>>,
+}
+
+impl MicMeterSource {
+    pub fn new(meter: Arc<watch::Sender<Option<f64>>>) -> Self {
+        Self { meter }
+    }
+}
+
+#[async_trait]
+impl LevelSource for MicMeterSource {
+    async fn level_db(&self) -> Option<f64> {
+        *self.meter.borrow()
+    }
+
+    fn is_microphone(&self) -> bool {
+        true
+    }
+}
+
+/// A level set from elsewhere, used by the Sampler to report when it's playing, and to drive
+/// the ducker by hand when testing.
+#[derive(Clone, Default)]
+pub(crate) struct InjectedLevel {
+    level: Level,
+}
+
+impl InjectedLevel {
+    pub fn new(level: Option<f64>) -> Self {
+        let injected = Self::default();
+        injected.set(level);
+        injected
+    }
+
+    pub fn set(&self, level: Option<f64>) {
+        if let Ok(mut current) = self.level.lock() {
+            *current = level;
+        }
+    }
+}
+
+#[async_trait]
+impl LevelSource for InjectedLevel {
+    async fn level_db(&self) -> Option<f64> {
+        self.level.lock().ok().and_then(|level| *level)
+    }
+}
+
+/// Measures the peak level of an OS capture device. cpal streams can't be moved between threads
+/// on all platforms, so the stream lives on its own thread until this source is dropped.
+pub(crate) struct CaptureSource {
+    level: CaptureLevel,
+
+    // Dropping this tells the capture thread to stop
+    _stop: mpsc::Sender<()>,
+}
+
+impl CaptureSource {
+    pub async fn open(name: String) -> Result<Self> {
+        let level: CaptureLevel = Default::default();
+        let (stop_tx, stop_rx) = mpsc::channel();
+        let (ready_tx, ready_rx) = oneshot::channel();
+
+        let thread_level = level.clone();
+        thread::spawn(move || {
+            let stream = match build_stream(&name, thread_level) {
+                Ok(stream) => stream,
+                Err(error) => {
+                    let _ = ready_tx.send(Err(error));
+                    return;
+                }
+            };
+            let _ = ready_tx.send(Ok(()));
+
+            // Wait until we're dropped, then let the stream go with us.
+            let _ = stop_rx.recv();
+            debug!("Stopping Capture Level Source: {}", name);
+            drop(stream);
+        });
+
+        ready_rx.await??;
+        Ok(Self {
+            level,
+            _stop: stop_tx,
+        })
+    }
+}
+
+#[async_trait]
+impl LevelSource for CaptureSource {
+    async fn level_db(&self) -> Option<f64> {
+        // A stream which has stalled or stopped isn't hearing anything
+        let level = self.level.lock().ok().and_then(|level| *level);
+        level
+            .filter(|(_, at)| at.elapsed() < CAPTURE_STALE_AFTER)
+            .map(|(db, _)| db)
+    }
+}
+
+fn build_stream(name: &str, level: CaptureLevel) -> Result<Stream> {
+    let host = cpal::default_host();
+    let mut devices = host.input_devices()?;
+    let device = devices
+        .find(|device| device.name().map(|n| n == name).unwrap_or(false))
+        .with_context(|| format!("Unable to find Capture Device: {}", name))?;
+
+    let input_config = device.default_input_config()?;
+    let config = input_config.config();
+    let stream = match input_config.sample_format() {
+        SampleFormat::F32 => build_capture::<f32>(&device, &config, level),
+        SampleFormat::I16 => build_capture::<i16>(&device, &config, level),
+        SampleFormat::I32 => build_capture::<i32>(&device, &config, level),
+        SampleFormat::U16 => build_capture::<u16>(&device, &config, level),
+        format => bail!("Unsupported Capture Sample Format: {:?}", format),
+    }?;
+    stream.play()?;
+    Ok(stream)
+}
+
+fn build_capture<T>(device: &Device, config: &StreamConfig, level: CaptureLevel) -> Result<Stream>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let error_level = level.clone();
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[T], _| {
+            let peak = data.iter().map(|s| s.to_sample::<f32>().abs());
+            let peak = peak.fold(0., f32::max) as f64;
+
+            let db = match peak > 0. {
+                true => (20. * peak.log10()).max(SILENCE_DB),
+                false => SILENCE_DB,
+            };
+            if let Ok(mut level) = level.lock() {
+                *level = Some((db, Instant::now()));
+            }
+        },
+        move |error| {
+            warn!("Capture Level Stream Error: {}", error);
+            if let Ok(mut level) = error_level.lock() {
+                *level = None;
+            }
+        },
+        None,
+    )?;
+    Ok(stream)
+}
@@ -0,0 +1,97 @@
+use anyhow::{bail, Context, Result};
+use log::debug;
+use strum::IntoEnumIterator;
+
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::colours::{Colour, ColourScheme, TwoColourTargets};
+use goxlr_shared::device::DeviceType;
+
+use crate::device::goxlr::components::fader::DeviceFader;
+use crate::device::goxlr::components::idle::IdleDetectionCrate;
+use crate::device::goxlr::components::lighting_layers::LightingLayersCrate;
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::device::GoXLR;
+
+/// Handles the lighting which isn't tied to a channel or button (the X logo and the light inside
+/// the device), along with the overall brightness. These are held in the colour scheme alongside
+/// everything else, so anything working from the scheme treats them the same as any other light.
+pub(crate) trait Lighting {
+    async fn set_accent_colour(&mut self, colour: Colour) -> Result<()>;
+    async fn set_global_colour(&mut self, colour: Colour) -> Result<()>;
+    async fn set_brightness(&mut self, brightness: u8) -> Result<()>;
+    async fn set_channel_linked(&mut self, enabled: bool) -> Result<()>;
+}
+
+impl Lighting for GoXLR {
+    async fn set_accent_colour(&mut self, colour: Colour) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            bail!("The GoXLR Mini doesn't have an Accent light");
+        }
+
+        debug!("Setting Accent Colour to {:?}", colour);
+        self.profile.lighting.accent = colour;
+        self.load_lighting();
+        self.apply_colours().await
+    }
+
+    async fn set_global_colour(&mut self, colour: Colour) -> Result<()> {
+        debug!("Setting Global Colour to {:?}", colour);
+        self.profile.lighting.global = colour;
+        self.load_lighting();
+        self.apply_colours().await
+    }
+
+    async fn set_brightness(&mut self, brightness: u8) -> Result<()> {
+        if brightness > 100 {
+            bail!("Brightness must be a percentage");
+        }
+
+        debug!("Setting Brightness to {}%", brightness);
+        self.profile.lighting.brightness = brightness;
+        self.apply_colours().await
+    }
+
+    async fn set_channel_linked(&mut self, enabled: bool) -> Result<()> {
+        debug!("Setting Channel Linked Lighting to {}", enabled);
+        self.profile.lighting.channel_linked = enabled;
+
+        // Only the channels currently on a fader are drawn, the rest follow when assigned
+        for channel in FaderChannels::iter() {
+            self.refresh_fader_colours(channel).await?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) trait LightingCrate {
+    /// Places the accent and global colours from the profile into the colour scheme
+    fn load_lighting(&mut self);
+
+    /// The colour scheme as it should be sent to the device, composed from the lighting layers
+    /// with the brightness applied
+    fn get_display_scheme(&self) -> ColourScheme;
+}
+
+impl LightingCrate for GoXLR {
+    fn load_lighting(&mut self) {
+        let lighting = self.profile.lighting;
+
+        let target = TwoColourTargets::LogoX;
+        self.colour_scheme.get_two_colour_target(target).colour1 = lighting.accent;
+
+        let target = TwoColourTargets::InternalLight;
+        self.colour_scheme.get_two_colour_target(target).colour1 = lighting.global;
+    }
+
+    fn get_display_scheme(&self) -> ColourScheme {
+        let lighting = self.profile.lighting;
+
+        // Dimming shouldn't brighten a device which is already set lower than the idle level
+        let brightness = match self.is_idle() {
+            true => lighting.brightness.min(lighting.idle.brightness),
+            false => lighting.brightness,
+        };
+        self.get_composed_scheme().with_brightness(brightness)
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::{bail, Result};
+use enum_map::{Enum, EnumMap};
+use log::debug;
+use strum::{EnumIter, IntoEnumIterator};
+
+use goxlr_shared::colours::ColourScheme;
+
+use crate::device::goxlr::components::alert_flash::AlertFlashCrate;
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::device::GoXLR;
+
+/// Layers which can sit above the profile's colour scheme, from lowest to highest. While a layer
+/// is active it covers everything below it, only alert flashes are shown above them all.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Enum, EnumIter)]
+pub(crate) enum LightingLayer {
+    /// A client driven animation, shown in place of the profile's colours
+    Animation,
+
+    /// An external program which has taken over the lighting
+    Takeover,
+
+    /// Colours the user is trying out, which need to be seen over whatever else is showing
+    Preview,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct LightingLayers {
+    layers: EnumMap<LightingLayer, Option<ColourScheme>>,
+}
+
+/// The lighting is composed from layers, with the profile's colour scheme at the bottom. Each
+/// layer only holds what it wants to show, so clearing one simply reveals whatever is beneath
+/// it, rather than each feature needing to remember (and restore) what was there before.
+pub(crate) trait LightingLayering {
+    async fn show_animation_frame(&mut self, frame: ColourScheme) -> Result<()>;
+    async fn stop_animation(&mut self) -> Result<()>;
+}
+
+impl LightingLayering for GoXLR {
+    async fn show_animation_frame(&mut self, frame: ColourScheme) -> Result<()> {
+        if !self.config.settings.lighting {
+            bail!("Lighting has been disabled");
+        }
+        self.set_lighting_layer(LightingLayer::Animation, Some(frame))
+            .await
+    }
+
+    async fn stop_animation(&mut self) -> Result<()> {
+        self.set_lighting_layer(LightingLayer::Animation, None)
+            .await
+    }
+}
+
+pub(crate) trait LightingLayersCrate {
+    /// Replaces what a layer is showing, None clears the layer
+    async fn set_lighting_layer(
+        &mut self,
+        layer: LightingLayer,
+        scheme: Option<ColourScheme>,
+    ) -> Result<()>;
+
+    /// The scheme from the highest active layer (or the profile's if none are active), with
+    /// any alert flashes on top
+    fn get_composed_scheme(&self) -> ColourScheme;
+}
+
+impl LightingLayersCrate for GoXLR {
+    async fn set_lighting_layer(
+        &mut self,
+        layer: LightingLayer,
+        scheme: Option<ColourScheme>,
+    ) -> Result<()> {
+        let current = &mut self.lighting_layers.layers[layer];
+        if current.is_some() != scheme.is_some() {
+            debug!("Lighting Layer {:?} Active: {}", layer, scheme.is_some());
+        }
+
+        // Clients won't know which firmware we're on, so keep our own idea of it
+        *current = scheme.map(|scheme| ColourScheme {
+            is_legacy: self.colour_scheme.is_legacy,
+            ..scheme
+        });
+        self.apply_colours().await
+    }
+
+    fn get_composed_scheme(&self) -> ColourScheme {
+        let layers = &self.lighting_layers.layers;
+        let mut scheme = LightingLayer::iter()
+            .rev()
+            .find_map(|layer| layers[layer])
+            .unwrap_or(self.colour_scheme);
+
+        self.apply_alert_flashes(&mut scheme);
+        scheme
+    }
+}
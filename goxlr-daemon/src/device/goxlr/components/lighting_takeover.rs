@@ -0,0 +1,111 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use log::{debug, info, warn};
+
+use goxlr_shared::colours::ColourScheme;
+
+use crate::device::goxlr::components::idle::IdleDetectionCrate;
+use crate::device::goxlr::components::lighting_layers::{LightingLayer, LightingLayersCrate};
+use crate::device::goxlr::device::GoXLR;
+
+// Each frame is a full colour update, so don't let a client saturate the USB connection
+const MAX_FPS: u8 = 30;
+
+// If a client stops sending frames (for example, it's crashed), hand the lighting back
+const FRAME_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Default)]
+pub(crate) struct LightingTakeover {
+    state: Option<TakeoverState>,
+}
+
+struct TakeoverState {
+    frame_interval: Duration,
+    last_frame: Option<Instant>,
+    started: Instant,
+}
+
+/// Allows an external program (such as an audio visualiser) to drive the lighting directly by
+/// streaming frames of colours. Frames are shown on the Takeover lighting layer, so changes to
+/// the profile's lighting are still tracked, and are revealed once the lighting is released.
+pub(crate) trait TakeoverLighting {
+    fn take_over_lighting(&mut self, fps: u8) -> Result<()>;
+    async fn send_lighting_frame(&mut self, frame: ColourScheme) -> Result<()>;
+    async fn release_lighting(&mut self) -> Result<()>;
+}
+
+impl TakeoverLighting for GoXLR {
+    fn take_over_lighting(&mut self, fps: u8) -> Result<()> {
+        if !self.config.settings.lighting {
+            bail!("Lighting has been disabled");
+        }
+        if fps == 0 || fps > MAX_FPS {
+            bail!("Lighting frame rate must be between 1 and {}", MAX_FPS);
+        }
+
+        info!("Lighting Taken Over at {}fps", fps);
+        self.lighting_takeover.state = Some(TakeoverState {
+            frame_interval: Duration::from_secs(1) / fps as u32,
+            last_frame: None,
+            started: Instant::now(),
+        });
+        Ok(())
+    }
+
+    async fn send_lighting_frame(&mut self, frame: ColourScheme) -> Result<()> {
+        let Some(state) = self.lighting_takeover.state.as_mut() else {
+            bail!("Lighting hasn't been taken over");
+        };
+
+        // Drop anything arriving early, rather than queueing up behind the device
+        if let Some(last_frame) = state.last_frame {
+            if last_frame.elapsed() < state.frame_interval {
+                return Ok(());
+            }
+        }
+        state.last_frame = Some(Instant::now());
+
+        // The client is still here, but there's nobody to watch its animation
+        if self.is_idle() {
+            return Ok(());
+        }
+
+        self.set_lighting_layer(LightingLayer::Takeover, Some(frame))
+            .await
+    }
+
+    async fn release_lighting(&mut self) -> Result<()> {
+        if self.lighting_takeover.state.take().is_none() {
+            return Ok(());
+        }
+
+        info!("Lighting Released");
+        self.set_lighting_layer(LightingLayer::Takeover, None).await
+    }
+}
+
+pub(crate) trait TakeoverLightingCrate {
+    async fn handle_lighting_takeover(&mut self);
+}
+
+impl TakeoverLightingCrate for GoXLR {
+    async fn handle_lighting_takeover(&mut self) {
+        let Some(state) = &self.lighting_takeover.state else {
+            return;
+        };
+
+        let last_seen = state.last_frame.unwrap_or(state.started);
+        if last_seen.elapsed() < FRAME_TIMEOUT {
+            return;
+        }
+
+        warn!(
+            "No Lighting Frames received for {:?}, releasing",
+            FRAME_TIMEOUT
+        );
+        if let Err(error) = self.release_lighting().await {
+            debug!("Unable to restore Lighting: {}", error);
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use anyhow::Result;
-use log::{debug, warn};
+use std::sync::atomic::Ordering;
+
+use anyhow::{bail, Result};
+use log::{debug, info, warn};
 use strum::IntoEnumIterator;
 
 use goxlr_profile::CoughBehaviour;
@@ -7,57 +9,93 @@ use goxlr_shared::buttons::Buttons::CoughButton;
 use goxlr_shared::channels::fader::FaderChannels;
 use goxlr_shared::channels::input::InputChannels;
 use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
-use goxlr_shared::colours::TwoColourTargets;
+use goxlr_shared::channels::CanFrom;
+use goxlr_shared::colours::{ColourScheme, TwoColourTargets};
 use goxlr_shared::mute::MuteState;
 use goxlr_shared::routing::RouteValue;
-use goxlr_usb::events::commands::BasicResultCommand;
 
+use crate::device::goxlr::components::button_cycles::ButtonCyclesCrate;
 use crate::device::goxlr::components::buttons::ButtonHandlers;
 use crate::device::goxlr::components::channel::Channels;
+use crate::device::goxlr::components::colour_buffer::ColourBufferCrate;
+use crate::device::goxlr::components::crossfade::RoutingCrossfadeCrate;
+use crate::device::goxlr::components::ducker::AudioDuckerTrait;
+use crate::device::goxlr::components::effects::load_effects::LoadEffects;
+use crate::device::goxlr::components::lighting::LightingCrate;
+use crate::device::goxlr::components::mix_modes::MixModesCrate;
 use crate::device::goxlr::components::mute_handler::{MuteHandler, MuteHandlerCrate};
+use crate::device::goxlr::components::notifications::DeviceNotifications;
 use crate::device::goxlr::components::pages::FaderPages;
+use crate::device::goxlr::components::push_to_talk::PushToTalkCrate;
 use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::components::solo::SoloCrate;
 use crate::device::goxlr::device::GoXLR;
+use crate::settings::LoadInputBehaviour;
 
 /// This trait contains all public methods needed to successfully load a profile, and are implemented
 /// for the GoXLR type immediately after. This code assumes that self.profile is accurate.
 pub(crate) trait LoadProfile {
     async fn load_profile(&mut self) -> Result<()>;
 
-    async fn apply_colours(&self) -> Result<()>;
+    /// Commits the colour scheme, or queues it for the next tick if colours are buffered
+    async fn apply_colours(&mut self) -> Result<()>;
 }
 
 impl LoadProfile for GoXLR {
     async fn load_profile(&mut self) -> Result<()> {
         debug!("Beginning Profile Load");
-        // These are setup methods, to do any pre-profile handling and setup..
-        self.setup_routing();
-        self.setup_button_states();
-        self.setup_colours();
-
-        // Go through the profile components and apply them to the GoXLR
-        self.load_current_page(false).await?;
+        let previous_scheme = self.colour_scheme;
+
+        // The ducking config may have changed, don't carry a transition over from the old one
+        self.reset_ducker();
+        self.cancel_crossfade();
+        self.load_capture_devices().await;
+
+        // Muting and other parts of the load can change the routing as they go, hold those back
+        // so outputs don't briefly receive audio from a partially loaded routing table. If the
+        // caller has already staged the routing, it's their job to commit it.
+        let owns_stage = !self.is_routing_staged();
+        if owns_stage && self.config.settings.stage_routing {
+            self.stage_routing();
+        }
 
-        // Load the Mute States..
-        self.load_mute_states().await?;
+        // Stop the device reporting input while we're setting it up, if polling is already
+        // paused (such as during startup) whoever paused it is responsible for resuming it.
+        let quiesce = self.config.settings.load_input == LoadInputBehaviour::Drop
+            && !self.pause_polling.swap(true, Ordering::Relaxed);
 
-        // Apply the volumes..
-        self.load_volumes().await?;
+        // Staged routing needs releasing even if the load fails part way through..
+        let result = self.apply_profile().await;
+        if quiesce {
+            debug!("Profile Applied, resuming Input Polling");
+            self.pause_polling.store(false, Ordering::Relaxed);
+        }
+        if owns_stage {
+            let committed = self.commit_routing().await;
+            result.and(committed)?;
+        } else {
+            result?;
+        }
 
-        // Finalise things setup earlier
-        self.apply_button_states().await?;
+        // Anything pressed during the load is handled once it's finished, before anything else
+        if self.config.settings.load_input == LoadInputBehaviour::Queue {
+            self.queue_pending_input();
+        }
 
-        self.load_colours().await?;
-        self.apply_routing().await?;
+        // Work out what actually happened, so it can be reported back..
+        self.build_load_report(previous_scheme);
+        self.notify_profile_loaded();
 
         debug!("Completed Profile Load");
         Ok(())
     }
 
-    async fn apply_colours(&self) -> Result<()> {
-        debug!("Applying Colour Scheme..");
-        let command = BasicResultCommand::SetColour(self.colour_scheme);
-        self.send_no_result(command).await
+    async fn apply_colours(&mut self) -> Result<()> {
+        self.queue_colours();
+        if self.config.settings.buffer_colours {
+            return Ok(());
+        }
+        self.commit_colours().await
     }
 }
 
@@ -65,22 +103,68 @@ impl LoadProfile for GoXLR {
 /// make functions available to other classes, but we should limit that level of communication only
 /// to things which should be exposed.
 trait LoadProfileLocal {
+    /// Runs through each of the load steps below, in order
+    async fn apply_profile(&mut self) -> Result<()>;
+
     /// These first three functions are for base setup, creating the scheme or the settings
     /// prior to actually doing any of the loading.
     fn setup_routing(&mut self);
     fn setup_colours(&mut self);
     fn setup_button_states(&mut self);
 
-    /// The next three are responsible for loading the various components of the device..
-    async fn load_volumes(&mut self) -> Result<()>;
-    async fn load_mute_states(&mut self) -> Result<()>;
+    /// The next three are responsible for loading the various components of the device, a
+    /// channel which fails doesn't stop the others, those that succeeded are returned.
+    async fn load_volumes(&mut self) -> Result<Vec<FaderChannels>>;
+    async fn load_mute_states(&mut self) -> Result<Vec<FaderChannels>>;
     async fn load_colours(&mut self) -> Result<()>;
 
-    /// And finally, apply anything that's been configured above
-    async fn apply_routing(&self) -> Result<()>;
+    /// And finally, apply anything that's been configured above, returning the inputs routed
+    async fn apply_routing(&self) -> Result<Vec<InputChannels>>;
+
+    /// Fills the load report with what was applied, anything skipped will already be present
+    /// from when the profile was adapted to the device.
+    fn build_load_report(&mut self, previous_scheme: ColourScheme);
 }
 
 impl LoadProfileLocal for GoXLR {
+    async fn apply_profile(&mut self) -> Result<()> {
+        // These are setup methods, to do any pre-profile handling and setup..
+        self.setup_routing();
+        self.setup_button_states();
+        self.setup_colours();
+
+        // Go through the profile components and apply them to the GoXLR
+        self.load_current_page(false).await?;
+
+        // Load the Mute States..
+        let muted = self.load_mute_states().await?;
+
+        // Push to Talk sits on top of the muting, so needs to come after..
+        self.load_push_to_talk().await?;
+
+        // Apply the volumes..
+        let volumes = self.load_volumes().await?;
+
+        // Apply the Effects..
+        self.load_effects().await?;
+
+        // Finalise things setup earlier
+        self.apply_button_states().await?;
+
+        self.load_colours().await?;
+        let routed = self.apply_routing().await?;
+
+        // Only channels which had everything applied are reported, outputs have no routing
+        let applied = |channel: &FaderChannels| {
+            let input = InputChannels::can_from(*channel).then(|| InputChannels::from(*channel));
+            muted.contains(channel)
+                && volumes.contains(channel)
+                && input.map_or(true, |input| routed.contains(&input))
+        };
+        self.load_report.channels = FaderChannels::iter().filter(applied).collect();
+        Ok(())
+    }
+
     fn setup_routing(&mut self) {
         debug!("Loading Routing from Profile: ");
         debug!("Routing Table: {:#?}", self.profile.routing);
@@ -126,28 +210,53 @@ impl LoadProfileLocal for GoXLR {
 
         let cough_state = self.get_cough_button_state();
         self.button_states.set_state(CoughButton, cough_state);
+
+        // Light up the button for the active Mix Mode..
+        self.set_mix_mode_button_states();
+        self.set_solo_button_state();
+        self.set_button_cycle_states();
     }
 
-    async fn load_volumes(&mut self) -> Result<()> {
+    async fn load_volumes(&mut self) -> Result<Vec<FaderChannels>> {
         debug!("Loading Volumes..");
 
+        let mut applied = vec![];
         for source in FaderChannels::iter() {
-            self.apply_channel_volume(source.into()).await?;
+            match self.apply_channel_volume(source.into()).await {
+                Ok(()) => applied.push(source),
+                Err(error) => warn!("Unable to set Volume for {:?}: {}", source, error),
+            }
         }
 
-        Ok(())
+        if applied.is_empty() {
+            bail!("Unable to set the Volume of any channel");
+        }
+        Ok(applied)
     }
 
-    async fn load_mute_states(&mut self) -> Result<()> {
+    async fn load_mute_states(&mut self) -> Result<Vec<FaderChannels>> {
         debug!("Loading Mute States");
 
+        let mut applied = vec![];
         for source in FaderChannels::iter() {
-            self.set_mute_initial(source).await?;
+            match self.set_mute_initial(source).await {
+                Ok(()) => applied.push(source),
+                Err(error) => warn!("Unable to set Mute State for {:?}: {}", source, error),
+            }
         }
-        Ok(())
+
+        if applied.is_empty() {
+            bail!("Unable to set the Mute State of any channel");
+        }
+        Ok(applied)
     }
 
     async fn load_colours(&mut self) -> Result<()> {
+        if !self.config.settings.lighting {
+            debug!("Lighting Disabled, skipping Colour Map");
+            return Ok(());
+        }
+
         debug!("Loading Colour Map..");
 
         // Colours Schemes for Scribbles, Faders and Mute are handled in fader.rs
@@ -164,15 +273,58 @@ impl LoadProfileLocal for GoXLR {
         swear_button.colour1 = self.profile.swear.colours.active_colour;
         swear_button.colour2 = self.profile.swear.colours.inactive_colour;
 
+        // Buttons which cycle show the colour of their current step..
+        self.load_button_cycle_colours();
+
+        // And the lights which aren't attached to anything..
+        self.load_lighting();
+
         self.apply_colours().await
     }
 
-    async fn apply_routing(&self) -> Result<()> {
+    async fn apply_routing(&self) -> Result<Vec<InputChannels>> {
         // Once we reach here, all routing changes should have been setup, so we apply routing
         // for all input channels.
+        let mut applied = vec![];
         for channel in InputChannels::iter() {
-            self.apply_routing_for_channel(channel).await?;
+            match self.apply_routing_for_channel(channel).await {
+                Ok(()) => applied.push(channel),
+                Err(error) => warn!("Unable to apply Routing for {:?}: {}", channel, error),
+            }
         }
-        Ok(())
+
+        if applied.is_empty() {
+            bail!("Unable to apply the Routing of any input");
+        }
+        Ok(applied)
+    }
+
+    fn build_load_report(&mut self, mut previous_scheme: ColourScheme) {
+        let mut current_scheme = self.colour_scheme;
+
+        self.load_report.routes = InputChannels::iter()
+            .flat_map(|input| OutputChannels::iter().map(move |output| (input, output)))
+            .filter(|(input, output)| self.profile.routing[*input][*output])
+            .collect();
+        self.load_report.colours_changed = TwoColourTargets::iter()
+            .filter(|target| {
+                let previous = previous_scheme.get_two_colour_target(*target);
+                let current = current_scheme.get_two_colour_target(*target);
+                previous.colour1 != current.colour1 || previous.colour2 != current.colour2
+            })
+            .collect();
+
+        let report = &self.load_report;
+        info!(
+            "Profile Loaded: {} channels set, {} routes enabled, {} colours changed, {} skipped",
+            report.channels.len(),
+            report.routes.len(),
+            report.colours_changed.len(),
+            report.skipped.len()
+        );
+        for skipped in &report.skipped {
+            info!("Skipped: {}", skipped);
+        }
+        debug!("Profile Load Report: {:#?}", report);
     }
 }
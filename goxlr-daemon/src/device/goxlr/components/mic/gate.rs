@@ -5,6 +5,7 @@ use goxlr_shared::gate::GateTimes;
 use goxlr_shared::microphone::{MicEffectKeys, MicParamKeys};
 use goxlr_usb::events::commands::BasicResultCommand;
 
+use crate::device::goxlr::components::schedule::ScheduleCrate;
 use crate::device::goxlr::device::GoXLR;
 
 static GATE_ATTENUATION: [i8; 26] = [
@@ -37,13 +38,8 @@ impl Gate for GoXLR {
 
         self.mic_profile.gate.threshold = threshold;
 
-        let effect = LinkedHashMap::from_iter([(MicEffectKeys::GateThreshold, threshold as i32)]);
-        let command = BasicResultCommand::SetMicEffects(effect);
-        self.send_no_result(command).await?;
-
-        let param = LinkedHashMap::from_iter([(MicParamKeys::GateThreshold, threshold as f32)]);
-        let command = BasicResultCommand::SetMicParams(param);
-        self.send_no_result(command).await
+        // A scheduled override keeps hold of the device until it ends
+        self.send_gate_threshold(self.get_gate_threshold()).await
     }
 
     async fn set_gate_attack(&mut self, attack: GateTimes) -> Result<()> {
@@ -96,6 +92,9 @@ impl Gate for GoXLR {
 pub(crate) trait GateCrate {
     fn get_gate_values(&self) -> LinkedHashMap<MicEffectKeys, i32>;
     fn get_gate_mini_values(&self) -> LinkedHashMap<MicParamKeys, f32>;
+
+    /// Sends a threshold to the device, without changing the Mic Profile
+    async fn send_gate_threshold(&self, threshold: i8) -> Result<()>;
 }
 
 impl GateCrate for GoXLR {
@@ -104,7 +103,7 @@ impl GateCrate for GoXLR {
 
         // Grab some variables..
         let enabled = self.mic_profile.gate.enabled as i32;
-        let threshold = self.mic_profile.gate.threshold as i32;
+        let threshold = self.get_gate_threshold() as i32;
         let attack = self.mic_profile.gate.attack as i32;
         let release = self.mic_profile.gate.release as i32;
 
@@ -123,7 +122,7 @@ impl GateCrate for GoXLR {
         let mut map = LinkedHashMap::new();
 
         // Grab some variables...
-        let threshold = self.mic_profile.gate.threshold as f32;
+        let threshold = self.get_gate_threshold() as f32;
         let attack = self.mic_profile.gate.attack as u8 as f32;
         let release = self.mic_profile.gate.release as u8 as f32;
         let attenuation = self.mic_profile.gate.attenuation as f32;
@@ -136,6 +135,16 @@ impl GateCrate for GoXLR {
 
         map
     }
+
+    async fn send_gate_threshold(&self, threshold: i8) -> Result<()> {
+        let effect = LinkedHashMap::from_iter([(MicEffectKeys::GateThreshold, threshold as i32)]);
+        let command = BasicResultCommand::SetMicEffects(effect);
+        self.send_no_result(command).await?;
+
+        let param = LinkedHashMap::from_iter([(MicParamKeys::GateThreshold, threshold as f32)]);
+        let command = BasicResultCommand::SetMicParams(param);
+        self.send_no_result(command).await
+    }
 }
 
 trait GateLocal {
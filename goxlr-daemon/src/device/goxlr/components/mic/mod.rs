@@ -4,3 +4,4 @@ pub mod extra;
 pub mod gate;
 pub mod load_profile;
 pub mod mic_type;
+pub mod preset;
@@ -0,0 +1,78 @@
+use anyhow::{Context, Result};
+use log::info;
+use serde::Deserialize;
+
+use goxlr_profile::{Compressor, Gate, MicProfile};
+use goxlr_shared::eq_frequencies::{Frequencies, MiniFrequencies};
+use goxlr_shared::microphone::{MicPreset, MicrophoneType};
+
+use crate::device::goxlr::components::mic::load_profile::LoadMicProfile;
+use crate::device::goxlr::device::GoXLR;
+
+/// A partial Mic Profile, anything not present is left as it is. If an Equaliser is present,
+/// any bands it doesn't mention are flattened, so the preset's curve is heard as intended.
+#[derive(Debug, Deserialize)]
+struct MicPresetFragment {
+    mic_gains: Option<Vec<(MicrophoneType, u8)>>,
+    equalizer: Option<Vec<(Frequencies, i8)>>,
+    equalizer_mini: Option<Vec<(MiniFrequencies, i8)>>,
+    compressor: Option<Compressor>,
+    gate: Option<Gate>,
+}
+
+pub trait MicPresets {
+    async fn apply_mic_preset(&mut self, preset: MicPreset) -> Result<()>;
+}
+
+impl MicPresets for GoXLR {
+    async fn apply_mic_preset(&mut self, preset: MicPreset) -> Result<()> {
+        let fragment: MicPresetFragment = serde_json::from_str(get_preset_json(preset))
+            .with_context(|| format!("Bundled Mic Preset {:?} is invalid", preset))?;
+
+        info!("Applying Mic Preset {:?}", preset);
+        apply_fragment(&mut self.mic_profile, fragment);
+        self.load_mic_profile().await
+    }
+}
+
+fn get_preset_json(preset: MicPreset) -> &'static str {
+    match preset {
+        MicPreset::PodcastVoice => include_str!("presets/podcast_voice.json"),
+        MicPreset::NoisyRoom => include_str!("presets/noisy_room.json"),
+        MicPreset::DynamicMicBoost => include_str!("presets/dynamic_mic_boost.json"),
+    }
+}
+
+fn apply_fragment(profile: &mut MicProfile, fragment: MicPresetFragment) {
+    if let Some(gains) = fragment.mic_gains {
+        for (mic_type, gain) in gains {
+            profile.microphone.mic_gains[mic_type] = gain;
+        }
+    }
+
+    if let Some(equalizer) = fragment.equalizer {
+        for value in profile.equalizer.values_mut() {
+            value.gain = 0;
+        }
+        for (freq, gain) in equalizer {
+            profile.equalizer[freq].gain = gain;
+        }
+    }
+
+    if let Some(equalizer) = fragment.equalizer_mini {
+        for value in profile.equalizer_mini.values_mut() {
+            value.gain = 0;
+        }
+        for (freq, gain) in equalizer {
+            profile.equalizer_mini[freq].gain = gain;
+        }
+    }
+
+    if let Some(compressor) = fragment.compressor {
+        profile.compressor = compressor;
+    }
+
+    if let Some(gate) = fragment.gate {
+        profile.gate = gate;
+    }
+}
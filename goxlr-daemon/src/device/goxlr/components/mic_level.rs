@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::debug;
+use tokio::sync::watch;
+
+use goxlr_shared::channels::ducking::DuckingInput;
+
+use crate::device::goxlr::components::ducker::AudioDuckerTrait;
+use crate::device::goxlr::components::flight_recorder::{FlightRecording, RecorderEvent};
+use crate::device::goxlr::components::idle::IdleDetectionCrate;
+use crate::device::goxlr::device::GoXLR;
+
+/// Holds the most recent Microphone level, polled from the device at most once per tick and
+/// shared with anything that needs it, rather than each feature polling the device itself.
+pub(crate) struct MicLevelSampler {
+    sender: Arc<watch::Sender<Option<f64>>>,
+    last_poll: Option<Instant>,
+}
+
+impl Default for MicLevelSampler {
+    fn default() -> Self {
+        let (sender, _) = watch::channel(None);
+        Self {
+            sender: Arc::new(sender),
+            last_poll: None,
+        }
+    }
+}
+
+impl MicLevelSampler {
+    /// Returns a receiver which is notified each time a new level is sampled
+    pub fn subscribe(&self) -> watch::Receiver<Option<f64>> {
+        self.sender.subscribe()
+    }
+
+    /// Shares the level without subscribing, so holding it won't cause the device to be polled
+    pub fn meter(&self) -> Arc<watch::Sender<Option<f64>>> {
+        self.sender.clone()
+    }
+
+    /// The most recently sampled level, if one is available
+    pub fn latest(&self) -> Option<f64> {
+        *self.sender.borrow()
+    }
+}
+
+pub(crate) trait MicLevel {
+    /// Called on every tick, polls the device if anything needs the level, and enough time
+    /// has passed since the last poll.
+    async fn sample_mic_level(&mut self);
+}
+
+impl MicLevel for GoXLR {
+    async fn sample_mic_level(&mut self) {
+        let ducking = &self.profile.ducking;
+        let ducker_needs_level = ducking.enabled && ducking.input_source[DuckingInput::Mic];
+
+        // Subscribers are only metering for display, which can wait until someone's back
+        let subscribed = self.mic_level.sender.receiver_count() > 0 && !self.is_idle();
+
+        // Nobody's listening, so don't bother the device..
+        if !ducker_needs_level && !subscribed {
+            if self.mic_level.latest().is_some() {
+                self.mic_level.sender.send_replace(None);
+            }
+            return;
+        }
+
+        let interval = Duration::from_millis(self.config.settings.mic_level_interval);
+        if let Some(last_poll) = self.mic_level.last_poll {
+            if last_poll.elapsed() < interval {
+                return;
+            }
+        }
+        self.mic_level.last_poll = Some(Instant::now());
+
+        match self.grab_mic_db().await {
+            Ok(db) => {
+                self.record_event(RecorderEvent::MicLevel(db));
+                self.mic_level.sender.send_replace(Some(db));
+            }
+            Err(error) => {
+                debug!("Unable to sample Mic Level: {}", error);
+                self.mic_level.sender.send_replace(None);
+            }
+        }
+    }
+}
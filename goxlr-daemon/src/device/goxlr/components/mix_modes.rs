@@ -0,0 +1,187 @@
+use anyhow::{bail, Context, Result};
+use enum_map::EnumMap;
+use log::{debug, info};
+
+use goxlr_profile::{ChannelLighting, MixMode};
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::device::DeviceType;
+use goxlr_shared::states::State;
+
+use crate::device::goxlr::components::adapt_profile::AdaptProfile;
+use crate::device::goxlr::components::buttons::ButtonHandlers;
+use crate::device::goxlr::components::crossfade::RoutingCrossfade;
+use crate::device::goxlr::device::GoXLR;
+
+/// Mix Modes are stored in the profile, and allow quickly switching between a set of volumes,
+/// routing, ducking and lighting (for example, 'Just Chatting', 'Gameplay' or 'BRB').
+pub(crate) trait MixModes {
+    async fn save_mix_mode(&mut self, name: String) -> Result<()>;
+    async fn activate_mix_mode(&mut self, name: String) -> Result<()>;
+    async fn delete_mix_mode(&mut self, name: String) -> Result<()>;
+    async fn set_mix_mode_button(&mut self, name: String, button: Option<Buttons>) -> Result<()>;
+    fn set_mix_mode_crossfade(&mut self, duration: u16) -> Result<()>;
+}
+
+impl MixModes for GoXLR {
+    async fn save_mix_mode(&mut self, name: String) -> Result<()> {
+        // Keep the button binding if we're replacing an existing mode..
+        let button = self
+            .profile
+            .mix_modes
+            .modes
+            .get(&name)
+            .and_then(|m| m.button);
+
+        let configs = &self.profile.channels.configs;
+        let lighting = EnumMap::from_fn(|channel| {
+            let display = &configs[channel].display;
+            ChannelLighting {
+                fader_colours: display.fader_colours,
+                mute_colours: display.mute_colours,
+                screen_colour: display.screen_display.colour,
+            }
+        });
+
+        let mode = MixMode {
+            button,
+            volumes: self.profile.channels.volumes,
+            routing: self.profile.routing,
+            ducking_enabled: self.profile.ducking.enabled,
+            lighting,
+        };
+
+        debug!("Saving Mix Mode: {}", name);
+        self.profile.mix_modes.modes.insert(name, mode);
+        Ok(())
+    }
+
+    async fn activate_mix_mode(&mut self, name: String) -> Result<()> {
+        let Some(mode) = self.profile.mix_modes.modes.get(&name).cloned() else {
+            bail!("Mix Mode {} not found", name);
+        };
+
+        info!("Activating Mix Mode: {}", name);
+        self.profile.channels.volumes = mode.volumes;
+        self.profile.routing = mode.routing;
+        self.profile.ducking.enabled = mode.ducking_enabled;
+
+        for (channel, lighting) in mode.lighting {
+            let display = &mut self.profile.channels.configs[channel].display;
+            display.fader_colours = lighting.fader_colours;
+            display.mute_colours = lighting.mute_colours;
+            display.screen_display.colour = lighting.screen_colour;
+        }
+        self.profile.mix_modes.active = Some(name);
+
+        // Reloading the profile ensures mute states are correctly applied over the new routing
+        self.adapt_profile()?;
+        self.load_profile_with_crossfade().await
+    }
+
+    async fn delete_mix_mode(&mut self, name: String) -> Result<()> {
+        if self.profile.mix_modes.modes.remove(&name).is_none() {
+            bail!("Mix Mode {} not found", name);
+        }
+
+        if self.profile.mix_modes.active.as_ref() == Some(&name) {
+            self.profile.mix_modes.active = None;
+        }
+
+        self.apply_mix_mode_buttons().await
+    }
+
+    async fn set_mix_mode_button(&mut self, name: String, button: Option<Buttons>) -> Result<()> {
+        if !self.profile.mix_modes.modes.contains_key(&name) {
+            bail!("Mix Mode {} not found", name);
+        }
+
+        if let Some(button) = button {
+            let device = self.device.as_ref().context("Device Not Found!")?;
+            if device.device_type == DeviceType::Mini {
+                bail!("Effect Select buttons are not available on the GoXLR Mini");
+            }
+
+            if !is_mix_mode_button(button) {
+                bail!("Mix Modes can only be assigned to Effect Select buttons");
+            }
+
+            // A button can only activate a single mode..
+            for mode in self.profile.mix_modes.modes.values_mut() {
+                if mode.button == Some(button) {
+                    mode.button = None;
+                }
+            }
+        }
+
+        if let Some(mode) = self.profile.mix_modes.modes.get_mut(&name) {
+            mode.button = button;
+        }
+
+        self.apply_mix_mode_buttons().await
+    }
+
+    fn set_mix_mode_crossfade(&mut self, duration: u16) -> Result<()> {
+        if duration > MAX_CROSSFADE {
+            bail!("Cross-fade must be {}ms or less", MAX_CROSSFADE);
+        }
+
+        self.profile.mix_modes.crossfade_ms = duration;
+        Ok(())
+    }
+}
+
+pub(crate) trait MixModesCrate {
+    fn get_mix_mode_for_button(&self, button: Buttons) -> Option<String>;
+    fn set_mix_mode_button_states(&mut self);
+}
+
+impl MixModesCrate for GoXLR {
+    fn get_mix_mode_for_button(&self, button: Buttons) -> Option<String> {
+        let modes = &self.profile.mix_modes.modes;
+        let mut bound = modes.iter().filter(|(_, mode)| mode.button == Some(button));
+        bound.next().map(|(name, _)| name.clone())
+    }
+
+    fn set_mix_mode_button_states(&mut self) {
+        // Reset all the Mix Mode buttons, then light the active one
+        for button in MIX_MODE_BUTTONS {
+            self.button_states.set_state(button, State::DimmedColour1);
+        }
+
+        let active = self.profile.mix_modes.active.as_ref();
+        for (name, mode) in &self.profile.mix_modes.modes {
+            if let Some(button) = mode.button {
+                if active == Some(name) {
+                    self.button_states.set_state(button, State::Colour1);
+                }
+            }
+        }
+    }
+}
+
+trait MixModesLocal {
+    async fn apply_mix_mode_buttons(&mut self) -> Result<()>;
+}
+
+impl MixModesLocal for GoXLR {
+    async fn apply_mix_mode_buttons(&mut self) -> Result<()> {
+        self.set_mix_mode_button_states();
+        self.apply_button_states().await
+    }
+}
+
+// Routing changes are held by the cross-fade until it finishes, so keep it short
+const MAX_CROSSFADE: u16 = 5000;
+
+const MIX_MODE_BUTTONS: [Buttons; 6] = [
+    Buttons::EffectSelect1,
+    Buttons::EffectSelect2,
+    Buttons::EffectSelect3,
+    Buttons::EffectSelect4,
+    Buttons::EffectSelect5,
+    Buttons::EffectSelect6,
+];
+
+fn is_mix_mode_button(button: Buttons) -> bool {
+    MIX_MODE_BUTTONS.contains(&button)
+}
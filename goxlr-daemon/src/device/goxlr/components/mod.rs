@@ -1,12 +1,60 @@
+pub(crate) mod adapt_profile;
+pub(crate) mod alert_flash;
+pub(crate) mod audit_log;
+pub(crate) mod automation;
+pub(crate) mod batch;
+pub(crate) mod button_cycles;
 pub(crate) mod buttons;
 pub(crate) mod channel;
+pub(crate) mod colour_buffer;
+pub(crate) mod colour_preview;
+pub(crate) mod controls;
+pub(crate) mod crossfade;
+pub(crate) mod desired_state;
+pub(crate) mod effects;
 pub(crate) mod fader;
+pub(crate) mod fader_events;
+pub(crate) mod fader_response;
+pub(crate) mod flight_recorder;
+pub(crate) mod gain_analyser;
+pub(crate) mod hard_mute;
+pub(crate) mod headphone_limit;
+pub(crate) mod idle;
+pub(crate) mod input_category;
+pub(crate) mod input_test;
+pub(crate) mod input_trim;
 pub(crate) mod interactions;
+pub(crate) mod latency;
+pub(crate) mod level_source;
+pub(crate) mod lighting;
+pub(crate) mod lighting_layers;
+pub(crate) mod lighting_takeover;
 pub(crate) mod load_profile;
 pub(crate) mod mic;
+pub(crate) mod mic_level;
+pub(crate) mod mix_modes;
+pub(crate) mod monitor_mix;
 pub(crate) mod mute_handler;
+pub(crate) mod notifications;
+pub(crate) mod output_ceiling;
 pub(crate) mod pages;
 pub(crate) mod profile;
+pub(crate) mod profile_storage;
+pub(crate) mod push_to_talk;
+pub(crate) mod route_arbiter;
 pub(crate) mod routing_handler;
+pub(crate) mod routing_rules;
+pub(crate) mod safe_mode;
+pub(crate) mod sample_player;
+pub(crate) mod sampler;
+pub(crate) mod schedule;
+pub(crate) mod scribble_widgets;
+pub(crate) mod session_stats;
+pub(crate) mod solo;
+pub(crate) mod state_cache;
+pub(crate) mod stereo_mode;
 pub(crate) mod submix;
+pub(crate) mod test_tone;
+pub(crate) mod voice_routing;
+pub(crate) mod dry_run;
 pub(crate) mod ducker;
@@ -0,0 +1,78 @@
+use anyhow::{bail, Result};
+use enum_map::EnumMap;
+use log::debug;
+use strum::IntoEnumIterator;
+
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::RoutingOutput;
+use goxlr_shared::routing::RouteValue;
+
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+type Row = EnumMap<RoutingOutput, RouteValue>;
+
+// The maximum value of a route, a monitor level of 32 is full volume
+const ROUTE_MAX: u8 = 32;
+
+/// Lets the Headphones hear each input at a different level to the stream. The routing table
+/// still holds what the broadcast mix hears, the monitor levels are only applied to the
+/// Headphone route as it's sent to the device, so muting, ducking and Push to Talk all behave
+/// exactly as they would without it.
+pub(crate) trait MonitorMix {
+    async fn set_monitor_mix_enabled(&mut self, enabled: bool) -> Result<()>;
+    async fn set_monitor_mix_level(&mut self, input: InputChannels, level: u8) -> Result<()>;
+}
+
+impl MonitorMix for GoXLR {
+    async fn set_monitor_mix_enabled(&mut self, enabled: bool) -> Result<()> {
+        debug!("Setting Monitor Mix Enabled: {}", enabled);
+        self.profile.monitor_mix.enabled = enabled;
+
+        for input in InputChannels::iter() {
+            self.apply_routing_for_channel(input).await?;
+        }
+        Ok(())
+    }
+
+    async fn set_monitor_mix_level(&mut self, input: InputChannels, level: u8) -> Result<()> {
+        if level > ROUTE_MAX {
+            bail!("Monitor Level must be {} or less", ROUTE_MAX);
+        }
+
+        debug!("Setting Monitor Mix Level for {:?} to {}", input, level);
+        self.profile.monitor_mix.levels[input] = level;
+
+        if self.profile.monitor_mix.enabled {
+            self.apply_routing_for_channel(input).await?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) trait MonitorMixCrate {
+    /// Applies the monitor level to the Headphone route of a row about to be sent to the device
+    fn apply_monitor_mix(&self, input: InputChannels, row: Row) -> Row;
+}
+
+impl MonitorMixCrate for GoXLR {
+    fn apply_monitor_mix(&self, input: InputChannels, mut row: Row) -> Row {
+        let monitor = &self.profile.monitor_mix;
+        if !monitor.enabled {
+            return row;
+        }
+
+        let level = monitor.levels[input];
+        row[RoutingOutput::Headphones] = match row[RoutingOutput::Headphones] {
+            RouteValue::Off => RouteValue::Off,
+            RouteValue::On => RouteValue::Value(level),
+
+            // Something else (probably the ducker) has lowered this route, so scale from there
+            RouteValue::Value(value) => {
+                let scaled = value as u16 * level as u16 / ROUTE_MAX as u16;
+                RouteValue::Value(scaled as u8)
+            }
+        };
+        row
+    }
+}
@@ -1,5 +1,8 @@
-use anyhow::Result;
-use log::debug;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use enum_map::EnumMap;
+use log::{debug, warn};
 use ritelinked::LinkedHashMap;
 use strum::IntoEnumIterator;
 
@@ -8,21 +11,55 @@ use goxlr_shared::buttons::Buttons::CoughButton;
 use goxlr_shared::channels::fader::FaderChannels;
 use goxlr_shared::channels::input::InputChannels;
 use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
+use goxlr_shared::channels::volume::VolumeChannels;
 use goxlr_shared::microphone::MicEffectKeys;
 use goxlr_shared::mute::ChannelMuteState::{Muted, Unmuted};
-use goxlr_shared::mute::{ChannelMuteState, MuteState};
+use goxlr_shared::mute::{ChannelMuteState, CoughTarget, MuteState};
 use goxlr_shared::routing::RouteValue;
 use goxlr_shared::states::State;
+use goxlr_shared::volume::Volume;
 use goxlr_usb::events::commands::BasicResultCommand;
 
 use crate::device::goxlr::components::buttons::ButtonHandlers;
+use crate::device::goxlr::components::channel::ChannelsCrate;
 use crate::device::goxlr::components::fader::DeviceFader;
+use crate::device::goxlr::components::hard_mute::HardMuteCrate;
+use crate::device::goxlr::components::push_to_talk::PushToTalkCrate;
 use crate::device::goxlr::components::routing_handler::RoutingHandler;
 use crate::device::goxlr::device::GoXLR;
 
 type Source = FaderChannels;
 type Target = Vec<OutputChannels>;
 
+// The actual mute is held back until a fade out finishes, so keep it short
+const MAX_MUTE_FADE: u16 = 2000;
+
+// How many volume changes to make during a fade, regardless of duration
+const FADE_STEPS: u16 = 16;
+
+/// Fades which are running, these are stepped along by the tick rather than waited on, so
+/// the device stays responsive while they run.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct MuteFadeState {
+    fades: EnumMap<FaderChannels, Option<MuteFade>>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct MuteFade {
+    direction: FadeDirection,
+    position: u16,
+    last_step: Instant,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum FadeDirection {
+    // Down to silent, followed by the Mute to All
+    Out,
+
+    // Up from silent, after an Unmute
+    In,
+}
+
 pub(crate) trait MuteHandler {
     /// Programmatically Setting the mute states..
     async fn set_mute_state(&mut self, source: Source, state: MuteState) -> Result<()>;
@@ -35,6 +72,7 @@ pub(crate) trait MuteHandler {
 
     /// Used for the Cough Buttons..
     async fn handle_cough_press(&mut self, hold: bool) -> Result<()>;
+    async fn set_cough_target(&mut self, target: CoughTarget) -> Result<()>;
 
     /// Returns the Button state for a mute button..
     fn get_mute_button_state(&self, source: Source) -> State;
@@ -44,6 +82,12 @@ pub(crate) trait MuteHandler {
 
     /// Returns whether a current source is 'Muted to All'
     fn is_muted_to_all(&self, source: Source) -> bool;
+
+    /// Sets how long the volume fades for when muting or unmuting to all
+    fn set_mute_fade(&mut self, source: Source, fade_ms: u16) -> Result<()>;
+
+    /// Moves any running fades along, called by the tick
+    async fn handle_mute_fades(&mut self);
 }
 
 impl MuteHandler for GoXLR {
@@ -185,9 +229,25 @@ impl MuteHandler for GoXLR {
         Ok(())
     }
 
+    async fn set_cough_target(&mut self, target: CoughTarget) -> Result<()> {
+        debug!("Setting Cough Target to {:?}", target);
+        self.profile.cough.target = target;
+
+        // If the Cough is active, the channel needs resyncing onto the new targets
+        let cough_source = self.profile.cough.channel_assignment;
+        let channel_state = self.profile.channels.configs[cough_source].mute_state;
+        self.set_mute_state(cough_source, channel_state).await
+    }
+
     fn get_mute_button_state(&self, source: Source) -> State {
         let channel = self.profile.channels.configs[source].clone();
 
+        // A Hard Muted Microphone is shown as muted, whatever its mute state
+        let hard_muted = source == FaderChannels::Microphone && self.is_hard_muted();
+        if hard_muted && channel.mute_state == MuteState::Unmuted {
+            return State::Colour1;
+        }
+
         match channel.mute_state {
             MuteState::Unmuted => State::from(channel.display.mute_colours.inactive_behaviour),
             MuteState::Pressed => State::Colour1,
@@ -222,11 +282,35 @@ impl MuteHandler for GoXLR {
         // If the target list is empty, we're muted to all.
         targets.is_empty()
     }
+
+    fn set_mute_fade(&mut self, source: Source, fade_ms: u16) -> Result<()> {
+        if fade_ms > MAX_MUTE_FADE {
+            bail!("Mute Fade must be {}ms or less", MAX_MUTE_FADE);
+        }
+
+        self.profile.channels.configs[source].mute_fade_ms = fade_ms;
+        Ok(())
+    }
+
+    async fn handle_mute_fades(&mut self) {
+        for source in FaderChannels::iter() {
+            if let Err(error) = self.step_mute_fade(source).await {
+                warn!("Unable to Fade {:?}: {}", source, error);
+                self.mute_fades.fades[source] = None;
+            }
+        }
+    }
 }
 
 pub(crate) trait MuteHandlerCrate {
     /// Used when loading profiles to set the initial state
     async fn set_mute_initial(&mut self, source: Source) -> Result<()>;
+
+    /// Returns whether a source is currently muted to an output (including the cough button)
+    fn is_muted_to(&self, source: Source, output: OutputChannels) -> bool;
+
+    /// Sends the Microphone's input mute to the device, for when the Hard Mute has changed
+    async fn sync_mic_mute_state(&self) -> Result<()>;
 }
 
 impl MuteHandlerCrate for GoXLR {
@@ -270,6 +354,22 @@ impl MuteHandlerCrate for GoXLR {
 
         Ok(())
     }
+
+    fn is_muted_to(&self, source: Source, output: OutputChannels) -> bool {
+        let state = self.profile.channels.configs[source].mute_state;
+        let targets = match state {
+            MuteState::Unmuted => self.add_cough_mute(source, None),
+            state => Some(self.get_targets_for_action(source, MuteAction::from(state))),
+        };
+
+        // An empty target list is Mute to All
+        targets.is_some_and(|targets| targets.is_empty() || targets.contains(&output))
+    }
+
+    async fn sync_mic_mute_state(&self) -> Result<()> {
+        let muted = self.mute_state[FaderChannels::Microphone] == Some(Muted);
+        self.send_mic_mute_state(muted).await
+    }
 }
 
 trait MuteHandlerLocal {
@@ -279,10 +379,15 @@ trait MuteHandlerLocal {
 
     async fn send_mute_state(&mut self, source: Source, state: ChannelMuteState) -> Result<()>;
     async fn send_mic_mute_state(&self, muted: bool) -> Result<()>;
+    async fn send_fade_volume(&self, source: Source, volume: Volume) -> Result<()>;
+    async fn finish_mute_to_all(&mut self, source: Source, faded: bool) -> Result<()>;
+    async fn step_mute_fade(&mut self, source: Source) -> Result<()>;
+    fn start_fade(&mut self, source: Source, direction: FadeDirection);
     async fn apply_mute_changes(&self, changes: MuteChanges) -> Result<()>;
 
     fn get_targets_for_action(&self, source: Source, mute_action: MuteAction) -> Target;
     fn add_cough_mute(&self, source: Source, current: Option<Target>) -> Option<Target>;
+    fn get_cough_targets(&self, action: MuteAction) -> Target;
     fn restore_routing_from_profile(&mut self, source: Source) -> Result<MuteChanges>;
 }
 
@@ -336,12 +441,17 @@ impl MuteHandlerLocal for GoXLR {
     async fn mute_to_all(&mut self, source: Source) -> Result<MuteChanges> {
         debug!("Muting Channel {:?} to All", source);
 
-        // The Microphone also has an 'Effect' which needs to be set when muting / unmuting
-        if source == FaderChannels::Microphone {
-            self.send_mic_mute_state(true).await?;
+        // Bring the volume down first, so the mute itself isn't heard as a pop. The tick will
+        // finish the mute once the fade is done.
+        let fade = self.profile.channels.configs[source].mute_fade_ms > 0
+            && self.mute_state[source] != Some(Muted);
+        if fade {
+            self.start_fade(source, FadeDirection::Out);
+            return Ok(Default::default());
         }
 
-        self.send_mute_state(source, Muted).await?;
+        self.mute_fades.fades[source] = None;
+        self.finish_mute_to_all(source, false).await?;
         Ok(Default::default())
     }
 
@@ -355,6 +465,16 @@ impl MuteHandlerLocal for GoXLR {
             updated_routes = self.restore_routing_from_profile(source)?.routing;
         }
 
+        // If we're coming out of a mute to all, start silent and fade in. If we're still fading
+        // out the mute never happened, so the fade just turns around.
+        let fading_out =
+            self.mute_fades.fades[source].is_some_and(|fade| fade.direction == FadeDirection::Out);
+        let fade = self.profile.channels.configs[source].mute_fade_ms > 0
+            && (self.mute_state[source] == Some(Muted) || fading_out);
+        if fade && !fading_out {
+            self.send_fade_volume(source, Volume::SILENT).await?;
+        }
+
         // The Microphone also has an 'Effect' which needs to be set when muting / unmuting
         if source == FaderChannels::Microphone {
             self.send_mic_mute_state(false).await?;
@@ -364,6 +484,14 @@ impl MuteHandlerLocal for GoXLR {
         debug!("Checking device state for {:?}", source);
         self.send_mute_state(source, Unmuted).await?;
 
+        if fade {
+            self.start_fade(source, FadeDirection::In);
+        } else if self.mute_fades.fades[source].take().is_some() {
+            // The fade was switched off part way through, so put the volume straight back
+            let volume = self.get_channel_volume(VolumeChannels::from(source));
+            self.send_fade_volume(source, volume).await?;
+        }
+
         Ok(MuteChanges {
             routing: updated_routes,
         })
@@ -394,11 +522,96 @@ impl MuteHandlerLocal for GoXLR {
     }
 
     async fn send_mic_mute_state(&self, muted: bool) -> Result<()> {
+        // Everything which unmutes the Microphone comes through here, so this is where the Hard
+        // Mute is held
+        let muted = muted || self.is_hard_muted();
         let map = LinkedHashMap::from_iter([(MicEffectKeys::MicInputMute, muted as i32)]);
         let command = BasicResultCommand::SetMicEffects(map);
         self.send_no_result(command).await
     }
 
+    async fn send_fade_volume(&self, source: Source, volume: Volume) -> Result<()> {
+        let command = BasicResultCommand::SetVolume(source.into(), volume.to_hardware());
+        self.send_no_result(command).await
+    }
+
+    /// Sends the actual mute, once any fade down has finished
+    async fn finish_mute_to_all(&mut self, source: Source, faded: bool) -> Result<()> {
+        // The Microphone also has an 'Effect' which needs to be set when muting / unmuting
+        if source == FaderChannels::Microphone {
+            self.send_mic_mute_state(true).await?;
+        }
+
+        self.send_mute_state(source, Muted).await?;
+
+        // Now we're muted, the volume can quietly go back to where it was
+        if faded {
+            let volume = self.get_channel_volume(VolumeChannels::from(source));
+            self.send_fade_volume(source, volume).await?;
+        }
+        Ok(())
+    }
+
+    /// Moves a fade along by however many steps have passed since the last one. The profile
+    /// volume isn't touched, this only changes what's on the device, and the volume is read each
+    /// step so a fader moved during the fade is followed.
+    async fn step_mute_fade(&mut self, source: Source) -> Result<()> {
+        let Some(mut fade) = self.mute_fades.fades[source] else {
+            return Ok(());
+        };
+
+        let fade_ms = self.profile.channels.configs[source].mute_fade_ms;
+        let step = Duration::from_millis(fade_ms as u64) / FADE_STEPS as u32;
+        let passed = if step.is_zero() {
+            FADE_STEPS
+        } else {
+            (fade.last_step.elapsed().as_millis() / step.as_millis().max(1)) as u16
+        };
+        if passed == 0 {
+            return Ok(());
+        }
+
+        fade.position = (fade.position + passed).min(FADE_STEPS);
+        fade.last_step += step * passed as u32;
+
+        let volume = self.get_channel_volume(VolumeChannels::from(source));
+        let volume = match fade.direction {
+            FadeDirection::Out => volume.step_towards(Volume::SILENT, fade.position, FADE_STEPS),
+            FadeDirection::In => Volume::SILENT.step_towards(volume, fade.position, FADE_STEPS),
+        };
+        self.send_fade_volume(source, volume).await?;
+
+        if fade.position < FADE_STEPS {
+            self.mute_fades.fades[source] = Some(fade);
+            return Ok(());
+        }
+
+        debug!("Fade {:?} of {:?} complete", fade.direction, source);
+        self.mute_fades.fades[source] = None;
+        if fade.direction == FadeDirection::Out {
+            self.finish_mute_to_all(source, true).await?;
+        }
+        Ok(())
+    }
+
+    fn start_fade(&mut self, source: Source, direction: FadeDirection) {
+        let fade_ms = self.profile.channels.configs[source].mute_fade_ms;
+        debug!("Fading {:?} {:?} over {}ms", source, direction, fade_ms);
+
+        // Turning a running fade around starts from wherever it had got to
+        let position = match self.mute_fades.fades[source] {
+            Some(fade) if fade.direction == direction => return,
+            Some(fade) => FADE_STEPS - fade.position,
+            None => 0,
+        };
+
+        self.mute_fades.fades[source] = Some(MuteFade {
+            direction,
+            position,
+            last_step: Instant::now(),
+        });
+    }
+
     async fn apply_mute_changes(&self, changes: MuteChanges) -> Result<()> {
         for channel in changes.routing {
             self.apply_routing_for_channel(channel).await?;
@@ -429,7 +642,7 @@ impl MuteHandlerLocal for GoXLR {
 
         // Ok, we need to adjust our target list to correctly match.
         let cough_action = MuteAction::from(cough_state);
-        let cough_targets = self.profile.cough.mute_actions[cough_action].clone();
+        let cough_targets = self.get_cough_targets(cough_action);
 
         return match current.clone() {
             None => {
@@ -455,6 +668,14 @@ impl MuteHandlerLocal for GoXLR {
         };
     }
 
+    fn get_cough_targets(&self, action: MuteAction) -> Target {
+        match self.profile.cough.target {
+            CoughTarget::Configured => self.profile.cough.mute_actions[action].clone(),
+            CoughTarget::ChatOnly => vec![OutputChannels::ChatMic],
+            CoughTarget::StreamOnly => vec![OutputChannels::StreamMix],
+        }
+    }
+
     /// This function simply updates the routing table to reset any transient mute states from
     /// the profile, to allow for general cleaning up before other changes.
     fn restore_routing_from_profile(&mut self, source: Source) -> Result<MuteChanges> {
@@ -473,8 +694,9 @@ impl MuteHandlerLocal for GoXLR {
                 let active = self.routing_state.get_routing(source, route);
 
                 // Because muting will never affect a routing value that's set to 'Off', we don't
-                // need to worry too much about handling false here.
-                if profile_value {
+                // need to worry too much about handling false here. Push to Talk manages its own
+                // routes, so leave those alone.
+                if profile_value && !self.is_push_to_talk_gated(source, output) {
                     // Compare it against the routing table..
                     match active {
                         RouteValue::Off => {
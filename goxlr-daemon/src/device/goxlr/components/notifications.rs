@@ -0,0 +1,77 @@
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::mute::MuteState;
+
+use crate::audio_cues::{play_cue, AudioCue};
+use crate::device::goxlr::device::GoXLR;
+use crate::notifications::{send_notification, Notification};
+
+#[derive(Default)]
+pub(crate) struct NotificationState {
+    // What the Microphone was last seen as, None until it's first been checked
+    mic_muted: Option<bool>,
+
+    // The first load is the device starting up, which isn't worth telling anyone about
+    profile_loaded: bool,
+}
+
+/// Watches for changes worth raising as a desktop notification, or playing an audio cue for.
+/// The mute state is compared each tick rather than at each place it can change, as between the
+/// buttons, the cough button and the IPC commands, there are a lot of those places.
+pub(crate) trait DeviceNotifications {
+    fn handle_notifications(&mut self);
+    fn notify_profile_loaded(&mut self);
+}
+
+impl DeviceNotifications for GoXLR {
+    fn handle_notifications(&mut self) {
+        let mic = FaderChannels::Microphone;
+        let cough = &self.profile.cough;
+        let coughing = cough.channel_assignment == mic && cough.mute_state != MuteState::Unmuted;
+        let muted = self.profile.channels.configs[mic].mute_state != MuteState::Unmuted || coughing;
+
+        let previous = self.notifications.mic_muted.replace(muted);
+        if previous.is_none() || previous == Some(muted) {
+            return;
+        }
+
+        let cue = match muted {
+            true => AudioCue::MicMuted,
+            false => AudioCue::MicUnmuted,
+        };
+        play_cue(&self.config.settings.audio_cues, cue);
+
+        let Some(serial) = self.get_serial() else {
+            return;
+        };
+        let notification = match muted {
+            true => Notification::MicMuted(serial),
+            false => Notification::MicUnmuted(serial),
+        };
+        send_notification(&self.config.settings.notifications, notification);
+    }
+
+    fn notify_profile_loaded(&mut self) {
+        if !self.notifications.profile_loaded {
+            self.notifications.profile_loaded = true;
+            return;
+        }
+        play_cue(&self.config.settings.audio_cues, AudioCue::ProfileLoaded);
+
+        let Some(serial) = self.get_serial() else {
+            return;
+        };
+        let mix_mode = self.profile.mix_modes.active.clone();
+        let notification = Notification::ProfileLoaded(serial, mix_mode);
+        send_notification(&self.config.settings.notifications, notification);
+    }
+}
+
+trait DeviceNotificationsLocal {
+    fn get_serial(&self) -> Option<String>;
+}
+
+impl DeviceNotificationsLocal for GoXLR {
+    fn get_serial(&self) -> Option<String> {
+        self.device.as_ref().map(|device| device.serial.clone())
+    }
+}
@@ -0,0 +1,64 @@
+use anyhow::{bail, Result};
+use enum_map::EnumMap;
+use log::debug;
+use strum::IntoEnumIterator;
+
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
+use goxlr_shared::routing::RouteValue;
+
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+type Row = EnumMap<RoutingOutput, RouteValue>;
+
+// The maximum value of a route, a ceiling of 32 leaves the output untouched
+const ROUTE_MAX: u8 = 32;
+
+/// Caps every route to an Output, so nothing which changes the routing (the Ducker, Mix Modes,
+/// cross-fades or anything else) can make it louder than the user has decided is safe. This is
+/// the last routing layer, so the ceiling holds whichever layers have changed a route before it.
+pub(crate) trait OutputCeiling {
+    async fn set_output_ceiling(&mut self, output: OutputChannels, ceiling: u8) -> Result<()>;
+}
+
+impl OutputCeiling for GoXLR {
+    async fn set_output_ceiling(&mut self, output: OutputChannels, ceiling: u8) -> Result<()> {
+        if ceiling > ROUTE_MAX {
+            bail!("Ceiling must be {} or less", ROUTE_MAX);
+        }
+
+        debug!("Setting Output Ceiling for {:?} to {}", output, ceiling);
+        self.profile.outputs[output].ceiling = ceiling;
+
+        // Every input can be routed to the output, so they all need to be sent again
+        for input in InputChannels::iter() {
+            self.apply_routing_for_channel(input).await?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) trait OutputCeilingCrate {
+    /// Lowers any route in a row about to be sent to the device which is above its ceiling
+    fn apply_output_ceiling(&self, row: Row) -> Row;
+}
+
+impl OutputCeilingCrate for GoXLR {
+    fn apply_output_ceiling(&self, mut row: Row) -> Row {
+        for output in OutputChannels::iter() {
+            let ceiling = self.profile.outputs[output].ceiling;
+            if ceiling >= ROUTE_MAX {
+                continue;
+            }
+
+            let value = &mut row[RoutingOutput::from(output)];
+            *value = match *value {
+                RouteValue::Off => RouteValue::Off,
+                RouteValue::On => RouteValue::Value(ceiling),
+                RouteValue::Value(value) => RouteValue::Value(value.min(ceiling)),
+            };
+        }
+        row
+    }
+}
@@ -9,6 +9,7 @@ use goxlr_usb::events::commands::BasicResultCommand;
 
 use crate::device::goxlr::components::fader::DeviceFader;
 use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::components::session_stats::SessionStatisticsCrate;
 use crate::device::goxlr::device::GoXLR;
 
 /// This trait is responsible for fader paging, anything that need to happen (including changing
@@ -100,6 +101,9 @@ impl FaderPages for GoXLR {
             bail!("Invalid Page Number: {}, Max: {}", page, page_count);
         }
         debug!("Changing Fader Page to {}", page);
+        if page != self.profile.pages.current {
+            self.record_page_switch();
+        }
         self.profile.pages.current = page;
         self.load_current_page(true).await
     }
@@ -0,0 +1,128 @@
+use std::time::{Duration, Instant};
+
+use log::{debug, info, warn};
+
+use goxlr_ipc::status::Configuration;
+
+use crate::device::goxlr::device::GoXLR;
+
+// How long the profile needs to be left alone before it's stored, so a fader being moved
+// doesn't cause a write for every step.
+const STORE_DELAY: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+pub(crate) struct ProfileStorage {
+    // When the profile was first changed since it was last stored
+    changed: Option<Instant>,
+
+    // What was last stored, so an unchanged profile isn't written again
+    stored: Option<String>,
+
+    // Set when the stored profiles couldn't be loaded, nothing is stored until the daemon restarts
+    disabled: bool,
+}
+
+pub(crate) trait ProfileStorageCrate {
+    /// Replaces the default profiles with the ones stored for this device, if there are any
+    fn load_stored_profiles(&mut self);
+
+    /// Called after anything which may have changed the profile
+    fn mark_profile_changed(&mut self);
+
+    /// Called on every tick, stores the profile once it's stopped changing
+    fn handle_profile_storage(&mut self);
+
+    /// Stores the profile now, if it's changed
+    fn store_profiles(&mut self);
+}
+
+impl ProfileStorageCrate for GoXLR {
+    fn load_stored_profiles(&mut self) {
+        let Some(serial) = self.device.as_ref().map(|device| device.serial.clone()) else {
+            return;
+        };
+
+        match self.config.profile_store.load(&serial) {
+            Ok(Some(configuration)) => {
+                info!("Loaded Stored Profiles for {}", serial);
+                self.profile = configuration.device;
+                self.mic_profile = configuration.mic_profile;
+                self.profile_storage.stored = serde_json::to_string(&self.get_configuration()).ok();
+            }
+            Ok(None) => debug!("No Stored Profiles for {}, using Defaults", serial),
+            Err(error) => {
+                warn!("Unable to load Stored Profiles, using Defaults: {}", error);
+                warn!("Profile changes won't be stored until the daemon is restarted");
+                self.profile_storage.disabled = true;
+
+                // Keep the unreadable profile, so it's not lost once storing starts again
+                if let Err(error) = self.config.profile_store.set_aside(&serial) {
+                    warn!("Unable to set aside the Stored Profiles: {}", error);
+                }
+            }
+        }
+    }
+
+    fn mark_profile_changed(&mut self) {
+        if self.profile_storage.changed.is_none() {
+            self.profile_storage.changed = Some(Instant::now());
+        }
+    }
+
+    fn handle_profile_storage(&mut self) {
+        if let Some(changed) = self.profile_storage.changed {
+            if changed.elapsed() >= STORE_DELAY {
+                self.store_profiles();
+            }
+        }
+    }
+
+    fn store_profiles(&mut self) {
+        self.profile_storage.changed = None;
+        if self.profile_storage.disabled {
+            return;
+        }
+
+        // Safe Mode is only a stand in, it mustn't replace the profile which failed to load
+        if self.load_report.safe_mode.is_some() {
+            debug!("In Safe Mode, not storing Profiles");
+            return;
+        }
+
+        let Some(serial) = self.device.as_ref().map(|device| device.serial.clone()) else {
+            return;
+        };
+
+        let configuration = self.get_configuration();
+        let json = match serde_json::to_string(&configuration) {
+            Ok(json) => json,
+            Err(error) => {
+                warn!("Unable to Serialise Profiles: {}", error);
+                return;
+            }
+        };
+        if self.profile_storage.stored.as_ref() == Some(&json) {
+            return;
+        }
+
+        debug!("Storing Profiles for {}", serial);
+        if let Err(error) = self.config.profile_store.save(&serial, &configuration) {
+            warn!("Unable to Store Profiles: {}", error);
+            return;
+        }
+        self.profile_storage.stored = Some(json);
+    }
+}
+
+trait ProfileStorageLocal {
+    fn get_configuration(&self) -> Configuration;
+}
+
+impl ProfileStorageLocal for GoXLR {
+    fn get_configuration(&self) -> Configuration {
+        Configuration {
+            device: self.profile.clone(),
+            mic_profile: self.mic_profile,
+        }
+    }
+}
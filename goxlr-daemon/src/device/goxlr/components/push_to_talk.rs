@@ -0,0 +1,169 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use enum_map::EnumMap;
+use log::{debug, warn};
+use strum::IntoEnumIterator;
+
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
+use goxlr_shared::mute::MuteState;
+use goxlr_shared::routing::RouteValue;
+
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PushToTalkState {
+    talking: bool,
+    released: Option<Instant>,
+
+    // The outputs we've currently removed the Microphone from
+    gated: EnumMap<OutputChannels, bool>,
+}
+
+impl PushToTalkState {
+    /// The Microphone is open while talking, and until the release tail has passed
+    pub fn is_open(&self) -> bool {
+        self.talking || self.released.is_some()
+    }
+}
+
+/// Push to Talk removes the Microphone from the configured outputs, and only restores it while
+/// the button (or an external hotkey) is held. This works on top of the routing table in the
+/// same way as transient mutes, so the profile's routing is left untouched.
+pub(crate) trait PushToTalk {
+    async fn set_push_to_talk_enabled(&mut self, enabled: bool) -> Result<()>;
+    fn set_push_to_talk_button(&mut self, button: Option<Buttons>);
+    async fn set_push_to_talk_outputs(&mut self, outputs: Vec<OutputChannels>) -> Result<()>;
+    fn set_push_to_talk_release_tail(&mut self, tail: u64);
+
+    async fn set_push_to_talk_pressed(&mut self, pressed: bool) -> Result<()>;
+    async fn handle_push_to_talk(&mut self);
+}
+
+impl PushToTalk for GoXLR {
+    async fn set_push_to_talk_enabled(&mut self, enabled: bool) -> Result<()> {
+        self.profile.push_to_talk.enabled = enabled;
+        self.apply_push_to_talk_gate().await
+    }
+
+    fn set_push_to_talk_button(&mut self, button: Option<Buttons>) {
+        self.profile.push_to_talk.button = button;
+    }
+
+    async fn set_push_to_talk_outputs(&mut self, outputs: Vec<OutputChannels>) -> Result<()> {
+        self.profile.push_to_talk.outputs = outputs;
+        self.apply_push_to_talk_gate().await
+    }
+
+    fn set_push_to_talk_release_tail(&mut self, tail: u64) {
+        self.profile.push_to_talk.release_tail = tail;
+    }
+
+    async fn set_push_to_talk_pressed(&mut self, pressed: bool) -> Result<()> {
+        let state = &mut self.push_to_talk;
+        if pressed {
+            debug!("Push to Talk Pressed");
+            state.talking = true;
+            state.released = None;
+        } else {
+            if !state.talking {
+                return Ok(());
+            }
+
+            debug!("Push to Talk Released");
+            state.talking = false;
+
+            // If there's a tail, the ticker will close the Microphone once it's passed..
+            if self.profile.push_to_talk.release_tail > 0 {
+                state.released = Some(Instant::now());
+                return Ok(());
+            }
+        }
+
+        self.apply_push_to_talk_gate().await
+    }
+
+    async fn handle_push_to_talk(&mut self) {
+        let Some(released) = self.push_to_talk.released else {
+            return;
+        };
+
+        let tail = Duration::from_millis(self.profile.push_to_talk.release_tail);
+        if released.elapsed() < tail {
+            return;
+        }
+
+        self.push_to_talk.released = None;
+        if let Err(error) = self.apply_push_to_talk_gate().await {
+            warn!("Unable to close Push to Talk: {}", error);
+        }
+        self.send_device_update().await;
+    }
+}
+
+pub(crate) trait PushToTalkCrate {
+    fn is_push_to_talk_button(&self, button: Buttons) -> bool;
+    fn is_push_to_talk_gated(&self, input: InputChannels, output: OutputChannels) -> bool;
+
+    /// Called during a profile load, after the routing table has been rebuilt
+    async fn load_push_to_talk(&mut self) -> Result<()>;
+}
+
+impl PushToTalkCrate for GoXLR {
+    fn is_push_to_talk_button(&self, button: Buttons) -> bool {
+        let config = &self.profile.push_to_talk;
+        config.enabled && config.button == Some(button)
+    }
+
+    fn is_push_to_talk_gated(&self, input: InputChannels, output: OutputChannels) -> bool {
+        input == InputChannels::Microphone && self.push_to_talk.gated[output]
+    }
+
+    async fn load_push_to_talk(&mut self) -> Result<()> {
+        // The routing has been reset from the profile, so nothing is gated anymore..
+        self.push_to_talk.gated = Default::default();
+        self.apply_push_to_talk_gate().await
+    }
+}
+
+trait PushToTalkLocal {
+    async fn apply_push_to_talk_gate(&mut self) -> Result<()>;
+}
+
+impl PushToTalkLocal for GoXLR {
+    async fn apply_push_to_talk_gate(&mut self) -> Result<()> {
+        let enabled = self.profile.push_to_talk.enabled;
+        let outputs = self.profile.push_to_talk.outputs.clone();
+        let open = self.push_to_talk.is_open();
+
+        let mic = InputChannels::Microphone;
+        let mute_state = self.profile.channels.configs[FaderChannels::Microphone].mute_state;
+        let muted = mute_state != MuteState::Unmuted;
+
+        let mut changed = false;
+        for output in OutputChannels::iter() {
+            let gated = enabled && !open && outputs.contains(&output);
+            if self.push_to_talk.gated[output] == gated {
+                continue;
+            }
+            self.push_to_talk.gated[output] = gated;
+
+            // When opening, only restore routes the profile has, and which aren't muted.
+            let value = match gated {
+                true => RouteValue::Off,
+                false if self.profile.routing[mic][output] && !muted => RouteValue::On,
+                false => continue,
+            };
+            changed |= self.set_route(mic, RoutingOutput::from(output), value)?;
+        }
+
+        if changed {
+            self.apply_routing_for_channel(mic).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use enum_map::EnumMap;
+use log::warn;
+use strum::IntoEnumIterator;
+
+use goxlr_ipc::commands::diagnostics::{
+    RouteClaim, RouteConflict, RouteConflictReport, RouteController,
+};
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::RoutingOutput;
+
+use crate::device::goxlr::device::GoXLR;
+
+// How many different conflicts to keep before dropping the oldest
+const MAX_CONFLICTS: usize = 32;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RouteArbiterState {
+    claims: EnumMap<InputChannels, EnumMap<RoutingOutput, Option<Claim>>>,
+    conflicts: Vec<RouteConflict>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Claim {
+    controller: RouteController,
+
+    // For Manual claims, the feature which was overridden, the claim goes when it lets go
+    overrides: Option<RouteController>,
+}
+
+/// Decides which feature controls a route when more than one wants to change it, so they don't
+/// undo each other's work every tick. The priority order is documented on RouteController, a
+/// feature which is refused leaves the route alone, and the clash is recorded for diagnostics.
+pub(crate) trait RouteArbiterCrate {
+    /// Asks for control of a route, returning whether the controller may change it. Manual
+    /// changes only take a claim when they're overriding another feature.
+    fn claim_route(
+        &mut self,
+        controller: RouteController,
+        input: InputChannels,
+        output: RoutingOutput,
+    ) -> bool;
+
+    /// Releases every route held by a controller, along with any Manual overrides of it
+    fn release_routes(&mut self, controller: RouteController);
+
+    fn get_route_conflicts(&self) -> RouteConflictReport;
+    fn clear_route_conflicts(&mut self);
+}
+
+impl RouteArbiterCrate for GoXLR {
+    fn claim_route(
+        &mut self,
+        controller: RouteController,
+        input: InputChannels,
+        output: RoutingOutput,
+    ) -> bool {
+        let claim = &mut self.route_arbiter.claims[input][output];
+        let Some(holder) = *claim else {
+            // Nothing's in control, so a manual change is just a change
+            if controller != RouteController::Manual {
+                *claim = Some(Claim {
+                    controller,
+                    overrides: None,
+                });
+            }
+            return true;
+        };
+
+        if holder.controller == controller {
+            return true;
+        }
+
+        if controller > holder.controller {
+            let overrides = match controller {
+                RouteController::Manual => Some(holder.controller),
+                _ => None,
+            };
+            *claim = Some(Claim {
+                controller,
+                overrides,
+            });
+            return true;
+        }
+
+        self.record_route_conflict(controller, holder.controller, input, output);
+        false
+    }
+
+    fn release_routes(&mut self, controller: RouteController) {
+        for row in self.route_arbiter.claims.values_mut() {
+            for claim in row.values_mut() {
+                let Some(held) = *claim else {
+                    continue;
+                };
+                if held.controller == controller || held.overrides == Some(controller) {
+                    *claim = None;
+                }
+            }
+        }
+    }
+
+    fn get_route_conflicts(&self) -> RouteConflictReport {
+        let mut claims = vec![];
+        for input in InputChannels::iter() {
+            for output in RoutingOutput::iter() {
+                if let Some(claim) = self.route_arbiter.claims[input][output] {
+                    claims.push(RouteClaim {
+                        input,
+                        output,
+                        controller: claim.controller,
+                    });
+                }
+            }
+        }
+
+        RouteConflictReport {
+            claims,
+            conflicts: self.route_arbiter.conflicts.clone(),
+        }
+    }
+
+    fn clear_route_conflicts(&mut self) {
+        self.route_arbiter.conflicts.clear();
+    }
+}
+
+trait RouteArbiterLocal {
+    fn record_route_conflict(
+        &mut self,
+        refused: RouteController,
+        holder: RouteController,
+        input: InputChannels,
+        output: RoutingOutput,
+    );
+}
+
+impl RouteArbiterLocal for GoXLR {
+    fn record_route_conflict(
+        &mut self,
+        refused: RouteController,
+        holder: RouteController,
+        input: InputChannels,
+        output: RoutingOutput,
+    ) {
+        let last_seen = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        let conflicts = &mut self.route_arbiter.conflicts;
+        let existing = conflicts.iter_mut().find(|conflict| {
+            conflict.input == input
+                && conflict.output == output
+                && conflict.refused == refused
+                && conflict.holder == holder
+        });
+
+        // Only the first of a clash is logged, it'll likely repeat on every step after
+        if let Some(conflict) = existing {
+            conflict.count += 1;
+            conflict.last_seen = last_seen;
+            return;
+        }
+
+        warn!(
+            "{:?} was refused {:?} -> {:?}, it's held by {:?}",
+            refused, input, output, holder
+        );
+        if conflicts.len() >= MAX_CONFLICTS {
+            conflicts.remove(0);
+        }
+        conflicts.push(RouteConflict {
+            input,
+            output,
+            refused,
+            holder,
+            count: 1,
+            last_seen,
+        });
+    }
+}
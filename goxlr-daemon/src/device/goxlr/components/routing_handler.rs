@@ -1,5 +1,6 @@
 use anyhow::{bail, Result};
 use enum_map::EnumMap;
+use goxlr_ipc::commands::diagnostics::{RouteController, RouteState, RoutingStateReport};
 use goxlr_shared::channels::fader::FaderChannels;
 use goxlr_shared::channels::input::InputChannels;
 use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
@@ -9,6 +10,13 @@ use strum::IntoEnumIterator;
 use goxlr_shared::routing::RouteValue;
 use goxlr_usb::events::commands::BasicResultCommand;
 
+use crate::device::goxlr::components::input_trim::InputTrimCrate;
+use crate::device::goxlr::components::monitor_mix::MonitorMixCrate;
+use crate::device::goxlr::components::output_ceiling::OutputCeilingCrate;
+use crate::device::goxlr::components::route_arbiter::RouteArbiterCrate;
+use crate::device::goxlr::components::sampler::SamplerRoutingCrate;
+use crate::device::goxlr::components::solo::SoloCrate;
+use crate::device::goxlr::components::voice_routing::VoiceRoutingCrate;
 use crate::device::goxlr::device::GoXLR;
 
 // These just help keep the function definitions slightly tidier...
@@ -24,12 +32,31 @@ pub(crate) trait RoutingHandler {
     fn disable_route(&mut self, input: In, out: Out) -> Result<bool>;
     fn set_route_value(&mut self, input: In, out: Out, value: u8) -> Result<bool>;
     fn set_route(&mut self, input: In, out: Out, value: Value) -> Result<bool>;
+
+    /// As set_route, for features which change routes while running. If another feature has
+    /// priority over the route, it's left alone and no change is reported.
+    fn set_route_as(
+        &mut self,
+        by: RouteController,
+        input: In,
+        out: Out,
+        value: Value,
+    ) -> Result<bool>;
     fn set_routing_row_from_profile(&mut self, input: In, values: EnumMap<OutputChannels, bool>);
     fn get_routing_input_row(&self, input: In) -> Row;
 
     // Commands for actually sending routing information to the GoXLR..
     async fn apply_routing_for_channel(&self, source: In) -> Result<()>;
 
+    /// Holds back routing changes until commit_routing is called, at which point each changed
+    /// input is sent once with its final routing.
+    fn stage_routing(&self);
+    fn is_routing_staged(&self) -> bool;
+    async fn commit_routing(&self) -> Result<()>;
+
+    /// Reports the routing values currently believed to be on the device, for debugging
+    fn get_routing_report(&self) -> RoutingStateReport;
+
     /// Global method for checking whether a target is valid for routing
     fn is_valid_routing_target(channel: FaderChannels) -> bool;
 }
@@ -52,6 +79,16 @@ impl RoutingHandler for GoXLR {
     }
 
     fn set_route(&mut self, input: In, out: Out, value: Value) -> Result<bool> {
+        self.set_route_as(RouteController::Manual, input, out, value)
+    }
+
+    fn set_route_as(
+        &mut self,
+        by: RouteController,
+        input: In,
+        out: Out,
+        value: Value,
+    ) -> Result<bool> {
         // Just in case someone is bypassing set_route_value..
         if let Value::Value(value) = value {
             if value > 32 {
@@ -64,6 +101,10 @@ impl RoutingHandler for GoXLR {
             return Ok(false);
         }
 
+        if !self.claim_route(by, input, out) {
+            return Ok(false);
+        }
+
         // Set the Routing State to 'On'
         self.routing_state.set_routing(input, out, value);
 
@@ -88,14 +129,83 @@ impl RoutingHandler for GoXLR {
     }
 
     async fn apply_routing_for_channel(&self, source: In) -> Result<()> {
+        if let Ok(mut stage) = self.routing_stage.lock() {
+            if let Some(pending) = stage.as_mut() {
+                debug!("Staging Routing change for {:?}", source);
+                pending[source] = true;
+                return Ok(());
+            }
+        }
+
+        // The routing state is what the broadcast mix hears, the Headphones may differ..
         let routes = self.get_routing_input_row(source);
+        let routes = self.apply_voice_routing(source, routes);
+        let routes = self.apply_monitor_mix(source, routes);
+        let routes = self.apply_solo(source, routes);
+        let routes = self.apply_sample_outputs(source, routes);
+        let routes = self.apply_input_trim(source, routes);
+        let routes = self.apply_output_ceiling(routes);
 
         debug!("Routing {:?} to {:?}", source, routes);
 
-        let command = BasicResultCommand::ApplyRouting(source, routes);
+        let stereo = self.profile.channels.stereo[source];
+        let command = BasicResultCommand::ApplyRouting(source, routes, stereo);
         self.send_no_result(command).await
     }
 
+    fn stage_routing(&self) {
+        if let Ok(mut stage) = self.routing_stage.lock() {
+            stage.get_or_insert_with(Default::default);
+        }
+    }
+
+    fn is_routing_staged(&self) -> bool {
+        self.routing_stage
+            .lock()
+            .map(|stage| stage.is_some())
+            .unwrap_or(false)
+    }
+
+    async fn commit_routing(&self) -> Result<()> {
+        let pending = match self.routing_stage.lock() {
+            Ok(mut stage) => stage.take(),
+            Err(_) => None,
+        };
+
+        if let Some(pending) = pending {
+            for (input, changed) in pending {
+                if changed {
+                    self.apply_routing_for_channel(input).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get_routing_report(&self) -> RoutingStateReport {
+        let mut routes = vec![];
+        for input in InputChannels::iter() {
+            for output in RoutingOutput::iter() {
+                // HardTune isn't part of the profile, it's managed by the effects..
+                let profile = OutputChannels::iter()
+                    .find(|&channel| RoutingOutput::from(channel) == output)
+                    .map(|channel| self.profile.routing[input][channel]);
+
+                routes.push(RouteState {
+                    input,
+                    output,
+                    value: self.routing_state.get_routing(input, output),
+                    profile,
+                });
+            }
+        }
+
+        RoutingStateReport {
+            routes,
+            staged: self.is_routing_staged(),
+        }
+    }
+
     /// Headphone, LineOut and MicrophoneMonitor *ARE* valid mute targets, but they're
     /// not valid routing targets. This helper method allows code to check.
     fn is_valid_routing_target(channel: FaderChannels) -> bool {
@@ -0,0 +1,60 @@
+use anyhow::{bail, Result};
+use log::debug;
+
+use goxlr_profile::RoutingRule;
+
+use crate::device::goxlr::device::GoXLR;
+
+/// Checks the profile's routing against a list of rules, so common mistakes (such as the
+/// Microphone not going to the Stream) can be pointed out. Broken rules are reported in the
+/// runtime status as warnings, nothing is changed or refused because of them.
+pub(crate) trait RoutingRules {
+    fn add_routing_rule(&mut self, rule: RoutingRule) -> Result<()>;
+    fn remove_routing_rule(&mut self, name: String) -> Result<()>;
+}
+
+impl RoutingRules for GoXLR {
+    fn add_routing_rule(&mut self, rule: RoutingRule) -> Result<()> {
+        if rule.name.is_empty() {
+            bail!("Routing Rules must have a name");
+        }
+
+        debug!("Adding Routing Rule: {}", rule.name);
+        let rules = &mut self.profile.routing_rules.rules;
+        rules.retain(|existing| existing.name != rule.name);
+        rules.push(rule);
+        Ok(())
+    }
+
+    fn remove_routing_rule(&mut self, name: String) -> Result<()> {
+        let rules = &mut self.profile.routing_rules.rules;
+        let count = rules.len();
+        rules.retain(|rule| rule.name != name);
+
+        if rules.len() == count {
+            bail!("Routing Rule {} not found", name);
+        }
+        Ok(())
+    }
+}
+
+pub(crate) trait RoutingRulesCrate {
+    fn get_routing_warnings(&self) -> Vec<String>;
+}
+
+impl RoutingRulesCrate for GoXLR {
+    fn get_routing_warnings(&self) -> Vec<String> {
+        // Rules are checked against the profile, mutes and Push to Talk are intentional
+        let active_mode = self.profile.mix_modes.active.as_ref();
+        let routing = &self.profile.routing;
+
+        let applies = |rule: &&RoutingRule| match &rule.mix_mode {
+            Some(mode) => active_mode == Some(mode),
+            None => true,
+        };
+
+        let rules = self.profile.routing_rules.rules.iter().filter(applies);
+        let broken = rules.filter(|rule| routing[rule.input][rule.output] != rule.routed);
+        broken.map(|rule| rule.name.clone()).collect()
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use log::{info, warn};
+use strum::IntoEnumIterator;
+
+use goxlr_profile::Profile;
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
+use goxlr_shared::colours::Colour;
+
+use crate::device::goxlr::components::adapt_profile::AdaptProfile;
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::device::GoXLR;
+
+/// If a profile fails to load part way through, the device can be left in a half configured
+/// state. Safe Mode replaces the profile with a minimal known-good configuration and loads that
+/// instead, flagging the load report so clients know the device is running degraded.
+pub(crate) trait SafeMode {
+    async fn load_safe_mode(&mut self, reason: String) -> Result<()>;
+}
+
+impl SafeMode for GoXLR {
+    async fn load_safe_mode(&mut self, reason: String) -> Result<()> {
+        warn!("Profile failed to load ({}), entering Safe Mode", reason);
+        self.profile = self.build_safe_profile();
+
+        self.adapt_profile()?;
+        self.load_profile().await?;
+
+        // adapt_profile resets the report, so this needs to be set once the load is done.
+        self.load_report.safe_mode = Some(reason);
+
+        info!("Safe Mode profile loaded");
+        Ok(())
+    }
+}
+
+trait SafeModeLocal {
+    fn build_safe_profile(&self) -> Profile;
+}
+
+impl SafeModeLocal for GoXLR {
+    fn build_safe_profile(&self) -> Profile {
+        // Start from the default profile, which provides the default routing table..
+        let mut profile = Profile::default();
+
+        // Everything at unity..
+        for channel in VolumeChannels::iter() {
+            profile.channels.volumes[channel] = 255;
+        }
+
+        // Turn off anything which might alter the volumes behind the user's back..
+        profile.configuration.submix_enabled = false;
+        profile.ducking.enabled = false;
+
+        // And use a plain white for all the lighting.
+        let white = Colour {
+            red: 255,
+            green: 255,
+            blue: 255,
+        };
+
+        for channel in FaderChannels::iter() {
+            let display = &mut profile.channels.configs[channel].display;
+            display.fader_colours.top_colour = Colour::black();
+            display.fader_colours.bottom_colour = white;
+            display.mute_colours.active_colour = white;
+            display.mute_colours.inactive_colour = Colour::black();
+            display.screen_display.colour = white;
+        }
+
+        profile.swear.colours.active_colour = white;
+        profile.cough.colours.active_colour = white;
+
+        profile
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, FromSample, SampleFormat, SizedSample, Stream, StreamConfig};
+use hound::WavReader;
+use log::{debug, info, warn};
+use tokio::sync::oneshot;
+
+use goxlr_ipc::status::SampleQueueStatus;
+use goxlr_shared::device::DeviceType;
+use goxlr_shared::sampler::{SampleBank, SampleButton};
+
+use crate::device::goxlr::components::sampler::SamplerRouting;
+use crate::device::goxlr::components::session_stats::SessionStatisticsCrate;
+use crate::device::goxlr::device::GoXLR;
+
+// How often the playback thread checks whether the sample has finished
+const FINISH_POLL: Duration = Duration::from_millis(50);
+
+#[derive(Default)]
+pub(crate) struct SamplePlayer {
+    // Dropping this stops the sample
+    stop: Option<mpsc::Sender<()>>,
+    finished: Arc<AtomicBool>,
+
+    // The file currently playing, and those to play after it
+    playing: Option<PathBuf>,
+    queue: VecDeque<PathBuf>,
+}
+
+/// Where a sample is played to. Both are OS output devices, the difference being whether the
+/// audio passes through the GoXLR's Sample channel (and so follows its routing), or goes
+/// straight to an output for devices which don't have one.
+#[derive(Debug, Clone)]
+enum SamplerBackend {
+    /// The OS device for the GoXLR's Sample channel, None to find it by name
+    Channel(Option<String>),
+
+    /// Any OS output device, None for the system default
+    Fallback(Option<String>),
+}
+
+/// Plays the samples assigned in the profile, so a soundboard only needs to tell the daemon
+/// which button was pressed. The GoXLR Mini has no Sample channel, so its samples are played
+/// through a configurable output instead, the rest of the Sampler behaves the same either way.
+///
+/// A button can also have a playlist (a stinger followed by a music bed, for example), which is
+/// queued behind its sample. The whole queue is heard on the outputs of the button which
+/// started it.
+pub(crate) trait SamplePlayback {
+    fn set_sample_file(&mut self, bank: SampleBank, button: SampleButton, file: Option<PathBuf>);
+    fn set_sample_playlist(&mut self, bank: SampleBank, button: SampleButton, files: Vec<PathBuf>);
+    async fn play_sample(&mut self, bank: SampleBank, button: SampleButton) -> Result<()>;
+    async fn stop_sample(&mut self) -> Result<()>;
+
+    fn enqueue_sample(&mut self, file: PathBuf) -> Result<()>;
+    fn dequeue_sample(&mut self, index: usize) -> Result<()>;
+    async fn skip_sample(&mut self) -> Result<()>;
+}
+
+impl SamplePlayback for GoXLR {
+    fn set_sample_file(&mut self, bank: SampleBank, button: SampleButton, file: Option<PathBuf>) {
+        debug!("Setting Sample {:?} {:?} to {:?}", bank, button, file);
+        self.profile.sampler.samples[bank][button] = file;
+    }
+
+    fn set_sample_playlist(&mut self, bank: SampleBank, button: SampleButton, files: Vec<PathBuf>) {
+        debug!(
+            "Setting Sample {:?} {:?} Playlist to {:?}",
+            bank, button, files
+        );
+        self.profile.sampler.playlists[bank][button] = files;
+    }
+
+    async fn play_sample(&mut self, bank: SampleBank, button: SampleButton) -> Result<()> {
+        let file = self.profile.sampler.samples[bank][button].clone();
+        let file =
+            file.with_context(|| format!("No Sample assigned to {:?} {:?}", bank, button))?;
+
+        self.stop_sample().await?;
+        self.start_playback(file).await?;
+
+        let playlist = &self.profile.sampler.playlists[bank][button];
+        self.sample_player.queue = playlist.iter().cloned().collect();
+        self.record_sample_trigger();
+        self.set_playing_sample(Some((bank, button))).await
+    }
+
+    async fn stop_sample(&mut self) -> Result<()> {
+        self.sample_player.queue.clear();
+        if let Some(stop) = self.sample_player.stop.take() {
+            let _ = stop.send(());
+            self.sample_player.playing = None;
+            self.set_playing_sample(None).await?;
+        }
+        Ok(())
+    }
+
+    fn enqueue_sample(&mut self, file: PathBuf) -> Result<()> {
+        if self.sample_player.playing.is_none() {
+            bail!("No Sample is playing, use Play to start a queue");
+        }
+
+        debug!("Queueing Sample {:?}", file);
+        self.sample_player.queue.push_back(file);
+        Ok(())
+    }
+
+    fn dequeue_sample(&mut self, index: usize) -> Result<()> {
+        let file = self.sample_player.queue.remove(index);
+        let file = file.with_context(|| format!("No Sample queued at {}", index))?;
+        debug!("Removed {:?} from the Sample Queue", file);
+        Ok(())
+    }
+
+    async fn skip_sample(&mut self) -> Result<()> {
+        if self.sample_player.playing.is_none() {
+            bail!("No Sample is playing");
+        }
+        self.play_next_sample().await
+    }
+}
+
+pub(crate) trait SamplePlaybackCrate {
+    fn get_sample_queue(&self) -> Option<SampleQueueStatus>;
+
+    /// Called on every tick, moves to the next queued sample (or releases the Sampler) once a
+    /// sample has played to the end
+    async fn handle_sample_playback(&mut self);
+}
+
+impl SamplePlaybackCrate for GoXLR {
+    fn get_sample_queue(&self) -> Option<SampleQueueStatus> {
+        let playing = self.sample_player.playing.clone()?;
+        let (bank, button) = self.sample_playing?;
+
+        Some(SampleQueueStatus {
+            bank,
+            button,
+            playing,
+            queued: self.sample_player.queue.iter().cloned().collect(),
+        })
+    }
+
+    async fn handle_sample_playback(&mut self) {
+        if self.sample_player.stop.is_none() {
+            return;
+        }
+
+        if self.sample_player.finished.load(Ordering::Relaxed) {
+            if let Err(error) = self.play_next_sample().await {
+                warn!("Unable to restore Sample Routing: {}", error);
+            }
+            self.send_device_update().await;
+        }
+    }
+}
+
+trait SamplePlaybackLocal {
+    fn get_sampler_backend(&self) -> Result<SamplerBackend>;
+    async fn start_playback(&mut self, file: PathBuf) -> Result<()>;
+
+    /// Stops the current file, and starts the next in the queue which can be played, the
+    /// Sampler is released if there isn't one
+    async fn play_next_sample(&mut self) -> Result<()>;
+}
+
+impl SamplePlaybackLocal for GoXLR {
+    fn get_sampler_backend(&self) -> Result<SamplerBackend> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        let settings = &self.config.settings;
+
+        Ok(match device.device_type {
+            DeviceType::Mini => SamplerBackend::Fallback(settings.sample_fallback_device.clone()),
+            _ => SamplerBackend::Channel(settings.sample_device.clone()),
+        })
+    }
+
+    async fn start_playback(&mut self, file: PathBuf) -> Result<()> {
+        let backend = self.get_sampler_backend()?;
+        info!("Playing {:?} through {:?}", file, backend);
+
+        let finished = Arc::new(AtomicBool::new(false));
+        let (stop_tx, stop_rx) = mpsc::channel();
+        let (ready_tx, ready_rx) = oneshot::channel();
+        let playback_finished = finished.clone();
+        let playback_file = file.clone();
+        thread::spawn(move || {
+            // cpal streams can't be moved between threads on all platforms, so the stream
+            // needs to live here until we're done.
+            let stream = match build_stream(&playback_file, &backend, playback_finished.clone()) {
+                Ok(stream) => stream,
+                Err(error) => {
+                    let _ = ready_tx.send(Err(error));
+                    return;
+                }
+            };
+            let _ = ready_tx.send(Ok(()));
+
+            // Play until we're told to stop, or the sample runs out
+            loop {
+                match stop_rx.recv_timeout(FINISH_POLL) {
+                    Err(RecvTimeoutError::Timeout) => {
+                        if playback_finished.load(Ordering::Relaxed) {
+                            break;
+                        }
+                    }
+                    _ => break,
+                }
+            }
+            debug!("Sample Stopped");
+            drop(stream);
+        });
+
+        ready_rx.await??;
+        self.sample_player.stop = Some(stop_tx);
+        self.sample_player.finished = finished;
+        self.sample_player.playing = Some(file);
+        Ok(())
+    }
+
+    async fn play_next_sample(&mut self) -> Result<()> {
+        if let Some(stop) = self.sample_player.stop.take() {
+            let _ = stop.send(());
+        }
+
+        while let Some(file) = self.sample_player.queue.pop_front() {
+            match self.start_playback(file).await {
+                Ok(()) => return Ok(()),
+                Err(error) => warn!("Unable to play queued Sample: {}", error),
+            }
+        }
+
+        self.sample_player.playing = None;
+        self.set_playing_sample(None).await
+    }
+}
+
+fn find_device(backend: &SamplerBackend) -> Result<Device> {
+    let host = cpal::default_host();
+    let by_name = |name: &str| -> Result<Device> {
+        let mut devices = host.output_devices()?;
+        devices
+            .find(|device| device.name().map(|n| n == name).unwrap_or(false))
+            .with_context(|| format!("Unable to find Playback Device: {}", name))
+    };
+
+    match backend {
+        SamplerBackend::Channel(Some(name)) | SamplerBackend::Fallback(Some(name)) => by_name(name),
+        SamplerBackend::Channel(None) => {
+            // The name varies between platforms, but always mentions both of these
+            let mut devices = host.output_devices()?;
+            devices
+                .find(|device| {
+                    let name = device.name().unwrap_or_default();
+                    name.contains("GoXLR") && name.contains("Sample")
+                })
+                .context("Unable to find the GoXLR Sample Playback Device")
+        }
+        SamplerBackend::Fallback(None) => host
+            .default_output_device()
+            .context("No Default Playback Device"),
+    }
+}
+
+fn build_stream(
+    file: &Path,
+    backend: &SamplerBackend,
+    finished: Arc<AtomicBool>,
+) -> Result<Stream> {
+    let device = find_device(backend)?;
+    let output_config = device.default_output_config()?;
+    let config = output_config.config();
+    let source = SampleSource::open(file, config.sample_rate.0, finished)?;
+
+    let stream = match output_config.sample_format() {
+        SampleFormat::F32 => build_playback::<f32>(&device, &config, source),
+        SampleFormat::I16 => build_playback::<i16>(&device, &config, source),
+        SampleFormat::I32 => build_playback::<i32>(&device, &config, source),
+        SampleFormat::U16 => build_playback::<u16>(&device, &config, source),
+        format => bail!("Unsupported Playback Sample Format: {:?}", format),
+    }?;
+    stream.play()?;
+    Ok(stream)
+}
+
+fn build_playback<T>(
+    device: &Device,
+    config: &StreamConfig,
+    mut source: SampleSource,
+) -> Result<Stream>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels as usize;
+    let stream = device.build_output_stream(
+        config,
+        move |data: &mut [T], _| {
+            for frame in data.chunks_mut(channels) {
+                source.next_frame(frame);
+            }
+        },
+        |error| debug!("Sample Stream Error: {}", error),
+        None,
+    )?;
+    Ok(stream)
+}
+
+/// A decoded WAV file, resampled (linearly) to the output rate as it's played
+pub(crate) struct SampleSource {
+    samples: Vec<f32>,
+    channels: usize,
+    frames: usize,
+
+    position: f64,
+    step: f64,
+    finished: Arc<AtomicBool>,
+}
+
+impl SampleSource {
+    pub(crate) fn open(file: &Path, output_rate: u32, finished: Arc<AtomicBool>) -> Result<Self> {
+        let mut reader = WavReader::open(file)
+            .with_context(|| format!("Unable to open Sample: {}", file.display()))?;
+        let spec = reader.spec();
+
+        let samples = match spec.sample_format {
+            hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<Vec<_>, _>>()?,
+            hound::SampleFormat::Int => {
+                let scale = (1_i64 << (spec.bits_per_sample - 1)) as f32;
+                reader
+                    .samples::<i32>()
+                    .map(|sample| sample.map(|sample| sample as f32 / scale))
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+        };
+
+        let channels = spec.channels as usize;
+        if channels == 0 || samples.is_empty() {
+            bail!("Sample {} has no audio", file.display());
+        }
+
+        Ok(Self {
+            frames: samples.len() / channels,
+            samples,
+            channels,
+            position: 0.,
+            step: spec.sample_rate as f64 / output_rate as f64,
+            finished,
+        })
+    }
+
+    pub(crate) fn next_frame<T>(&mut self, frame: &mut [T])
+    where
+        T: SizedSample + FromSample<f32>,
+    {
+        let index = self.position as usize;
+        if index + 1 >= self.frames {
+            self.finished.store(true, Ordering::Relaxed);
+            frame.fill(T::EQUILIBRIUM);
+            return;
+        }
+
+        let fraction = (self.position - index as f64) as f32;
+        for (channel, sample) in frame.iter_mut().enumerate() {
+            // Mono samples are sent to every channel, extra channels reuse the file's channels
+            let channel = channel % self.channels;
+            let current = self.samples[index * self.channels + channel];
+            let next = self.samples[(index + 1) * self.channels + channel];
+            *sample = T::from_sample(current + (next - current) * fraction);
+        }
+        self.position += self.step;
+    }
+}
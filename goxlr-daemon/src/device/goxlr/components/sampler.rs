@@ -0,0 +1,133 @@
+use anyhow::{bail, Result};
+use enum_map::EnumMap;
+use log::debug;
+
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
+use goxlr_shared::routing::RouteValue;
+use goxlr_shared::sampler::{SampleBank, SampleButton};
+
+use crate::device::goxlr::components::ducker::AudioDuckerTrait;
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+type Row = EnumMap<RoutingOutput, RouteValue>;
+
+/// Samples are played by a client rather than the daemon, so it tells us when one starts and
+/// stops. While a sample with outputs configured is playing, the Sample channel is only routed
+/// to those outputs. Like Solo, this is only applied as the routing is sent, so the routing
+/// table is left alone, and is put back as soon as the sample stops.
+pub(crate) trait SamplerRouting {
+    async fn set_sample_bank_outputs(
+        &mut self,
+        bank: SampleBank,
+        outputs: Option<Vec<OutputChannels>>,
+    ) -> Result<()>;
+    async fn set_sample_button_outputs(
+        &mut self,
+        bank: SampleBank,
+        button: SampleButton,
+        outputs: Option<Vec<OutputChannels>>,
+    ) -> Result<()>;
+    async fn set_playing_sample(
+        &mut self,
+        sample: Option<(SampleBank, SampleButton)>,
+    ) -> Result<()>;
+}
+
+impl SamplerRouting for GoXLR {
+    async fn set_sample_bank_outputs(
+        &mut self,
+        bank: SampleBank,
+        outputs: Option<Vec<OutputChannels>>,
+    ) -> Result<()> {
+        validate_outputs(&outputs)?;
+
+        debug!("Setting Sample Bank {:?} Outputs to {:?}", bank, outputs);
+        self.profile.sampler.bank_outputs[bank] = outputs;
+        self.apply_routing_for_channel(InputChannels::Sample).await
+    }
+
+    async fn set_sample_button_outputs(
+        &mut self,
+        bank: SampleBank,
+        button: SampleButton,
+        outputs: Option<Vec<OutputChannels>>,
+    ) -> Result<()> {
+        validate_outputs(&outputs)?;
+
+        debug!(
+            "Setting Sample {:?} {:?} Outputs to {:?}",
+            bank, button, outputs
+        );
+        self.profile.sampler.button_outputs[bank][button] = outputs;
+        self.apply_routing_for_channel(InputChannels::Sample).await
+    }
+
+    async fn set_playing_sample(
+        &mut self,
+        sample: Option<(SampleBank, SampleButton)>,
+    ) -> Result<()> {
+        if self.sample_playing == sample {
+            return Ok(());
+        }
+
+        debug!("Sample Playing: {:?}", sample);
+        self.sample_playing = sample;
+
+        // A sample playing is also a Ducking trigger, so there's only one thing to tell us
+        self.set_sample_playing(sample.is_some());
+        self.apply_routing_for_channel(InputChannels::Sample).await
+    }
+}
+
+pub(crate) trait SamplerRoutingCrate {
+    /// Replaces the Sample channel's routes in a row about to be sent, while a sample plays
+    fn apply_sample_outputs(&self, input: InputChannels, row: Row) -> Row;
+}
+
+impl SamplerRoutingCrate for GoXLR {
+    fn apply_sample_outputs(&self, input: InputChannels, mut row: Row) -> Row {
+        if input != InputChannels::Sample {
+            return row;
+        }
+
+        let Some((bank, button)) = self.sample_playing else {
+            return row;
+        };
+
+        let sampler = &self.profile.sampler;
+        let outputs = sampler.button_outputs[bank][button].as_ref();
+        let Some(outputs) = outputs.or(sampler.bank_outputs[bank].as_ref()) else {
+            return row;
+        };
+
+        for (output, value) in row.iter_mut() {
+            if output == RoutingOutput::HardTune {
+                continue;
+            }
+
+            let enabled = outputs
+                .iter()
+                .any(|&target| RoutingOutput::from(target) == output);
+            *value = match (enabled, *value) {
+                (false, _) => RouteValue::Off,
+
+                // Keep anything which has lowered the route (such as the ducker)
+                (true, RouteValue::Value(value)) => RouteValue::Value(value),
+                (true, _) => RouteValue::On,
+            };
+        }
+        row
+    }
+}
+
+fn validate_outputs(outputs: &Option<Vec<OutputChannels>>) -> Result<()> {
+    if let Some(outputs) = outputs {
+        // Samples can't be routed back into the Sampler, as that's what's recording them
+        if outputs.contains(&OutputChannels::Sampler) {
+            bail!("Samples can't be routed to the Sampler");
+        }
+    }
+    Ok(())
+}
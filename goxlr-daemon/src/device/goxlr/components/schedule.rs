@@ -0,0 +1,99 @@
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+use log::{info, warn};
+
+use crate::device::goxlr::components::mic::gate::GateCrate;
+use crate::device::goxlr::device::GoXLR;
+use crate::settings::ScheduledOverride;
+
+// The windows are set in minutes, so there's no need to look at the clock every tick. An override
+// can start or end up to this long after its time.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default)]
+pub(crate) struct ScheduleState {
+    // The position (in the settings) of the override in use, if any
+    active: Option<usize>,
+    last_check: Option<Instant>,
+}
+
+/// Applies the time of day overrides from the daemon settings. The profile is never changed, the
+/// overridden values are used in its place while an override is active, so ending one (or saving
+/// the profile during one) can't lose the user's own settings.
+pub(crate) trait ScheduleCrate {
+    /// Called on every tick, starts or ends overrides as the time passes their windows
+    async fn handle_schedule(&mut self);
+
+    fn get_schedule_override(&self) -> Option<&ScheduledOverride>;
+
+    // The values to use, either the profile's or the active override's
+    fn get_gate_threshold(&self) -> i8;
+    fn get_ducking_trigger_db(&self) -> f64;
+    fn get_mic_trigger_offset(&self) -> i8;
+    fn get_ducking_release_time(&self) -> u64;
+}
+
+impl ScheduleCrate for GoXLR {
+    async fn handle_schedule(&mut self) {
+        if self.config.settings.schedule.is_empty() {
+            return;
+        }
+
+        let checked = self.schedule.last_check.map(|time| time.elapsed());
+        if checked.is_some_and(|elapsed| elapsed < CHECK_INTERVAL) {
+            return;
+        }
+        self.schedule.last_check = Some(Instant::now());
+
+        let now = Local::now().time();
+        let schedule = &self.config.settings.schedule;
+        let active = schedule.iter().position(|entry| entry.is_active(now));
+        if active == self.schedule.active {
+            return;
+        }
+
+        if let Some(entry) = self.get_schedule_override() {
+            info!("Scheduled Override {} - {} Ended", entry.start, entry.end);
+        }
+        self.schedule.active = active;
+        if let Some(entry) = self.get_schedule_override() {
+            info!("Scheduled Override {} - {} Started", entry.start, entry.end);
+        }
+
+        // The Ducking values are read as they're needed, but the gate lives on the device
+        let threshold = self.get_gate_threshold();
+        if let Err(error) = self.send_gate_threshold(threshold).await {
+            warn!("Unable to apply Scheduled Gate Threshold: {}", error);
+        }
+    }
+
+    fn get_schedule_override(&self) -> Option<&ScheduledOverride> {
+        let index = self.schedule.active?;
+        self.config.settings.schedule.get(index)
+    }
+
+    fn get_gate_threshold(&self) -> i8 {
+        let scheduled = self.get_schedule_override();
+        let threshold = scheduled.and_then(|entry| entry.gate_threshold);
+        threshold.unwrap_or(self.mic_profile.gate.threshold)
+    }
+
+    fn get_ducking_trigger_db(&self) -> f64 {
+        let scheduled = self.get_schedule_override();
+        let level = scheduled.and_then(|entry| entry.duck_trigger_db);
+        level.unwrap_or(self.profile.ducking.trigger_db)
+    }
+
+    fn get_mic_trigger_offset(&self) -> i8 {
+        let scheduled = self.get_schedule_override();
+        let offset = scheduled.and_then(|entry| entry.duck_mic_offset);
+        offset.unwrap_or(self.profile.ducking.mic_trigger_offset)
+    }
+
+    fn get_ducking_release_time(&self) -> u64 {
+        let scheduled = self.get_schedule_override();
+        let release = scheduled.and_then(|entry| entry.duck_release);
+        release.unwrap_or(self.profile.ducking.release_time)
+    }
+}
@@ -0,0 +1,111 @@
+use std::time::Instant;
+
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use enum_map::EnumMap;
+use goxlr_scribbles::get_scribble;
+use log::{debug, info};
+use strum::IntoEnumIterator;
+
+use goxlr_shared::device::DeviceType;
+use goxlr_shared::faders::Fader;
+use goxlr_shared::scribbles::ScribbleWidget;
+use goxlr_usb::events::commands::BasicResultCommand;
+
+use crate::device::goxlr::components::fader::DeviceFader;
+use crate::device::goxlr::device::GoXLR;
+
+#[derive(Default)]
+pub(crate) struct ScribbleWidgets {
+    active: EnumMap<Fader, Option<ActiveWidget>>,
+}
+
+struct ActiveWidget {
+    widget: ScribbleWidget,
+    started: Instant,
+
+    // What was last drawn, so the display is only sent an update when the text changes
+    drawn: Option<String>,
+}
+
+impl ActiveWidget {
+    fn get_text(&self) -> String {
+        match self.widget {
+            ScribbleWidget::Clock => Local::now().format("%H:%M").to_string(),
+            ScribbleWidget::Timer => {
+                let elapsed = self.started.elapsed().as_secs();
+                let (hours, minutes, seconds) = (elapsed / 3600, elapsed / 60 % 60, elapsed % 60);
+                format!("{}:{:02}:{:02}", hours, minutes, seconds)
+            }
+        }
+    }
+}
+
+/// Widgets replace the channel name on a fader's scribble with something the daemon keeps up
+/// to date, such as a clock, or a timer started when a stream goes live. The fader itself
+/// keeps working as normal, and the channel name returns when the widget is cleared.
+pub(crate) trait ScribbleWidgetHandler {
+    fn show_scribble_widget(&mut self, fader: Fader, widget: ScribbleWidget) -> Result<()>;
+    async fn clear_scribble_widget(&mut self, fader: Fader) -> Result<()>;
+}
+
+impl ScribbleWidgetHandler for GoXLR {
+    fn show_scribble_widget(&mut self, fader: Fader, widget: ScribbleWidget) -> Result<()> {
+        let device = self.device.as_ref().context("Device Not Found!")?;
+        if device.device_type == DeviceType::Mini {
+            bail!("The GoXLR Mini doesn't have Scribble displays");
+        }
+
+        info!("Showing {:?} Widget on Fader {:?}", widget, fader);
+        self.scribble_widgets.active[fader] = Some(ActiveWidget {
+            widget,
+            started: Instant::now(),
+            drawn: None,
+        });
+        Ok(())
+    }
+
+    async fn clear_scribble_widget(&mut self, fader: Fader) -> Result<()> {
+        if self.scribble_widgets.active[fader].take().is_none() {
+            return Ok(());
+        }
+
+        info!("Removing Widget from Fader {:?}", fader);
+        self.refresh_scribble(fader).await
+    }
+}
+
+pub(crate) trait ScribbleWidgetsCrate {
+    fn has_scribble_widget(&self, fader: Fader) -> bool;
+    async fn handle_scribble_widgets(&mut self);
+}
+
+impl ScribbleWidgetsCrate for GoXLR {
+    fn has_scribble_widget(&self, fader: Fader) -> bool {
+        self.scribble_widgets.active[fader].is_some()
+    }
+
+    async fn handle_scribble_widgets(&mut self) {
+        for fader in Fader::iter() {
+            let Some(active) = &self.scribble_widgets.active[fader] else {
+                continue;
+            };
+
+            let text = active.get_text();
+            if active.drawn.as_ref() == Some(&text) {
+                continue;
+            }
+
+            let scribble = get_scribble(None, Some(text.clone()), None, false);
+            let command = BasicResultCommand::SetScribble(fader, scribble);
+            if let Err(error) = self.send_no_result(command).await {
+                debug!("Unable to draw Widget on Fader {:?}: {}", fader, error);
+                continue;
+            }
+
+            if let Some(active) = &mut self.scribble_widgets.active[fader] {
+                active.drawn = Some(text);
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::time::{Duration, Instant};
+
+use log::debug;
+
+use goxlr_ipc::commands::diagnostics::SessionStatsReport;
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::mute::MuteState;
+
+use crate::device::goxlr::components::hard_mute::HardMuteCrate;
+use crate::device::goxlr::device::GoXLR;
+
+/// Counters covering everything since the daemon started (or the stats were last reset), for
+/// looking back over a stream, or showing on an overlay.
+#[derive(Clone)]
+pub(crate) struct SessionStats {
+    since: Instant,
+    mic_live: Duration,
+    mutes: u32,
+    sample_triggers: u32,
+    page_switches: u32,
+
+    // When the last tick happened, and whether the mic was live for it
+    last_tick: Option<(Instant, bool)>,
+}
+
+impl Default for SessionStats {
+    fn default() -> Self {
+        Self {
+            since: Instant::now(),
+            mic_live: Duration::ZERO,
+            mutes: 0,
+            sample_triggers: 0,
+            page_switches: 0,
+            last_tick: None,
+        }
+    }
+}
+
+pub(crate) trait SessionStatistics {
+    fn get_session_stats(&self) -> SessionStatsReport;
+    fn reset_session_stats(&mut self);
+}
+
+impl SessionStatistics for GoXLR {
+    fn get_session_stats(&self) -> SessionStatsReport {
+        let stats = &self.session_stats;
+
+        // Include the time since the last tick, if the mic is still live..
+        let current = match stats.last_tick {
+            Some((at, true)) => at.elapsed(),
+            _ => Duration::ZERO,
+        };
+
+        SessionStatsReport {
+            tracked_ms: stats.since.elapsed().as_millis() as u64,
+            mic_live_ms: (stats.mic_live + current).as_millis() as u64,
+            mutes: stats.mutes,
+            sample_triggers: stats.sample_triggers,
+            page_switches: stats.page_switches,
+        }
+    }
+
+    fn reset_session_stats(&mut self) {
+        debug!("Resetting Session Statistics");
+        let live = self.is_mic_live();
+        self.session_stats = SessionStats {
+            last_tick: Some((Instant::now(), live)),
+            ..Default::default()
+        };
+    }
+}
+
+pub(crate) trait SessionStatisticsCrate {
+    fn record_mute(&mut self, previous: MuteState, state: MuteState);
+    fn record_sample_trigger(&mut self);
+    fn record_page_switch(&mut self);
+
+    fn handle_session_stats(&mut self);
+}
+
+impl SessionStatisticsCrate for GoXLR {
+    fn record_mute(&mut self, previous: MuteState, state: MuteState) {
+        // Moving between Pressed and Held is still the same mute
+        if previous == MuteState::Unmuted && state != MuteState::Unmuted {
+            self.session_stats.mutes += 1;
+        }
+    }
+
+    fn record_sample_trigger(&mut self) {
+        self.session_stats.sample_triggers += 1;
+    }
+
+    fn record_page_switch(&mut self) {
+        self.session_stats.page_switches += 1;
+    }
+
+    fn handle_session_stats(&mut self) {
+        let now = Instant::now();
+        let live = self.is_mic_live();
+
+        if let Some((at, true)) = self.session_stats.last_tick {
+            self.session_stats.mic_live += now.duration_since(at);
+        }
+        self.session_stats.last_tick = Some((now, live));
+    }
+}
+
+trait SessionStatisticsLocal {
+    fn is_mic_live(&self) -> bool;
+}
+
+impl SessionStatisticsLocal for GoXLR {
+    /// The mic is live when it's unmuted (and not Hard Muted), not being coughed, and Push to
+    /// Talk (if enabled) is letting it through.
+    fn is_mic_live(&self) -> bool {
+        if self.is_hard_muted() {
+            return false;
+        }
+
+        let mic = FaderChannels::Microphone;
+        if self.profile.channels.configs[mic].mute_state != MuteState::Unmuted {
+            return false;
+        }
+
+        let cough = &self.profile.cough;
+        if cough.channel_assignment == mic && cough.mute_state != MuteState::Unmuted {
+            return false;
+        }
+
+        !self.profile.push_to_talk.enabled || self.push_to_talk.is_open()
+    }
+}
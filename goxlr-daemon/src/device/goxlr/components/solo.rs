@@ -0,0 +1,100 @@
+use anyhow::Result;
+use enum_map::EnumMap;
+use log::debug;
+use strum::IntoEnumIterator;
+
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::RoutingOutput;
+use goxlr_shared::routing::RouteValue;
+use goxlr_shared::states::State;
+
+use crate::device::goxlr::components::buttons::ButtonHandlers;
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+type Row = EnumMap<RoutingOutput, RouteValue>;
+
+/// Solo removes every other Input from the Headphones, so a single channel can be checked
+/// without changing what the stream hears. Like the Monitor Mix, this is only applied as the
+/// routing is sent, so releasing the Solo puts back whatever the routing table holds.
+pub(crate) trait ChannelSolo {
+    async fn set_solo(&mut self, input: Option<InputChannels>) -> Result<()>;
+    async fn set_solo_button(&mut self, button: Option<Buttons>) -> Result<()>;
+    fn set_solo_input(&mut self, input: InputChannels);
+}
+
+impl ChannelSolo for GoXLR {
+    async fn set_solo(&mut self, input: Option<InputChannels>) -> Result<()> {
+        if self.solo == input {
+            return Ok(());
+        }
+
+        debug!("Setting Solo to {:?}", input);
+        self.solo = input;
+
+        for input in InputChannels::iter() {
+            self.apply_routing_for_channel(input).await?;
+        }
+
+        self.set_solo_button_state();
+        self.apply_button_states().await
+    }
+
+    async fn set_solo_button(&mut self, button: Option<Buttons>) -> Result<()> {
+        // Let go of the old button's light, before lighting the new one
+        if let Some(previous) = self.profile.solo.button {
+            self.button_states.set_state(previous, State::DimmedColour1);
+        }
+
+        self.profile.solo.button = button;
+        self.set_solo_button_state();
+        self.apply_button_states().await
+    }
+
+    fn set_solo_input(&mut self, input: InputChannels) {
+        self.profile.solo.input = input;
+    }
+}
+
+pub(crate) trait SoloCrate {
+    fn is_solo_button(&self, button: Buttons) -> bool;
+    async fn toggle_solo(&mut self) -> Result<()>;
+    fn set_solo_button_state(&mut self);
+
+    /// Removes the Headphone route of a row about to be sent to the device, if it's not soloed
+    fn apply_solo(&self, input: InputChannels, row: Row) -> Row;
+}
+
+impl SoloCrate for GoXLR {
+    fn is_solo_button(&self, button: Buttons) -> bool {
+        self.profile.solo.button == Some(button)
+    }
+
+    async fn toggle_solo(&mut self) -> Result<()> {
+        let input = self.profile.solo.input;
+        match self.solo == Some(input) {
+            true => self.set_solo(None).await,
+            false => self.set_solo(Some(input)).await,
+        }
+    }
+
+    fn set_solo_button_state(&mut self) {
+        if let Some(button) = self.profile.solo.button {
+            let state = match self.solo == Some(self.profile.solo.input) {
+                true => State::Colour1,
+                false => State::DimmedColour1,
+            };
+            self.button_states.set_state(button, state);
+        }
+    }
+
+    fn apply_solo(&self, input: InputChannels, mut row: Row) -> Row {
+        if let Some(solo) = self.solo {
+            if solo != input {
+                row[RoutingOutput::Headphones] = RouteValue::Off;
+            }
+        }
+        row
+    }
+}
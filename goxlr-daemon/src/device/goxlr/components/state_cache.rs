@@ -0,0 +1,243 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use enum_map::EnumMap;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::oneshot;
+
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::RoutingOutput;
+use goxlr_shared::channels::volume::VolumeChannels;
+use goxlr_shared::encoders::Encoders;
+use goxlr_shared::faders::Fader;
+use goxlr_shared::interaction::CurrentStates;
+use goxlr_shared::mute::ChannelMuteState;
+use goxlr_shared::routing::{RouteValue, StereoMode};
+use goxlr_usb::events::commands::{BasicResultCommand, CommandSender};
+
+use crate::device::goxlr::components::headphone_limit::HeadphoneLimitCrate;
+use crate::device::goxlr::device::GoXLR;
+
+/// A record of the last values sent to the device, persisted to disk when the daemon shuts down.
+/// On the next start, if neither the device or the profile appear to have changed, commands which
+/// would set a value the device already holds can be skipped.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct DeviceStateCache {
+    volumes: EnumMap<VolumeChannels, Option<u8>>,
+    mute_states: EnumMap<FaderChannels, Option<ChannelMuteState>>,
+    faders: EnumMap<Fader, Option<FaderChannels>>,
+    routing: EnumMap<InputChannels, Option<(EnumMap<RoutingOutput, RouteValue>, StereoMode)>>,
+    encoders: EnumMap<Encoders, Option<i8>>,
+
+    /// The physical state of the device when the cache was written, if this doesn't match when
+    /// the daemon starts, something has changed the device while we weren't running.
+    physical: Option<PhysicalState>,
+
+    /// A digest of the profile (and the headphone limit applied to it) when the cache was written.
+    /// Loading a different profile may set values which aren't held here, so it has to be sent
+    /// in full.
+    #[serde(default)]
+    profile: Option<String>,
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+struct PhysicalState {
+    fader_positions: EnumMap<Fader, u8>,
+    encoders: EnumMap<Encoders, i8>,
+}
+
+impl From<CurrentStates> for PhysicalState {
+    fn from(value: CurrentStates) -> Self {
+        let mut state = PhysicalState::default();
+        for (fader, position) in value.volumes {
+            state.fader_positions[fader.into()] = position;
+        }
+        for (encoder, position) in value.encoders {
+            state.encoders[encoder.into()] = position;
+        }
+        state
+    }
+}
+
+impl DeviceStateCache {
+    /// Returns true if the command would set a value the device is already known to hold
+    pub fn is_unchanged(&self, command: &BasicResultCommand) -> bool {
+        match command {
+            BasicResultCommand::SetVolume(channel, volume) => {
+                self.volumes[*channel] == Some(*volume)
+            }
+            BasicResultCommand::SetMuteState(channel, state) => {
+                self.mute_states[*channel] == Some(*state)
+            }
+            BasicResultCommand::AssignFader(fader, channel) => {
+                self.faders[*fader] == Some(*channel)
+            }
+            BasicResultCommand::ApplyRouting(input, routing, stereo) => {
+                self.routing[*input] == Some((*routing, *stereo))
+            }
+            BasicResultCommand::SetEncoderValue(encoder, value) => {
+                self.encoders[*encoder] == Some(*value)
+            }
+            _ => false,
+        }
+    }
+
+    /// Records the value a command has set on the device
+    pub fn record(&mut self, command: &BasicResultCommand) {
+        match command {
+            BasicResultCommand::SetVolume(channel, volume) => {
+                self.volumes[*channel] = Some(*volume)
+            }
+            BasicResultCommand::SetMuteState(channel, state) => {
+                self.mute_states[*channel] = Some(*state)
+            }
+            BasicResultCommand::AssignFader(fader, channel) => self.faders[*fader] = Some(*channel),
+            BasicResultCommand::ApplyRouting(input, routing, stereo) => {
+                self.routing[*input] = Some((*routing, *stereo))
+            }
+            BasicResultCommand::SetEncoderValue(encoder, value) => {
+                self.encoders[*encoder] = Some(*value)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Handles loading the cache when the device starts, and storing it when the daemon stops.
+pub(crate) trait StateCache {
+    async fn restore_state_cache(&mut self);
+    async fn store_state_cache(&self);
+}
+
+impl StateCache for GoXLR {
+    async fn restore_state_cache(&mut self) {
+        let Some(path) = self.get_state_cache_path() else {
+            return;
+        };
+
+        let cache = match read_cache(&path) {
+            Ok(Some(cache)) => cache,
+            Ok(None) => return,
+            Err(error) => {
+                warn!("Unable to read Device State Cache: {}", error);
+                return;
+            }
+        };
+
+        // The cache is only valid for a single restart, if we crash we can't be sure that it
+        // still reflects the device, so remove it now that it's been read.
+        if let Err(error) = fs::remove_file(&path) {
+            warn!("Unable to remove Device State Cache: {}", error);
+            return;
+        }
+
+        let physical = match self.get_current_states().await {
+            Ok(states) => PhysicalState::from(states),
+            Err(error) => {
+                warn!("Unable to read Device State, ignoring cache: {}", error);
+                return;
+            }
+        };
+
+        if cache.physical != Some(physical) {
+            warn!("Device has been changed since the daemon last ran, ignoring cached state");
+            return;
+        }
+
+        if cache.profile.is_none() || cache.profile != self.get_profile_digest() {
+            info!("Profile has been changed since the daemon last ran, ignoring cached state");
+            return;
+        }
+
+        info!("Device unchanged since last run, skipping unchanged values during load");
+        if let Ok(mut state_cache) = self.state_cache.lock() {
+            *state_cache = cache;
+        }
+        self.fast_start = true;
+    }
+
+    async fn store_state_cache(&self) {
+        let Some(path) = self.get_state_cache_path() else {
+            return;
+        };
+
+        let physical = match self.get_current_states().await {
+            Ok(states) => PhysicalState::from(states),
+            Err(error) => {
+                warn!("Unable to read Device State, not storing cache: {}", error);
+                return;
+            }
+        };
+
+        let cache = match self.state_cache.lock() {
+            Ok(state_cache) => DeviceStateCache {
+                physical: Some(physical),
+                profile: self.get_profile_digest(),
+                ..state_cache.clone()
+            },
+            Err(_) => return,
+        };
+
+        debug!("Writing Device State Cache to {:?}", path);
+        if let Err(error) = write_cache(&path, &cache) {
+            warn!("Unable to write Device State Cache: {}", error);
+        }
+    }
+}
+
+trait StateCacheLocal {
+    fn get_state_cache_path(&self) -> Option<PathBuf>;
+    fn get_profile_digest(&self) -> Option<String>;
+    async fn get_current_states(&self) -> Result<CurrentStates>;
+}
+
+impl StateCacheLocal for GoXLR {
+    fn get_state_cache_path(&self) -> Option<PathBuf> {
+        let directory = self.config.settings.state_cache_dir.as_ref()?;
+        let device = self.device.as_ref()?;
+        Some(directory.join(format!("{}.json", device.serial)))
+    }
+
+    fn get_profile_digest(&self) -> Option<String> {
+        let mut hasher = Sha256::new();
+        hasher.update(serde_json::to_vec(&self.profile).ok()?);
+        hasher.update(format!("{:?}", self.get_headphone_limit()));
+
+        let digest = hasher.finalize();
+        Some(digest.iter().map(|byte| format!("{:02x}", byte)).collect())
+    }
+
+    async fn get_current_states(&self) -> Result<CurrentStates> {
+        let (msg_send, msg_receive) = oneshot::channel();
+
+        if let Some(sender) = self.command_sender.clone() {
+            let command = CommandSender::GetButtonStates(msg_send);
+            let _ = sender.send(command).await;
+
+            return msg_receive.await?;
+        }
+        bail!("Sender Failure");
+    }
+}
+
+fn read_cache(path: &Path) -> Result<Option<DeviceStateCache>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path)?;
+    Ok(Some(serde_json::from_str(&content)?))
+}
+
+fn write_cache(path: &Path, cache: &DeviceStateCache) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context("Unable to create State Cache directory")?;
+    }
+
+    fs::write(path, serde_json::to_string(cache)?)?;
+    Ok(())
+}
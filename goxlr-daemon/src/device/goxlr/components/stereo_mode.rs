@@ -0,0 +1,28 @@
+use anyhow::{bail, Result};
+use log::debug;
+
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::routing::StereoMode;
+
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+
+/// Changes how the two sides of an input reach its outputs, for sources with an odd stereo image
+/// (such as a console only sending audio on one side). This is handled entirely by the routing
+/// matrix, so it's sent with each input's routing rather than as a separate setting.
+pub(crate) trait InputStereoMode {
+    async fn set_stereo_mode(&mut self, input: InputChannels, mode: StereoMode) -> Result<()>;
+}
+
+impl InputStereoMode for GoXLR {
+    async fn set_stereo_mode(&mut self, input: InputChannels, mode: StereoMode) -> Result<()> {
+        // Both sides of the Microphone carry the same signal, so there's nothing to change
+        if input == InputChannels::Microphone {
+            bail!("The Microphone is a mono input");
+        }
+
+        debug!("Setting Stereo Mode for {:?} to {:?}", input, mode);
+        self.profile.channels.stereo[input] = mode;
+        self.apply_routing_for_channel(input).await
+    }
+}
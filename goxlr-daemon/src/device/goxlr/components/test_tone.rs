@@ -0,0 +1,206 @@
+use std::f32::consts::PI;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, FromSample, SampleFormat, SizedSample, Stream, StreamConfig};
+use log::{debug, info, warn};
+use tokio::sync::oneshot;
+
+use goxlr_ipc::commands::diagnostics::{TestSignal, TestTone};
+use goxlr_shared::routing::RouteValue;
+
+use crate::device::goxlr::device::GoXLR;
+
+// Long enough to walk round the routing, short enough that a forgotten tone stops on its own
+const MAX_DURATION: u32 = 300;
+
+#[derive(Default)]
+pub(crate) struct TestTonePlayer {
+    // Dropping this stops the tone
+    stop: Option<mpsc::Sender<()>>,
+}
+
+/// Plays a generated signal into an Input via the OS, so routing and levels can be checked
+/// without needing any other software. Unlike the latency test, the tone plays in the
+/// background, so the device can still be adjusted while listening.
+pub(crate) trait TestTones {
+    async fn play_test_tone(&mut self, tone: TestTone) -> Result<()>;
+    fn stop_test_tone(&mut self);
+}
+
+impl TestTones for GoXLR {
+    async fn play_test_tone(&mut self, tone: TestTone) -> Result<()> {
+        if tone.level_db > 0. {
+            bail!("Test Tone level must be 0dBFS or lower");
+        }
+        if tone.duration == 0 || tone.duration > MAX_DURATION {
+            bail!("Test Tone duration must be between 1 and {}s", MAX_DURATION);
+        }
+        if let TestSignal::Sine(frequency) = tone.signal {
+            if !(20. ..=20000.).contains(&frequency) {
+                bail!("Test Tone frequency must be between 20Hz and 20kHz");
+            }
+        }
+
+        self.stop_test_tone();
+
+        // Not an error, but probably not what the user expected to hear..
+        let routes = self.routing_state.get_input_routes(tone.input);
+        if routes.values().all(|value| *value == RouteValue::Off) {
+            warn!(
+                "{:?} isn't routed to any outputs, the tone won't be heard",
+                tone.input
+            );
+        }
+
+        info!(
+            "Playing {:?} at {}dBFS into {:?} for {}s",
+            tone.signal, tone.level_db, tone.input, tone.duration
+        );
+
+        let (stop_tx, stop_rx) = mpsc::channel();
+        let (ready_tx, ready_rx) = oneshot::channel();
+        thread::spawn(move || {
+            // cpal streams can't be moved between threads on all platforms, so the stream
+            // needs to live here until we're done.
+            let stream = match build_stream(&tone) {
+                Ok(stream) => stream,
+                Err(error) => {
+                    let _ = ready_tx.send(Err(error));
+                    return;
+                }
+            };
+            let _ = ready_tx.send(Ok(()));
+
+            // Play until we're told to stop, or run out of time
+            let duration = Duration::from_secs(tone.duration.into());
+            let _ = stop_rx.recv_timeout(duration);
+            debug!("Test Tone Stopped");
+            drop(stream);
+        });
+
+        ready_rx.await??;
+        self.test_tone.stop = Some(stop_tx);
+        Ok(())
+    }
+
+    fn stop_test_tone(&mut self) {
+        if let Some(stop) = self.test_tone.stop.take() {
+            let _ = stop.send(());
+        }
+    }
+}
+
+fn build_stream(tone: &TestTone) -> Result<Stream> {
+    let host = cpal::default_host();
+    let mut devices = host.output_devices()?;
+    let device = devices
+        .find(|device| {
+            device
+                .name()
+                .map(|n| n == tone.playback_device)
+                .unwrap_or(false)
+        })
+        .with_context(|| format!("Unable to find Playback Device: {}", tone.playback_device))?;
+
+    let output_config = device.default_output_config()?;
+    let config = output_config.config();
+    let generator = SignalGenerator::new(tone, config.sample_rate.0);
+
+    let stream = match output_config.sample_format() {
+        SampleFormat::F32 => build_playback::<f32>(&device, &config, generator),
+        SampleFormat::I16 => build_playback::<i16>(&device, &config, generator),
+        SampleFormat::I32 => build_playback::<i32>(&device, &config, generator),
+        SampleFormat::U16 => build_playback::<u16>(&device, &config, generator),
+        format => bail!("Unsupported Playback Sample Format: {:?}", format),
+    }?;
+    stream.play()?;
+    Ok(stream)
+}
+
+fn build_playback<T>(
+    device: &Device,
+    config: &StreamConfig,
+    mut generator: SignalGenerator,
+) -> Result<Stream>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels as usize;
+    let stream = device.build_output_stream(
+        config,
+        move |data: &mut [T], _| {
+            for samples in data.chunks_mut(channels) {
+                let value = generator.next_sample();
+                for sample in samples {
+                    *sample = T::from_sample(value);
+                }
+            }
+        },
+        |error| debug!("Test Tone Stream Error: {}", error),
+        None,
+    )?;
+    Ok(stream)
+}
+
+struct SignalGenerator {
+    signal: TestSignal,
+    amplitude: f32,
+    rate: f32,
+    phase: f32,
+
+    // Pink Noise filter state, and a simple xorshift for the white noise feeding it
+    pink: [f32; 7],
+    seed: u32,
+}
+
+impl SignalGenerator {
+    fn new(tone: &TestTone, rate: u32) -> Self {
+        Self {
+            signal: tone.signal,
+            amplitude: 10_f32.powf(tone.level_db / 20.),
+            rate: rate as f32,
+            phase: 0.,
+            pink: [0.; 7],
+            seed: 0x2545_f491,
+        }
+    }
+
+    fn next_sample(&mut self) -> f32 {
+        let value = match self.signal {
+            TestSignal::Sine(frequency) => {
+                self.phase = (self.phase + frequency / self.rate) % 1.;
+                (2. * PI * self.phase).sin()
+            }
+            TestSignal::PinkNoise => self.next_pink(),
+        };
+        value * self.amplitude
+    }
+
+    fn next_white(&mut self) -> f32 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+        (self.seed as f32 / u32::MAX as f32) * 2. - 1.
+    }
+
+    /// Paul Kellet's refined pink noise filter, which is accurate to within 0.05dB above 9.2Hz
+    fn next_pink(&mut self) -> f32 {
+        let white = self.next_white();
+        let b = &mut self.pink;
+        b[0] = 0.99886 * b[0] + white * 0.0555179;
+        b[1] = 0.99332 * b[1] + white * 0.0750759;
+        b[2] = 0.96900 * b[2] + white * 0.1538520;
+        b[3] = 0.86650 * b[3] + white * 0.3104856;
+        b[4] = 0.55000 * b[4] + white * 0.5329522;
+        b[5] = -0.7616 * b[5] - white * 0.0168980;
+        let pink = b[0] + b[1] + b[2] + b[3] + b[4] + b[5] + b[6] + white * 0.5362;
+        b[6] = white * 0.115926;
+
+        // Roughly bring the output back into range
+        (pink * 0.11).clamp(-1., 1.)
+    }
+}
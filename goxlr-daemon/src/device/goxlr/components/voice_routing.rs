@@ -0,0 +1,176 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use enum_map::EnumMap;
+use log::{debug, info, warn};
+use tokio::task::JoinHandle;
+
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
+use goxlr_shared::routing::RouteValue;
+
+use crate::device::goxlr::components::mute_handler::MuteHandlerCrate;
+use crate::device::goxlr::components::push_to_talk::PushToTalkCrate;
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::device::GoXLR;
+use crate::platform::get_audio_applications;
+
+type Row = EnumMap<RoutingOutput, RouteValue>;
+
+// Listing the audio applications means asking the OS, so it's not done every tick
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+pub(crate) struct VoiceRoutingState {
+    // The configured application which is currently running
+    active: Option<String>,
+
+    last_check: Option<Instant>,
+    check: Option<JoinHandle<Result<Vec<String>>>>,
+
+    // Only complain once if the OS can't tell us, rather than every check
+    failed: bool,
+}
+
+/// While a configured voice application (Discord, for example) is running, the Microphone is
+/// routed to the Chat Mic, and Chat to the Headphones, so calls work without touching the routing.
+/// Like the Monitor Mix this is applied as the routing is sent to the device, so the profile's
+/// routing is left alone, and mutes and Push to Talk still apply.
+pub(crate) trait VoiceRouting {
+    async fn set_voice_applications(&mut self, applications: Vec<String>) -> Result<()>;
+}
+
+impl VoiceRouting for GoXLR {
+    async fn set_voice_applications(&mut self, applications: Vec<String>) -> Result<()> {
+        debug!("Setting Voice Applications: {:?}", applications);
+        self.profile.routing_rules.voice_applications = applications;
+
+        // Check again straight away, rather than waiting for the next interval
+        self.voice_routing.last_check = None;
+        self.voice_routing.failed = false;
+        if self.profile.routing_rules.voice_applications.is_empty() {
+            self.set_voice_application(None).await?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) trait VoiceRoutingCrate {
+    fn get_voice_application(&self) -> Option<String>;
+
+    /// Adds the voice routes to a row about to be sent to the device
+    fn apply_voice_routing(&self, input: InputChannels, row: Row) -> Row;
+
+    async fn handle_voice_routing(&mut self);
+}
+
+impl VoiceRoutingCrate for GoXLR {
+    fn get_voice_application(&self) -> Option<String> {
+        self.voice_routing.active.clone()
+    }
+
+    fn apply_voice_routing(&self, input: InputChannels, mut row: Row) -> Row {
+        if self.voice_routing.active.is_none() {
+            return row;
+        }
+
+        let (source, output) = match input {
+            InputChannels::Microphone => (FaderChannels::Microphone, OutputChannels::ChatMic),
+            InputChannels::Chat => (FaderChannels::Chat, OutputChannels::Headphones),
+            _ => return row,
+        };
+
+        if self.is_muted_to(source, output) || self.is_push_to_talk_gated(input, output) {
+            return row;
+        }
+
+        // Only add the route, anything already there (including a ducked value) is left alone
+        let route = RoutingOutput::from(output);
+        if row[route] == RouteValue::Off {
+            row[route] = RouteValue::On;
+        }
+        row
+    }
+
+    async fn handle_voice_routing(&mut self) {
+        if self.profile.routing_rules.voice_applications.is_empty() {
+            return;
+        }
+
+        // Pick up the result of the last check, if it's finished..
+        let finished = self
+            .voice_routing
+            .check
+            .as_ref()
+            .map(|check| check.is_finished());
+        if finished == Some(true) {
+            let Some(check) = self.voice_routing.check.take() else {
+                return;
+            };
+
+            let running = match check.await {
+                Ok(Ok(applications)) => applications,
+                Ok(Err(error)) => {
+                    if !self.voice_routing.failed {
+                        warn!("Unable to detect Voice Applications: {}", error);
+                        self.voice_routing.failed = true;
+                    }
+                    return;
+                }
+                Err(error) => {
+                    debug!("Voice Application check stopped: {}", error);
+                    return;
+                }
+            };
+
+            let configured = &self.profile.routing_rules.voice_applications;
+            let active = configured.iter().find(|name| {
+                let name = name.to_lowercase();
+                running.iter().any(|app| app.to_lowercase() == name)
+            });
+
+            if let Err(error) = self.set_voice_application(active.cloned()).await {
+                warn!("Unable to apply Voice Routing: {}", error);
+            }
+            return;
+        }
+
+        if self.voice_routing.check.is_some() {
+            return;
+        }
+
+        let due = match self.voice_routing.last_check {
+            Some(last) => last.elapsed() >= CHECK_INTERVAL,
+            None => true,
+        };
+        if due {
+            self.voice_routing.last_check = Some(Instant::now());
+            self.voice_routing.check = Some(tokio::spawn(get_audio_applications()));
+        }
+    }
+}
+
+trait VoiceRoutingLocal {
+    async fn set_voice_application(&mut self, application: Option<String>) -> Result<()>;
+}
+
+impl VoiceRoutingLocal for GoXLR {
+    async fn set_voice_application(&mut self, application: Option<String>) -> Result<()> {
+        if self.voice_routing.active == application {
+            return Ok(());
+        }
+
+        match &application {
+            Some(name) => info!("Voice Application {} detected, enabling Chat Routing", name),
+            None => info!("Voice Application closed, restoring Routing"),
+        }
+
+        self.voice_routing.active = application;
+        self.apply_routing_for_channel(InputChannels::Microphone)
+            .await?;
+        self.apply_routing_for_channel(InputChannels::Chat).await?;
+        self.send_device_update().await;
+        Ok(())
+    }
+}
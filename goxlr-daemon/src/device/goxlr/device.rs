@@ -1,11 +1,14 @@
+use std::collections::VecDeque;
+use std::future::pending;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use anyhow::{bail, Context, Result};
 use enum_map::EnumMap;
+use goxlr_ipc::commands::diagnostics::AuditOrigin;
 use goxlr_ipc::commands::GoXLRCommandResponse;
-use goxlr_ipc::status::Configuration;
+use goxlr_ipc::status::{Configuration, InputTestInput, ProfileLoadReport, RuntimeStatus};
 use log::{debug, error, trace, warn};
 use tokio::sync::{mpsc, oneshot};
 use tokio::{join, select, task, time};
@@ -13,24 +16,68 @@ use tokio::{join, select, task, time};
 use goxlr_profile::{MicProfile, Profile};
 use goxlr_shared::buttons::Buttons;
 use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
 use goxlr_shared::colours::ColourScheme;
 use goxlr_shared::device::DeviceInfo;
+use goxlr_shared::encoders::Encoders;
 use goxlr_shared::faders::Fader;
 use goxlr_shared::mute::ChannelMuteState;
 use goxlr_shared::routing::RoutingTable;
+use goxlr_shared::sampler::{SampleBank, SampleButton};
 use goxlr_shared::states::ButtonDisplayStates;
+use goxlr_shared::volume::Volume;
+use goxlr_usb::error::DeviceClaimError;
 use goxlr_usb::events::commands::{BasicResultCommand, CommandSender};
 use goxlr_usb::events::interaction::InteractionEvent;
 use goxlr_usb::runners::device::DeviceMessage;
 use goxlr_usb::runners::device::{start_usb_device_runner, GoXLRUSBConfiguration};
 
 use crate::device::device_manager::{ManagerMessage, RunnerMessage, RunnerState};
+use crate::device::goxlr::components::adapt_profile::AdaptProfile;
+use crate::device::goxlr::components::alert_flash::{AlertFlashCrate, AlertFlashes};
+use crate::device::goxlr::components::audit_log::{AuditLog, AuditLogging};
+use crate::device::goxlr::components::automation::{AutomationState, AutomationsCrate};
+use crate::device::goxlr::components::colour_buffer::{ColourBuffer, ColourBufferCrate};
+use crate::device::goxlr::components::colour_preview::{ColourPreview, ColourPreviewCrate};
+use crate::device::goxlr::components::crossfade::{CrossfadeState, RoutingCrossfade};
+use crate::device::goxlr::components::dry_run::{DryRun, DryRunCrate, DryRunState};
 use crate::device::goxlr::components::ducker::{AudioDucker, AudioDuckerTrait};
+use crate::device::goxlr::components::fader_response::FaderResponseState;
+use crate::device::goxlr::components::flight_recorder::{
+    FlightRecorder, FlightRecording, RecorderEvent,
+};
+use crate::device::goxlr::components::gain_analyser::{GainAnalyser, GainAnalysis};
+use crate::device::goxlr::components::hard_mute::{HardMuteCrate, HardMuteState};
+use crate::device::goxlr::components::headphone_limit::{HeadphoneLimitCrate, HeadphoneLimitState};
+use crate::device::goxlr::components::idle::{IdleDetectionCrate, IdleState};
+use crate::device::goxlr::components::input_test::{InputTestCrate, InputTestState};
 use crate::device::goxlr::components::interactions::Interactions;
+use crate::device::goxlr::components::latency::{Latency, LatencyState};
+use crate::device::goxlr::components::lighting_layers::LightingLayers;
+use crate::device::goxlr::components::lighting_takeover::{
+    LightingTakeover, TakeoverLightingCrate,
+};
 use crate::device::goxlr::components::load_profile::LoadProfile;
 use crate::device::goxlr::components::mic::load_profile::LoadMicProfile;
+use crate::device::goxlr::components::mic_level::{MicLevel, MicLevelSampler};
+use crate::device::goxlr::components::mute_handler::{MuteFadeState, MuteHandler};
+use crate::device::goxlr::components::notifications::{DeviceNotifications, NotificationState};
+use crate::device::goxlr::components::profile_storage::{ProfileStorage, ProfileStorageCrate};
+use crate::device::goxlr::components::push_to_talk::{PushToTalk, PushToTalkState};
+use crate::device::goxlr::components::route_arbiter::RouteArbiterState;
+use crate::device::goxlr::components::routing_rules::RoutingRulesCrate;
+use crate::device::goxlr::components::safe_mode::SafeMode;
+use crate::device::goxlr::components::sample_player::{SamplePlaybackCrate, SamplePlayer};
+use crate::device::goxlr::components::schedule::{ScheduleCrate, ScheduleState};
+use crate::device::goxlr::components::scribble_widgets::{ScribbleWidgets, ScribbleWidgetsCrate};
+use crate::device::goxlr::components::session_stats::{SessionStatisticsCrate, SessionStats};
+use crate::device::goxlr::components::state_cache::{DeviceStateCache, StateCache};
+use crate::device::goxlr::components::test_tone::TestTonePlayer;
+use crate::device::goxlr::components::voice_routing::{VoiceRoutingCrate, VoiceRoutingState};
 use crate::device::goxlr::device_config::GoXLRDeviceConfiguration;
 use crate::device::goxlr::ipc::handler::IPCCommandHandler;
+use crate::device::watchdog::{start_watchdog, Heartbeat};
 use crate::stop::Stop;
 
 pub(crate) struct GoXLR {
@@ -39,24 +86,87 @@ pub(crate) struct GoXLR {
 
     pub pause_polling: Arc<AtomicBool>,
 
+    // Physical interactions from the device, and any held back during a profile load
+    interaction_recv: Option<mpsc::Receiver<InteractionEvent>>,
+    pub queued_input: VecDeque<InteractionEvent>,
+
     pub profile: Profile,
     pub mic_profile: MicProfile,
+    pub load_report: ProfileLoadReport,
 
     // These are 'caches' of the state which are manipulated directly.
     pub colour_scheme: ColourScheme,
+    pub colour_buffer: ColourBuffer,
     pub button_states: ButtonDisplayStates,
     pub routing_state: RoutingTable,
+
+    // Inputs with routing changes waiting to be sent, while routing is being staged
+    pub routing_stage: Mutex<Option<EnumMap<InputChannels, bool>>>,
+
+    // Which feature currently holds each route, and any attempts to take one from it
+    pub route_arbiter: RouteArbiterState,
+
+    // Which of the time of day overrides is in use
+    pub schedule: ScheduleState,
     pub mute_state: EnumMap<FaderChannels, Option<ChannelMuteState>>,
+
+    // Volumes set at a higher resolution than the profile holds
+    pub precise_volumes: EnumMap<VolumeChannels, Volume>,
     pub fader_state: EnumMap<Fader, Option<FaderChannels>>,
 
     // For tracking button 'held' state..
     pub button_down_states: EnumMap<Buttons, Option<ButtonState>>,
 
+    pub mic_level: MicLevelSampler,
     pub ducking: AudioDucker,
+    pub gain_analyser: GainAnalyser,
+    pub latency: LatencyState,
+    pub mute_fades: MuteFadeState,
+    pub crossfade: CrossfadeState,
+    pub test_tone: TestTonePlayer,
+    pub sample_player: SamplePlayer,
+    pub lighting_layers: LightingLayers,
+    pub alert_flashes: AlertFlashes,
+    pub lighting_takeover: LightingTakeover,
+    pub colour_preview: ColourPreview,
+    pub push_to_talk: PushToTalkState,
+    pub hard_mute: HardMuteState,
+    pub headphone_limit: HeadphoneLimitState,
+    pub fader_response: FaderResponseState,
+    pub scribble_widgets: ScribbleWidgets,
+    pub notifications: NotificationState,
+    pub idle: IdleState,
+    pub profile_storage: ProfileStorage,
+    pub automation: AutomationState,
+    pub input_test: InputTestState,
+    pub session_stats: SessionStats,
+    pub voice_routing: VoiceRoutingState,
+
+    // The Input currently soloed in the Headphones
+    pub solo: Option<InputChannels>,
+
+    // The sample currently being played by a client, if any
+    pub sample_playing: Option<(SampleBank, SampleButton)>,
+
+    // A record of recent events, for attaching to bug reports
+    pub flight_recorder: Mutex<FlightRecorder>,
+
+    // A record of what's changed the configuration
+    pub audit_log: AuditLog,
+
+    // Commands captured, rather than sent, while a command is being Dry Run
+    pub dry_run: DryRunState,
+
+    // The last known state of the device, and whether it can be used to skip unchanged values
+    pub state_cache: Mutex<DeviceStateCache>,
+    pub fast_start: bool,
 
     pub timer_interval: u64,
 
-    config: GoXLRDeviceConfiguration,
+    // Tells the watchdog that the event loop is still making progress
+    pub(crate) heartbeat: Heartbeat,
+
+    pub(crate) config: GoXLRDeviceConfiguration,
     shutdown: Stop,
 }
 
@@ -68,18 +178,60 @@ impl GoXLR {
 
             pause_polling: Arc::new(AtomicBool::new(true)),
 
+            interaction_recv: None,
+            queued_input: Default::default(),
+
             colour_scheme: Default::default(),
+            colour_buffer: Default::default(),
             profile: Default::default(),
             mic_profile: Default::default(),
+            load_report: Default::default(),
             button_states: Default::default(),
             routing_state: Default::default(),
+            routing_stage: Default::default(),
+            route_arbiter: Default::default(),
+            schedule: Default::default(),
             mute_state: Default::default(),
+            precise_volumes: Default::default(),
             fader_state: Default::default(),
             button_down_states: Default::default(),
 
+            mic_level: Default::default(),
             ducking: Default::default(),
-
-            timer_interval: 20,
+            gain_analyser: Default::default(),
+            latency: Default::default(),
+            mute_fades: Default::default(),
+            crossfade: Default::default(),
+            test_tone: Default::default(),
+            sample_player: Default::default(),
+            lighting_layers: Default::default(),
+            alert_flashes: Default::default(),
+            lighting_takeover: Default::default(),
+            colour_preview: Default::default(),
+            push_to_talk: Default::default(),
+            hard_mute: Default::default(),
+            headphone_limit: HeadphoneLimitState::new(config.settings.headphone_limit),
+            fader_response: Default::default(),
+            scribble_widgets: Default::default(),
+            notifications: Default::default(),
+            idle: Default::default(),
+            profile_storage: Default::default(),
+            automation: Default::default(),
+            input_test: Default::default(),
+            session_stats: Default::default(),
+            voice_routing: Default::default(),
+            solo: None,
+            sample_playing: None,
+
+            flight_recorder: Mutex::new(FlightRecorder::new(config.settings.flight_recorder_size)),
+            audit_log: Default::default(),
+            dry_run: Default::default(),
+            state_cache: Default::default(),
+            fast_start: false,
+
+            timer_interval: config.settings.tick_interval,
+
+            heartbeat: Heartbeat::new(),
 
             config,
             shutdown,
@@ -90,6 +242,16 @@ impl GoXLR {
     /// there's ultimately no need to have loads of set up / tear down code for the messaging
     /// system all over the place if we're not expecting to handle anything.
     pub(crate) async fn send_no_result(&self, command: BasicResultCommand) -> Result<()> {
+        if self.capture_dry_run(&command) {
+            trace!("Captured for Dry Run: {:#?}", command);
+            return Ok(());
+        }
+
+        if self.fast_start && self.is_cached(&command) {
+            trace!("Skipping Unchanged: {:#?}", command);
+            return Ok(());
+        }
+
         trace!("Sending: {:#?}", command);
         let (msg_send, msg_receive) = oneshot::channel();
 
@@ -97,13 +259,50 @@ impl GoXLR {
         let sender = command_sender.context("Sender not configured!")?;
 
         // Send the message..
-        let command = CommandSender::BasicResultCommand(command, msg_send);
-        let _ = sender.send(command).await;
+        let message = CommandSender::BasicResultCommand(command.clone(), msg_send);
+        let _ = sender.send(message).await;
 
         trace!("Message sent, awaiting response..");
 
-        // Return the Response...
-        msg_receive.await?
+        // Wait for the Response, then keep track of what the device now holds..
+        if let Err(error) = msg_receive.await? {
+            self.record_event(RecorderEvent::UsbError(error.to_string()));
+            self.heartbeat.record_usb_result(false);
+            return Err(error);
+        }
+        self.heartbeat.beat();
+        self.heartbeat.record_usb_result(true);
+        self.record_cached(&command);
+        Ok(())
+    }
+
+    /// Checks whether the State Cache already holds the value set by this command
+    pub(crate) fn is_cached(&self, command: &BasicResultCommand) -> bool {
+        match self.state_cache.lock() {
+            Ok(cache) => cache.is_unchanged(command),
+            Err(_) => false,
+        }
+    }
+
+    /// Updates the State Cache with a value that's been set on the device
+    pub(crate) fn record_cached(&self, command: &BasicResultCommand) {
+        if let Ok(mut cache) = self.state_cache.lock() {
+            cache.record(command);
+        }
+    }
+
+    /// Builds the status for anything held by the daemon, rather than in the profile
+    pub(crate) fn get_runtime_status(&self) -> RuntimeStatus {
+        RuntimeStatus {
+            push_to_talk_active: self.push_to_talk.is_open(),
+            hard_muted: self.is_hard_muted(),
+            routing_warnings: self.get_routing_warnings(),
+            voice_application: self.get_voice_application(),
+            input_test: self.get_input_test_events(),
+            sample_queue: self.get_sample_queue(),
+            headphone_limit: self.get_headphone_limit(),
+            headphone_limit_overridden: self.is_headphone_limit_overridden(),
+        }
     }
 
     pub(crate) async fn send_device_update(&self) {
@@ -112,6 +311,57 @@ impl GoXLR {
         }
     }
 
+    /// Holds back any interactions which arrived while the event loop was busy, so they're
+    /// handled (in order) before anything else once it's free again
+    pub(crate) fn queue_pending_input(&mut self) {
+        let Some(receiver) = self.interaction_recv.as_mut() else {
+            return;
+        };
+        while let Ok(event) = receiver.try_recv() {
+            self.queued_input.push_back(event);
+        }
+    }
+
+    async fn handle_interaction(&mut self, event: InteractionEvent) {
+        self.mark_activity().await;
+
+        // While testing, the controls are only reported, they don't change anything
+        if self.is_input_testing() {
+            self.handle_input_test(get_test_input(&event)).await;
+            let _ = self.send_device_update().await;
+            return;
+        }
+
+        let trigger = get_audit_trigger(&event);
+        let before = self.audit_snapshot();
+        let result = match event {
+            InteractionEvent::ButtonDown(button) => {
+                self.record_event(RecorderEvent::ButtonDown(button.into()));
+                self.on_button_down(button.into()).await
+            }
+            InteractionEvent::ButtonUp(button) => {
+                self.record_event(RecorderEvent::ButtonUp(button.into()));
+                self.on_button_up(button.into()).await
+            }
+            InteractionEvent::VolumeChange(fader, value) => {
+                self.record_event(RecorderEvent::VolumeChange(fader.into(), value));
+                self.on_volume_change(fader.into(), value).await
+            }
+            InteractionEvent::EncoderChange(encoder, value) => {
+                self.record_event(RecorderEvent::EncoderChange(encoder.into(), value));
+                self.on_encoder_change(encoder.into(), value).await
+            }
+        };
+
+        if let Err(error) = result {
+            warn!("Error Handling Button Press: {:?}", error);
+        }
+        self.audit_changes(AuditOrigin::Physical, trigger, before);
+        self.mark_profile_changed();
+
+        let _ = self.send_device_update().await;
+    }
+
     pub async fn run(&mut self) -> Result<()> {
         debug!("[GoXLR]{} Starting Event Loop", self.config.device);
 
@@ -122,7 +372,8 @@ impl GoXLR {
         let (command_send, command_recv) = mpsc::channel(32);
 
         // These are callbacks for physical interactions with the device (Buttons Pressed / Volumes Changed)
-        let (interaction_send, mut interaction_recv) = mpsc::channel(128);
+        let (interaction_send, interaction_recv) = mpsc::channel(128);
+        self.interaction_recv = Some(interaction_recv);
 
         // A signalling channel to tell the device workers to stop
         let (stop_send, stop_recv) = oneshot::channel();
@@ -147,7 +398,17 @@ impl GoXLR {
         // Use the ready signal to hold here, until the usb running is running, this will also
         // provide us with the device info (such as serial, features, versions, etc).
         let device = match ready_recv.await {
-            Ok(recv) => recv,
+            Ok(Ok(recv)) => recv,
+            Ok(Err(e)) => {
+                // If we know why the device couldn't be claimed, let the manager know so it can
+                // be reported, rather than just being retried.
+                if let Some(error) = e.downcast_ref::<DeviceClaimError>() {
+                    let location = self.config.device.clone();
+                    let message = RunnerMessage::ClaimFailed(location, error.clone());
+                    let _ = self.config.manager_sender.send(message).await;
+                }
+                bail!("Unable to Start Device: {}", e);
+            }
             Err(e) => {
                 bail!("Error on Starting Receiver, aborting: {}", e);
             }
@@ -157,16 +418,58 @@ impl GoXLR {
         self.device = Some(device);
         self.command_sender = Some(command_send);
 
+        // From here on, if we stop responding (or the USB keeps failing), the watchdog should
+        // reset us..
+        let settings = &self.config.settings;
+        let timeout = Some(settings.watchdog_timeout).filter(|timeout| *timeout > 0);
+        let failure_limit = Some(settings.usb_failure_limit).filter(|limit| *limit > 0);
+        let watchdog = match (timeout, failure_limit) {
+            (None, None) => None,
+            (timeout, failure_limit) => {
+                self.heartbeat.beat();
+                Some(task::spawn(start_watchdog(
+                    self.config.device.clone(),
+                    self.heartbeat.clone(),
+                    timeout.map(Duration::from_secs),
+                    failure_limit,
+                    self.config.manager_sender.clone(),
+                )))
+            }
+        };
+
         // Let the device runner know we're up and running
         let run_msg =
             RunnerMessage::UpdateState(self.config.device.clone(), RunnerState::Running(serial));
         let _ = self.config.manager_sender.send(run_msg).await;
 
-        // Load the profile.
+        // Pick up where this device left off, if its profiles have been stored
+        self.load_stored_profiles();
+        self.restore_hard_mute();
+
+        // Make sure the profile is compatible with this device, then load it.
         let mut load_fail = false;
-        if let Err(error) = self.load_profile().await {
-            warn!("Error While Loading Profile: {}", error);
-            load_fail = true;
+        let mut load_error = None;
+        if let Err(error) = self.adapt_profile() {
+            warn!("Error While Adapting Profile: {}", error);
+            load_error = Some(error);
+        } else {
+            // If neither the device or the profile have changed since we last ran, unchanged
+            // values can be skipped.
+            self.restore_state_cache().await;
+
+            if let Err(error) = self.load_profile().await {
+                warn!("Error While Loading Profile: {}", error);
+                load_error = Some(error);
+            }
+        }
+
+        // If the profile couldn't be loaded, fall back to Safe Mode rather than leaving the
+        // device half configured. Only if that fails too do we give up.
+        if let Some(error) = load_error {
+            if let Err(error) = self.load_safe_mode(error.to_string()).await {
+                warn!("Error While Loading Safe Mode: {}", error);
+                load_fail = true;
+            }
         }
 
         if let Err(error) = self.load_mic_profile().await {
@@ -174,6 +477,9 @@ impl GoXLR {
             load_fail = true;
         }
 
+        // Loading is done, from here everything should be sent.
+        self.fast_start = false;
+
         // Permit the USB handler to poll for changes..
         self.pause_polling.store(false, Ordering::Relaxed);
 
@@ -183,6 +489,11 @@ impl GoXLR {
         if !load_fail {
             // Sit and wait for various signals to come, and process them as they do.
             loop {
+                // Input held back during a profile load is handled before anything else
+                while let Some(event) = self.queued_input.pop_front() {
+                    self.handle_interaction(event).await;
+                }
+
                 select! {
                     Some(event) = self.config.manager_recv.recv() => {
                         match event {
@@ -197,12 +508,25 @@ impl GoXLR {
                             ManagerMessage::GetDevice(tx) => {
                                 let _ = tx.send(self.device.as_ref().unwrap().clone());
                             }
+                            ManagerMessage::GetLoadReport(tx) => {
+                                let _ = tx.send(self.load_report.clone());
+                            }
+                            ManagerMessage::GetRuntimeStatus(tx) => {
+                                let _ = tx.send(self.get_runtime_status());
+                            }
                             ManagerMessage::Execute(command, tx) => {
                                 debug!("Handling IPC Command: {:?}", command);
+                                let trigger = format!("{:?}", command);
+                                self.record_event(RecorderEvent::Command(trigger.clone()));
 
+                                let before = self.audit_snapshot();
                                 let result = self.handle_ipc_command(command).await;
+                                self.audit_changes(AuditOrigin::Command, trigger, before);
+                                self.mark_profile_changed();
+
                                 let message = result.unwrap_or_else(|e| {
                                         warn!("Execution Error: {}", e.to_string());
+                                        self.record_event(RecorderEvent::CommandError(e.to_string()));
                                         GoXLRCommandResponse::Error(e.to_string())
                                     });
                                 let _ = tx.send(message);
@@ -212,6 +536,16 @@ impl GoXLR {
                                 debug!("Device Sending Status Change..");
                                 let _ = self.send_device_update().await;
                             },
+                            ManagerMessage::DryRun(command, tx) => {
+                                debug!("Dry Running IPC Command: {:?}", command);
+
+                                // Nothing's changed once this returns, so there's no update to send
+                                let message = match self.dry_run_ipc_command(command).await {
+                                    Ok(report) => GoXLRCommandResponse::DryRun(report),
+                                    Err(e) => GoXLRCommandResponse::Error(e.to_string()),
+                                };
+                                let _ = tx.send(message);
+                            },
 
                         }
                     }
@@ -219,41 +553,50 @@ impl GoXLR {
                         debug!("[GoXLR]{} Event: {:?}", self.config.device, event);
                         match event {
                             DeviceMessage::Error => {
+                                self.record_event(RecorderEvent::DeviceError);
                                 warn!("[GoXLR]{} Error Sent back from Handler, bail!", self.config.device);
                                 break;
                             }
                         }
                     }
-                    Some(event) = interaction_recv.recv() => {
-                        let result = match event {
-                            InteractionEvent::ButtonDown(button) => {
-                                self.on_button_down(button.into()).await
-                            },
-                            InteractionEvent::ButtonUp(button) => {
-                                self.on_button_up(button.into()).await
-                            },
-                            InteractionEvent::VolumeChange(fader, value) => {
-                                self.on_volume_change(fader.into(), value).await
-                            },
-                            InteractionEvent::EncoderChange(encoder, value) => {
-                                self.on_encoder_change(encoder.into(), value).await
-                            }
-                        };
-
-                        if let Err(error) = result {
-                            warn!("Error Handling Button Press: {:?}", error);
-                        }
-
-                        let _ = self.send_device_update().await;
+                    Some(event) = next_interaction(&mut self.interaction_recv) => {
+                        self.handle_interaction(event).await;
                     }
                     _ = ticker.tick() => {
-                        // Things to do every 20ms..
+                        self.heartbeat.beat();
+
+                        // Things to do every tick..
                         let _ = self.check_held().await;
 
+                        // Grab the mic level first, so everything below sees the same value
+                        self.sample_mic_level().await;
+                        self.handle_schedule().await;
                         self.handle_ducking().await;
+                        self.handle_gain_analysis();
+                        self.handle_latency_test().await;
+                        self.handle_mute_fades().await;
+                        self.handle_crossfade().await;
+                        self.handle_push_to_talk().await;
+                        self.handle_session_stats();
+                        self.handle_voice_routing().await;
+                        self.handle_lighting_takeover().await;
+                        self.handle_colour_preview().await;
+                        self.handle_alert_flashes().await;
+                        if let Err(error) = self.commit_colours().await {
+                            warn!("Unable to Commit Colour Scheme: {}", error);
+                        }
+                        self.handle_scribble_widgets().await;
+                        self.handle_notifications();
+                        self.handle_idle().await;
+                        self.handle_sample_playback().await;
+                        self.handle_automation().await;
+                        self.handle_profile_storage();
                     }
                     _ = self.shutdown.recv() => {
                         debug!("[GoXLR]{} Shutdown Triggered!", self.config.device);
+
+                        // The device is still attached, so store its state for the next run
+                        self.store_state_cache().await;
                         break;
                     }
                     _ = self.config.stop.recv() => {
@@ -265,7 +608,14 @@ impl GoXLR {
         }
 
         // Our loop has been broken (or never started), let the device know we're done..
+        if !load_fail {
+            self.store_profiles();
+        }
+
         let device = &self.config.device;
+        if let Some(watchdog) = watchdog {
+            watchdog.abort();
+        }
 
         let _ = stop_send.send(());
         debug!("[GoXLR]{} Event Loop Ended", device);
@@ -294,6 +644,42 @@ pub async fn start_goxlr(config: GoXLRDeviceConfiguration, shutdown: Stop) {
     }
 }
 
+/// Waits for the next interaction, never resolving if the device runner hasn't been started
+async fn next_interaction(
+    receiver: &mut Option<mpsc::Receiver<InteractionEvent>>,
+) -> Option<InteractionEvent> {
+    match receiver {
+        Some(receiver) => receiver.recv().await,
+        None => pending().await,
+    }
+}
+
+/// Describes an interaction for the Audit Log, volume and encoder values are left out so that
+/// repeated movements can be grouped together.
+fn get_audit_trigger(event: &InteractionEvent) -> String {
+    match *event {
+        InteractionEvent::ButtonDown(button) => format!("Button Down: {:?}", Buttons::from(button)),
+        InteractionEvent::ButtonUp(button) => format!("Button Up: {:?}", Buttons::from(button)),
+        InteractionEvent::VolumeChange(fader, _) => {
+            format!("Fader Moved: {:?}", Fader::from(fader))
+        }
+        InteractionEvent::EncoderChange(encoder, _) => {
+            format!("Encoder Turned: {:?}", Encoders::from(encoder))
+        }
+    }
+}
+
+fn get_test_input(event: &InteractionEvent) -> InputTestInput {
+    match *event {
+        InteractionEvent::ButtonDown(button) => InputTestInput::ButtonDown(button.into()),
+        InteractionEvent::ButtonUp(button) => InputTestInput::ButtonUp(button.into()),
+        InteractionEvent::VolumeChange(fader, value) => InputTestInput::Fader(fader.into(), value),
+        InteractionEvent::EncoderChange(encoder, value) => {
+            InputTestInput::Encoder(encoder.into(), value)
+        }
+    }
+}
+
 /// This is a simple struct that tracks how long long a button has been pressed for..
 #[derive(Debug, Default, Copy, Clone)]
 pub(crate) struct ButtonState {
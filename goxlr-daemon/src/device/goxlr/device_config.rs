@@ -1,9 +1,15 @@
+use std::sync::Arc;
+
+use tokio::sync::broadcast;
 use tokio::sync::mpsc::{Receiver, Sender};
 
+use goxlr_ipc::commands::FaderMovement;
 use goxlr_usb::USBLocation;
 
 use crate::device::device_manager::{ManagerMessage, RunnerMessage};
+use crate::settings::DeviceSettings;
 use crate::stop::Stop;
+use crate::storage::ProfileStore;
 
 pub struct GoXLRDeviceConfiguration {
     pub(crate) stop: Stop,
@@ -11,4 +17,7 @@ pub struct GoXLRDeviceConfiguration {
     pub(crate) update_sender: Sender<()>,
     pub(crate) manager_sender: Sender<RunnerMessage>,
     pub(crate) manager_recv: Receiver<ManagerMessage>,
+    pub(crate) fader_events: broadcast::Sender<FaderMovement>,
+    pub(crate) settings: DeviceSettings,
+    pub(crate) profile_store: Arc<dyn ProfileStore>,
 }
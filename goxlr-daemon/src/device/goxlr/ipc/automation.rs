@@ -0,0 +1,28 @@
+use goxlr_ipc::commands::automation::AutomationCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::automation::Automations;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = AutomationCommand;
+
+pub trait IPCAutomationHandler {
+    async fn ipc_automation(&mut self, command: Command) -> Response;
+}
+
+impl IPCAutomationHandler for GoXLR {
+    async fn ipc_automation(&mut self, command: Command) -> Response {
+        match command {
+            Command::StartRecording => self.start_automation_recording()?,
+            Command::StopRecording(name) => self.stop_automation_recording(name)?,
+            Command::Play(name) => self.play_automation(name)?,
+            Command::StopPlayback => self.stop_automation_playback(),
+            Command::List => {
+                return Ok(GoXLRCommandResponse::Automations(self.list_automations()?));
+            }
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
@@ -0,0 +1,23 @@
+use goxlr_ipc::commands::button_cycles::ButtonCycleCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::button_cycles::ButtonCycles;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = ButtonCycleCommand;
+
+pub trait IPCButtonCycleHandler {
+    async fn ipc_button_cycles(&mut self, command: Command) -> Response;
+}
+
+impl IPCButtonCycleHandler for GoXLR {
+    async fn ipc_button_cycles(&mut self, command: Command) -> Response {
+        match command {
+            Command::Set(button, cycle) => self.set_button_cycle(button, cycle).await?,
+            Command::Next(button) => self.next_button_cycle_step(button).await?,
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
@@ -2,9 +2,16 @@ use log::debug;
 
 use goxlr_ipc::commands::channels::{ChannelCommands, SubMixCommands};
 use goxlr_ipc::commands::GoXLRCommandResponse;
+use goxlr_shared::volume::Volume;
 
 use crate::device::goxlr::components::channel::Channels;
+use crate::device::goxlr::components::fader::DeviceFader;
+use crate::device::goxlr::components::headphone_limit::HeadphoneLimit;
+use crate::device::goxlr::components::input_category::InputCategories;
+use crate::device::goxlr::components::input_trim::InputTrim;
 use crate::device::goxlr::components::mute_handler::MuteHandler;
+use crate::device::goxlr::components::output_ceiling::OutputCeiling;
+use crate::device::goxlr::components::stereo_mode::InputStereoMode;
 use crate::device::goxlr::components::submix::SubMix;
 use crate::device::goxlr::device::GoXLR;
 use crate::device::goxlr::ipc::handler::Response;
@@ -22,10 +29,40 @@ impl IPCChannelHandler for GoXLR {
                 self.set_channel_volume(params.channel, params.volume)
                     .await?;
             }
+            Command::PreciseVolume(params) => {
+                let volume = Volume::from_fraction(params.volume)?;
+                self.set_channel_volume_precise(params.channel, volume)
+                    .await?;
+            }
             Command::Mute(params) => {
                 debug!("Applying Mute State..");
                 self.set_mute_state(params.channel, params.state).await?;
             }
+            Command::Name(params) => {
+                self.set_channel_name(params.channel, params.name).await?;
+            }
+            Command::MuteFade(params) => {
+                self.set_mute_fade(params.channel, params.fade_ms)?;
+            }
+            Command::Trim(params) => {
+                self.set_input_trim(params.channel, params.trim).await?;
+            }
+            Command::Stereo(params) => {
+                self.set_stereo_mode(params.channel, params.mode).await?;
+            }
+            Command::Category(params) => {
+                self.set_input_category(params.channel, params.category)
+                    .await?;
+            }
+            Command::HeadphoneLimit(limit) => self.set_headphone_limit(limit).await?,
+            Command::HeadphoneLimitOverride(overridden) => {
+                self.set_headphone_limit_override(overridden).await?;
+            }
+            Command::OutputCeiling(params) => {
+                self.set_output_ceiling(params.output, params.ceiling)
+                    .await?;
+            }
+            Command::CoughTarget(target) => self.set_cough_target(target).await?,
 
             Command::SubMix(command) => {
                 let channel = command.channel;
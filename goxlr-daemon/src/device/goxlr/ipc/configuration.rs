@@ -1,8 +1,15 @@
 use goxlr_ipc::commands::configuration::ConfigurationCommand;
 use goxlr_ipc::commands::GoXLRCommandResponse;
+use crate::device::goxlr::components::adapt_profile::AdaptProfile;
 use crate::device::goxlr::components::buttons::ButtonHandlers;
+use crate::device::goxlr::components::desired_state::DesiredStates;
+use crate::device::goxlr::components::load_profile::LoadProfile;
+use crate::device::goxlr::components::mic::load_profile::LoadMicProfile;
 use crate::device::goxlr::components::pages::FaderPages;
+use crate::device::goxlr::components::routing_rules::RoutingRules;
+use crate::device::goxlr::components::safe_mode::SafeMode;
 use crate::device::goxlr::components::submix::SubMix;
+use crate::device::goxlr::components::voice_routing::VoiceRouting;
 
 use crate::device::goxlr::device::GoXLR;
 use crate::device::goxlr::ipc::handler::Response;
@@ -29,6 +36,59 @@ impl IPCConfigurationHandler for GoXLR {
                 self.set_change_page_with_buttons(enabled).await?;
                 Ok(GoXLRCommandResponse::Ok)
             }
+            Command::ReloadProfile => {
+                self.adapt_profile()?;
+                if let Err(error) = self.load_profile().await {
+                    self.load_safe_mode(error.to_string()).await?;
+                }
+
+                let report = self.load_report.clone();
+                Ok(GoXLRCommandResponse::ProfileLoaded(report))
+            }
+            Command::ApplyState(state) => {
+                let differences = self.apply_state(state).await?;
+                Ok(GoXLRCommandResponse::StateDiff(differences))
+            }
+            Command::DiffState(state) => {
+                let differences = self.diff_state(&state)?;
+                Ok(GoXLRCommandResponse::StateDiff(differences))
+            }
+            Command::AddRoutingRule(rule) => {
+                self.add_routing_rule(rule)?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::RemoveRoutingRule(name) => {
+                self.remove_routing_rule(name)?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::SetVoiceApplications(applications) => {
+                self.set_voice_applications(applications).await?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::Restore(config) => {
+                self.profile = config.device;
+                self.mic_profile = config.mic_profile;
+
+                self.adapt_profile()?;
+                if let Err(error) = self.load_profile().await {
+                    self.load_safe_mode(error.to_string()).await?;
+                }
+                self.load_mic_profile().await?;
+
+                let report = self.load_report.clone();
+                Ok(GoXLRCommandResponse::ProfileLoaded(report))
+            }
+            Command::LoadProfile(profile) => {
+                self.profile = *profile;
+
+                self.adapt_profile()?;
+                if let Err(error) = self.load_profile().await {
+                    self.load_safe_mode(error.to_string()).await?;
+                }
+
+                let report = self.load_report.clone();
+                Ok(GoXLRCommandResponse::ProfileLoaded(report))
+            }
         }
     }
 }
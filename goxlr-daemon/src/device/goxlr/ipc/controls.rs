@@ -0,0 +1,23 @@
+use goxlr_ipc::commands::controls::ControlCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::controls::ControlEnumeration;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = ControlCommand;
+
+pub trait IPCControlHandler {
+    async fn ipc_controls(&mut self, command: Command) -> Response;
+}
+
+impl IPCControlHandler for GoXLR {
+    async fn ipc_controls(&mut self, command: Command) -> Response {
+        match command {
+            Command::List => return Ok(GoXLRCommandResponse::Controls(self.get_controls())),
+            Command::Adjust(params) => self.adjust_control(&params.id, params.action).await?,
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
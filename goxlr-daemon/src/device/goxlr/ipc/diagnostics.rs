@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use goxlr_ipc::commands::diagnostics::DiagnosticsCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::audit_log::AuditLogging;
+use crate::device::goxlr::components::flight_recorder::FlightRecording;
+use crate::device::goxlr::components::gain_analyser::GainAnalysis;
+use crate::device::goxlr::components::input_test::InputTest;
+use crate::device::goxlr::components::latency::Latency;
+use crate::device::goxlr::components::route_arbiter::RouteArbiterCrate;
+use crate::device::goxlr::components::routing_handler::RoutingHandler;
+use crate::device::goxlr::components::session_stats::SessionStatistics;
+use crate::device::goxlr::components::test_tone::TestTones;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = DiagnosticsCommand;
+
+pub trait IPCDiagnosticsHandler {
+    async fn ipc_diagnostics(&mut self, command: Command) -> Response;
+}
+
+impl IPCDiagnosticsHandler for GoXLR {
+    async fn ipc_diagnostics(&mut self, command: Command) -> Response {
+        match command {
+            Command::MeasureLatency(test) => {
+                self.start_latency_test(test).await?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::GetLatencyReport => {
+                let report = self.get_latency_report()?;
+                Ok(GoXLRCommandResponse::LatencyMeasured(report))
+            }
+            Command::StartGainAnalysis(seconds) => {
+                self.start_gain_analysis(Duration::from_secs(seconds.into()));
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::GetGainAnalysis => {
+                let report = self.get_gain_report();
+                Ok(GoXLRCommandResponse::GainAnalysis(report))
+            }
+            Command::GetRoutingState => {
+                let report = self.get_routing_report();
+                Ok(GoXLRCommandResponse::RoutingState(report))
+            }
+            Command::DumpFlightRecorder => {
+                let recording = self.dump_flight_recorder()?;
+                Ok(GoXLRCommandResponse::FlightRecording(recording))
+            }
+            Command::GetAuditLog(filter) => {
+                let entries = self.get_audit_log(filter);
+                Ok(GoXLRCommandResponse::AuditLog(entries))
+            }
+            Command::PlayTestTone(tone) => {
+                self.play_test_tone(tone).await?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::StopTestTone => {
+                self.stop_test_tone();
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::InputTest(enabled) => {
+                self.set_input_test(enabled).await?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::GetSessionStats => {
+                let report = self.get_session_stats();
+                Ok(GoXLRCommandResponse::SessionStats(report))
+            }
+            Command::ResetSessionStats => {
+                self.reset_session_stats();
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::GetRouteConflicts => {
+                let report = self.get_route_conflicts();
+                Ok(GoXLRCommandResponse::RouteConflicts(report))
+            }
+            Command::ClearRouteConflicts => {
+                self.clear_route_conflicts();
+                Ok(GoXLRCommandResponse::Ok)
+            }
+        }
+    }
+}
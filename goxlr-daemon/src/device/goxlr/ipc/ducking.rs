@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use goxlr_ipc::commands::ducking::{DuckingCommand, DuckingLevelSource};
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::ducker::AudioDuckerTrait;
+use crate::device::goxlr::components::level_source::{CaptureSource, InjectedLevel, LevelSource};
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = DuckingCommand;
+
+pub trait IPCDuckingHandler {
+    async fn ipc_ducking(&mut self, command: Command) -> Response;
+}
+
+impl IPCDuckingHandler for GoXLR {
+    async fn ipc_ducking(&mut self, command: Command) -> Response {
+        let mut settings = self.profile.ducking.clone();
+        match command {
+            Command::Enabled(enabled) => {
+                settings.enabled = enabled;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::InputSource(input, enabled) => {
+                self.set_ducking_input_source(input, enabled);
+            }
+            Command::LevelSource(input, source) => {
+                let device = match &source {
+                    DuckingLevelSource::CaptureDevice(name) => Some(name.clone()),
+                    _ => None,
+                };
+                let source = open_level_source(source).await?;
+                self.set_level_source(input, source, device);
+            }
+            Command::OutputRoute(input, output, enabled) => {
+                settings.output_routing[input][output] = enabled;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::CategoryRoute(category, output, enabled) => {
+                settings.category_routing[category][output] = enabled;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::Transition(ducking, unducking) => {
+                settings.transition.ducking = ducking;
+                settings.transition.unducking = unducking;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::AttackTime(time) => {
+                settings.attack_time = time;
+                settings.sensitivity = None;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::ReleaseTime(time) => {
+                settings.release_time = time;
+                settings.sensitivity = None;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::TriggerLevel(level) => {
+                settings.trigger_db = level;
+                settings.sensitivity = None;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::InputTriggerLevel(input, level) => {
+                settings.input_trigger_db[input] = level;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::MicTriggerOffset(offset) => {
+                settings.mic_trigger_offset = offset;
+                settings.sensitivity = None;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::Sensitivity(sensitivity) => {
+                self.set_ducking_sensitivity(sensitivity).await?;
+            }
+            Command::SensitivityButton(button) => {
+                settings.sensitivity_button = button;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::AdaptiveEnabled(enabled) => {
+                settings.adaptive.enabled = enabled;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::MusicLevelSource(source) => {
+                let source = open_level_source(source).await?;
+                self.set_music_level_source(source);
+            }
+            Command::AdaptiveLevels(quiet, loud) => {
+                settings.adaptive.quiet_db = quiet;
+                settings.adaptive.loud_db = loud;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::AdaptiveVolumes(quiet, loud) => {
+                settings.adaptive.quiet_volume = quiet;
+                settings.adaptive.loud_volume = loud;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::SamplePlaying(playing) => {
+                self.set_sample_playing(playing);
+            }
+            Command::FadeButton(button) => {
+                settings.fade_button = button;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::FastUnduckOnMute(enabled) => {
+                settings.fast_unduck_on_mute = enabled;
+                self.apply_ducking_settings(settings).await?;
+            }
+            Command::GetStats => {
+                let report = self.get_ducking_stats();
+                return Ok(GoXLRCommandResponse::DuckingStats(report));
+            }
+            Command::ResetStats => {
+                self.reset_ducking_stats();
+            }
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
+
+async fn open_level_source(source: DuckingLevelSource) -> Result<Option<Arc<dyn LevelSource>>> {
+    Ok(match source {
+        DuckingLevelSource::Default => None,
+        DuckingLevelSource::CaptureDevice(name) => Some(Arc::new(CaptureSource::open(name).await?)),
+        DuckingLevelSource::Injected(level) => Some(Arc::new(InjectedLevel::new(level))),
+    })
+}
@@ -0,0 +1,28 @@
+use crate::device::goxlr::components::effects::echo::Echo;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+use goxlr_ipc::commands::effects::echo::EchoCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+type Command = EchoCommand;
+pub trait IPCEchoHandler {
+    async fn ipc_echo(&mut self, command: Command) -> Response;
+}
+
+impl IPCEchoHandler for GoXLR {
+    async fn ipc_echo(&mut self, command: Command) -> Response {
+        match command {
+            Command::SetStyle(style) => self.set_echo_style(style).await?,
+            Command::SetAmount(amount) => self.set_echo_amount(amount).await?,
+            Command::SetFeedback(feedback) => self.set_echo_feedback(feedback).await?,
+            Command::SetTempo(tempo) => self.set_echo_tempo(tempo).await?,
+            Command::SetDelayLeft(delay) => self.set_echo_delay_left(delay).await?,
+            Command::SetDelayRight(delay) => self.set_echo_delay_right(delay).await?,
+            Command::SetFeedbackLeft(value) => self.set_echo_feedback_left(value).await?,
+            Command::SetFeedbackRight(value) => self.set_echo_feedback_right(value).await?,
+            Command::SetFeedbackXFBLtoR(value) => self.set_echo_feedback_xfb_l_to_r(value).await?,
+            Command::SetFeedbackXFBRtoL(value) => self.set_echo_feedback_xfb_r_to_l(value).await?,
+        }
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
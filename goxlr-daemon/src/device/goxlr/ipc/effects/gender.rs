@@ -0,0 +1,20 @@
+use crate::device::goxlr::components::effects::gender::Gender;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+use goxlr_ipc::commands::effects::gender::GenderCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+type Command = GenderCommand;
+pub trait IPCGenderHandler {
+    async fn ipc_gender(&mut self, command: Command) -> Response;
+}
+
+impl IPCGenderHandler for GoXLR {
+    async fn ipc_gender(&mut self, command: Command) -> Response {
+        match command {
+            Command::SetStyle(style) => self.set_gender_style(style).await?,
+            Command::SetAmount(amount) => self.set_gender_amount(amount).await?,
+        }
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::device::goxlr::components::effects::hard_tune::HardTune;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+use goxlr_ipc::commands::effects::hard_tune::HardTuneCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+type Command = HardTuneCommand;
+pub trait IPCHardTuneHandler {
+    async fn ipc_hard_tune(&mut self, command: Command) -> Response;
+}
+
+impl IPCHardTuneHandler for GoXLR {
+    async fn ipc_hard_tune(&mut self, command: Command) -> Response {
+        match command {
+            Command::SetEnabled(enabled) => self.set_hard_tune_enabled(enabled).await?,
+        }
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::effects::echo::IPCEchoHandler;
+use crate::device::goxlr::ipc::effects::gender::IPCGenderHandler;
+use crate::device::goxlr::ipc::effects::hard_tune::IPCHardTuneHandler;
+use crate::device::goxlr::ipc::effects::pitch::IPCPitchHandler;
+use crate::device::goxlr::ipc::effects::reverb::IPCReverbHandler;
+use crate::device::goxlr::ipc::handler::Response;
+use goxlr_ipc::commands::effects::EffectsCommand;
+
+mod echo;
+mod gender;
+mod hard_tune;
+mod pitch;
+mod reverb;
+
+type Command = EffectsCommand;
+pub trait IPCEffectsHandler {
+    async fn ipc_effects(&mut self, command: Command) -> Response;
+}
+
+impl IPCEffectsHandler for GoXLR {
+    async fn ipc_effects(&mut self, command: Command) -> Response {
+        match command {
+            Command::Reverb(command) => self.ipc_reverb(command).await,
+            Command::Echo(command) => self.ipc_echo(command).await,
+            Command::Pitch(command) => self.ipc_pitch(command).await,
+            Command::Gender(command) => self.ipc_gender(command).await,
+            Command::HardTune(command) => self.ipc_hard_tune(command).await,
+        }
+    }
+}
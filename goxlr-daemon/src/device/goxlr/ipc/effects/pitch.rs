@@ -0,0 +1,21 @@
+use crate::device::goxlr::components::effects::pitch::Pitch;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+use goxlr_ipc::commands::effects::pitch::PitchCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+type Command = PitchCommand;
+pub trait IPCPitchHandler {
+    async fn ipc_pitch(&mut self, command: Command) -> Response;
+}
+
+impl IPCPitchHandler for GoXLR {
+    async fn ipc_pitch(&mut self, command: Command) -> Response {
+        match command {
+            Command::SetStyle(style) => self.set_pitch_style(style).await?,
+            Command::SetAmount(amount) => self.set_pitch_amount(amount).await?,
+            Command::SetCharacter(character) => self.set_pitch_character(character).await?,
+        }
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::device::goxlr::components::effects::reverb::Reverb;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+use goxlr_ipc::commands::effects::reverb::ReverbCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+type Command = ReverbCommand;
+pub trait IPCReverbHandler {
+    async fn ipc_reverb(&mut self, command: Command) -> Response;
+}
+
+impl IPCReverbHandler for GoXLR {
+    async fn ipc_reverb(&mut self, command: Command) -> Response {
+        match command {
+            Command::SetStyle(style) => self.set_reverb_style(style).await?,
+            Command::SetAmount(amount) => self.set_reverb_amount(amount).await?,
+            Command::SetDecay(decay) => self.set_reverb_decay(decay).await?,
+            Command::SetEarlyLevel(level) => self.set_reverb_early_level(level).await?,
+            Command::SetPreDelay(delay) => self.set_reverb_pre_delay(delay).await?,
+            Command::SetLowColour(colour) => self.set_reverb_low_colour(colour).await?,
+            Command::SetHighColour(colour) => self.set_reverb_high_colour(colour).await?,
+            Command::SetHighFactor(factor) => self.set_reverb_high_factor(factor).await?,
+            Command::SetDiffuse(diffuse) => self.set_reverb_diffuse(diffuse).await?,
+            Command::SetModSpeed(speed) => self.set_reverb_mod_speed(speed).await?,
+            Command::SetModDepth(depth) => self.set_reverb_mod_depth(depth).await?,
+        }
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
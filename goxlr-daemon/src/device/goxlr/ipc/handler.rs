@@ -1,26 +1,64 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 use goxlr_ipc::commands::{GoXLRCommand, GoXLRCommandResponse};
 
+use crate::device::goxlr::components::batch::BatchCommands;
 use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::automation::IPCAutomationHandler;
+use crate::device::goxlr::ipc::button_cycles::IPCButtonCycleHandler;
 use crate::device::goxlr::ipc::channels::IPCChannelHandler;
 use crate::device::goxlr::ipc::configuration::IPCConfigurationHandler;
+use crate::device::goxlr::ipc::controls::IPCControlHandler;
+use crate::device::goxlr::ipc::diagnostics::IPCDiagnosticsHandler;
+use crate::device::goxlr::ipc::ducking::IPCDuckingHandler;
+use crate::device::goxlr::ipc::effects::IPCEffectsHandler;
+use crate::device::goxlr::ipc::lighting::IPCLightingHandler;
 use crate::device::goxlr::ipc::microphone::IPCMicrophoneHandler;
+use crate::device::goxlr::ipc::mix_modes::IPCMixModeHandler;
+use crate::device::goxlr::ipc::monitor_mix::IPCMonitorMixHandler;
 use crate::device::goxlr::ipc::pages::IPCPageHandler;
+use crate::device::goxlr::ipc::push_to_talk::IPCPushToTalkHandler;
+use crate::device::goxlr::ipc::sampler::IPCSamplerHandler;
+use crate::device::goxlr::ipc::scribbles::IPCScribbleHandler;
+use crate::device::goxlr::ipc::solo::IPCSoloHandler;
 
 pub type Response = Result<GoXLRCommandResponse>;
 
 pub trait IPCCommandHandler {
     async fn handle_ipc_command(&mut self, command: GoXLRCommand) -> Response;
+
+    /// Handles a command which isn't a Batch, for running the commands inside one
+    async fn handle_single_command(&mut self, command: GoXLRCommand) -> Response;
 }
 
 impl IPCCommandHandler for GoXLR {
     async fn handle_ipc_command(&mut self, command: GoXLRCommand) -> Response {
+        match command {
+            GoXLRCommand::Batch(commands) => self.run_batch(commands).await,
+            command => self.handle_single_command(command).await,
+        }
+    }
+
+    async fn handle_single_command(&mut self, command: GoXLRCommand) -> Response {
         match command {
             GoXLRCommand::Configuration(command) => self.ipc_configuration(command).await,
             GoXLRCommand::Channels(command) => self.ipc_channel(command).await,
             GoXLRCommand::Pages(command) => self.ipc_page(command).await,
             GoXLRCommand::Microphone(command) => self.ipc_microphone(command).await,
+            GoXLRCommand::Effects(command) => self.ipc_effects(command).await,
+            GoXLRCommand::Diagnostics(command) => self.ipc_diagnostics(command).await,
+            GoXLRCommand::MixModes(command) => self.ipc_mix_modes(command).await,
+            GoXLRCommand::Ducking(command) => self.ipc_ducking(command).await,
+            GoXLRCommand::PushToTalk(command) => self.ipc_push_to_talk(command).await,
+            GoXLRCommand::MonitorMix(command) => self.ipc_monitor_mix(command).await,
+            GoXLRCommand::Lighting(command) => self.ipc_lighting(command).await,
+            GoXLRCommand::Solo(command) => self.ipc_solo(command).await,
+            GoXLRCommand::Scribbles(command) => self.ipc_scribbles(command).await,
+            GoXLRCommand::Sampler(command) => self.ipc_sampler(command).await,
+            GoXLRCommand::Controls(command) => self.ipc_controls(command).await,
+            GoXLRCommand::Automation(command) => self.ipc_automation(command).await,
+            GoXLRCommand::ButtonCycles(command) => self.ipc_button_cycles(command).await,
+            GoXLRCommand::Batch(_) => bail!("Batches can't be nested"),
         }
     }
 }
@@ -0,0 +1,44 @@
+use goxlr_ipc::commands::lighting::LightingCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::alert_flash::AlertFlash;
+use crate::device::goxlr::components::colour_preview::ColourPreviewing;
+use crate::device::goxlr::components::idle::IdleDetection;
+use crate::device::goxlr::components::lighting::Lighting;
+use crate::device::goxlr::components::lighting_layers::LightingLayering;
+use crate::device::goxlr::components::lighting_takeover::TakeoverLighting;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = LightingCommand;
+
+pub trait IPCLightingHandler {
+    async fn ipc_lighting(&mut self, command: Command) -> Response;
+}
+
+impl IPCLightingHandler for GoXLR {
+    async fn ipc_lighting(&mut self, command: Command) -> Response {
+        match command {
+            Command::Accent(colour) => self.set_accent_colour(colour).await?,
+            Command::Global(colour) => self.set_global_colour(colour).await?,
+            Command::Brightness(brightness) => self.set_brightness(brightness).await?,
+            Command::IdleTimeout(minutes) => self.set_idle_timeout(minutes),
+            Command::IdleBrightness(brightness) => self.set_idle_brightness(brightness).await?,
+            Command::ChannelLinked(enabled) => self.set_channel_linked(enabled).await?,
+            Command::TakeOver(fps) => self.take_over_lighting(fps)?,
+            Command::Frame(frame) => self.send_lighting_frame(frame).await?,
+            Command::Release => self.release_lighting().await?,
+            Command::Animation(frame) => self.show_animation_frame(frame).await?,
+            Command::StopAnimation => self.stop_animation().await?,
+            Command::FlashButton(target, colour, duration, pattern) => {
+                self.flash_button(target, colour, duration, pattern).await?
+            }
+            Command::PreviewColours(scheme, duration) => {
+                self.preview_colours(scheme, duration).await?
+            }
+            Command::CancelPreview => self.cancel_colour_preview().await?,
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::device::goxlr::components::hard_mute::HardMute;
+use crate::device::goxlr::components::mic::preset::MicPresets;
 use crate::device::goxlr::device::GoXLR;
 use crate::device::goxlr::ipc::handler::Response;
 use crate::device::goxlr::ipc::microphone::compressor::IPCMicCompressorHandler;
@@ -27,8 +29,20 @@ impl IPCMicrophoneHandler for GoXLR {
             Command::Equaliser(command) => self.ipc_mic_equaliser(command).await,
             Command::Compressor(command) => self.ipc_mic_compressor(command).await,
             Command::Gate(command) => self.ipc_mic_gate(command).await,
+            Command::ApplyPreset(preset) => {
+                self.apply_mic_preset(preset).await?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
 
             Command::GetMicLevel => Ok(GoXLRCommandResponse::MicLevel(self.get_mic_level().await?)),
+            Command::HardMute(engaged) => {
+                self.set_hard_mute(engaged).await?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
+            Command::HardMuteButtons(buttons) => {
+                self.set_hard_mute_buttons(buttons)?;
+                Ok(GoXLRCommandResponse::Ok)
+            }
         }
     }
 }
@@ -0,0 +1,26 @@
+use goxlr_ipc::commands::mix_modes::MixModeCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::mix_modes::MixModes;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = MixModeCommand;
+
+pub trait IPCMixModeHandler {
+    async fn ipc_mix_modes(&mut self, command: Command) -> Response;
+}
+
+impl IPCMixModeHandler for GoXLR {
+    async fn ipc_mix_modes(&mut self, command: Command) -> Response {
+        match command {
+            Command::Save(name) => self.save_mix_mode(name).await?,
+            Command::Activate(name) => self.activate_mix_mode(name).await?,
+            Command::Delete(name) => self.delete_mix_mode(name).await?,
+            Command::SetButton(name, button) => self.set_mix_mode_button(name, button).await?,
+            Command::SetCrossfade(duration) => self.set_mix_mode_crossfade(duration)?,
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
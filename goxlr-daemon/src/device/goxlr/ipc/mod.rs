@@ -1,5 +1,18 @@
+mod automation;
+mod button_cycles;
 pub(crate) mod channels;
 pub(crate) mod handler;
+mod effects;
+mod lighting;
 mod microphone;
+mod mix_modes;
+mod monitor_mix;
 mod pages;
+mod push_to_talk;
+mod sampler;
+mod scribbles;
+mod solo;
 mod configuration;
+mod controls;
+mod diagnostics;
+mod ducking;
@@ -0,0 +1,23 @@
+use goxlr_ipc::commands::monitor_mix::MonitorMixCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::monitor_mix::MonitorMix;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = MonitorMixCommand;
+
+pub trait IPCMonitorMixHandler {
+    async fn ipc_monitor_mix(&mut self, command: Command) -> Response;
+}
+
+impl IPCMonitorMixHandler for GoXLR {
+    async fn ipc_monitor_mix(&mut self, command: Command) -> Response {
+        match command {
+            Command::Enabled(enabled) => self.set_monitor_mix_enabled(enabled).await?,
+            Command::Level(input, level) => self.set_monitor_mix_level(input, level).await?,
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
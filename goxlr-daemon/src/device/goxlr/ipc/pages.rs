@@ -1,5 +1,6 @@
 use goxlr_ipc::commands::GoXLRCommandResponse;
 
+use crate::device::goxlr::components::fader_response::FaderResponse;
 use crate::device::goxlr::components::pages::FaderPages;
 use goxlr_ipc::commands::pages::PageCommand;
 
@@ -26,6 +27,10 @@ impl IPCPageHandler for GoXLR {
                 )
                 .await?
             }
+            Command::FaderCurve(fader, curve) => self.set_fader_curve(fader, curve).await?,
+            Command::FaderDeadZones(fader, bottom, top) => {
+                self.set_fader_dead_zones(fader, bottom, top).await?
+            }
         }
 
         Ok(GoXLRCommandResponse::Ok)
@@ -0,0 +1,26 @@
+use goxlr_ipc::commands::push_to_talk::PushToTalkCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::push_to_talk::PushToTalk;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = PushToTalkCommand;
+
+pub trait IPCPushToTalkHandler {
+    async fn ipc_push_to_talk(&mut self, command: Command) -> Response;
+}
+
+impl IPCPushToTalkHandler for GoXLR {
+    async fn ipc_push_to_talk(&mut self, command: Command) -> Response {
+        match command {
+            Command::Enabled(enabled) => self.set_push_to_talk_enabled(enabled).await?,
+            Command::Button(button) => self.set_push_to_talk_button(button),
+            Command::Outputs(outputs) => self.set_push_to_talk_outputs(outputs).await?,
+            Command::ReleaseTail(tail) => self.set_push_to_talk_release_tail(tail),
+            Command::Pressed(pressed) => self.set_push_to_talk_pressed(pressed).await?,
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
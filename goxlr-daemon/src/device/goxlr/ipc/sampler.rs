@@ -0,0 +1,37 @@
+use goxlr_ipc::commands::sampler::SamplerCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::sample_player::SamplePlayback;
+use crate::device::goxlr::components::sampler::SamplerRouting;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = SamplerCommand;
+
+pub trait IPCSamplerHandler {
+    async fn ipc_sampler(&mut self, command: Command) -> Response;
+}
+
+impl IPCSamplerHandler for GoXLR {
+    async fn ipc_sampler(&mut self, command: Command) -> Response {
+        match command {
+            Command::BankOutputs(bank, outputs) => {
+                self.set_sample_bank_outputs(bank, outputs).await?;
+            }
+            Command::ButtonOutputs(bank, button, outputs) => {
+                self.set_sample_button_outputs(bank, button, outputs)
+                    .await?;
+            }
+            Command::File(bank, button, file) => self.set_sample_file(bank, button, file),
+            Command::Playlist(bank, button, files) => self.set_sample_playlist(bank, button, files),
+            Command::Play(bank, button) => self.play_sample(bank, button).await?,
+            Command::Stop => self.stop_sample().await?,
+            Command::Enqueue(file) => self.enqueue_sample(file)?,
+            Command::Dequeue(index) => self.dequeue_sample(index)?,
+            Command::Skip => self.skip_sample().await?,
+            Command::Playing(sample) => self.set_playing_sample(sample).await?,
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
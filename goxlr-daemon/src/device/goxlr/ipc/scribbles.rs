@@ -0,0 +1,23 @@
+use goxlr_ipc::commands::scribbles::ScribbleCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::scribble_widgets::ScribbleWidgetHandler;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = ScribbleCommand;
+
+pub trait IPCScribbleHandler {
+    async fn ipc_scribbles(&mut self, command: Command) -> Response;
+}
+
+impl IPCScribbleHandler for GoXLR {
+    async fn ipc_scribbles(&mut self, command: Command) -> Response {
+        match command {
+            Command::ShowWidget(fader, widget) => self.show_scribble_widget(fader, widget)?,
+            Command::ClearWidget(fader) => self.clear_scribble_widget(fader).await?,
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
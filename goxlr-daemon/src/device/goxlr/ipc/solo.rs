@@ -0,0 +1,24 @@
+use goxlr_ipc::commands::solo::SoloCommand;
+use goxlr_ipc::commands::GoXLRCommandResponse;
+
+use crate::device::goxlr::components::solo::ChannelSolo;
+use crate::device::goxlr::device::GoXLR;
+use crate::device::goxlr::ipc::handler::Response;
+
+type Command = SoloCommand;
+
+pub trait IPCSoloHandler {
+    async fn ipc_solo(&mut self, command: Command) -> Response;
+}
+
+impl IPCSoloHandler for GoXLR {
+    async fn ipc_solo(&mut self, command: Command) -> Response {
+        match command {
+            Command::Set(input) => self.set_solo(input).await?,
+            Command::Button(button) => self.set_solo_button(button).await?,
+            Command::Input(input) => self.set_solo_input(input),
+        }
+
+        Ok(GoXLRCommandResponse::Ok)
+    }
+}
@@ -4,8 +4,15 @@ use goxlr_ipc::commands::{
     DaemonCommand, DaemonResponse, DaemonStatus, GoXLRCommand, GoXLRCommandResponse,
 };
 
+// The bool on the Run messages is whether the command was Forced past Live Mode
 pub enum DeviceMessage {
     GetStatus(oneshot::Sender<DaemonStatus>),
-    RunDaemon(DaemonCommand, oneshot::Sender<DaemonResponse>),
-    RunDevice(String, GoXLRCommand, oneshot::Sender<GoXLRCommandResponse>),
+    RunDaemon(DaemonCommand, bool, oneshot::Sender<DaemonResponse>),
+    RunDevice(
+        String,
+        GoXLRCommand,
+        bool,
+        oneshot::Sender<GoXLRCommandResponse>,
+    ),
+    DryRunDevice(String, GoXLRCommand, oneshot::Sender<GoXLRCommandResponse>),
 }
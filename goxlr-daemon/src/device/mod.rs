@@ -1,6 +1,9 @@
+mod claim;
 mod messaging;
 
 pub mod device_manager;
 pub mod packet;
 
+pub(crate) mod watchdog;
+
 pub(crate) mod goxlr;
@@ -2,9 +2,13 @@ use anyhow::{anyhow, Context, Result};
 use tokio::sync::mpsc::Sender;
 use tokio::sync::oneshot;
 
-use goxlr_ipc::commands::{DaemonRequest, DaemonResponse, DeviceCommand};
+use goxlr_ipc::commands::{
+    DaemonCommand, DaemonRequest, DaemonResponse, DeviceCommand, GoXLRCommand, ProfileImport,
+    SessionResume,
+};
 
 use crate::device::messaging::DeviceMessage;
+use crate::imports::fetch_shared_profile;
 
 pub type Messenger = Sender<DeviceMessage>;
 type Response = Result<DaemonResponse>;
@@ -14,6 +18,13 @@ pub async fn handle_packet(request: DaemonRequest, sender: Messenger) -> Respons
     // Ok, we just match the request, and send it off where it needs to go..
     match request {
         DaemonRequest::Ping => Ok(DaemonResponse::Ok),
+
+        // Subscriptions are tracked by the transport, by the time we get here there's nothing
+        // left to do (the websocket is always subscribed).
+        DaemonRequest::Subscribe | DaemonRequest::Unsubscribe => Ok(DaemonResponse::Ok),
+        DaemonRequest::SubscribeFaderEvents | DaemonRequest::UnsubscribeFaderEvents => Ok(
+            DaemonResponse::Err(String::from("Fader Events are only available over IPC")),
+        ),
         DaemonRequest::GetStatus => {
             let (tx, rx) = oneshot::channel();
 
@@ -26,30 +37,96 @@ pub async fn handle_packet(request: DaemonRequest, sender: Messenger) -> Respons
             let result = rx.await.context("Error from device manager")?;
             Ok(DaemonResponse::Status(result))
         }
-        DaemonRequest::Daemon(daemon_command) => {
+        DaemonRequest::ResumeSession(position) => {
             let (tx, rx) = oneshot::channel();
+
             sender
-                .send(DeviceMessage::RunDaemon(daemon_command, tx))
+                .send(DeviceMessage::GetStatus(tx))
                 .await
                 .map_err(|e| anyhow!(e.to_string()))
                 .context("Failed to send message to device manager")?;
 
-            rx.await.context("Error from device manager")?;
-            Ok(DaemonResponse::Ok)
+            let status = rx.await.context("Error from device manager")?;
+            let resumed = position.is_some_and(|position| {
+                position.session == status.session && position.sequence == status.sequence
+            });
+            Ok(DaemonResponse::Session(SessionResume { status, resumed }))
         }
-        DaemonRequest::DeviceCommand(command) => {
-            let DeviceCommand { serial, command } = command;
-            {
-                let (tx, rx) = oneshot::channel();
-                sender
-                    .send(DeviceMessage::RunDevice(serial, command, tx))
-                    .await
-                    .map_err(|e| anyhow!(e.to_string()))
-                    .context("Failed to send message to device manager")?;
-
-                let result = rx.await.context("Error from Device Manager")?;
-                Ok(DaemonResponse::DeviceCommand(result))
-            }
+        DaemonRequest::Daemon(command) => run_daemon(command, false, sender).await,
+        DaemonRequest::DeviceCommand(command) => run_device(command, false, sender).await,
+        DaemonRequest::Forced(request) => match *request {
+            DaemonRequest::Daemon(command) => run_daemon(command, true, sender).await,
+            DaemonRequest::DeviceCommand(command) => run_device(command, true, sender).await,
+            _ => Ok(DaemonResponse::Err(String::from(
+                "Only Daemon and Device commands can be Forced",
+            ))),
+        },
+        DaemonRequest::DryRun(command) => run_dry(command, sender).await,
+    }
+}
+
+async fn run_daemon(command: DaemonCommand, force: bool, sender: Messenger) -> Response {
+    // Imports wait on the network, so they're handled here rather than holding up the manager
+    match command {
+        DaemonCommand::PreviewProfileImport(url) => {
+            return match fetch_shared_profile(&url).await {
+                Ok(shared) => Ok(DaemonResponse::ProfileImport(shared.get_preview())),
+                Err(error) => Ok(DaemonResponse::Err(format!("{:#}", error))),
+            };
         }
+        DaemonCommand::ImportProfile(import) => return run_import(import, force, sender).await,
+        _ => {}
     }
+
+    let (tx, rx) = oneshot::channel();
+    sender
+        .send(DeviceMessage::RunDaemon(command, force, tx))
+        .await
+        .map_err(|e| anyhow!(e.to_string()))
+        .context("Failed to send message to device manager")?;
+
+    let result = rx.await.context("Error from device manager")?;
+    Ok(result)
+}
+
+async fn run_import(import: ProfileImport, force: bool, sender: Messenger) -> Response {
+    let shared = match fetch_shared_profile(&import.url).await {
+        Ok(shared) => shared,
+        Err(error) => return Ok(DaemonResponse::Err(format!("{:#}", error))),
+    };
+
+    if import.digest.is_some_and(|digest| digest != shared.digest) {
+        let error = "The Profile has changed since it was previewed";
+        return Ok(DaemonResponse::Err(error.to_string()));
+    }
+
+    let command = GoXLRCommand::Configuration(shared.into_command());
+    let serial = import.serial;
+    run_device(DeviceCommand { serial, command }, force, sender).await
+}
+
+async fn run_device(command: DeviceCommand, force: bool, sender: Messenger) -> Response {
+    let DeviceCommand { serial, command } = command;
+    let (tx, rx) = oneshot::channel();
+    sender
+        .send(DeviceMessage::RunDevice(serial, command, force, tx))
+        .await
+        .map_err(|e| anyhow!(e.to_string()))
+        .context("Failed to send message to device manager")?;
+
+    let result = rx.await.context("Error from Device Manager")?;
+    Ok(DaemonResponse::DeviceCommand(result))
+}
+
+async fn run_dry(command: DeviceCommand, sender: Messenger) -> Response {
+    let DeviceCommand { serial, command } = command;
+    let (tx, rx) = oneshot::channel();
+    sender
+        .send(DeviceMessage::DryRunDevice(serial, command, tx))
+        .await
+        .map_err(|e| anyhow!(e.to_string()))
+        .context("Failed to send message to device manager")?;
+
+    let result = rx.await.context("Error from Device Manager")?;
+    Ok(DaemonResponse::DeviceCommand(result))
 }
@@ -0,0 +1,115 @@
+/*
+   The Watchdog keeps an eye on a device's event loop, if it stops ticking and isn't getting
+   responses from the device, it's likely stuck waiting on USB and will never recover by itself.
+   It also watches for USB commands failing over and over (such as after a flaky hub event),
+   where the loop is still running, but the device needs to be torn down and reconnected.
+*/
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use log::{debug, error};
+use tokio::sync::mpsc;
+use tokio::time;
+
+use goxlr_usb::USBLocation;
+
+use crate::device::device_manager::RunnerMessage;
+
+// How often to check the heartbeat, this doesn't need to be particularly precise
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Marks the last time the event loop made progress, either by ticking or by getting a response
+/// from the device.
+#[derive(Debug, Clone)]
+pub(crate) struct Heartbeat {
+    last_beat: Arc<Mutex<Instant>>,
+
+    // How many USB commands have failed in a row
+    failures: Arc<AtomicU32>,
+}
+
+impl Heartbeat {
+    pub fn new() -> Self {
+        Self {
+            last_beat: Arc::new(Mutex::new(Instant::now())),
+            failures: Arc::new(AtomicU32::new(0)),
+        }
+    }
+
+    /// Records whether a USB command succeeded, any success resets the failure count
+    pub fn record_usb_result(&self, success: bool) {
+        match success {
+            true => self.failures.store(0, Ordering::Relaxed),
+            false => {
+                self.failures.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub fn failures(&self) -> u32 {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    pub fn beat(&self) {
+        if let Ok(mut last_beat) = self.last_beat.lock() {
+            *last_beat = Instant::now();
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        match self.last_beat.lock() {
+            Ok(last_beat) => last_beat.elapsed(),
+            Err(_) => Duration::ZERO,
+        }
+    }
+}
+
+/// Runs until the heartbeat has been missing for longer than the timeout, or too many USB
+/// commands have failed in a row, then lets the manager know that the device needs resetting.
+/// Either check can be disabled, this is expected to be aborted when the device stops.
+pub(crate) async fn start_watchdog(
+    device: USBLocation,
+    heartbeat: Heartbeat,
+    timeout: Option<Duration>,
+    failure_limit: Option<u32>,
+    sender: mpsc::Sender<RunnerMessage>,
+) {
+    debug!(
+        "[Watchdog]{} Starting, timeout {:?}, failure limit {:?}",
+        device, timeout, failure_limit
+    );
+    let mut ticker = time::interval(CHECK_INTERVAL);
+
+    loop {
+        ticker.tick().await;
+
+        if let Some(limit) = failure_limit {
+            let failures = heartbeat.failures();
+            if failures >= limit {
+                error!(
+                    "[Watchdog]{} {} USB Commands failed in a row, reconnecting device",
+                    device, failures
+                );
+                let _ = sender.send(RunnerMessage::Failing(device, failures)).await;
+                return;
+            }
+        }
+
+        let Some(timeout) = timeout else {
+            continue;
+        };
+
+        let elapsed = heartbeat.elapsed();
+        if elapsed >= timeout {
+            error!(
+                "[Watchdog]{} Event Loop hasn't responded in {}s, resetting device",
+                device,
+                elapsed.as_secs()
+            );
+            let _ = sender.send(RunnerMessage::Hung(device, elapsed)).await;
+            return;
+        }
+    }
+}
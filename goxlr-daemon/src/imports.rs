@@ -0,0 +1,253 @@
+/*
+   Profiles shared by URL, so a setup can be passed around as a link (or a QR code of one). A
+   shared profile is fetched, validated and summarised before anything is applied, and the
+   summary's digest lets a client make sure what's applied is exactly what was previewed.
+*/
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use log::debug;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::redirect::Policy;
+use reqwest::{Client, Url};
+use sha2::{Digest, Sha256};
+use strum::IntoEnumIterator;
+use tokio::net::lookup_host;
+
+use goxlr_ipc::commands::configuration::ConfigurationCommand;
+use goxlr_ipc::commands::ProfileImportPreview;
+use goxlr_ipc::status::Configuration;
+use goxlr_profile::Profile;
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+
+// Profiles are a few tens of KB, anything much larger isn't one
+const MAX_SIZE: usize = 1024 * 1024;
+const TIMEOUT: Duration = Duration::from_secs(15);
+
+// Pastebins and raw file hosts don't always say they're serving JSON, but a web page never is
+const ALLOWED_TYPES: [&str; 3] = ["application/json", "text/plain", "application/octet-stream"];
+
+/// A profile which has been fetched, and parsed
+pub struct SharedProfile {
+    pub url: String,
+    pub size: usize,
+    pub digest: String,
+    content: SharedContent,
+}
+
+enum SharedContent {
+    Profile(Box<Profile>),
+
+    // A Backup's device entry, or the configuration section of a status, includes the Mic
+    Configuration(Box<Configuration>),
+}
+
+impl SharedProfile {
+    pub fn get_preview(&self) -> ProfileImportPreview {
+        let profile = self.get_profile();
+        let rules = &profile.routing_rules;
+
+        let mut routes = vec![];
+        for input in InputChannels::iter() {
+            for output in OutputChannels::iter() {
+                if profile.routing[input][output] {
+                    routes.push((input, output));
+                }
+            }
+        }
+
+        let cycles = Buttons::iter().filter(|button| profile.button_cycles[*button].is_some());
+
+        let sampler = &profile.sampler;
+        let files = sampler
+            .samples
+            .values()
+            .flat_map(|bank| bank.values().flatten());
+        let playlists = sampler
+            .playlists
+            .values()
+            .flat_map(|bank| bank.values().flatten());
+        let mut samples: Vec<_> = files.chain(playlists).cloned().collect();
+        samples.sort();
+        samples.dedup();
+
+        ProfileImportPreview {
+            url: self.url.clone(),
+            size: self.size,
+            digest: self.digest.clone(),
+            mic_profile: matches!(self.content, SharedContent::Configuration(_)),
+            pages: profile.pages.page_list.len(),
+            mix_modes: profile.mix_modes.modes.keys().cloned().collect(),
+            lighting: profile.lighting,
+            routes,
+            routing_rules: rules.rules.iter().map(|rule| rule.name.clone()).collect(),
+            voice_applications: rules.voice_applications.clone(),
+            button_cycles: cycles.collect(),
+            samples,
+        }
+    }
+
+    /// The command which loads this profile onto a device
+    pub fn into_command(self) -> ConfigurationCommand {
+        match self.content {
+            SharedContent::Profile(profile) => ConfigurationCommand::LoadProfile(profile),
+            SharedContent::Configuration(config) => ConfigurationCommand::Restore(config),
+        }
+    }
+
+    fn get_profile(&self) -> &Profile {
+        match &self.content {
+            SharedContent::Profile(profile) => profile,
+            SharedContent::Configuration(config) => &config.device,
+        }
+    }
+}
+
+pub async fn fetch_shared_profile(url: &str) -> Result<SharedProfile> {
+    if !url.starts_with("https://") && !url.starts_with("http://") {
+        bail!("Profiles can only be imported from http or https URLs");
+    }
+
+    debug!("Fetching Shared Profile from {}", url);
+    let url = Url::parse(url).context("Invalid URL")?;
+    let client = get_client(&url).await?;
+    let mut response = client.get(url.clone()).send().await?.error_for_status()?;
+
+    // Redirects aren't followed, as where they lead hasn't been checked
+    if response.status().is_redirection() {
+        bail!("The URL redirects somewhere else, import from there instead");
+    }
+
+    if let Some(length) = response.content_length() {
+        if length as usize > MAX_SIZE {
+            bail!("The Profile is too large ({} bytes)", length);
+        }
+    }
+
+    if let Some(content_type) = response.headers().get(CONTENT_TYPE) {
+        let content_type = content_type.to_str().unwrap_or_default();
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+        if !ALLOWED_TYPES.contains(&mime.to_lowercase().as_str()) {
+            bail!("The URL is {}, rather than a Profile", mime);
+        }
+    }
+
+    // The length can't be trusted, so the limit is checked again as the body arrives
+    let mut body = vec![];
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > MAX_SIZE {
+            bail!("The Profile is larger than {} bytes", MAX_SIZE);
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    let shared = SharedProfile {
+        url: url.to_string(),
+        size: body.len(),
+        digest: get_digest(&body),
+        content: parse_shared_profile(&body)?,
+    };
+    validate_profile(shared.get_profile())?;
+    Ok(shared)
+}
+
+fn parse_shared_profile(body: &[u8]) -> Result<SharedContent> {
+    if let Ok(config) = serde_json::from_slice::<Configuration>(body) {
+        return Ok(SharedContent::Configuration(Box::new(config)));
+    }
+
+    let profile = serde_json::from_slice::<Profile>(body).context("The URL isn't a Profile")?;
+    Ok(SharedContent::Profile(Box::new(profile)))
+}
+
+/// Builds a client which can only reach the URL's host at an address we've checked. Without
+/// this a shared link could be used to reach things on the local network, or the daemon's own
+/// HTTP server.
+async fn get_client(url: &Url) -> Result<Client> {
+    let host = url.host_str().context("The URL has no host")?;
+    let port = url.port_or_known_default().context("The URL has no port")?;
+
+    // IPv6 addresses are bracketed in URLs, but not when they're looked up
+    let name = host.trim_start_matches('[').trim_end_matches(']');
+    let addresses: Vec<SocketAddr> = match name.parse::<IpAddr>() {
+        Ok(address) => vec![SocketAddr::new(address, port)],
+        Err(_) => lookup_host((name, port)).await?.collect(),
+    };
+
+    if let Some(address) = addresses.iter().find(|address| !is_public(address.ip())) {
+        bail!("Profiles can't be imported from {}", address.ip());
+    }
+    let address = addresses
+        .first()
+        .context("The URL's host couldn't be found")?;
+
+    // The request goes to the address which was checked, the host can't be looked up again
+    let client = Client::builder()
+        .timeout(TIMEOUT)
+        .redirect(Policy::none())
+        .resolve(name, *address)
+        .build()?;
+    Ok(client)
+}
+
+fn is_public(address: IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => {
+            let [first, second, ..] = address.octets();
+
+            // The Carrier Grade NAT range (100.64.0.0/10) isn't reachable from the internet
+            let shared = first == 100 && (second & 0xc0) == 64;
+            !(address.is_loopback()
+                || address.is_private()
+                || address.is_link_local()
+                || address.is_unspecified()
+                || address.is_broadcast()
+                || address.is_multicast()
+                || address.is_documentation()
+                || shared)
+        }
+        IpAddr::V6(address) => {
+            if let Some(mapped) = address.to_ipv4_mapped() {
+                return is_public(IpAddr::V4(mapped));
+            }
+
+            // Unique Local (fc00::/7) and Link Local (fe80::/10) addresses
+            let segment = address.segments()[0];
+            let local = (segment & 0xfe00) == 0xfc00 || (segment & 0xffc0) == 0xfe80;
+            !(address.is_loopback() || address.is_unspecified() || address.is_multicast() || local)
+        }
+    }
+}
+
+/// Checks the things a profile can hold which it'd parse fine with, but which would break
+/// something once loaded
+fn validate_profile(profile: &Profile) -> Result<()> {
+    let pages = &profile.pages;
+    if pages.page_list.is_empty() || pages.current >= pages.page_list.len() {
+        bail!("The Profile's Fader Pages are invalid");
+    }
+
+    for button in Buttons::iter() {
+        let Some(cycle) = &profile.button_cycles[button] else {
+            continue;
+        };
+        if cycle.steps.is_empty() || cycle.current >= cycle.steps.len() {
+            bail!("The Profile's Button Cycle on {:?} is invalid", button);
+        }
+    }
+
+    let transition = &profile.ducking.transition;
+    if transition.ducking.is_empty() || transition.unducking.is_empty() {
+        bail!("The Profile's Ducking transitions need at least one step");
+    }
+    Ok(())
+}
+
+fn get_digest(body: &[u8]) -> String {
+    let digest = Sha256::digest(body);
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
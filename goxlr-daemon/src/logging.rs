@@ -0,0 +1,151 @@
+/*
+   Sets up logging to the terminal, and optionally to a set of rotating files on disk so that logs
+   can be retrieved for bug reports.
+*/
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use log::LevelFilter;
+use simplelog::{
+    ColorChoice, CombinedLogger, ConfigBuilder, SharedLogger, TermLogger, TerminalMode, WriteLogger,
+};
+
+use crate::settings::LogSettings;
+
+const LOG_NAME: &str = "goxlr-daemon";
+
+pub fn init_logging(settings: &LogSettings) -> Result<()> {
+    let mut loggers: Vec<Box<dyn SharedLogger>> = vec![TermLogger::new(
+        LevelFilter::Debug,
+        ConfigBuilder::new().build(),
+        TerminalMode::Mixed,
+        ColorChoice::Auto,
+    )];
+
+    if let Some(directory) = &settings.directory {
+        let file = RotatingFile::new(directory, settings.clone())
+            .context("Could not open the log file")?;
+        loggers.push(WriteLogger::new(
+            LevelFilter::Debug,
+            ConfigBuilder::new().build(),
+            file,
+        ));
+    }
+
+    CombinedLogger::init(loggers).context("Could not configure the logger")?;
+    Ok(())
+}
+
+/// Returns the path of the log file currently being written to
+pub fn get_log_path(directory: &Path) -> PathBuf {
+    directory.join(format!("{}.log", LOG_NAME))
+}
+
+/// Reads the last lines from the current log file
+pub fn read_log_tail(directory: &Path, lines: usize) -> Result<Vec<String>> {
+    let file = File::open(get_log_path(directory)).context("Unable to open the log file")?;
+
+    let mut tail = VecDeque::with_capacity(lines);
+    for line in BufReader::new(file).lines() {
+        if tail.len() >= lines {
+            tail.pop_front();
+        }
+        tail.push_back(line?);
+    }
+    Ok(tail.into())
+}
+
+/// A log file which is moved aside once it gets too large or too old. Old files are numbered,
+/// with .1 being the most recent, and anything beyond the configured count is removed.
+struct RotatingFile {
+    directory: PathBuf,
+    settings: LogSettings,
+
+    file: File,
+    size: u64,
+    opened: Instant,
+}
+
+impl RotatingFile {
+    fn new(directory: &Path, settings: LogSettings) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+
+        let file = open_log(directory)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            settings,
+            file,
+            size,
+            opened: Instant::now(),
+        })
+    }
+
+    fn needs_rotation(&self) -> bool {
+        if self.size >= self.settings.max_size {
+            return true;
+        }
+
+        match self.settings.max_age {
+            Some(max_age) => self.size > 0 && self.opened.elapsed() >= max_age,
+            None => false,
+        }
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        // Shuffle the old files up, dropping the oldest..
+        let rotated = |index| self.directory.join(format!("{}.{}.log", LOG_NAME, index));
+        let oldest = rotated(self.settings.keep);
+        if oldest.exists() {
+            fs::remove_file(oldest)?;
+        }
+        for index in (1..self.settings.keep).rev() {
+            let path = rotated(index);
+            if path.exists() {
+                fs::rename(path, rotated(index + 1))?;
+            }
+        }
+
+        let current = get_log_path(&self.directory);
+        if self.settings.keep > 0 {
+            fs::rename(&current, rotated(1))?;
+        } else {
+            fs::remove_file(&current)?;
+        }
+
+        self.file = open_log(&self.directory)?;
+        self.size = 0;
+        self.opened = Instant::now();
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.needs_rotation() {
+            self.rotate()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn open_log(directory: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_log_path(directory))
+}
@@ -1,31 +1,62 @@
-use anyhow::{bail, Context, Result};
+use anyhow::{bail, Result};
+use clap::Parser;
 use goxlr_ipc::commands::HttpSettings;
-use log::{debug, error, LevelFilter};
-use simplelog::{ColorChoice, CombinedLogger, ConfigBuilder, TermLogger, TerminalMode};
+use log::{debug, error};
 use tokio::sync::{broadcast, mpsc};
 use tokio::{join, task};
 
+use crate::cli::Cli;
 use crate::device::device_manager::start_device_manager;
+use crate::logging::init_logging;
 use crate::platform::spawn_runtime;
+use crate::replay::{load_replay, run_replay};
+use crate::schema::print_schema;
 use crate::servers::http_server::spawn_http_server;
 use crate::servers::ipc_server::{bind_socket, spawn_ipc_server};
+use crate::simulate::{load_simulation, run_simulation};
 use crate::stop::Stop;
+use crate::updates::apply_staged_update;
 
+mod audio_cues;
+mod backups;
+mod cli;
 mod device;
+mod imports;
+mod logging;
+mod notifications;
+mod replay;
+mod schema;
 mod servers;
 mod settings;
+mod simulate;
 mod stop;
+mod storage;
+mod updates;
 mod platform;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    CombinedLogger::init(vec![TermLogger::new(
-        LevelFilter::Debug,
-        ConfigBuilder::new().build(),
-        TerminalMode::Mixed,
-        ColorChoice::Auto,
-    )])
-    .context("Could not configure the logger")?;
+    let cli = Cli::parse();
+
+    // Printing a schema doesn't need the daemon to start, or anything to be logged
+    if let Some(format) = cli.export_schema {
+        return print_schema(format);
+    }
+
+    let log_settings = cli.log_settings();
+    init_logging(&log_settings)?;
+
+    // A simulation runs on its own, without any of the servers, and exits once it's printed
+    if let Some(profile) = &cli.simulate {
+        let simulation = load_simulation(profile, cli.mic_trace.as_deref())?;
+        return run_simulation(simulation, cli.device_settings()).await;
+    }
+
+    // Load the replay now, so a bad file stops us before anything starts..
+    let replay = match &cli.replay {
+        Some(path) => Some(load_replay(path)?),
+        None => None,
+    };
 
     // Spawn the Shutdown Handler..
     let shutdown = Stop::new();
@@ -33,8 +64,17 @@ async fn main() -> Result<()> {
     // Create the Global Manager Channels..
     let (manager_send, manager_recv) = mpsc::channel(32);
 
+    // Create the Patch broadcaster, used to notify subscribed clients of changes..
+    let (broadcast_tx, broadcast_rx) = broadcast::channel(16);
+    drop(broadcast_rx);
+
+    // And the Fader Event broadcaster, for clients following physical fader movements
+    let (fader_tx, fader_rx) = broadcast::channel(64);
+    drop(fader_rx);
+
     // Prepare the IPC Socket..
-    let ipc_socket = bind_socket().await;
+    let ipc_settings = cli.ipc_settings();
+    let ipc_socket = bind_socket(&ipc_settings).await;
     if ipc_socket.is_err() {
         error!("Error Starting Daemon: ");
         bail!("{}", ipc_socket.err().unwrap());
@@ -42,7 +82,10 @@ async fn main() -> Result<()> {
     let ipc_socket = ipc_socket.unwrap();
     let communications_handle = tokio::spawn(spawn_ipc_server(
         ipc_socket,
+        ipc_settings,
         manager_send.clone(),
+        broadcast_tx.clone(),
+        fader_tx.clone(),
         shutdown.clone(),
     ));
 
@@ -52,11 +95,11 @@ async fn main() -> Result<()> {
         bind_address: "localhost".to_string(),
         cors_enabled: false,
         port: 14564,
+        rate_limit: cli.http_rate_limit,
+        rate_burst: cli.http_rate_burst,
     };
 
     let (httpd_tx, httpd_rx) = tokio::sync::oneshot::channel();
-    let (broadcast_tx, broadcast_rx) = broadcast::channel(16);
-    drop(broadcast_rx);
 
     tokio::spawn(spawn_http_server(
         manager_send.clone(),
@@ -66,6 +109,17 @@ async fn main() -> Result<()> {
     ));
     let http_server = httpd_rx.await?;
 
+    // Prepare the gRPC Server, if it's been asked for..
+    #[cfg(feature = "grpc")]
+    if let Some(port) = cli.grpc_port {
+        tokio::spawn(servers::grpc_server::spawn_grpc_server(
+            port,
+            manager_send.clone(),
+            broadcast_tx.clone(),
+            shutdown.clone(),
+        ));
+    }
+
     // We're going to go to sleep, then trigger the shutdown..
     // sleep(Duration::from_secs(5)).await;
     // shutdown.trigger();
@@ -74,13 +128,28 @@ async fn main() -> Result<()> {
         manager_recv,
         shutdown.clone(),
         broadcast_tx.clone(),
+        fader_tx,
+        cli.device_settings(),
+        log_settings.directory,
     ));
     
+    if let Some(replay) = replay {
+        task::spawn(run_replay(replay, shutdown.clone()));
+    }
+
     let runtime = task::spawn(spawn_runtime(shutdown.clone()));
     
     let _ = join!(task, communications_handle, runtime);
     http_server.stop(false).await;
 
+    // Anything staged is applied now everything's stopped, ready for the next start
+    let updates = cli.update_settings();
+    if updates.channel.is_some() {
+        if let Err(error) = apply_staged_update(&updates.directory) {
+            error!("Unable to apply the Update: {:#}", error);
+        }
+    }
+
     debug!("Should be done!");
     Ok(())
 }
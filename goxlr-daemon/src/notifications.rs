@@ -0,0 +1,72 @@
+use log::{debug, warn};
+use notify_rust::Notification as DesktopNotification;
+use tokio::task;
+
+use crate::settings::NotificationSettings;
+
+/// Events which can be shown to the user as a desktop notification
+#[derive(Debug, Clone)]
+pub enum Notification {
+    MicMuted(String),
+    MicUnmuted(String),
+    ProfileLoaded(String, Option<String>),
+    Disconnected(Option<String>),
+}
+
+impl Notification {
+    fn is_enabled(&self, settings: &NotificationSettings) -> bool {
+        match self {
+            Notification::MicMuted(_) | Notification::MicUnmuted(_) => settings.mic_mute,
+            Notification::ProfileLoaded(..) => settings.profile,
+            Notification::Disconnected(_) => settings.disconnect,
+        }
+    }
+
+    fn get_text(&self) -> (String, String) {
+        match self {
+            Notification::MicMuted(serial) => (
+                String::from("Microphone Muted"),
+                format!("GoXLR {}", serial),
+            ),
+            Notification::MicUnmuted(serial) => {
+                (String::from("Microphone Live"), format!("GoXLR {}", serial))
+            }
+            Notification::ProfileLoaded(serial, mix_mode) => {
+                let body = match mix_mode {
+                    Some(mix_mode) => format!("GoXLR {} is now using '{}'", serial, mix_mode),
+                    None => format!("GoXLR {} has reloaded its Profile", serial),
+                };
+                (String::from("Profile Loaded"), body)
+            }
+            Notification::Disconnected(serial) => {
+                let body = match serial {
+                    Some(serial) => format!("GoXLR {} has been removed", serial),
+                    None => String::from("A GoXLR has been removed"),
+                };
+                (String::from("GoXLR Disconnected"), body)
+            }
+        }
+    }
+}
+
+/// Shows a desktop notification, if it's enabled. Showing a notification can block while the
+/// desktop responds, so it's handed off rather than holding up the caller.
+pub fn send_notification(settings: &NotificationSettings, notification: Notification) {
+    if !notification.is_enabled(settings) {
+        return;
+    }
+
+    debug!("Sending Desktop Notification: {:?}", notification);
+    let (summary, body) = notification.get_text();
+    task::spawn_blocking(move || {
+        let result = DesktopNotification::new()
+            .appname("GoXLR")
+            .summary(&summary)
+            .body(&body)
+            .show();
+
+        if let Err(error) = result {
+            warn!("Unable to show Desktop Notification: {}", error);
+        }
+    });
+}
@@ -1,6 +1,7 @@
 use crate::stop::Stop;
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use log::debug;
+use tokio::process::Command;
 use tokio::select;
 use tokio::signal::ctrl_c;
 use tokio::signal::unix::{signal, SignalKind};
@@ -27,3 +28,34 @@ pub async fn spawn_platform_runtime(mut stop: Stop) -> Result<()> {
     debug!("Platform Runtime Ended");
     Ok(())
 }
+
+/// Lists the applications with an audio stream open (playing or recording), by both their name
+/// and executable. pactl is used as it works with both PulseAudio and PipeWire.
+pub async fn get_audio_applications() -> Result<Vec<String>> {
+    let mut applications = vec![];
+    for list in ["sink-inputs", "source-outputs"] {
+        let output = Command::new("pactl")
+            .args(["list", list])
+            .output()
+            .await
+            .context("Unable to run pactl")?;
+
+        if !output.status.success() {
+            bail!("pactl failed: {}", String::from_utf8_lossy(&output.stderr));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        for line in output.lines() {
+            let Some((key, value)) = line.trim().split_once(" = ") else {
+                continue;
+            };
+            if key == "application.name" || key == "application.process.binary" {
+                let value = value.trim_matches('"').to_string();
+                if !applications.contains(&value) {
+                    applications.push(value);
+                }
+            }
+        }
+    }
+    Ok(applications)
+}
@@ -9,23 +9,39 @@ cfg_if! {
         pub async fn spawn_runtime(stop: Stop) -> Result<()> {
             windows::spawn_platform_runtime(stop).await
         }
+
+        pub async fn get_audio_applications() -> Result<Vec<String>> {
+            anyhow::bail!("Detecting Audio Applications isn't supported on Windows yet")
+        }
     } else if #[cfg(target_os = "linux")] {
         mod linux;
 
         pub async fn spawn_runtime(stop: Stop) -> Result<()> {
             linux::spawn_platform_runtime(stop).await
         }
+
+        pub async fn get_audio_applications() -> Result<Vec<String>> {
+            linux::get_audio_applications().await
+        }
     } else if #[cfg(target_os = "macos")] {
         mod macos;
 
         pub async fn spawn_runtime(_stop: Stop) -> Result<()> {
             Ok(())
         }
+
+        pub async fn get_audio_applications() -> Result<Vec<String>> {
+            anyhow::bail!("Detecting Audio Applications isn't supported on macOS yet")
+        }
     } else {
         use anyhow::bail;
 
         pub async fn spawn_runtime(_stop: Stop) -> Result<()> {
             Ok(())
         }
+
+        pub async fn get_audio_applications() -> Result<Vec<String>> {
+            bail!("Detecting Audio Applications isn't supported on this platform")
+        }
     }
 }
@@ -0,0 +1,97 @@
+/*
+   Replays a recorded session against a Mock Device, so that timing sensitive behaviour can be
+   reproduced without the original hardware (or user). The recording format matches the Flight
+   Recorder dump, any events which aren't physical interactions are skipped.
+*/
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use log::{debug, info};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::select;
+use tokio::time::{sleep_until, Instant};
+
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::encoders::Encoders;
+use goxlr_shared::faders::Fader;
+use goxlr_usb::mock::MockDevice;
+
+use crate::stop::Stop;
+
+const REPLAY_SERIAL: &str = "REPLAY000000";
+
+#[derive(Debug, Deserialize)]
+enum ReplayEvent {
+    ButtonDown(Buttons),
+    ButtonUp(Buttons),
+    VolumeChange(Fader, u8),
+    EncoderChange(Encoders, i8),
+    MicLevel(f64),
+}
+
+#[derive(Debug)]
+pub struct ReplayEntry {
+    /// Milliseconds since the Unix Epoch
+    timestamp: u64,
+    event: ReplayEvent,
+}
+
+pub fn load_replay(path: &Path) -> Result<Vec<ReplayEntry>> {
+    let content = fs::read_to_string(path).context("Unable to read the Replay file")?;
+    let recorded: Vec<Value> = serde_json::from_str(&content)?;
+
+    let mut entries = vec![];
+    for entry in recorded {
+        let Some(timestamp) = entry["timestamp"].as_u64() else {
+            bail!("Replay entry is missing a timestamp: {}", entry);
+        };
+
+        // Anything that isn't an interaction (commands, errors, etc) is just context
+        if let Ok(event) = serde_json::from_value(entry["event"].clone()) {
+            entries.push(ReplayEntry { timestamp, event });
+        }
+    }
+
+    if entries.is_empty() {
+        bail!("No replayable events found in {:?}", path);
+    }
+    entries.sort_by_key(|entry| entry.timestamp);
+    Ok(entries)
+}
+
+pub async fn run_replay(entries: Vec<ReplayEntry>, mut shutdown: Stop) {
+    let mock = MockDevice::attach(0, REPLAY_SERIAL);
+
+    // Wait for the daemon to pick up the device and start polling it before starting.
+    info!("[Replay] Waiting for Mock Device to start..");
+    select! {
+        _ = mock.wait_for_poll() => {},
+        _ = shutdown.recv() => return,
+    }
+
+    info!("[Replay] Replaying {} events", entries.len());
+    let start = Instant::now();
+    let first = entries[0].timestamp;
+
+    for entry in entries {
+        let offset = Duration::from_millis(entry.timestamp - first);
+        select! {
+            _ = sleep_until(start + offset) => {},
+            _ = shutdown.recv() => return,
+        }
+
+        debug!("[Replay] {:?}: {:?}", offset, entry.event);
+        match entry.event {
+            ReplayEvent::ButtonDown(button) => mock.set_button(button, true),
+            ReplayEvent::ButtonUp(button) => mock.set_button(button, false),
+            ReplayEvent::VolumeChange(fader, volume) => mock.set_volume(fader, volume),
+            ReplayEvent::EncoderChange(encoder, value) => mock.set_encoder(encoder, value),
+            ReplayEvent::MicLevel(db) => mock.set_mic_level(db),
+        }
+    }
+    info!("[Replay] Replay Complete, the Mock Device will remain attached");
+}
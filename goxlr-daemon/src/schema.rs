@@ -0,0 +1,33 @@
+/*
+   JSON Schema for the files which are commonly written or edited by hand, generated straight from
+   the types they're loaded into, so they can't drift from what the daemon actually accepts.
+*/
+
+use anyhow::Result;
+use clap::ValueEnum;
+use schemars::schema_for;
+
+use goxlr_ipc::commands::configuration::DesiredState;
+use goxlr_profile::{MicProfile, Profile};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum SchemaFormat {
+    /// A device Profile
+    Profile,
+
+    /// A Microphone Profile
+    MicProfile,
+
+    /// A Desired State, as applied with the client's 'state apply'
+    DesiredState,
+}
+
+pub fn print_schema(format: SchemaFormat) -> Result<()> {
+    let schema = match format {
+        SchemaFormat::Profile => schema_for!(Profile),
+        SchemaFormat::MicProfile => schema_for!(MicProfile),
+        SchemaFormat::DesiredState => schema_for!(DesiredState),
+    };
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
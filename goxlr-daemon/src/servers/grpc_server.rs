@@ -0,0 +1,259 @@
+/*
+   An optional gRPC server, so integrations in other languages can generate a typed client from
+   proto/goxlr.proto rather than building JSON by hand. Everything here goes through
+   handle_packet, exactly as the IPC and WebSocket requests do.
+*/
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::pin::Pin;
+
+use log::{debug, info, warn};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::sync::broadcast::Sender;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+use goxlr_ipc::commands::channels::{ChannelCommands, ChannelVolume, MuteCommand};
+use goxlr_ipc::commands::{
+    DaemonRequest, DaemonResponse, DeviceCommand, GoXLRCommand, GoXLRCommandResponse,
+};
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
+use goxlr_shared::device::DeviceType as SharedDeviceType;
+use goxlr_shared::mute::MuteState as SharedMuteState;
+
+use crate::device::packet::{handle_packet, Messenger};
+use crate::servers::http_server::PatchEvent;
+use crate::stop::Stop;
+
+use proto::daemon_server::{Daemon, DaemonServer};
+use proto::{
+    CommandRequest, Device, DeviceLifecycle, DeviceList, DeviceType, Empty, FaderChannel, Json,
+    MuteState, SetMuteRequest, SetVolumeRequest, VolumeChannel,
+};
+
+mod proto {
+    tonic::include_proto!("goxlr.v1");
+}
+
+type GrpcResult<T> = Result<Response<T>, Status>;
+
+struct GrpcDaemon {
+    usb_tx: Messenger,
+    broadcast_tx: Sender<PatchEvent>,
+}
+
+impl GrpcDaemon {
+    async fn send(&self, request: DaemonRequest) -> Result<DaemonResponse, Status> {
+        let response = handle_packet(request, self.usb_tx.clone()).await;
+        match response.map_err(|error| Status::internal(error.to_string()))? {
+            DaemonResponse::Err(error) => Err(Status::failed_precondition(error)),
+            DaemonResponse::DeviceCommand(GoXLRCommandResponse::Error(error)) => {
+                Err(Status::failed_precondition(error))
+            }
+
+            // Aborted, rather than a failed precondition, so clients can offer to Force it
+            DaemonResponse::LiveModeBlocked(reason)
+            | DaemonResponse::DeviceCommand(GoXLRCommandResponse::LiveModeBlocked(reason)) => {
+                Err(Status::aborted(reason))
+            }
+            response => Ok(response),
+        }
+    }
+
+    async fn send_command(&self, serial: String, command: GoXLRCommand) -> GrpcResult<Empty> {
+        let request = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+        self.send(request).await?;
+        Ok(Response::new(Empty {}))
+    }
+}
+
+#[tonic::async_trait]
+impl Daemon for GrpcDaemon {
+    async fn ping(&self, _: Request<Empty>) -> GrpcResult<Empty> {
+        self.send(DaemonRequest::Ping).await?;
+        Ok(Response::new(Empty {}))
+    }
+
+    async fn list_devices(&self, _: Request<Empty>) -> GrpcResult<DeviceList> {
+        let DaemonResponse::Status(status) = self.send(DaemonRequest::GetStatus).await? else {
+            return Err(Status::internal("Unexpected response to GetStatus"));
+        };
+
+        let devices = status.lifecycle.iter().map(|(location, lifecycle)| {
+            let hardware = lifecycle
+                .serial
+                .as_ref()
+                .and_then(|serial| status.devices.get(serial))
+                .map(|device| &device.hardware);
+
+            let device_type = match hardware.map(|hardware| hardware.device_type) {
+                Some(SharedDeviceType::Full) => DeviceType::Full,
+                Some(SharedDeviceType::Mini) => DeviceType::Mini,
+                None => DeviceType::Unspecified,
+            };
+
+            Device {
+                serial: lifecycle.serial.clone().unwrap_or_default(),
+                device_type: device_type.into(),
+                lifecycle: get_lifecycle(lifecycle.state).into(),
+                usb_location: location.clone(),
+            }
+        });
+
+        let devices = devices.collect();
+        Ok(Response::new(DeviceList { devices }))
+    }
+
+    async fn get_status(&self, _: Request<Empty>) -> GrpcResult<Json> {
+        let DaemonResponse::Status(status) = self.send(DaemonRequest::GetStatus).await? else {
+            return Err(Status::internal("Unexpected response to GetStatus"));
+        };
+        Ok(Response::new(to_json(&status)?))
+    }
+
+    async fn set_volume(&self, request: Request<SetVolumeRequest>) -> GrpcResult<Empty> {
+        let request = request.into_inner();
+        let channel = get_volume_channel(request.channel())?;
+        let volume = u8::try_from(request.volume)
+            .map_err(|_| Status::invalid_argument("Volume must be between 0 and 255"))?;
+
+        let command = ChannelCommands::Volume(ChannelVolume { channel, volume });
+        let command = GoXLRCommand::Channels(command);
+        self.send_command(request.serial, command).await
+    }
+
+    async fn set_mute(&self, request: Request<SetMuteRequest>) -> GrpcResult<Empty> {
+        let request = request.into_inner();
+        let channel = get_fader_channel(request.channel())?;
+        let state = get_mute_state(request.state())?;
+
+        let command = ChannelCommands::Mute(MuteCommand { channel, state });
+        let command = GoXLRCommand::Channels(command);
+        self.send_command(request.serial, command).await
+    }
+
+    async fn run_command(&self, request: Request<CommandRequest>) -> GrpcResult<Json> {
+        let request = request.into_inner();
+        let command = from_json(&request.json)?;
+        let serial = request.serial;
+
+        let request = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+        let DaemonResponse::DeviceCommand(response) = self.send(request).await? else {
+            return Err(Status::internal("Unexpected response to a Device Command"));
+        };
+        Ok(Response::new(to_json(&response)?))
+    }
+
+    async fn run_daemon_command(&self, request: Request<Json>) -> GrpcResult<Json> {
+        let command = from_json(&request.into_inner().json)?;
+        let response = self.send(DaemonRequest::Daemon(command)).await?;
+        Ok(Response::new(to_json(&response)?))
+    }
+
+    type SubscribeStream = Pin<Box<dyn Stream<Item = Result<Json, Status>> + Send>>;
+
+    async fn subscribe(&self, _: Request<Empty>) -> GrpcResult<Self::SubscribeStream> {
+        let patches = BroadcastStream::new(self.broadcast_tx.subscribe());
+        let patches = patches.filter_map(|event| match event {
+            Ok(event) => Some(to_json(&event.data)),
+            Err(error) => {
+                // A slow client has missed some patches, which it can't recover from
+                warn!("gRPC Subscriber fell behind: {}", error);
+                Some(Err(Status::data_loss(
+                    "Patches were missed, fetch the status again",
+                )))
+            }
+        });
+        Ok(Response::new(Box::pin(patches)))
+    }
+}
+
+pub async fn spawn_grpc_server(
+    port: u16,
+    usb_tx: Messenger,
+    broadcast_tx: Sender<PatchEvent>,
+    mut shutdown: Stop,
+) {
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let service = DaemonServer::new(GrpcDaemon {
+        usb_tx,
+        broadcast_tx,
+    });
+
+    info!("Starting gRPC Server on {}", address);
+    let result = Server::builder()
+        .add_service(service)
+        .serve_with_shutdown(address, async move { shutdown.recv().await })
+        .await;
+
+    match result {
+        Ok(()) => debug!("gRPC Server Stopped"),
+        Err(error) => warn!("gRPC Server Failed: {}", error),
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<Json, Status> {
+    let json = serde_json::to_string(value).map_err(|e| Status::internal(e.to_string()))?;
+    Ok(Json { json })
+}
+
+fn from_json<T: DeserializeOwned>(json: &str) -> Result<T, Status> {
+    serde_json::from_str(json).map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+fn get_lifecycle(state: goxlr_ipc::status::DeviceLifecycle) -> DeviceLifecycle {
+    use goxlr_ipc::status::DeviceLifecycle as State;
+    match state {
+        State::Discovered => DeviceLifecycle::Discovered,
+        State::Initialising => DeviceLifecycle::Initialising,
+        State::Running => DeviceLifecycle::Running,
+        State::Degraded => DeviceLifecycle::Degraded,
+        State::Disconnected => DeviceLifecycle::Disconnected,
+    }
+}
+
+fn get_volume_channel(channel: VolumeChannel) -> Result<VolumeChannels, Status> {
+    Ok(match channel {
+        VolumeChannel::Unspecified => return Err(Status::invalid_argument("No Channel Given")),
+        VolumeChannel::Microphone => VolumeChannels::Microphone,
+        VolumeChannel::Chat => VolumeChannels::Chat,
+        VolumeChannel::Music => VolumeChannels::Music,
+        VolumeChannel::Game => VolumeChannels::Game,
+        VolumeChannel::Console => VolumeChannels::Console,
+        VolumeChannel::LineIn => VolumeChannels::LineIn,
+        VolumeChannel::System => VolumeChannels::System,
+        VolumeChannel::Sample => VolumeChannels::Sample,
+        VolumeChannel::Headphones => VolumeChannels::Headphones,
+        VolumeChannel::LineOut => VolumeChannels::LineOut,
+        VolumeChannel::MicrophoneMonitor => VolumeChannels::MicrophoneMonitor,
+    })
+}
+
+fn get_fader_channel(channel: FaderChannel) -> Result<FaderChannels, Status> {
+    Ok(match channel {
+        FaderChannel::Unspecified => return Err(Status::invalid_argument("No Channel Given")),
+        FaderChannel::Microphone => FaderChannels::Microphone,
+        FaderChannel::Chat => FaderChannels::Chat,
+        FaderChannel::Music => FaderChannels::Music,
+        FaderChannel::Game => FaderChannels::Game,
+        FaderChannel::Console => FaderChannels::Console,
+        FaderChannel::LineIn => FaderChannels::LineIn,
+        FaderChannel::System => FaderChannels::System,
+        FaderChannel::Sample => FaderChannels::Sample,
+        FaderChannel::Headphones => FaderChannels::Headphones,
+        FaderChannel::LineOut => FaderChannels::LineOut,
+    })
+}
+
+fn get_mute_state(state: MuteState) -> Result<SharedMuteState, Status> {
+    Ok(match state {
+        MuteState::Unspecified => return Err(Status::invalid_argument("No Mute State Given")),
+        MuteState::Unmuted => SharedMuteState::Unmuted,
+        MuteState::Pressed => SharedMuteState::Pressed,
+        MuteState::Held => SharedMuteState::Held,
+    })
+}
@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
 use std::ops::DerefMut;
+use std::time::Duration;
 
 use actix::{
     Actor, ActorContext, AsyncContext, ContextFutureSpawner, Handler, Message, StreamHandler,
@@ -13,23 +16,36 @@ use actix_web::{get, post, web, App, HttpRequest, HttpResponse, HttpServer};
 use actix_web_actors::ws;
 use actix_web_actors::ws::{CloseCode, CloseReason};
 use anyhow::{anyhow, Result};
+use futures_util::stream;
 use include_dir::{include_dir, Dir};
 use json_patch::Patch;
 use log::{debug, error, info, warn};
 use mime_guess::MimeGuess;
 use serde_json::Value;
+use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::broadcast::Sender as BroadcastSender;
 use tokio::sync::oneshot::Sender;
 use tokio::sync::Mutex;
 
 use goxlr_ipc::commands::{
-    DaemonRequest, DaemonResponse, DaemonStatus, HttpSettings, WebsocketRequest, WebsocketResponse,
+    Backup, DaemonCommand, DaemonRequest, DaemonResponse, DaemonStatus, HttpSettings,
+    WebsocketRequest, WebsocketResponse,
 };
 
 use crate::device::packet::{handle_packet, Messenger};
+use crate::servers::rate_limit::RateLimiter;
+use crate::servers::variables::{get_device_variables, get_variables, Variables};
 
 const WEB_CONTENT: Dir = include_dir!("./goxlr-daemon/web-content/");
 
+// The largest raw body accepted, which is only read by /api/restore. A Backup holds the profiles
+// of every device, so can be well beyond actix's default of 256KiB.
+const MAX_BACKUP_SIZE: usize = 16 * 1024 * 1024;
+
+// How many clients' worth of commands the HTTP server as a whole will take, so someone with a
+// handful of addresses can't get around the limit by spreading their requests across them.
+const GLOBAL_LIMIT_CLIENTS: u32 = 4;
+
 #[derive(Debug, Clone)]
 pub struct PatchEvent {
     pub data: Patch,
@@ -38,6 +54,9 @@ pub struct PatchEvent {
 struct Websocket {
     usb_tx: Messenger,
     broadcast_tx: BroadcastSender<PatchEvent>,
+
+    // Each socket is limited separately, so a script can't starve the UI's connection
+    limiter: RateLimiter,
 }
 
 impl Actor for Websocket {
@@ -94,6 +113,14 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for Websocket {
             Ok(ws::Message::Text(text)) => {
                 match serde_json::from_slice::<WebsocketRequest>(text.as_ref()) {
                     Ok(request) => {
+                        if let Err(wait) = self.limiter.check() {
+                            ctx.address().do_send(WsResponse(WebsocketResponse {
+                                id: request.id,
+                                data: DaemonResponse::Err(rate_limited(wait)),
+                            }));
+                            return;
+                        }
+
                         let recipient = ctx.address().recipient();
                         let usb_tx = self.usb_tx.clone();
                         let future = async move {
@@ -125,6 +152,48 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for Websocket {
                                             data: DaemonResponse::DeviceCommand(result),
                                         }));
                                     }
+                                    DaemonResponse::LogTail(lines) => {
+                                        recipient.do_send(WsResponse(WebsocketResponse {
+                                            id: request_id,
+                                            data: DaemonResponse::LogTail(lines),
+                                        }));
+                                    }
+                                    DaemonResponse::Backup(backup) => {
+                                        recipient.do_send(WsResponse(WebsocketResponse {
+                                            id: request_id,
+                                            data: DaemonResponse::Backup(backup),
+                                        }));
+                                    }
+                                    DaemonResponse::Backups(names) => {
+                                        recipient.do_send(WsResponse(WebsocketResponse {
+                                            id: request_id,
+                                            data: DaemonResponse::Backups(names),
+                                        }));
+                                    }
+                                    DaemonResponse::Health(report) => {
+                                        recipient.do_send(WsResponse(WebsocketResponse {
+                                            id: request_id,
+                                            data: DaemonResponse::Health(report),
+                                        }));
+                                    }
+                                    DaemonResponse::Session(session) => {
+                                        recipient.do_send(WsResponse(WebsocketResponse {
+                                            id: request_id,
+                                            data: DaemonResponse::Session(session),
+                                        }));
+                                    }
+                                    DaemonResponse::LiveModeBlocked(reason) => {
+                                        recipient.do_send(WsResponse(WebsocketResponse {
+                                            id: request_id,
+                                            data: DaemonResponse::LiveModeBlocked(reason),
+                                        }));
+                                    }
+                                    DaemonResponse::ProfileImport(preview) => {
+                                        recipient.do_send(WsResponse(WebsocketResponse {
+                                            id: request_id,
+                                            data: DaemonResponse::ProfileImport(preview),
+                                        }));
+                                    }
                                     _ => {
                                         panic!("Unexpected Response!");
                                     }
@@ -197,6 +266,15 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for Websocket {
 struct AppData {
     messenger: Messenger,
     broadcast_tx: BroadcastSender<PatchEvent>,
+
+    rate_limit: u32,
+    rate_burst: u32,
+
+    // HTTP requests don't have a connection to hang a limiter off, so these go by the client's
+    // IP. The port changes with every new connection, so can't be used to tell clients apart, and
+    // local clients sharing an IP are limited together.
+    limiters: HashMap<IpAddr, RateLimiter>,
+    global_limiter: RateLimiter,
 }
 
 pub async fn spawn_http_server(
@@ -205,6 +283,21 @@ pub async fn spawn_http_server(
     broadcast_tx: tokio::sync::broadcast::Sender<PatchEvent>,
     settings: HttpSettings,
 ) {
+    // Every worker shares the same data, otherwise each would have its own limiters, and a client
+    // could get a new allowance just by landing on another worker
+    let app_data = Data::new(Mutex::new(AppData {
+        broadcast_tx,
+        messenger,
+        rate_limit: settings.rate_limit,
+        rate_burst: settings.rate_burst,
+        limiters: HashMap::new(),
+        global_limiter: RateLimiter::new(
+            settings.rate_limit.saturating_mul(GLOBAL_LIMIT_CLIENTS),
+            settings.rate_burst.saturating_mul(GLOBAL_LIMIT_CLIENTS),
+        ),
+    }));
+
+    let cors_enabled = settings.cors_enabled;
     let server = HttpServer::new(move || {
         let cors = Cors::default()
             .allowed_origin_fn(|origin, _req_head| {
@@ -215,13 +308,18 @@ pub async fn spawn_http_server(
             .allow_any_header()
             .max_age(300);
         App::new()
-            .wrap(Condition::new(settings.cors_enabled, cors))
-            .app_data(Data::new(Mutex::new(AppData {
-                broadcast_tx: broadcast_tx.clone(),
-                messenger: messenger.clone(),
-            })))
+            .wrap(Condition::new(cors_enabled, cors))
+            .app_data(app_data.clone())
+            .app_data(web::PayloadConfig::new(MAX_BACKUP_SIZE))
             .service(execute_command)
             .service(get_devices)
+            .service(get_backup)
+            .service(healthz)
+            .service(readyz)
+            .service(restore_backup)
+            .service(get_all_variables)
+            .service(get_serial_variables)
+            .service(variable_events)
             .service(websocket)
             .default_service(web::to(default))
     })
@@ -260,6 +358,7 @@ async fn websocket(
         Websocket {
             usb_tx: data.messenger.clone(),
             broadcast_tx: data.broadcast_tx.clone(),
+            limiter: RateLimiter::new(data.rate_limit, data.rate_burst),
         },
         &req,
         stream,
@@ -272,12 +371,36 @@ async fn websocket(
 async fn execute_command(
     request: web::Json<DaemonRequest>,
     app_data: Data<Mutex<AppData>>,
+    req: HttpRequest,
 ) -> HttpResponse {
-    let mut guard = app_data.lock().await;
-    let sender = guard.deref_mut();
+    // Only hold the lock long enough to check the limiter, a slow command shouldn't hold up
+    // every other request while it runs.
+    let messenger = {
+        let mut guard = app_data.lock().await;
+        let sender = guard.deref_mut();
+
+        let (rate, burst) = (sender.rate_limit, sender.rate_burst);
+        sender.limiters.retain(|_, limiter| !limiter.is_idle());
+
+        let allowed = match req.peer_addr() {
+            Some(address) => {
+                let limiter = sender.limiters.entry(address.ip());
+                let limiter = limiter.or_insert_with(|| RateLimiter::new(rate, burst));
+                limiter.check()
+            }
+            None => Ok(()),
+        };
+        if let Err(wait) = allowed.and_then(|_| sender.global_limiter.check()) {
+            let retry_after = wait.as_secs() + 1;
+            return HttpResponse::TooManyRequests()
+                .insert_header(("Retry-After", retry_after.to_string()))
+                .json(DaemonResponse::Err(rate_limited(wait)));
+        }
+        sender.messenger.clone()
+    };
 
     // Errors propagate weirdly in the javascript world, so send all as OK, and handle there.
-    match handle_packet(request.0, sender.messenger.clone()).await {
+    match handle_packet(request.0, messenger).await {
         Ok(result) => HttpResponse::Ok().json(result),
         Err(error) => HttpResponse::Ok().json(DaemonResponse::Err(error.to_string())),
     }
@@ -291,6 +414,137 @@ async fn get_devices(app_data: Data<Mutex<AppData>>) -> HttpResponse {
     HttpResponse::InternalServerError().finish()
 }
 
+#[get("/api/backup")]
+async fn get_backup(app_data: Data<Mutex<AppData>>) -> HttpResponse {
+    let request = DaemonRequest::Daemon(DaemonCommand::GetBackup);
+    let messenger = app_data.lock().await.messenger.clone();
+
+    match handle_packet(request, messenger).await {
+        Ok(DaemonResponse::Backup(backup)) => HttpResponse::Ok()
+            .insert_header((
+                "Content-Disposition",
+                "attachment; filename=\"goxlr-backup.json\"",
+            ))
+            .json(backup),
+        Ok(response) => HttpResponse::InternalServerError().json(response),
+        Err(error) => {
+            HttpResponse::InternalServerError().json(DaemonResponse::Err(error.to_string()))
+        }
+    }
+}
+
+// Supervisors only look at the status code, so these are kept simple. Being able to respond at
+// all means we're live, but the device manager still has to answer for us to be ready.
+#[get("/healthz")]
+async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().body("ok")
+}
+
+#[get("/readyz")]
+async fn readyz(app_data: Data<Mutex<AppData>>) -> HttpResponse {
+    let request = DaemonRequest::Daemon(DaemonCommand::GetHealth);
+    let messenger = app_data.lock().await.messenger.clone();
+
+    match handle_packet(request, messenger).await {
+        Ok(DaemonResponse::Health(report)) if report.ready => HttpResponse::Ok().json(report),
+        Ok(DaemonResponse::Health(report)) => HttpResponse::ServiceUnavailable().json(report),
+        Ok(response) => HttpResponse::InternalServerError().json(response),
+        Err(error) => {
+            HttpResponse::ServiceUnavailable().json(DaemonResponse::Err(error.to_string()))
+        }
+    }
+}
+
+// Backups can easily be larger than the default JSON limit, so this is parsed by hand from the
+// raw body, which is limited to MAX_BACKUP_SIZE instead.
+#[post("/api/restore")]
+async fn restore_backup(body: web::Bytes, app_data: Data<Mutex<AppData>>) -> HttpResponse {
+    let backup = match serde_json::from_slice::<Backup>(&body) {
+        Ok(backup) => backup,
+        Err(error) => {
+            let error = format!("Invalid Backup: {}", error);
+            return HttpResponse::BadRequest().json(DaemonResponse::Err(error));
+        }
+    };
+
+    let request = DaemonRequest::Daemon(DaemonCommand::RestoreBackup(backup));
+    let messenger = app_data.lock().await.messenger.clone();
+    match handle_packet(request, messenger).await {
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(error) => HttpResponse::Ok().json(DaemonResponse::Err(error.to_string())),
+    }
+}
+
+#[get("/api/variables")]
+async fn get_all_variables(app_data: Data<Mutex<AppData>>) -> HttpResponse {
+    match get_status(app_data).await {
+        Ok(status) => HttpResponse::Ok().json(get_variables(&status)),
+        Err(error) => {
+            HttpResponse::InternalServerError().json(DaemonResponse::Err(error.to_string()))
+        }
+    }
+}
+
+#[get("/api/variables/{serial}")]
+async fn get_serial_variables(
+    serial: web::Path<String>,
+    app_data: Data<Mutex<AppData>>,
+) -> HttpResponse {
+    match get_status(app_data).await {
+        Ok(status) => match status.devices.get(serial.as_str()) {
+            Some(device) => HttpResponse::Ok().json(get_device_variables(device)),
+            None => HttpResponse::NotFound().json(DaemonResponse::Err(String::from("Not Found"))),
+        },
+        Err(error) => {
+            HttpResponse::InternalServerError().json(DaemonResponse::Err(error.to_string()))
+        }
+    }
+}
+
+// Server-Sent Events, the current variables are sent straight away, then again whenever any of
+// them change. Patches are only used as a prompt to look, so a lagging client misses nothing.
+#[get("/api/variables/{serial}/events")]
+async fn variable_events(
+    serial: web::Path<String>,
+    app_data: Data<Mutex<AppData>>,
+) -> HttpResponse {
+    let (messenger, patches) = {
+        let data = app_data.lock().await;
+        (data.messenger.clone(), data.broadcast_tx.subscribe())
+    };
+
+    let state = (patches, messenger, serial.into_inner(), None::<Variables>);
+    let events = stream::unfold(
+        state,
+        |(mut patches, messenger, serial, mut last)| async move {
+            loop {
+                if last.is_some() {
+                    if let Err(RecvError::Closed) = patches.recv().await {
+                        return None;
+                    }
+                }
+
+                let status = fetch_status(messenger.clone()).await.ok()?;
+                let device = status.devices.get(&serial);
+                let variables = device.map(get_device_variables).unwrap_or_default();
+                if last.as_ref() == Some(&variables) {
+                    continue;
+                }
+
+                let event = format!("data: {}\n\n", serde_json::to_string(&variables).ok()?);
+                last = Some(variables);
+                let event = Ok::<_, actix_web::Error>(web::Bytes::from(event));
+                return Some((event, (patches, messenger, serial, last)));
+            }
+        },
+    );
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .streaming(events)
+}
+
 async fn default(req: HttpRequest) -> HttpResponse {
     let path = if req.path() == "/" || req.path() == "" {
         "/index.html"
@@ -310,15 +564,20 @@ async fn default(req: HttpRequest) -> HttpResponse {
 }
 
 async fn get_status(app_data: Data<Mutex<AppData>>) -> Result<DaemonStatus> {
-    // Unwrap the Mutex Guard..
-    let mut guard = app_data.lock().await;
-    let sender = guard.deref_mut();
+    let messenger = app_data.lock().await.messenger.clone();
+    fetch_status(messenger).await
+}
 
+async fn fetch_status(messenger: Messenger) -> Result<DaemonStatus> {
     let request = DaemonRequest::GetStatus;
 
-    let result = handle_packet(request, sender.messenger.clone()).await?;
+    let result = handle_packet(request, messenger).await?;
     match result {
         DaemonResponse::Status(status) => Ok(status),
         _ => Err(anyhow!("Unexpected Daemon Status Result: {:?}", result)),
     }
 }
+
+fn rate_limited(wait: Duration) -> String {
+    format!("Too Many Requests, retry in {}ms", wait.as_millis())
+}
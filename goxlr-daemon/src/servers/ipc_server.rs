@@ -1,22 +1,36 @@
 use anyhow::{bail, Result};
 use interprocess::local_socket::tokio::{LocalSocketListener, LocalSocketStream};
 use interprocess::local_socket::NameTypeSupport;
+use json_patch::diff;
 use log::{debug, info, warn};
+use serde_json::Value;
 use std::fs;
+use std::future::pending;
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::broadcast::{Receiver, Sender};
 
 use NameTypeSupport::*;
 
 use crate::device::packet::{handle_packet, Messenger};
+use crate::servers::http_server::PatchEvent;
 use goxlr_ipc::clients::ipc::ipc_socket::Socket;
-use goxlr_ipc::commands::{DaemonRequest, DaemonResponse};
+use goxlr_ipc::commands::{DaemonRequest, DaemonResponse, FaderMovement};
 
+use crate::settings::IPCSettings;
 use crate::Stop;
 
-static SOCKET_PATH: &str = "/tmp/goxlr.socket";
-static NAMED_PIPE: &str = "@goxlr.socket";
+static CLIENT_ID: AtomicU64 = AtomicU64::new(0);
 
-async fn ipc_tidy() -> Result<()> {
+/// The state of a single connected IPC client, this lives for as long as the connection does.
+struct ClientState {
+    id: u64,
+    patches: Option<Receiver<PatchEvent>>,
+    fader_events: Option<Receiver<FaderMovement>>,
+}
+
+async fn ipc_tidy(settings: &IPCSettings) -> Result<()> {
     // We only need a possible cleanup if we're using file based sockets..
     let socket_type = NameTypeSupport::query();
     if socket_type == OnlyNamespaced {
@@ -24,16 +38,17 @@ async fn ipc_tidy() -> Result<()> {
     }
 
     // Check to see if the socket exists,
-    if !Path::new(SOCKET_PATH).exists() {
+    let socket_path = settings.socket_path.as_str();
+    if !Path::new(socket_path).exists() {
         return Ok(());
     }
 
     debug!("Existing Socket Present, testing..");
     // Try sending a message to the socket, see if we get a reply..
-    let connection = LocalSocketStream::connect(SOCKET_PATH).await;
+    let connection = LocalSocketStream::connect(socket_path).await;
     if connection.is_err() {
         debug!("Unable to connect to the socket, removing..");
-        fs::remove_file(SOCKET_PATH)?;
+        fs::remove_file(socket_path)?;
         return Ok(());
     }
 
@@ -42,7 +57,7 @@ async fn ipc_tidy() -> Result<()> {
     let mut socket: Socket<DaemonResponse, DaemonRequest> = Socket::new(connection);
     if socket.send(DaemonRequest::Ping).await.is_err() {
         debug!("Socket Not Active, removing file..");
-        fs::remove_file(SOCKET_PATH)?;
+        fs::remove_file(socket_path)?;
         return Ok(());
     }
 
@@ -50,24 +65,62 @@ async fn ipc_tidy() -> Result<()> {
     bail!("The GoXLR Daemon is already running.");
 }
 
-pub async fn bind_socket() -> Result<LocalSocketListener> {
-    ipc_tidy().await?;
+pub async fn bind_socket(settings: &IPCSettings) -> Result<LocalSocketListener> {
+    ipc_tidy(settings).await?;
 
     let name = {
         match NameTypeSupport::query() {
-            OnlyPaths | Both => SOCKET_PATH,
-            OnlyNamespaced => NAMED_PIPE,
+            OnlyPaths | Both => settings.socket_path.as_str(),
+            OnlyNamespaced => settings.pipe_name.as_str(),
         }
     };
 
     let listener = LocalSocketListener::bind(name)?;
     info!("Bound IPC Socket @ {}", name);
+
+    // Permissions and Ownership only apply to file based sockets..
+    if NameTypeSupport::query() != OnlyNamespaced {
+        apply_socket_permissions(settings)?;
+    }
+
     Ok(listener)
 }
 
+#[cfg(unix)]
+fn apply_socket_permissions(settings: &IPCSettings) -> Result<()> {
+    use std::os::unix::fs::{chown, PermissionsExt};
+
+    let path = settings.socket_path.as_str();
+    if let Some(mode) = settings.socket_mode {
+        debug!("Setting IPC Socket Permissions to {:o}", mode);
+        fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+    }
+
+    if settings.socket_uid.is_some() || settings.socket_gid.is_some() {
+        debug!(
+            "Setting IPC Socket Owner to {:?}:{:?}",
+            settings.socket_uid, settings.socket_gid
+        );
+        chown(path, settings.socket_uid, settings.socket_gid)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn apply_socket_permissions(settings: &IPCSettings) -> Result<()> {
+    let has_ownership = settings.socket_uid.is_some() || settings.socket_gid.is_some();
+    if settings.socket_mode.is_some() || has_ownership {
+        warn!("IPC Socket Permissions are only supported on Unix, ignoring");
+    }
+    Ok(())
+}
+
 pub async fn spawn_ipc_server(
     listener: LocalSocketListener,
+    settings: IPCSettings,
     usb_tx: Messenger,
+    broadcast_tx: Sender<PatchEvent>,
+    fader_tx: Sender<FaderMovement>,
     mut shutdown_signal: Stop,
 ) {
     debug!("Running IPC Server..");
@@ -76,15 +129,17 @@ pub async fn spawn_ipc_server(
             Ok(connection) = listener.accept() => {
                 let socket = Socket::new(connection);
                 let usb_tx = usb_tx.clone();
+                let broadcast_tx = broadcast_tx.clone();
+                let fader_tx = fader_tx.clone();
                 tokio::spawn(async move {
-                    handle_connection(socket, usb_tx).await;
+                    handle_connection(socket, usb_tx, broadcast_tx, fader_tx).await;
                 });
             }
             () = shutdown_signal.recv() => {
                 // If we're using a unix domain socket, remove it.
                 match NameTypeSupport::query() {
                     OnlyPaths | Both => {
-                        let _ = fs::remove_file(SOCKET_PATH);
+                        let _ = fs::remove_file(&settings.socket_path);
                     },
                     OnlyNamespaced => {},
                 }
@@ -94,25 +149,140 @@ pub async fn spawn_ipc_server(
     }
 }
 
-async fn handle_connection(mut socket: Socket<DaemonRequest, DaemonResponse>, usb_tx: Messenger) {
-    while let Some(msg) = socket.read().await {
-        match msg {
-            Ok(msg) => match handle_packet(msg, usb_tx.clone()).await {
-                Ok(response) => {
-                    if let Err(e) = socket.send(response).await {
-                        warn!("Couldn't reply to {:?}: {}", socket.address(), e);
-                        return;
+async fn handle_connection(
+    mut socket: Socket<DaemonRequest, DaemonResponse>,
+    usb_tx: Messenger,
+    broadcast_tx: Sender<PatchEvent>,
+    fader_tx: Sender<FaderMovement>,
+) {
+    let mut state = ClientState {
+        id: CLIENT_ID.fetch_add(1, Ordering::Relaxed),
+        patches: None,
+        fader_events: None,
+    };
+    debug!("[IPC] Client {} Connected", state.id);
+
+    loop {
+        tokio::select! {
+            msg = socket.read() => {
+                let response = match msg {
+                    Some(Ok(DaemonRequest::Subscribe)) => {
+                        debug!("[IPC] Client {} Subscribed", state.id);
+                        state.patches = Some(broadcast_tx.subscribe());
+                        DaemonResponse::Ok
                     }
-                }
-                Err(e) => {
-                    if let Err(e) = socket.send(DaemonResponse::Err(e.to_string())).await {
-                        warn!("Couldn't reply to {:?}: {}", socket.address(), e);
-                        return;
+                    Some(Ok(DaemonRequest::ResumeSession(position))) => {
+                        // Subscribe first, so nothing can be missed between the two
+                        debug!("[IPC] Client {} Resuming Session", state.id);
+                        state.patches = Some(broadcast_tx.subscribe());
+                        let request = DaemonRequest::ResumeSession(position);
+                        match handle_packet(request, usb_tx.clone()).await {
+                            Ok(response) => response,
+                            Err(e) => DaemonResponse::Err(e.to_string()),
+                        }
+                    }
+                    Some(Ok(DaemonRequest::Unsubscribe)) => {
+                        debug!("[IPC] Client {} Unsubscribed", state.id);
+                        state.patches = None;
+                        DaemonResponse::Ok
                     }
+                    Some(Ok(DaemonRequest::SubscribeFaderEvents)) => {
+                        debug!("[IPC] Client {} Subscribed to Fader Events", state.id);
+                        state.fader_events = Some(fader_tx.subscribe());
+                        DaemonResponse::Ok
+                    }
+                    Some(Ok(DaemonRequest::UnsubscribeFaderEvents)) => {
+                        debug!("[IPC] Client {} Unsubscribed from Fader Events", state.id);
+                        state.fader_events = None;
+                        DaemonResponse::Ok
+                    }
+                    Some(Ok(msg)) => match handle_packet(msg, usb_tx.clone()).await {
+                        Ok(response) => response,
+                        Err(e) => DaemonResponse::Err(e.to_string()),
+                    },
+                    Some(Err(e)) => {
+                        warn!("[IPC] Invalid message from Client {}: {}", state.id, e);
+                        DaemonResponse::Err(e.to_string())
+                    }
+                    None => break,
+                };
+
+                if let Err(e) = socket.send(response).await {
+                    warn!("[IPC] Couldn't reply to Client {}: {}", state.id, e);
+                    break;
+                }
+            }
+            Some(patch) = next_patch(&mut state, usb_tx.clone()) => {
+                if let Err(e) = socket.send(patch).await {
+                    warn!("[IPC] Couldn't send patch to Client {}: {}", state.id, e);
+                    break;
+                }
+            }
+            Some(movement) = next_fader_movement(&mut state) => {
+                if let Err(e) = socket.send(DaemonResponse::FaderMoved(movement)).await {
+                    warn!("[IPC] Couldn't send fader event to Client {}: {}", state.id, e);
+                    break;
                 }
-            },
-            Err(e) => warn!("Invalid message from {:?}: {}", socket.address(), e),
+            }
+        }
+    }
+
+    // Dropping the state here also drops the broadcast receiver, unsubscribing the client.
+    debug!("[IPC] Client {} Disconnected", state.id);
+}
+
+/// Waits for the next patch for a subscribed client, if the client isn't subscribed this will
+/// never resolve.
+async fn next_patch(state: &mut ClientState, usb_tx: Messenger) -> Option<DaemonResponse> {
+    let Some(receiver) = state.patches.as_mut() else {
+        return pending().await;
+    };
+
+    match receiver.recv().await {
+        Ok(event) => Some(DaemonResponse::Patch(event.data)),
+        Err(RecvError::Lagged(count)) => {
+            // The broadcast channel has dropped the oldest patches for this client, so any
+            // further patches would apply to a stale state, replace the whole status instead.
+            // This is still sent as a Patch, so it can't be mistaken for a reply to a request.
+            warn!(
+                "[IPC] Client {} lagging, {} patches dropped",
+                state.id, count
+            );
+            let request = DaemonRequest::GetStatus;
+            let Ok(DaemonResponse::Status(status)) = handle_packet(request, usb_tx).await else {
+                return None;
+            };
+            let status = serde_json::to_value(status).ok()?;
+            Some(DaemonResponse::Patch(diff(&Value::Null, &status)))
+        }
+        Err(RecvError::Closed) => {
+            state.patches = None;
+            None
+        }
+    }
+}
+
+/// Waits for the next fader movement for a client subscribed to them, as with patches this never
+/// resolves if the client isn't subscribed.
+async fn next_fader_movement(state: &mut ClientState) -> Option<FaderMovement> {
+    let Some(receiver) = state.fader_events.as_mut() else {
+        return pending().await;
+    };
+
+    loop {
+        match receiver.recv().await {
+            Ok(movement) => return Some(movement),
+            Err(RecvError::Lagged(count)) => {
+                // Movements are only of use as they happen, so the missed ones are just skipped
+                warn!(
+                    "[IPC] Client {} lagging, {} fader events dropped",
+                    state.id, count
+                );
+            }
+            Err(RecvError::Closed) => {
+                state.fader_events = None;
+                return None;
+            }
         }
     }
-    debug!("Disconnected {:?}", socket.address());
 }
@@ -1,2 +1,6 @@
+#[cfg(feature = "grpc")]
+pub(crate) mod grpc_server;
 pub(crate) mod http_server;
 pub(crate) mod ipc_server;
+pub(crate) mod rate_limit;
+pub(crate) mod variables;
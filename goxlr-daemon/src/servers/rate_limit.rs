@@ -0,0 +1,53 @@
+use std::time::{Duration, Instant};
+
+/// A token bucket for limiting how quickly a client can send commands. Each command takes a
+/// token, and tokens refill at the configured rate up to the burst size, so short bursts (such
+/// as a UI loading) are allowed, while a runaway script can't flood the device.
+pub(crate) struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `rate` commands per second, 0 disables limiting
+    pub fn new(rate: u32, burst: u32) -> Self {
+        // A burst smaller than a single token would never allow anything through
+        let burst = burst.max(1) as f64;
+        Self {
+            rate: rate as f64,
+            burst,
+            tokens: burst,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Takes a token if one is available, otherwise returns how long until one will be
+    pub fn check(&mut self) -> Result<(), Duration> {
+        if self.rate == 0. {
+            return Ok(());
+        }
+
+        self.refill();
+        if self.tokens >= 1. {
+            self.tokens -= 1.;
+            return Ok(());
+        }
+
+        let wait = (1. - self.tokens) / self.rate;
+        Err(Duration::from_secs_f64(wait))
+    }
+
+    /// Whether the bucket has completely refilled, at which point it can be safely dropped
+    pub fn is_idle(&mut self) -> bool {
+        self.refill();
+        self.tokens >= self.burst
+    }
+
+    fn refill(&mut self) {
+        let elapsed = self.last_refill.elapsed().as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.last_refill = Instant::now();
+    }
+}
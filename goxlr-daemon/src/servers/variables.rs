@@ -0,0 +1,64 @@
+/*
+   Named values for Bitfocus Companion's variable system (and anything else that prefers them),
+   flattened out of the status so a button can show a value without needing to understand the
+   profile. Names are stable and snake_case, values are plain JSON numbers, strings or booleans.
+*/
+
+use std::collections::BTreeMap;
+
+use serde_json::{json, Value};
+
+use goxlr_ipc::commands::DaemonStatus;
+use goxlr_ipc::status::DeviceStatus;
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::mute::MuteState;
+
+pub type Variables = BTreeMap<String, Value>;
+
+/// The variables for every device, by serial
+pub fn get_variables(status: &DaemonStatus) -> BTreeMap<String, Variables> {
+    let devices = status.devices.iter();
+    let variables = devices.map(|(serial, device)| (serial.clone(), get_device_variables(device)));
+    variables.collect()
+}
+
+pub fn get_device_variables(device: &DeviceStatus) -> Variables {
+    let profile = &device.config.device;
+    let mut variables = Variables::new();
+    let mut set = |name: &str, value: Value| {
+        variables.insert(name.to_string(), value);
+    };
+
+    // There are no named profiles, the active Mix Mode is what gets switched between
+    let mix_mode = profile.mix_modes.active.clone().unwrap_or_default();
+    set("mix_mode", json!(mix_mode));
+
+    let mic = profile.channels.configs[FaderChannels::Microphone].mute_state;
+    set("mic_muted", json!(mic != MuteState::Unmuted));
+
+    // Pages are counted from one, as they are on the device
+    set("page", json!(profile.pages.current + 1));
+    set("ducking_enabled", json!(profile.ducking.enabled));
+    set(
+        "push_to_talk_active",
+        json!(device.runtime.push_to_talk_active),
+    );
+
+    for (channel, volume) in profile.channels.volumes {
+        let name = format!("volume_{}", to_snake_case(&format!("{:?}", channel)));
+        let percent = (volume as f32 / 255. * 100.).round() as u8;
+        set(&name, json!(percent));
+    }
+    variables
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
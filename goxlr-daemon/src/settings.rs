@@ -0,0 +1,416 @@
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::NaiveTime;
+use clap::ValueEnum;
+
+use goxlr_ipc::clients::ipc::ipc_client::{DEFAULT_NAMED_PIPE, DEFAULT_SOCKET_PATH};
+use goxlr_ipc::status::ReleaseChannel;
+use goxlr_shared::locale::Locale;
+
+/// Settings for the IPC Socket, by default these match what the clients expect, but they can be
+/// changed to run several daemons side by side, or to place the socket somewhere more accessible.
+#[derive(Debug, Clone)]
+pub struct IPCSettings {
+    /// The Unix Socket Path, used on platforms with path based sockets
+    pub socket_path: String,
+
+    /// The Named Pipe, used on platforms with namespaced sockets (Windows)
+    pub pipe_name: String,
+
+    /// The permissions to apply to the socket file (Unix Only)
+    pub socket_mode: Option<u32>,
+
+    /// The User and Group to assign ownership of the socket file to (Unix Only)
+    pub socket_uid: Option<u32>,
+    pub socket_gid: Option<u32>,
+}
+
+impl Default for IPCSettings {
+    fn default() -> Self {
+        Self {
+            socket_path: DEFAULT_SOCKET_PATH.to_string(),
+            pipe_name: DEFAULT_NAMED_PIPE.to_string(),
+            socket_mode: None,
+            socket_uid: None,
+            socket_gid: None,
+        }
+    }
+}
+
+/// Settings which are passed down to every device the daemon manages.
+#[derive(Debug, Clone)]
+pub struct DeviceSettings {
+    /// Where devices should persist their State Cache, None if disabled
+    pub state_cache_dir: Option<PathBuf>,
+
+    /// Whether routing changes made during multi-step operations (such as a profile load) are
+    /// held back and applied once at the end, rather than as each step happens.
+    pub stage_routing: bool,
+
+    /// Whether colours are sent to the device, disabled for setups where the lighting can't be
+    /// seen (such as rack mounted devices). Everything else behaves the same either way.
+    pub lighting: bool,
+
+    /// Whether colour changes are collected and sent once per tick, rather than as they're made,
+    /// so the device never shows a scheme which is only partly updated.
+    pub buffer_colours: bool,
+
+    /// The language channel names are drawn on the scribbles in, unless a name has been set
+    pub locale: Locale,
+
+    /// What happens to buttons pressed, and faders moved, while a profile is loading
+    pub load_input: LoadInputBehaviour,
+
+    /// The highest the Headphones volume can be set (0 - 255), None for no limit
+    pub headphone_limit: Option<u8>,
+
+    /// The OS playback device for the GoXLR's Sample channel, None to find it by name
+    pub sample_device: Option<String>,
+
+    /// Where samples are played on devices without a Sample channel, None for the default
+    pub sample_fallback_device: Option<String>,
+
+    /// Which events are shown as desktop notifications
+    pub notifications: NotificationSettings,
+
+    /// Which events play a sound, and what they play
+    pub audio_cues: AudioCueSettings,
+
+    /// How often (in ms) each device handles its timed work, such as ducking and the gate
+    pub tick_interval: u64,
+
+    /// How often (in ms) the Microphone level is polled while something needs it
+    pub mic_level_interval: u64,
+
+    /// How many events the Flight Recorder should hold, 0 if disabled
+    pub flight_recorder_size: usize,
+
+    /// How long (in seconds) the device event loop can go without responding before it's reset,
+    /// 0 if the watchdog is disabled
+    pub watchdog_timeout: u64,
+
+    /// How many USB commands can fail in a row before the device is reconnected, 0 to disable
+    pub usb_failure_limit: u32,
+
+    /// The longest (in seconds) the daemon will wait between attempts to reconnect a device
+    pub reconnect_max_delay: u64,
+
+    /// Whether every USB command and response is logged from startup, this can also be turned
+    /// on and off while the daemon is running
+    pub usb_traffic_log: bool,
+
+    /// The file USB traffic is logged to
+    pub usb_traffic_file: PathBuf,
+
+    /// Where devices should append their Audit Log, None if it's only kept in memory
+    pub audit_log_dir: Option<PathBuf>,
+
+    /// Where the configuration is backed up before being replaced, None if disabled
+    pub backup_dir: Option<PathBuf>,
+
+    /// How many automatic backups to keep before removing the oldest
+    pub backup_keep: usize,
+
+    /// Where device profiles are stored between runs
+    pub profile_backend: ProfileBackend,
+
+    /// The directory the profile backend keeps its files in
+    pub profile_dir: PathBuf,
+
+    /// Where recorded Automations are stored, and played from
+    pub automation_dir: PathBuf,
+
+    /// Whether, and how, the daemon keeps itself up to date
+    pub updates: UpdateSettings,
+
+    /// Gate and Ducking values used in place of the profile's at certain times of day
+    pub schedule: Vec<ScheduledOverride>,
+}
+
+/// Settings for updating the daemon from its published releases. Updates are disabled unless a
+/// channel is chosen, and are only ever applied as the daemon stops.
+#[derive(Debug, Clone)]
+pub struct UpdateSettings {
+    /// The release channel to follow, None if updates are disabled
+    pub channel: Option<ReleaseChannel>,
+
+    /// How often (in hours) to check for a new release, 0 to only check when asked
+    pub check_hours: u64,
+
+    /// Whether a new release is downloaded as soon as it's found, rather than when asked
+    pub auto_download: bool,
+
+    /// Where downloaded updates are kept until they're applied
+    pub directory: PathBuf,
+}
+
+/// How device profiles are stored between runs
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum ProfileBackend {
+    /// A JSON file per device, named by its serial
+    #[default]
+    Json,
+
+    /// A single SQLite database, which also keeps earlier versions of each profile
+    Sqlite,
+
+    /// Nothing is stored, devices always start with the default profiles
+    Defaults,
+}
+
+/// Physical input which arrives during a profile load can race with it, such as a mute being
+/// pressed while the mute states are being set up. This controls how that input is handled.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum LoadInputBehaviour {
+    /// Hold the input back, and handle it in order once the load has finished
+    #[default]
+    Queue,
+
+    /// Stop polling the device during the load, so presses made during it are ignored. Anything
+    /// still different once polling resumes (a held button, or a moved fader) is then handled.
+    Drop,
+}
+
+/// Desktop notifications for things a user may want to know about, even when nothing else is
+/// open. Each is disabled by default.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct NotificationSettings {
+    /// When the Microphone is muted or unmuted
+    pub mic_mute: bool,
+
+    /// When a profile is loaded, such as by activating a Mix Mode
+    pub profile: bool,
+
+    /// When a device is removed
+    pub disconnect: bool,
+}
+
+/// Sounds played as things happen, so the state of the daemon can be heard without looking at
+/// anything. Each is disabled by default.
+#[derive(Debug, Default, Clone)]
+pub struct AudioCueSettings {
+    /// The OS playback device cues are played through, None for the system default. This can
+    /// be the GoXLR's Sample device, so the cues follow the Sample channel's routing.
+    pub device: Option<String>,
+
+    /// When the daemon has started
+    pub ready: Option<CueSound>,
+
+    /// When a device has finished initialising
+    pub connected: Option<CueSound>,
+
+    /// When a profile is loaded, such as by activating a Mix Mode
+    pub profile: Option<CueSound>,
+
+    /// When the Microphone is muted or unmuted
+    pub mic_mute: Option<CueSound>,
+}
+
+/// What's played for a cue, either 'chime' for the built in sound, or the path to a WAV file
+#[derive(Debug, Clone)]
+pub enum CueSound {
+    Chime,
+    File(PathBuf),
+}
+
+impl FromStr for CueSound {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "chime" => CueSound::Chime,
+            path => CueSound::File(PathBuf::from(path)),
+        })
+    }
+}
+
+/// Gate and Ducking values which replace the profile's between two times of day, such as raising
+/// the gate at night when the room is quieter. Written as 'HH:MM-HH:MM,name=value,...' where the
+/// names are gate-threshold, duck-trigger, duck-mic-offset and duck-release. A window which ends
+/// before it starts runs over midnight.
+#[derive(Debug, Clone)]
+pub struct ScheduledOverride {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+
+    /// The Microphone's gate threshold (-59 to 0 dB)
+    pub gate_threshold: Option<i8>,
+
+    /// The level (in dB) an Input other than the Microphone needs to reach to trigger ducking
+    pub duck_trigger_db: Option<f64>,
+
+    /// Added to the gate threshold when the Microphone is a ducking trigger (-24 to 24 dB)
+    pub duck_mic_offset: Option<i8>,
+
+    /// How long (in ms) all triggers need to be inactive before unducking (up to 5000)
+    pub duck_release: Option<u64>,
+}
+
+impl ScheduledOverride {
+    pub fn is_active(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl FromStr for ScheduledOverride {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split(',');
+        let window = parts.next().unwrap_or_default();
+        let Some((start, end)) = window.split_once('-') else {
+            return Err(format!("Invalid window {}, expected HH:MM-HH:MM", window));
+        };
+
+        let parse_time = |time: &str| {
+            NaiveTime::parse_from_str(time.trim(), "%H:%M")
+                .map_err(|_| format!("Invalid Time {}, expected HH:MM", time))
+        };
+        let mut scheduled = Self {
+            start: parse_time(start)?,
+            end: parse_time(end)?,
+            gate_threshold: None,
+            duck_trigger_db: None,
+            duck_mic_offset: None,
+            duck_release: None,
+        };
+
+        for part in parts {
+            let Some((name, setting)) = part.split_once('=') else {
+                return Err(format!("Expected name=value, found {}", part));
+            };
+            let invalid = || format!("Invalid value for {}: {}", name, setting);
+            let setting = setting.trim();
+            match name.trim() {
+                "gate-threshold" => {
+                    let threshold = setting.parse().map_err(|_| invalid())?;
+                    if !(-59..=0).contains(&threshold) {
+                        return Err(String::from("The gate-threshold must be between -59 and 0"));
+                    }
+                    scheduled.gate_threshold = Some(threshold);
+                }
+                "duck-trigger" => {
+                    let level: f64 = setting.parse().map_err(|_| invalid())?;
+                    if !(-72.0..0.).contains(&level) {
+                        return Err(String::from("The duck-trigger must be between -72 and 0"));
+                    }
+                    scheduled.duck_trigger_db = Some(level);
+                }
+                "duck-mic-offset" => {
+                    let offset = setting.parse().map_err(|_| invalid())?;
+                    if !(-24..=24).contains(&offset) {
+                        return Err(String::from("The duck-mic-offset must be within 24"));
+                    }
+                    scheduled.duck_mic_offset = Some(offset);
+                }
+                "duck-release" => {
+                    let release = setting.parse().map_err(|_| invalid())?;
+                    if release > 5000 {
+                        return Err(String::from("The duck-release must be 5000 or less"));
+                    }
+                    scheduled.duck_release = Some(release);
+                }
+                name => return Err(format!("Unknown Override {}", name)),
+            }
+        }
+        Ok(scheduled)
+    }
+}
+
+/// Settings for writing the daemon log to disk
+#[derive(Debug, Clone)]
+pub struct LogSettings {
+    /// Where log files are written, None if file logging is disabled
+    pub directory: Option<PathBuf>,
+
+    /// The size (in bytes) a log file can reach before it's rotated
+    pub max_size: u64,
+
+    /// How long a log file can be written to before it's rotated, None to only rotate on size
+    pub max_age: Option<Duration>,
+
+    /// How many rotated log files to keep alongside the current one
+    pub keep: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::NaiveTime;
+
+    use crate::settings::ScheduledOverride;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn override_is_parsed() {
+        let value = "22:00-07:30,gate-threshold=-30,duck-trigger=-35.5,duck-mic-offset=-6,\
+            duck-release=750";
+        let scheduled = ScheduledOverride::from_str(value).unwrap();
+
+        assert_eq!(scheduled.start, time(22, 0));
+        assert_eq!(scheduled.end, time(7, 30));
+        assert_eq!(scheduled.gate_threshold, Some(-30));
+        assert_eq!(scheduled.duck_trigger_db, Some(-35.5));
+        assert_eq!(scheduled.duck_mic_offset, Some(-6));
+        assert_eq!(scheduled.duck_release, Some(750));
+    }
+
+    #[test]
+    fn override_values_are_optional() {
+        let scheduled = ScheduledOverride::from_str("09:00-17:00").unwrap();
+        assert_eq!(scheduled.gate_threshold, None);
+        assert_eq!(scheduled.duck_trigger_db, None);
+        assert_eq!(scheduled.duck_mic_offset, None);
+        assert_eq!(scheduled.duck_release, None);
+    }
+
+    #[test]
+    fn invalid_overrides_are_rejected() {
+        let invalid = [
+            "09:00",
+            "9am-5pm",
+            "09:00-17:00,gate-threshold",
+            "09:00-17:00,gate-threshold=-60",
+            "09:00-17:00,duck-trigger=0",
+            "09:00-17:00,duck-mic-offset=25",
+            "09:00-17:00,duck-release=5001",
+            "09:00-17:00,duck-release=-1",
+            "09:00-17:00,volume=50",
+        ];
+        for value in invalid {
+            assert!(ScheduledOverride::from_str(value).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn window_is_active() {
+        let scheduled = ScheduledOverride::from_str("09:00-17:00").unwrap();
+        assert!(!scheduled.is_active(time(8, 59)));
+        assert!(scheduled.is_active(time(9, 0)));
+        assert!(scheduled.is_active(time(16, 59)));
+        assert!(!scheduled.is_active(time(17, 0)));
+    }
+
+    #[test]
+    fn window_over_midnight_is_active() {
+        let scheduled = ScheduledOverride::from_str("22:00-07:00").unwrap();
+        assert!(scheduled.is_active(time(22, 0)));
+        assert!(scheduled.is_active(time(23, 59)));
+        assert!(scheduled.is_active(time(0, 0)));
+        assert!(scheduled.is_active(time(6, 59)));
+        assert!(!scheduled.is_active(time(7, 0)));
+        assert!(!scheduled.is_active(time(12, 0)));
+        assert!(!scheduled.is_active(time(21, 59)));
+    }
+}
@@ -0,0 +1,331 @@
+/*
+   Runs a profile against a Mock Device and prints what it did, so a profile author can check the
+   routing, colours and ducking without needing the hardware. The profile goes through the same
+   load as a real device, and the mic trace is fed through the Mock's meter in real time, so the
+   ducking timeline is exactly what a device would see.
+*/
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::{sleep, timeout, Instant};
+
+use goxlr_ipc::commands::configuration::ConfigurationCommand;
+use goxlr_ipc::commands::diagnostics::{DiagnosticsCommand, RoutingStateReport};
+use goxlr_ipc::commands::ducking::DuckingCommand;
+use goxlr_ipc::commands::{
+    DaemonRequest, DaemonResponse, DeviceCommand, GoXLRCommand, GoXLRCommandResponse,
+};
+use goxlr_ipc::status::{Configuration, DeviceStatus};
+use goxlr_profile::Profile;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::RoutingOutput;
+use goxlr_shared::colours::Colour;
+use goxlr_shared::faders::Fader;
+use goxlr_shared::routing::RouteValue;
+use goxlr_usb::mock::MockDevice;
+
+use crate::device::device_manager::start_device_manager;
+use crate::device::packet::{handle_packet, Messenger};
+use crate::settings::DeviceSettings;
+use crate::stop::Stop;
+
+const SIMULATE_SERIAL: &str = "SIMULATE0000";
+
+// How long the device has to be picked up and start running before we give up
+const START_TIMEOUT: Duration = Duration::from_secs(10);
+
+// How often the ducker is checked while the trace plays
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(10);
+
+// Extra time after the release, so the end of the unduck is always on the timeline
+const TRACE_TAIL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Deserialize)]
+pub struct MicTraceEntry {
+    /// Milliseconds from the start of the trace
+    at: u64,
+
+    /// The Microphone level, in dB
+    level: f64,
+}
+
+pub struct Simulation {
+    profile: Profile,
+    trace: Vec<MicTraceEntry>,
+}
+
+/// Loads the profile, and the mic trace if there is one, so a bad file stops us before the device
+/// manager has been started
+pub fn load_simulation(profile: &Path, trace: Option<&Path>) -> Result<Simulation> {
+    let content = fs::read_to_string(profile).context("Unable to read the Profile")?;
+    let profile = serde_json::from_str(&content).context("The Profile is invalid")?;
+
+    let mut trace: Vec<MicTraceEntry> = match trace {
+        Some(path) => {
+            let content = fs::read_to_string(path).context("Unable to read the Mic Trace")?;
+            serde_json::from_str(&content).context("The Mic Trace is invalid")?
+        }
+        None => vec![],
+    };
+    trace.sort_by_key(|entry| entry.at);
+    Ok(Simulation { profile, trace })
+}
+
+pub async fn run_simulation(simulation: Simulation, settings: DeviceSettings) -> Result<()> {
+    let shutdown = Stop::new();
+    let (manager_send, manager_recv) = mpsc::channel(32);
+    let (broadcast_tx, broadcast_rx) = broadcast::channel(16);
+    drop(broadcast_rx);
+    let (fader_tx, fader_rx) = broadcast::channel(16);
+    drop(fader_rx);
+
+    let manager = tokio::spawn(start_device_manager(
+        manager_recv,
+        shutdown.clone(),
+        broadcast_tx,
+        fader_tx,
+        settings,
+        None,
+    ));
+
+    let mock = MockDevice::attach(0, SIMULATE_SERIAL);
+    let result = simulate(simulation, &mock, manager_send).await;
+
+    mock.detach();
+    shutdown.trigger();
+    let _ = manager.await;
+    result
+}
+
+async fn simulate(simulation: Simulation, mock: &MockDevice, messenger: Messenger) -> Result<()> {
+    let Simulation { profile, trace } = simulation;
+
+    timeout(START_TIMEOUT, mock.wait_for_poll())
+        .await
+        .context("The Mock Device wasn't started")?;
+
+    // The device starts with its default Mic Profile, which is kept, only the Profile is replaced
+    let status = get_device_status(messenger.clone()).await?;
+    let configuration = Configuration {
+        device: profile,
+        mic_profile: status.config.mic_profile,
+    };
+    let command = ConfigurationCommand::Restore(Box::new(configuration));
+    let response = send_command(messenger.clone(), GoXLRCommand::Configuration(command)).await?;
+    let GoXLRCommandResponse::ProfileLoaded(report) = response else {
+        bail!("Unexpected Response: {:?}", response);
+    };
+
+    if let Some(reason) = &report.safe_mode {
+        println!(
+            "The Profile failed to load, Safe Mode was applied: {}",
+            reason
+        );
+    }
+    for skipped in report.skipped.iter().chain(&report.timings) {
+        println!("Warning: {}", skipped);
+    }
+
+    let status = get_device_status(messenger.clone()).await?;
+    for warning in &status.runtime.routing_warnings {
+        println!("Routing Rule Broken: {}", warning);
+    }
+
+    println!();
+    println!("Routing:");
+    print_routing(&get_routing_state(messenger.clone()).await?);
+
+    println!();
+    println!("Colours:");
+    print_colours(&status.config.device);
+
+    if !trace.is_empty() {
+        println!();
+        println!("Ducking Timeline:");
+        if !status.config.device.ducking.enabled {
+            println!("  Ducking is disabled in this Profile");
+        }
+        run_trace(&status.config.device, trace, mock, messenger).await?;
+    }
+    Ok(())
+}
+
+fn print_routing(report: &RoutingStateReport) {
+    let routes: HashMap<_, _> = report
+        .routes
+        .iter()
+        .map(|route| ((route.input, route.output), route.value))
+        .collect();
+
+    let mut header = format!("  {:<14}", "");
+    for output in RoutingOutput::iter() {
+        header.push_str(&format!("{:>12}", format!("{:?}", output)));
+    }
+    println!("{}", header);
+
+    for input in InputChannels::iter() {
+        let mut line = format!("  {:<14}", format!("{:?}", input));
+        for output in RoutingOutput::iter() {
+            let value = routes.get(&(input, output)).copied().unwrap_or_default();
+            line.push_str(&format!("{:>12}", format_route(value)));
+        }
+        println!("{}", line);
+    }
+}
+
+fn print_colours(profile: &Profile) {
+    let lighting = &profile.lighting;
+    println!(
+        "  Accent {}, Global {}, Brightness {}%",
+        format_colour(lighting.accent),
+        format_colour(lighting.global),
+        lighting.brightness
+    );
+
+    for (index, page) in profile.pages.page_list.iter().enumerate() {
+        let current = match index == profile.pages.current {
+            true => " (Current)",
+            false => "",
+        };
+        println!("  Page {}{}", index + 1, current);
+
+        for fader in Fader::iter() {
+            let channel = page.faders[fader];
+            let display = &profile.channels.configs[channel].display;
+            println!(
+                "    {:?}: {:<14} Fader {} / {}, Mute {} / {}",
+                fader,
+                format!("{:?}", channel),
+                format_colour(display.fader_colours.top_colour),
+                format_colour(display.fader_colours.bottom_colour),
+                format_colour(display.mute_colours.active_colour),
+                format_colour(display.mute_colours.inactive_colour),
+            );
+        }
+    }
+}
+
+/// Plays the trace through the Mock's meter, printing a line whenever the mic level, or the
+/// ducker's state, changes
+async fn run_trace(
+    profile: &Profile,
+    trace: Vec<MicTraceEntry>,
+    mock: &MockDevice,
+    messenger: Messenger,
+) -> Result<()> {
+    let ducking = &profile.ducking;
+    let unduck: u64 = ducking
+        .transition
+        .unducking
+        .iter()
+        .map(|v| v.wait_time)
+        .sum();
+    let release = Duration::from_millis(ducking.release_time + unduck);
+    let last = Duration::from_millis(trace.last().map_or(0, |entry| entry.at));
+    let end = last + release + TRACE_TAIL;
+
+    let mut trace = trace.into_iter().peekable();
+    let mut last_state = None;
+    let start = Instant::now();
+
+    while start.elapsed() <= end {
+        let elapsed = start.elapsed();
+        while let Some(entry) = trace.next_if(|entry| entry.at <= elapsed.as_millis() as u64) {
+            mock.set_mic_level(entry.level);
+            println!("  {:>7}ms  Mic {:.1}dB", entry.at, entry.level);
+        }
+
+        let command = GoXLRCommand::Ducking(DuckingCommand::GetStats);
+        let response = send_command(messenger.clone(), command).await?;
+        let GoXLRCommandResponse::DuckingStats(stats) = response else {
+            bail!("Unexpected Response: {:?}", response);
+        };
+
+        // Only the routes being lowered are shown, everything else is as in the Routing above
+        let report = get_routing_state(messenger.clone()).await?;
+        let lowered: Vec<String> = report
+            .routes
+            .iter()
+            .filter_map(|route| match route.value {
+                RouteValue::Value(value) => Some(format!(
+                    "{:?} > {:?} at {}",
+                    route.input, route.output, value
+                )),
+                _ => None,
+            })
+            .collect();
+
+        let state = (stats.ducked, lowered);
+        if last_state.as_ref() != Some(&state) {
+            let (ducked, lowered) = &state;
+            let label = match ducked {
+                true => "Ducked",
+                false => "Unducked",
+            };
+            let time = elapsed.as_millis();
+            match lowered.is_empty() {
+                true => println!("  {:>7}ms  {}", time, label),
+                false => println!("  {:>7}ms  {}: {}", time, label, lowered.join(", ")),
+            }
+            last_state = Some(state);
+        }
+        sleep(SAMPLE_INTERVAL).await;
+    }
+    Ok(())
+}
+
+async fn get_device_status(messenger: Messenger) -> Result<DeviceStatus> {
+    // The device can be polled slightly before it appears in the status
+    let started = Instant::now();
+    loop {
+        let response = handle_packet(DaemonRequest::GetStatus, messenger.clone()).await?;
+        let DaemonResponse::Status(mut status) = response else {
+            bail!("Unexpected Daemon Status Result: {:?}", response);
+        };
+        if let Some(device) = status.devices.remove(SIMULATE_SERIAL) {
+            return Ok(device);
+        }
+        if started.elapsed() > START_TIMEOUT {
+            bail!("The Mock Device didn't appear in the status");
+        }
+        sleep(SAMPLE_INTERVAL).await;
+    }
+}
+
+async fn get_routing_state(messenger: Messenger) -> Result<RoutingStateReport> {
+    let command = GoXLRCommand::Diagnostics(DiagnosticsCommand::GetRoutingState);
+    match send_command(messenger, command).await? {
+        GoXLRCommandResponse::RoutingState(report) => Ok(report),
+        response => bail!("Unexpected Response: {:?}", response),
+    }
+}
+
+async fn send_command(messenger: Messenger, command: GoXLRCommand) -> Result<GoXLRCommandResponse> {
+    let serial = String::from(SIMULATE_SERIAL);
+    let request = DaemonRequest::DeviceCommand(DeviceCommand { serial, command });
+
+    match handle_packet(request, messenger).await? {
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::Error(error)) => Err(anyhow!(error)),
+        DaemonResponse::DeviceCommand(response) => Ok(response),
+        response => bail!("Unexpected Response: {:?}", response),
+    }
+}
+
+fn format_route(value: RouteValue) -> String {
+    match value {
+        RouteValue::On => String::from("on"),
+        RouteValue::Off => String::from("-"),
+        RouteValue::Value(value) => value.to_string(),
+    }
+}
+
+fn format_colour(colour: Colour) -> String {
+    format!("#{:02X}{:02X}{:02X}", colour.red, colour.green, colour.blue)
+}
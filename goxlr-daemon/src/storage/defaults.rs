@@ -0,0 +1,25 @@
+use anyhow::Result;
+use log::debug;
+
+use goxlr_ipc::status::Configuration;
+
+use crate::storage::ProfileStore;
+
+/// Nothing is stored, every device starts with the built in profiles, and anything changed is
+/// lost when the daemon stops. Useful for testing, or for kiosk style setups.
+pub struct DefaultProfileStore;
+
+impl ProfileStore for DefaultProfileStore {
+    fn load(&self, _: &str) -> Result<Option<Configuration>> {
+        Ok(None)
+    }
+
+    fn save(&self, serial: &str, _: &Configuration) -> Result<()> {
+        debug!("Profile Storage is read only, not storing {}", serial);
+        Ok(())
+    }
+
+    fn set_aside(&self, _: &str) -> Result<()> {
+        Ok(())
+    }
+}
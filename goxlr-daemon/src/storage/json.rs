@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+
+use goxlr_ipc::status::Configuration;
+
+use crate::storage::ProfileStore;
+
+/// A JSON file per device in a directory, named by its serial
+pub struct JsonProfileStore {
+    directory: PathBuf,
+}
+
+impl JsonProfileStore {
+    pub fn new(directory: &Path) -> Self {
+        Self {
+            directory: directory.to_path_buf(),
+        }
+    }
+
+    fn get_path(&self, serial: &str) -> PathBuf {
+        self.directory.join(format!("{}.json", serial))
+    }
+}
+
+impl ProfileStore for JsonProfileStore {
+    fn load(&self, serial: &str) -> Result<Option<Configuration>> {
+        let path = self.get_path(serial);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let configuration = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid Profile at {}", path.display()))?;
+        Ok(Some(configuration))
+    }
+
+    fn save(&self, serial: &str, configuration: &Configuration) -> Result<()> {
+        fs::create_dir_all(&self.directory).context("Unable to create Profile directory")?;
+
+        // Written alongside then moved into place, so a crash part way through never leaves a
+        // broken profile behind.
+        let path = self.get_path(serial);
+        let temp = path.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_string_pretty(configuration)?)?;
+        fs::rename(&temp, &path)?;
+        Ok(())
+    }
+
+    fn set_aside(&self, serial: &str) -> Result<()> {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        // Named so it's never picked up as a device's profile again
+        let path = self.get_path(serial);
+        let aside = path.with_extension(format!("json.{}.unreadable", time));
+        fs::rename(&path, &aside).context("Unable to move the unreadable Profile")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::Value;
+
+    use goxlr_ipc::status::Configuration;
+    use goxlr_profile::{MicProfile, Profile};
+    use goxlr_shared::channels::ducking::DuckingSensitivity;
+
+    use crate::storage::json::JsonProfileStore;
+    use crate::storage::ProfileStore;
+
+    // Settings added to the profile since profiles started being stored, by where they are
+    const NEWER_FIELDS: [(&str, &[&str]); 7] = [
+        ("", &["hard_mute", "button_cycles", "fader_response"]),
+        ("/channels", &["categories"]),
+        (
+            "/ducking",
+            &[
+                "category_routing",
+                "trigger_db",
+                "input_trigger_db",
+                "capture_devices",
+                "mic_trigger_offset",
+                "sensitivity",
+                "sensitivity_button",
+                "fade_button",
+                "fast_unduck_on_mute",
+            ],
+        ),
+        ("/cough", &["target"]),
+        ("/lighting", &["channel_linked"]),
+        ("/routing_rules", &["voice_applications"]),
+        ("/sampler", &["playlists"]),
+    ];
+
+    #[test]
+    fn older_stored_profile_loads() {
+        let configuration = Configuration {
+            device: Profile::default(),
+            mic_profile: MicProfile::default(),
+        };
+
+        // Write out a profile as it would've been stored before the newer settings existed
+        let mut device = serde_json::to_value(&configuration.device).unwrap();
+        for (path, fields) in NEWER_FIELDS {
+            let section = device.pointer_mut(path).unwrap().as_object_mut().unwrap();
+            for field in fields {
+                assert!(section.remove(*field).is_some(), "{}/{}", path, field);
+            }
+        }
+        let outputs = device["outputs"].as_object_mut().unwrap();
+        for output in outputs.values_mut() {
+            output.as_object_mut().unwrap().remove("ceiling");
+        }
+
+        let stored = Value::Object(serde_json::Map::from_iter([
+            (String::from("device"), device),
+            (
+                String::from("mic_profile"),
+                serde_json::to_value(&configuration.mic_profile).unwrap(),
+            ),
+        ]));
+
+        let directory = std::env::temp_dir().join(format!("goxlr-json-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("OLDER.json"), stored.to_string()).unwrap();
+
+        let store = JsonProfileStore::new(&directory);
+        let loaded = store.load("OLDER");
+        fs::remove_dir_all(&directory).unwrap();
+
+        let profile = loaded.unwrap().unwrap().device;
+        let default = configuration.device;
+        assert_eq!(profile.channels.categories, default.channels.categories);
+        assert_eq!(
+            profile.ducking.category_routing,
+            default.ducking.category_routing
+        );
+        assert_eq!(profile.ducking.trigger_db, -40.);
+        assert_eq!(
+            profile.ducking.sensitivity,
+            Some(DuckingSensitivity::Medium)
+        );
+        assert!(profile.outputs.values().all(|output| output.ceiling == 32));
+        assert!(profile.hard_mute.engaged.is_none());
+    }
+}
@@ -0,0 +1,43 @@
+/*
+   Where device profiles are kept between runs. Each backend stores the Profile and Mic Profile
+   of a device by its serial, so nothing else in the daemon needs to know where they end up.
+*/
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use goxlr_ipc::status::Configuration;
+
+use crate::settings::ProfileBackend;
+use crate::storage::defaults::DefaultProfileStore;
+use crate::storage::json::JsonProfileStore;
+use crate::storage::sqlite::SqliteProfileStore;
+
+pub mod defaults;
+mod json;
+mod sqlite;
+
+pub trait ProfileStore: Send + Sync {
+    /// The stored configuration of a device, None if nothing has been stored for it yet
+    fn load(&self, serial: &str) -> Result<Option<Configuration>>;
+
+    /// Stores a device's configuration, replacing what's there
+    fn save(&self, serial: &str, configuration: &Configuration) -> Result<()>;
+
+    /// Moves a stored configuration which couldn't be loaded out of the way, so it's kept for the
+    /// user to recover rather than being replaced by the next save
+    fn set_aside(&self, serial: &str) -> Result<()>;
+}
+
+pub fn open_profile_store(
+    backend: ProfileBackend,
+    directory: &Path,
+) -> Result<Arc<dyn ProfileStore>> {
+    Ok(match backend {
+        ProfileBackend::Json => Arc::new(JsonProfileStore::new(directory)),
+        ProfileBackend::Sqlite => Arc::new(SqliteProfileStore::open(directory)?),
+        ProfileBackend::Defaults => Arc::new(DefaultProfileStore),
+    })
+}
@@ -0,0 +1,99 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use goxlr_ipc::status::Configuration;
+
+use crate::storage::ProfileStore;
+
+const DATABASE_NAME: &str = "profiles.sqlite";
+
+// How many previous versions of each device's configuration are kept
+const HISTORY_SIZE: u32 = 50;
+
+/// A single SQLite database holding every device. Each save adds a new row rather than replacing
+/// the last, so earlier versions of a configuration are kept (up to HISTORY_SIZE) for anything
+/// which wants to look back through them.
+pub struct SqliteProfileStore {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteProfileStore {
+    pub fn open(directory: &Path) -> Result<Self> {
+        fs::create_dir_all(directory).context("Unable to create Profile directory")?;
+
+        let connection = Connection::open(directory.join(DATABASE_NAME))
+            .context("Unable to open the Profile database")?;
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS profiles (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                serial TEXT NOT NULL,
+                saved INTEGER NOT NULL,
+                configuration TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS profiles_serial ON profiles (serial, id);",
+        )?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+}
+
+impl ProfileStore for SqliteProfileStore {
+    fn load(&self, serial: &str) -> Result<Option<Configuration>> {
+        let connection = self.connection.lock().map_err(|e| anyhow!(e.to_string()))?;
+        let configuration: Option<String> = connection
+            .query_row(
+                "SELECT configuration FROM profiles WHERE serial = ?1 ORDER BY id DESC LIMIT 1",
+                params![serial],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        let Some(configuration) = configuration else {
+            return Ok(None);
+        };
+        let configuration = serde_json::from_str(&configuration)
+            .with_context(|| format!("Invalid Profile stored for {}", serial))?;
+        Ok(Some(configuration))
+    }
+
+    fn save(&self, serial: &str, configuration: &Configuration) -> Result<()> {
+        let saved = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+        let json = serde_json::to_string(configuration)?;
+
+        let connection = self.connection.lock().map_err(|e| anyhow!(e.to_string()))?;
+        connection.execute(
+            "INSERT INTO profiles (serial, saved, configuration) VALUES (?1, ?2, ?3)",
+            params![serial, saved, json],
+        )?;
+        connection.execute(
+            "DELETE FROM profiles WHERE serial = ?1 AND id NOT IN (
+                SELECT id FROM profiles WHERE serial = ?1 ORDER BY id DESC LIMIT ?2
+            )",
+            params![serial, HISTORY_SIZE],
+        )?;
+        Ok(())
+    }
+
+    fn set_aside(&self, serial: &str) -> Result<()> {
+        // Moving the row under a different serial keeps it out of the device's history, so it's
+        // neither loaded nor pruned by later saves
+        let connection = self.connection.lock().map_err(|e| anyhow!(e.to_string()))?;
+        connection.execute(
+            "UPDATE profiles SET serial = ?1 || '.unreadable' WHERE id = (
+                SELECT id FROM profiles WHERE serial = ?1 ORDER BY id DESC LIMIT 1
+            )",
+            params![serial],
+        )?;
+        Ok(())
+    }
+}
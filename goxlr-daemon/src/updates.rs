@@ -0,0 +1,287 @@
+/*
+   Keeps the daemon up to date with its published releases. A release is only ever staged if it's
+   been signed by the release key built into the daemon, and is swapped in as the daemon stops, so
+   the running binary is never replaced underneath itself.
+
+   The signature covers the release's version as well as its binary, so an older signed release
+   can't be staged in place of a newer one to downgrade the daemon.
+*/
+
+use std::env::consts::{ARCH, EXE_SUFFIX, OS};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ed25519_dalek::{Signature, VerifyingKey};
+use log::{debug, info};
+use semver::Version;
+use serde::Deserialize;
+
+use goxlr_ipc::status::{ReleaseChannel, ReleaseInfo};
+
+const RELEASES_URL: &str = "https://api.github.com/repos/JulanDeAlb/goxlr-playground/releases";
+const USER_AGENT: &str = concat!("goxlr-daemon/", env!("CARGO_PKG_VERSION"));
+
+// The base64 ed25519 public key releases are signed with, builds without one can check for
+// updates, but can't install them
+const RELEASE_KEY: Option<&str> = option_env!("GOXLR_RELEASE_KEY");
+
+// The names the verified binary, its version, and its signature, are staged under
+const STAGED_BINARY: &str = "goxlr-daemon.update";
+const STAGED_VERSION: &str = "goxlr-daemon.update.version";
+const STAGED_SIGNATURE: &str = "goxlr-daemon.update.sig";
+
+/// A release which is newer than the running daemon, and has a binary for this platform
+#[derive(Debug, Clone)]
+pub struct Release {
+    pub version: Version,
+    pub name: String,
+    pub url: String,
+    pub prerelease: bool,
+
+    binary: String,
+    signature: String,
+}
+
+impl Release {
+    pub fn get_info(&self) -> ReleaseInfo {
+        ReleaseInfo {
+            version: self.version.to_string(),
+            name: self.name.clone(),
+            url: self.url.clone(),
+            prerelease: self.prerelease,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    name: Option<String>,
+    html_url: String,
+    prerelease: bool,
+    draft: bool,
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// Finds the newest release on the channel, returning None if the running daemon is up to date
+pub async fn check_for_update(channel: ReleaseChannel) -> Result<Option<Release>> {
+    let releases: Vec<GitHubRelease> = get_client()?
+        .get(RELEASES_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await
+        .context("Unable to read the Release list")?;
+
+    let current = get_current_version()?;
+    let asset = get_asset_name();
+
+    let newest = releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .filter(|release| channel == ReleaseChannel::Beta || !release.prerelease)
+        .filter_map(|release| to_release(release, &asset))
+        .max_by(|a, b| a.version.cmp(&b.version));
+    Ok(newest.filter(|release| release.version > current))
+}
+
+/// Downloads the release's binary and signature, and stages them to be applied once they've
+/// been verified
+pub async fn download_update(release: &Release, directory: &Path) -> Result<()> {
+    let key = get_release_key()?;
+    let client = get_client()?;
+
+    debug!("Downloading Update from {}", release.binary);
+    let binary = client.get(&release.binary).send().await?;
+    let binary = binary.error_for_status()?.bytes().await?;
+    let signature = client.get(&release.signature).send().await?;
+    let signature = signature.error_for_status()?.text().await?;
+
+    let version = &release.version;
+    verify(&key, version, &binary, &signature)
+        .context("The downloaded Update failed verification")?;
+
+    create_directory(directory).context("Unable to create the Update directory")?;
+    check_directory(directory)?;
+    fs::write(directory.join(STAGED_BINARY), &binary)?;
+    fs::write(directory.join(STAGED_VERSION), version.to_string())?;
+    fs::write(directory.join(STAGED_SIGNATURE), signature.trim())?;
+    info!("Update {} Staged, applying on restart", release.version);
+    Ok(())
+}
+
+/// Whether there's an update waiting to be applied
+pub fn is_update_staged(directory: &Path) -> bool {
+    let staged = [STAGED_BINARY, STAGED_VERSION, STAGED_SIGNATURE];
+    staged.iter().all(|name| directory.join(name).exists())
+}
+
+/// Replaces the daemon's binary with the staged update, if there is one. The update is verified
+/// again first, as the directory may have been changed since it was downloaded, and is only
+/// applied if it's newer than the running daemon. Returns whether an update was applied.
+pub fn apply_staged_update(directory: &Path) -> Result<bool> {
+    if !is_update_staged(directory) {
+        return Ok(false);
+    }
+    check_directory(directory)?;
+
+    let staged = directory.join(STAGED_BINARY);
+    let binary = fs::read(&staged)?;
+    let version = fs::read_to_string(directory.join(STAGED_VERSION))?;
+    let version = Version::parse(version.trim()).context("The staged Version is invalid")?;
+    let signature = fs::read_to_string(directory.join(STAGED_SIGNATURE))?;
+    verify(&get_release_key()?, &version, &binary, &signature)
+        .context("The staged Update failed verification")?;
+
+    if version <= get_current_version()? {
+        remove_staged(directory);
+        bail!("The staged Update ({}) isn't newer than this", version);
+    }
+
+    // The running binary can't be overwritten on every platform, but it can be moved aside
+    let executable = std::env::current_exe()?;
+    let previous = get_previous_path(&executable);
+    let _ = fs::remove_file(&previous);
+    fs::rename(&executable, &previous).context("Unable to move the current daemon aside")?;
+
+    if let Err(error) = write_executable(&executable, &binary) {
+        let _ = fs::rename(&previous, &executable);
+        return Err(error.context("Unable to install the Update"));
+    }
+
+    remove_staged(directory);
+    info!(
+        "Update {} Applied, old daemon kept at {:?}",
+        version, previous
+    );
+    Ok(true)
+}
+
+fn remove_staged(directory: &Path) {
+    for name in [STAGED_BINARY, STAGED_VERSION, STAGED_SIGNATURE] {
+        let _ = fs::remove_file(directory.join(name));
+    }
+}
+
+fn to_release(release: GitHubRelease, asset: &str) -> Option<Release> {
+    let tag = release.tag_name.trim_start_matches('v');
+    let Ok(version) = Version::parse(tag) else {
+        debug!("Ignoring Release {}, it isn't a version", release.tag_name);
+        return None;
+    };
+
+    let signature_name = format!("{}.sig", asset);
+    let find = |name: &str| {
+        let found = release.assets.iter().find(|asset| asset.name == name);
+        found.map(|asset| asset.browser_download_url.clone())
+    };
+
+    let (Some(binary), Some(signature)) = (find(asset), find(&signature_name)) else {
+        debug!("Ignoring Release {}, it has no signed {}", version, asset);
+        return None;
+    };
+
+    Some(Release {
+        name: release.name.unwrap_or_else(|| release.tag_name.clone()),
+        version,
+        url: release.html_url,
+        prerelease: release.prerelease,
+        binary,
+        signature,
+    })
+}
+
+/// Checks the signature, which is made over the version (as a line of text) followed by the
+/// binary itself
+fn verify(key: &VerifyingKey, version: &Version, binary: &[u8], signature: &str) -> Result<()> {
+    let signature = STANDARD.decode(signature.trim())?;
+    let Ok(signature) = <[u8; 64]>::try_from(signature) else {
+        bail!("The Signature is the wrong length");
+    };
+
+    let mut message = format!("{}\n", version).into_bytes();
+    message.extend_from_slice(binary);
+    key.verify_strict(&message, &Signature::from_bytes(&signature))?;
+    Ok(())
+}
+
+fn create_directory(directory: &Path) -> Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    Ok(builder.create(directory)?)
+}
+
+/// Anyone else who can write to the directory could swap what's staged between it being checked
+/// and applied, so updates are refused from directories other users can write to
+fn check_directory(directory: &Path) -> Result<()> {
+    let metadata = fs::symlink_metadata(directory)?;
+    if !metadata.is_dir() {
+        bail!("The Update directory {:?} isn't a directory", directory);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o022 != 0 {
+            bail!("{:?} can be written to by others", directory);
+        }
+    }
+    Ok(())
+}
+
+fn write_executable(path: &Path, binary: &[u8]) -> Result<()> {
+    fs::write(path, binary)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(())
+}
+
+fn get_client() -> Result<reqwest::Client> {
+    Ok(reqwest::Client::builder().user_agent(USER_AGENT).build()?)
+}
+
+fn get_release_key() -> Result<VerifyingKey> {
+    let Some(key) = RELEASE_KEY else {
+        bail!("This daemon was built without a Release Key, so updates can't be verified");
+    };
+
+    let Ok(key) = <[u8; 32]>::try_from(STANDARD.decode(key)?) else {
+        bail!("The Release Key is the wrong length");
+    };
+    Ok(VerifyingKey::from_bytes(&key)?)
+}
+
+fn get_current_version() -> Result<Version> {
+    Ok(Version::parse(env!("CARGO_PKG_VERSION"))?)
+}
+
+/// The release asset holding the daemon for this platform, such as goxlr-daemon-linux-x86_64
+fn get_asset_name() -> String {
+    format!("goxlr-daemon-{}-{}{}", OS, ARCH, EXE_SUFFIX)
+}
+
+fn get_previous_path(executable: &Path) -> PathBuf {
+    let mut name = executable.as_os_str().to_owned();
+    name.push(".old");
+    PathBuf::from(name)
+}
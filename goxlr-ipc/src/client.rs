@@ -1,11 +1,33 @@
-use crate::commands::{DaemonRequest, DaemonStatus, GoXLRCommand};
-use anyhow::Result;
+use crate::commands::{
+    DaemonRequest, DaemonResponse, DaemonStatus, GoXLRCommand, GoXLRCommandResponse,
+};
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait Client {
     async fn send(&mut self, request: DaemonRequest) -> Result<()>;
+
+    /// Sends a request, returning the daemon's response rather than discarding it
+    async fn request(&mut self, request: DaemonRequest) -> Result<DaemonResponse>;
+
     async fn poll_status(&mut self) -> Result<()>;
     async fn command(&mut self, serial: &str, command: GoXLRCommand) -> Result<()>;
     fn status(&self) -> &DaemonStatus;
 }
+
+/// Turns errors from the daemon into our own, and rejects anything which should never be sent
+/// in reply to a request. This is common between all clients.
+pub(crate) fn check_response(response: DaemonResponse) -> Result<DaemonResponse> {
+    match response {
+        DaemonResponse::Err(error) => bail!("{}", error),
+        DaemonResponse::Patch(_) => bail!("Unexpected PATCH"),
+        DaemonResponse::FaderMoved(_) => bail!("Unexpected Fader Movement"),
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::Error(error)) => bail!("{}", error),
+        DaemonResponse::LiveModeBlocked(reason) => bail!("Blocked by Live Mode: {}", reason),
+        DaemonResponse::DeviceCommand(GoXLRCommandResponse::LiveModeBlocked(reason)) => {
+            bail!("Blocked by Live Mode: {}", reason)
+        }
+        response => Ok(response),
+    }
+}
@@ -1,15 +1,15 @@
-use crate::client::Client;
+use crate::client::{check_response, Client};
 use crate::clients::ipc::ipc_socket::Socket;
 use crate::commands::{
     DaemonRequest, DaemonResponse, DaemonStatus, DeviceCommand, GoXLRCommand, GoXLRCommandResponse,
 };
-use anyhow::{anyhow, bail, Context, Result};
+use anyhow::{bail, Context, Result};
 use async_trait::async_trait;
 use interprocess::local_socket::tokio::LocalSocketStream;
 use interprocess::local_socket::NameTypeSupport;
 
-static SOCKET_PATH: &str = "/tmp/goxlr.socket";
-static NAMED_PIPE: &str = "@goxlr.socket";
+pub static DEFAULT_SOCKET_PATH: &str = "/tmp/goxlr.socket";
+pub static DEFAULT_NAMED_PIPE: &str = "@goxlr.socket";
 
 #[derive(Debug)]
 pub struct IPCClient {
@@ -19,9 +19,15 @@ pub struct IPCClient {
 
 impl IPCClient {
     pub async fn connect() -> Result<Self> {
+        Self::connect_to(DEFAULT_SOCKET_PATH, DEFAULT_NAMED_PIPE).await
+    }
+
+    /// Connects to a daemon on a specific socket, whether the path or the pipe name is used
+    /// depends on what the platform supports.
+    pub async fn connect_to(socket_path: &str, pipe_name: &str) -> Result<Self> {
         let connection = LocalSocketStream::connect(match NameTypeSupport::query() {
-            NameTypeSupport::OnlyPaths | NameTypeSupport::Both => SOCKET_PATH,
-            NameTypeSupport::OnlyNamespaced => NAMED_PIPE,
+            NameTypeSupport::OnlyPaths | NameTypeSupport::Both => socket_path,
+            NameTypeSupport::OnlyNamespaced => pipe_name,
         })
         .await?;
         let socket: Socket<DaemonResponse, DaemonRequest> = Socket::new(connection);
@@ -40,32 +46,40 @@ impl IPCClient {
 #[async_trait]
 impl Client for IPCClient {
     async fn send(&mut self, request: DaemonRequest) -> Result<()> {
-        println!("Sending: {:?}", serde_json::to_string(&request));
+        match self.request(request).await? {
+            DaemonResponse::DeviceCommand(GoXLRCommandResponse::MicLevel(_)) => {
+                bail!("Unexpected MicLevel")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn request(&mut self, request: DaemonRequest) -> Result<DaemonResponse> {
         self.socket
             .send(request)
             .await
             .context("Failed to send a command to the GoXLR daemon process")?;
-        let result = self
-            .socket
-            .read()
-            .await
-            .context("Failed to retrieve the command result from the GoXLR daemon process")?
-            .context("Failed to parse the command result from the GoXLR daemon process")?;
 
-        match result {
-            DaemonResponse::Status(status) => {
-                self.status = status.clone();
-                Ok(())
+        // If this connection is subscribed, patches and fader movements can arrive before the
+        // reply, they're not what we're waiting for so are skipped.
+        let result = loop {
+            let result = self
+                .socket
+                .read()
+                .await
+                .context("Failed to retrieve the command result from the GoXLR daemon process")?
+                .context("Failed to parse the command result from the GoXLR daemon process")?;
+
+            match result {
+                DaemonResponse::Patch(_) | DaemonResponse::FaderMoved(_) => continue,
+                result => break result,
             }
-            DaemonResponse::Ok => Ok(()),
-            DaemonResponse::Err(error) => bail!("{}", error),
-            DaemonResponse::Patch(_) => bail!("Unexpected PATCH"),
-            DaemonResponse::DeviceCommand(response) => match response {
-                GoXLRCommandResponse::Ok => Ok(()),
-                GoXLRCommandResponse::MicLevel(_) => bail!("Unexpected MicLevel"),
-                GoXLRCommandResponse::Error(error) => Err(anyhow!("{}", error)),
-            },
+        };
+
+        if let DaemonResponse::Status(status) = &result {
+            self.status = status.clone();
         }
+        check_response(result)
     }
 
     async fn poll_status(&mut self) -> Result<()> {
@@ -1,4 +1,4 @@
-use crate::client::Client;
+use crate::client::{check_response, Client};
 use anyhow::Result;
 
 use crate::commands::{
@@ -29,6 +29,15 @@ impl WebClient {
 #[async_trait]
 impl Client for WebClient {
     async fn send(&mut self, request: DaemonRequest) -> anyhow::Result<()> {
+        match self.request(request).await? {
+            DaemonResponse::DeviceCommand(GoXLRCommandResponse::MicLevel(_)) => {
+                bail!("Unexpected MicLevel")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn request(&mut self, request: DaemonRequest) -> anyhow::Result<DaemonResponse> {
         let resp = reqwest::Client::new()
             .post(&self.url)
             .json(&request)
@@ -37,21 +46,10 @@ impl Client for WebClient {
             .json::<DaemonResponse>()
             .await?;
 
-        // Should probably abstract this part, it's common between clients..
-        match resp {
-            DaemonResponse::Status(status) => {
-                self.status = status.clone();
-                Ok(())
-            }
-            DaemonResponse::Ok => Ok(()),
-            DaemonResponse::Err(error) => bail!("{}", error),
-            DaemonResponse::Patch(_) => bail!("Received PATCH!"),
-            DaemonResponse::DeviceCommand(response) => match response {
-                GoXLRCommandResponse::Ok => Ok(()),
-                GoXLRCommandResponse::MicLevel(_) => bail!("Unexpected MicLevel"),
-                GoXLRCommandResponse::Error(error) => bail!("{}", error),
-            },
+        if let DaemonResponse::Status(status) = &resp {
+            self.status = status.clone();
         }
+        check_response(resp)
     }
 
     async fn poll_status(&mut self) -> anyhow::Result<()> {
@@ -0,0 +1,43 @@
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AutomationCommand {
+    /// Starts recording changes to the volumes and routing, whatever makes them
+    StartRecording,
+
+    /// Stops recording, and stores what was recorded under the given name
+    StopRecording(String),
+
+    /// Plays a stored Automation by name, replacing anything already playing
+    Play(String),
+
+    /// Stops the Automation which is playing, leaving everything as it currently is
+    StopPlayback,
+
+    /// Lists the names of the stored Automations
+    List,
+}
+
+/// A timeline of changes, stored as a file so it can be shared, or written by hand
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Automation {
+    /// The format of the file, a daemon won't play a newer format than it understands
+    pub version: u32,
+    pub events: Vec<AutomationEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationEvent {
+    /// When the change happens, in milliseconds from the start of the Automation
+    pub at: u64,
+    pub change: AutomationChange,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AutomationChange {
+    Volume(VolumeChannels, u8),
+    Route(InputChannels, OutputChannels, bool),
+}
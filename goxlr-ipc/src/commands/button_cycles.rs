@@ -0,0 +1,12 @@
+use goxlr_profile::ButtonCycle;
+use goxlr_shared::buttons::Buttons;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ButtonCycleCommand {
+    /// Sets the steps a button cycles through, or None to return the button to normal
+    Set(Buttons, Option<ButtonCycle>),
+
+    /// Moves a button on to its next step, as if it had been pressed
+    Next(Buttons),
+}
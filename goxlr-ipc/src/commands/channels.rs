@@ -1,14 +1,45 @@
+use goxlr_shared::channels::category::InputCategory;
 use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
 use goxlr_shared::channels::sub_mix::SubMixChannels;
 use goxlr_shared::channels::volume::VolumeChannels;
-use goxlr_shared::mute::MuteState;
+use goxlr_shared::mute::{CoughTarget, MuteState};
+use goxlr_shared::routing::StereoMode;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ChannelCommands {
     Volume(ChannelVolume),
+
+    /// Sets a Volume as a fraction of full volume (0.0 - 1.0), at a higher resolution than the
+    /// 0 - 255 scale used elsewhere.
+    PreciseVolume(ChannelPreciseVolume),
     Mute(MuteCommand),
+    Name(ChannelName),
+    MuteFade(ChannelMuteFade),
     SubMix(SubMix),
+
+    /// Trims an Input before it's routed, from 0 to 32 (untouched)
+    Trim(ChannelTrim),
+
+    /// Sets how the left and right sides of an Input are sent to its outputs
+    Stereo(ChannelStereo),
+
+    /// Moves an Input into a category, or out of any category with None
+    Category(ChannelCategory),
+
+    /// Sets the highest the Headphones volume can be set (0 - 255), None to remove the limit
+    HeadphoneLimit(Option<u8>),
+
+    /// Temporarily lifts (or restores) the Headphone limit, without changing it
+    HeadphoneLimitOverride(bool),
+
+    /// Sets the loudest any route to an Output can be, from 0 to 32 (no ceiling)
+    OutputCeiling(ChannelOutputCeiling),
+
+    /// Sets where the Cough button mutes to, Chat or Stream only, or its mute actions
+    CoughTarget(CoughTarget),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,12 +48,54 @@ pub struct ChannelVolume {
     pub volume: u8,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelPreciseVolume {
+    pub channel: VolumeChannels,
+    pub volume: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MuteCommand {
     pub channel: FaderChannels,
     pub state: MuteState,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelName {
+    pub channel: FaderChannels,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelMuteFade {
+    pub channel: FaderChannels,
+    pub fade_ms: u16,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelTrim {
+    pub channel: InputChannels,
+    pub trim: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelOutputCeiling {
+    pub output: OutputChannels,
+    pub ceiling: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelStereo {
+    pub channel: InputChannels,
+    pub mode: StereoMode,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelCategory {
+    pub channel: InputChannels,
+    pub category: Option<InputCategory>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubMix {
     pub channel: SubMixChannels,
@@ -1,8 +1,81 @@
+use std::collections::HashMap;
+
+use goxlr_profile::{Profile, RoutingRule};
+use goxlr_shared::channels::ducking::DuckingInput;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
+use goxlr_shared::colours::Colour;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::status::Configuration;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ConfigurationCommand {
     SubMixEnabled(bool),
     ButtonHoldTime(u16),
     ChangePageWithButtons(bool),
+
+    /// Re-applies the current profile to the device, responding with a ProfileLoaded report
+    ReloadProfile,
+
+    /// Brings the device in line with a Desired State, responding with what was changed
+    ApplyState(DesiredState),
+
+    /// Responds with what would be changed by applying a Desired State, without changing it
+    DiffState(DesiredState),
+
+    /// Adds a Routing Rule, replacing any existing rule with the same name
+    AddRoutingRule(RoutingRule),
+
+    /// Removes a Routing Rule by name
+    RemoveRoutingRule(String),
+
+    /// Sets the voice applications which, while running, route the Microphone to the Chat Mic
+    /// and Chat to the Headphones. An empty list disables this.
+    SetVoiceApplications(Vec<String>),
+
+    /// Replaces the Profile and Mic Profile, then loads them, responding with a ProfileLoaded
+    /// report. Used when restoring a Backup.
+    Restore(Box<Configuration>),
+    /// Replaces the Profile, keeping the current Mic Profile, then loads it, responding with a
+    /// ProfileLoaded report. Used when importing a shared profile.
+    LoadProfile(Box<Profile>),
+}
+
+/// A description of how the device should be configured, normally loaded from a file. Anything
+/// which isn't specified is left as it is, so a state only needs to list what it cares about.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DesiredState {
+    pub volumes: Option<HashMap<VolumeChannels, u8>>,
+
+    /// Routes for each Input, Outputs not listed for an Input are left alone
+    pub routing: Option<HashMap<InputChannels, HashMap<OutputChannels, bool>>>,
+
+    pub ducking: Option<DesiredDucking>,
+    pub lighting: Option<DesiredLighting>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DesiredDucking {
+    pub enabled: Option<bool>,
+
+    /// The inputs which trigger ducking, any not listed are disabled
+    pub inputs: Option<Vec<DuckingInput>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DesiredLighting {
+    pub accent: Option<Colour>,
+    pub global: Option<Colour>,
+    pub brightness: Option<u8>,
+}
+
+/// A single setting which differs between the device and a Desired State
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateDifference {
+    pub setting: String,
+    pub current: String,
+    pub desired: String,
 }
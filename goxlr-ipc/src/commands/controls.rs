@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// Every control is reachable through these two commands, by an ID taken from the list, so a
+/// client (such as a screen reader front end, or a simple remote) doesn't need to know anything
+/// about the device to drive it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ControlCommand {
+    /// Lists every control, with its current value
+    List,
+
+    /// Adjusts a control by its ID
+    Adjust(ControlAdjust),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlAdjust {
+    pub id: String,
+    pub action: ControlAction,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ControlAction {
+    /// Moves a Range up by its step, or turns a Toggle on
+    Increment,
+
+    /// Moves a Range down by its step, or turns a Toggle off
+    Decrement,
+
+    /// Flips a Toggle, Ranges can't be toggled
+    Toggle,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Control {
+    /// A stable identifier for the control, such as `volume.Music`
+    pub id: String,
+
+    /// A readable name for the control, such as `Music Volume`
+    pub label: String,
+    pub value: ControlValue,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ControlValue {
+    Range(ControlRange),
+    Toggle(bool),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ControlRange {
+    pub value: u8,
+    pub min: u8,
+    pub max: u8,
+
+    /// How far an Increment or Decrement moves the value
+    pub step: u8,
+}
@@ -0,0 +1,274 @@
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::{OutputChannels, RoutingOutput};
+use goxlr_shared::routing::RouteValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DiagnosticsCommand {
+    /// Plays a test tone into an Input, and measures how long it takes to arrive at an Output.
+    /// The measurement runs in the background for a few seconds, fetch the result with
+    /// GetLatencyReport.
+    MeasureLatency(LatencyTest),
+
+    /// Fetches the running (or last) Latency Measurement, responds with a LatencyMeasured report
+    GetLatencyReport,
+
+    /// Starts monitoring channel levels for the provided number of seconds
+    StartGainAnalysis(u32),
+
+    /// Fetches the results of the current (or last) Gain Analysis, responds with a GainAnalysis
+    /// report.
+    GetGainAnalysis,
+
+    /// Fetches the routing values the daemon believes are currently on the device, responds
+    /// with a RoutingState report.
+    GetRoutingState,
+
+    /// Fetches the recent events held by the Flight Recorder, responds with a FlightRecording
+    /// which can be saved and replayed with the daemon's --replay option.
+    DumpFlightRecorder,
+
+    /// Fetches recent configuration changes which match the filter, responds with an AuditLog
+    GetAuditLog(AuditFilter),
+
+    /// Plays a generated signal into an Input, replacing any tone already playing
+    PlayTestTone(TestTone),
+
+    /// Stops the test tone, if one is playing
+    StopTestTone,
+
+    /// Enables or Disables Input Test Mode, where the device's buttons, faders and encoders are
+    /// reported in the status (and lit when used) without doing anything else, so the hardware
+    /// can be checked safely.
+    InputTest(bool),
+
+    /// Fetches counters covering the session so far (time live, mutes, samples, page switches),
+    /// responds with a SessionStats report.
+    GetSessionStats,
+
+    /// Clears the session counters, starting a new session from now
+    ResetSessionStats,
+
+    /// Fetches which features currently control each route, and the times they've clashed,
+    /// responds with a RouteConflicts report.
+    GetRouteConflicts,
+
+    /// Forgets the recorded route conflicts
+    ClearRouteConflicts,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyTest {
+    /// The Input channel to test, the tone will be played into this channel
+    pub input: InputChannels,
+
+    /// The Output channel to test, the tone will be listened for on this channel
+    pub output: OutputChannels,
+
+    /// The OS Playback Device which feeds the Input (eg. 'System')
+    pub playback_device: String,
+
+    /// The OS Capture Device which is fed by the Output (eg. 'Sample')
+    pub capture_device: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestTone {
+    /// The Input channel the tone is being played into
+    pub input: InputChannels,
+
+    /// The OS Playback Device which feeds the Input (eg. 'System')
+    pub playback_device: String,
+
+    pub signal: TestSignal,
+
+    /// The level of the signal in dBFS, 0 or lower
+    pub level_db: f32,
+
+    /// How long to play the tone for, in seconds
+    pub duration: u32,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub enum TestSignal {
+    /// A sine wave at the provided frequency (in Hz)
+    Sine(f32),
+
+    /// Noise with equal energy per octave, useful for checking levels across the spectrum
+    PinkNoise,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyReport {
+    pub input: InputChannels,
+    pub output: OutputChannels,
+
+    /// Whether the measurement is still running, the results are empty until it's done
+    pub running: bool,
+
+    /// Why the measurement failed, if it did
+    pub error: Option<String>,
+
+    /// The Sample Rate used by the Playback and Capture devices
+    pub sample_rate: Option<u32>,
+
+    /// The time between the tone being played, and it being observed on the capture
+    pub latency_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GainReport {
+    /// Whether the analysis is still collecting levels
+    pub running: bool,
+
+    /// How long levels have been collected for
+    pub duration_ms: u64,
+
+    /// The results for each channel that was monitored
+    pub channels: Vec<ChannelGainReport>,
+
+    /// Channels which can't be monitored, as the device doesn't provide levels for them
+    pub unmetered: Vec<InputChannels>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelGainReport {
+    pub channel: InputChannels,
+
+    /// The highest and average levels seen, in dBFS
+    pub peak_db: f64,
+    pub average_db: f64,
+
+    /// The space left between the peak level and 0dBFS
+    pub headroom_db: f64,
+
+    /// The number of times the level reached the clipping threshold
+    pub clip_events: u32,
+
+    /// A suggested change to the channel's gain, if it's too hot or too quiet
+    pub suggested_adjustment_db: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RoutingStateReport {
+    /// Every route, as last sent to the device (including any attenuation from the ducker)
+    pub routes: Vec<RouteState>,
+
+    /// Whether routing changes are currently being held back, and not yet sent
+    pub staged: bool,
+}
+
+/// Features which change route values while running. When two want the same route, the one
+/// later in this list wins, and the other leaves the route alone until it's released.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum RouteController {
+    /// Lowers routes while a ducking input is active, until it's fully unducked
+    Ducker,
+
+    /// A route changed by the user (or a profile load) while something else controlled it, held
+    /// until the feature it overrode lets go of the route
+    Manual,
+
+    /// Fades between routing tables when a Mix Mode is activated, until the fade finishes
+    Crossfade,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RouteConflictReport {
+    /// The routes currently controlled by a feature
+    pub claims: Vec<RouteClaim>,
+
+    /// Times a feature was refused a route, as the same clash repeats it's counted, not repeated
+    pub conflicts: Vec<RouteConflict>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteClaim {
+    pub input: InputChannels,
+    pub output: RoutingOutput,
+    pub controller: RouteController,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteConflict {
+    pub input: InputChannels,
+    pub output: RoutingOutput,
+
+    /// The feature which was refused the route
+    pub refused: RouteController,
+
+    /// The feature which held the route at the time
+    pub holder: RouteController,
+
+    /// How many times the feature has been refused
+    pub count: u32,
+
+    /// When the feature was last refused, in seconds since the Unix Epoch
+    pub last_seen: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionStatsReport {
+    /// How long the session has been tracked for
+    pub tracked_ms: u64,
+
+    /// How long the Microphone was live (unmuted, not coughed, and open if Push to Talk is on)
+    pub mic_live_ms: u64,
+
+    /// The number of times any channel was muted
+    pub mutes: u32,
+
+    /// The number of samples played
+    pub sample_triggers: u32,
+
+    /// The number of times the Fader Page was changed
+    pub page_switches: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteState {
+    pub input: InputChannels,
+    pub output: RoutingOutput,
+    pub value: RouteValue,
+
+    /// What the profile has for this route, None if the profile doesn't control it
+    pub profile: Option<bool>,
+}
+
+/// Where a configuration change came from
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AuditOrigin {
+    /// A command sent over IPC (by the UI, the client, or any other tool)
+    Command,
+
+    /// Someone pressed a button, moved a fader or turned an encoder on the device
+    Physical,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Milliseconds since the Unix Epoch
+    pub timestamp: u64,
+    pub origin: AuditOrigin,
+
+    /// The command or interaction which caused the change
+    pub trigger: String,
+
+    /// The settings which were changed, as paths into the Configuration (eg. /device/routing)
+    pub changes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditFilter {
+    /// Only include changes from this origin
+    pub origin: Option<AuditOrigin>,
+
+    /// Only include changes to settings whose path starts with this
+    pub setting: Option<String>,
+
+    /// Only include changes made at or after this time (ms since the Unix Epoch)
+    pub since: Option<u64>,
+
+    /// The maximum number of entries to return, the most recent are kept
+    pub limit: Option<usize>,
+}
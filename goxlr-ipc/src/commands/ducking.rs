@@ -0,0 +1,106 @@
+use goxlr_profile::DuckingVolume;
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::category::InputCategory;
+use goxlr_shared::channels::ducking::{DuckingInput, DuckingSensitivity};
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DuckingCommand {
+    Enabled(bool),
+
+    /// Enables or Disables an Input as a trigger for Ducking
+    InputSource(DuckingInput, bool),
+
+    /// Changes where the level for an Input is measured from
+    LevelSource(DuckingInput, DuckingLevelSource),
+
+    /// Sets whether a route is lowered while ducked
+    OutputRoute(InputChannels, OutputChannels, bool),
+
+    /// Sets whether a route is lowered while ducked for every Input in a category
+    CategoryRoute(InputCategory, OutputChannels, bool),
+
+    /// Sets the steps taken when ducking, and unducking, neither can be empty
+    Transition(Vec<DuckingVolume>, Vec<DuckingVolume>),
+
+    /// How long (in ms) a trigger needs to be active before ducking, and inactive before unducking
+    AttackTime(u64),
+    ReleaseTime(u64),
+
+    /// The level (in dB) an Input other than the Microphone needs to reach to trigger ducking
+    TriggerLevel(f64),
+
+    /// The level (in dB) a single Input needs to reach to trigger ducking, None to use the
+    /// Trigger Level above. This can't be set for the Microphone.
+    InputTriggerLevel(DuckingInput, Option<f64>),
+
+    /// Moves the Microphone's trigger away from its gate threshold (in dB), from -24 to 24
+    MicTriggerOffset(i8),
+
+    /// Sets the trigger levels, attack and release times from a preset
+    Sensitivity(DuckingSensitivity),
+
+    /// Sets the button which steps through the sensitivity presets, None to disable it
+    SensitivityButton(Option<Buttons>),
+
+    /// Enables or Disables scaling the duck depth by the loudness of the Music
+    AdaptiveEnabled(bool),
+
+    /// Changes where the Music's level is measured from for Adaptive Ducking, by default nothing
+    /// is measured and the transition is used as is.
+    MusicLevelSource(DuckingLevelSource),
+
+    /// The Music levels (in dB) considered quiet and loud, the quiet level must be lower
+    AdaptiveLevels(f64, f64),
+
+    /// The route volumes ducked to when the Music is quiet, and when it's loud
+    AdaptiveVolumes(u8, u8),
+
+    /// Informs the ducker that a sample has started or stopped playing
+    SamplePlaying(bool),
+
+    /// Sets the button which fades the ducked routes down while held, None to disable it
+    FadeButton(Option<Buttons>),
+
+    /// Unducks straight away when the Microphone is muted, if nothing else is holding the duck
+    FastUnduckOnMute(bool),
+
+    /// Fetches the Ducking activity since the stats were last reset, responds with a
+    /// DuckingStats report
+    GetStats,
+
+    /// Clears the Ducking activity, for example at the start of a stream
+    ResetStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DuckingLevelSource {
+    /// The Input's built in source, the Microphone meter or the Sampler. The Chat Mic and Line In
+    /// don't have one, and will never trigger.
+    Default,
+
+    /// The peak level of an OS capture device, by name
+    CaptureDevice(String),
+
+    /// A fixed level (in dB) for testing, None will never trigger
+    Injected(Option<f64>),
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DuckingStatsReport {
+    /// How long activity has been tracked for
+    pub tracked_ms: u64,
+
+    /// The number of times ducking has started
+    pub activations: u32,
+    pub activations_per_hour: f64,
+
+    /// The total, and average, time spent ducked
+    pub total_ducked_ms: u64,
+    pub average_duck_ms: u64,
+
+    /// Whether the ducker is currently active
+    pub ducked: bool,
+}
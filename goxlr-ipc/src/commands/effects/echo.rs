@@ -0,0 +1,16 @@
+use goxlr_shared::effects::EchoStyle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EchoCommand {
+    SetStyle(EchoStyle),
+    SetAmount(u8),
+    SetFeedback(u8),
+    SetTempo(u16),
+    SetDelayLeft(u16),
+    SetDelayRight(u16),
+    SetFeedbackLeft(u8),
+    SetFeedbackRight(u8),
+    SetFeedbackXFBLtoR(u8),
+    SetFeedbackXFBRtoL(u8),
+}
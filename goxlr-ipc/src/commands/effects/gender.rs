@@ -0,0 +1,8 @@
+use goxlr_shared::effects::GenderStyle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GenderCommand {
+    SetStyle(GenderStyle),
+    SetAmount(i8),
+}
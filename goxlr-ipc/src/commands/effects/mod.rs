@@ -0,0 +1,21 @@
+pub mod echo;
+pub mod gender;
+pub mod hard_tune;
+pub mod pitch;
+pub mod reverb;
+
+use crate::commands::effects::echo::EchoCommand;
+use crate::commands::effects::gender::GenderCommand;
+use crate::commands::effects::hard_tune::HardTuneCommand;
+use crate::commands::effects::pitch::PitchCommand;
+use crate::commands::effects::reverb::ReverbCommand;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EffectsCommand {
+    Reverb(ReverbCommand),
+    Echo(EchoCommand),
+    Pitch(PitchCommand),
+    Gender(GenderCommand),
+    HardTune(HardTuneCommand),
+}
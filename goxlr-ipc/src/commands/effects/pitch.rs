@@ -0,0 +1,9 @@
+use goxlr_shared::effects::PitchStyle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PitchCommand {
+    SetStyle(PitchStyle),
+    SetAmount(i8),
+    SetCharacter(u8),
+}
@@ -0,0 +1,17 @@
+use goxlr_shared::effects::ReverbStyle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReverbCommand {
+    SetStyle(ReverbStyle),
+    SetAmount(u8),
+    SetDecay(u16),
+    SetEarlyLevel(i8),
+    SetPreDelay(u8),
+    SetLowColour(i8),
+    SetHighColour(i8),
+    SetHighFactor(i8),
+    SetDiffuse(i8),
+    SetModSpeed(i8),
+    SetModDepth(i8),
+}
@@ -0,0 +1,75 @@
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::colours::{Colour, ColourScheme};
+use goxlr_shared::faders::Fader;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LightingCommand {
+    /// Sets the colour of the X logo, this isn't available on the Mini
+    Accent(Colour),
+
+    /// Sets the colour of the light inside the device
+    Global(Colour),
+
+    /// Sets the brightness of all the lighting, as a percentage
+    Brightness(u8),
+
+    /// Sets how long (in minutes) the device can go without input before the lighting is
+    /// dimmed, 0 disables dimming
+    IdleTimeout(u16),
+
+    /// Sets the brightness the lighting is dimmed to while idle, as a percentage
+    IdleBrightness(u8),
+
+    /// Enables or Disables fader colours following their channel's volume and mute state
+    ChannelLinked(bool),
+
+    /// Hands the lighting over to this client, who can then send frames at up to the requested
+    /// rate (in frames per second). The profile's lighting is restored on release, or if frames
+    /// stop arriving.
+    TakeOver(u8),
+
+    /// A full set of colours to display, frames sent faster than the requested rate are dropped
+    Frame(ColourScheme),
+
+    /// Hands the lighting back, and restores the profile's colours
+    Release,
+
+    /// Shows a set of colours above the profile's, but below any takeover, for a client driven
+    /// animation. Each frame replaces the last, until the animation is stopped.
+    Animation(ColourScheme),
+
+    /// Stops the animation, revealing whatever is underneath it
+    StopAnimation,
+
+    /// Flashes a light in a colour for a time (in ms), such as to alert to a donation, before
+    /// returning it to normal. A new flash on the same light replaces the old one.
+    FlashButton(FlashTarget, Colour, u64, FlashPattern),
+
+    /// Shows a set of colours for a time (in ms) without changing the profile, for trying them
+    /// out. Each preview replaces the last, and the profile's colours return once it ends.
+    PreviewColours(ColourScheme, u64),
+
+    /// Ends a preview early, returning to the profile's colours
+    CancelPreview,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FlashTarget {
+    Button(Buttons),
+
+    /// The light strip alongside a fader
+    Strip(Fader),
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub enum FlashPattern {
+    /// The colour is shown for the whole duration
+    Solid,
+
+    /// The colour is turned on and off every half a second
+    Blink,
+
+    /// The colour is turned on and off several times a second
+    FastBlink,
+}
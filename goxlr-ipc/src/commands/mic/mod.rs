@@ -7,6 +7,8 @@ use crate::commands::mic::compressor::CompressorCommand;
 use crate::commands::mic::equaliser::EqualiserCommand;
 use crate::commands::mic::gate::GateCommand;
 use crate::commands::mic::setup::SetupCommand;
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::microphone::MicPreset;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,5 +17,15 @@ pub enum MicrophoneCommand {
     Equaliser(EqualiserCommand),
     Compressor(CompressorCommand),
     Gate(GateCommand),
+
+    /// Applies one of the bundled Mic Presets over the current Mic Profile
+    ApplyPreset(MicPreset),
     GetMicLevel,
+
+    /// Engages or Releases the Hard Mute. A Hard Mute engaged here can only be released here,
+    /// one engaged on the device can only be released on the device.
+    HardMute(bool),
+
+    /// Sets the buttons which, pressed together, engage or release the Hard Mute
+    HardMuteButtons(Vec<Buttons>),
 }
@@ -0,0 +1,21 @@
+use goxlr_shared::buttons::Buttons;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MixModeCommand {
+    /// Stores the current volumes, routing, ducking and lighting as a Mix Mode, replacing any
+    /// existing mode with the same name
+    Save(String),
+
+    /// Applies a Mix Mode to the device
+    Activate(String),
+
+    /// Removes a Mix Mode
+    Delete(String),
+
+    /// Binds a Mix Mode to an Effect Select button, or removes the binding
+    SetButton(String, Option<Buttons>),
+
+    /// Sets how long (in milliseconds) routing changes are cross-faded over when switching
+    SetCrossfade(u16),
+}
@@ -1,18 +1,58 @@
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 use json_patch::Patch;
 use serde::{Deserialize, Serialize};
 
+use goxlr_profile::Lighting;
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::faders::Fader;
+
+use crate::commands::automation::AutomationCommand;
+use crate::commands::button_cycles::ButtonCycleCommand;
 use crate::commands::channels::ChannelCommands;
-use crate::commands::configuration::ConfigurationCommand;
+use crate::commands::configuration::{ConfigurationCommand, StateDifference};
+use crate::commands::controls::{Control, ControlCommand};
+use crate::commands::diagnostics::{
+    AuditEntry, DiagnosticsCommand, GainReport, LatencyReport, RouteConflictReport,
+    RoutingStateReport, SessionStatsReport,
+};
+use crate::commands::ducking::{DuckingCommand, DuckingStatsReport};
+use crate::commands::effects::EffectsCommand;
+use crate::commands::lighting::LightingCommand;
 use crate::commands::mic::MicrophoneCommand;
+use crate::commands::mix_modes::MixModeCommand;
+use crate::commands::monitor_mix::MonitorMixCommand;
 use crate::commands::pages::PageCommand;
-use crate::status::DeviceStatus;
+use crate::commands::push_to_talk::PushToTalkCommand;
+use crate::commands::sampler::SamplerCommand;
+use crate::commands::scribbles::ScribbleCommand;
+use crate::commands::solo::SoloCommand;
+use crate::status::{
+    Configuration, DeviceIncident, DeviceLifecycle, DeviceStatus, LifecycleStatus,
+    ProfileLoadReport, UnclaimedDevice, UpdateStatus,
+};
 
+pub mod automation;
+pub mod button_cycles;
 pub mod channels;
 pub mod configuration;
+pub mod controls;
+pub mod diagnostics;
+pub mod ducking;
+pub mod effects;
+pub mod lighting;
 pub mod mic;
+pub mod mix_modes;
+pub mod monitor_mix;
 pub mod pages;
+pub mod push_to_talk;
+pub mod sampler;
+pub mod scribbles;
+pub mod solo;
 
 /// This is the base IPC request structure, it's async driven so each request will require a
 /// response 'oneshot' channel for receiving a reply, this allows us to better manage a request /
@@ -25,8 +65,38 @@ pub enum DaemonRequest {
     /// This fetches the full status for all devices
     GetStatus,
 
+    /// Subscribes this connection to status patches, these will be sent as they occur alongside
+    /// regular responses. A client that falls behind will have the oldest patches dropped, and
+    /// will be sent a full Status to resync.
+    Subscribe,
+
+    /// Stops the sending of status patches to this connection
+    Unsubscribe,
+
+    /// Subscribes this connection to physical fader movements, sent as FaderMoved alongside
+    /// regular responses. Only available over the IPC socket.
+    SubscribeFaderEvents,
+
+    /// Stops the sending of fader movements to this connection
+    UnsubscribeFaderEvents,
+
+    /// Subscribes to patches (as above) and fetches the full status, for a client connecting or
+    /// reconnecting. If given the session and sequence it last saw, the response says whether
+    /// anything has been missed since, so a client which is still current can keep its state.
+    ResumeSession(Option<SessionPosition>),
+
     Daemon(DaemonCommand),
     DeviceCommand(DeviceCommand),
+
+    /// Runs a Daemon or Device command even if Live Mode would block it, for when the user has
+    /// confirmed the change after receiving a LiveModeBlocked response.
+    Forced(Box<DaemonRequest>),
+
+    /// Validates a Device command, and responds with what it would change without applying any
+    /// of it, so the change can be confirmed (or checked by a script) before it's made for real.
+    /// Commands which couldn't be undone afterwards (such as profile loads, or sample playback)
+    /// are refused.
+    DryRun(DeviceCommand),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +112,22 @@ pub enum DaemonResponse {
     Patch(Patch),
     Status(DaemonStatus),
     DeviceCommand(GoXLRCommandResponse),
+    LogTail(Vec<String>),
+    Backup(Backup),
+
+    /// The names of the stored Backups, newest first
+    Backups(Vec<String>),
+    Health(HealthReport),
+    Session(SessionResume),
+
+    /// The command was refused because Live Mode is enabled, it can be sent again as Forced
+    LiveModeBlocked(String),
+
+    /// A physical fader has moved, sent to connections subscribed to Fader Events
+    FaderMoved(FaderMovement),
+
+    /// What a shared profile contains, in response to PreviewProfileImport
+    ProfileImport(ProfileImportPreview),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,7 +137,137 @@ pub struct WebsocketResponse {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum DaemonCommand {}
+pub enum DaemonCommand {
+    /// Fetches the last number of lines from the daemon's log file
+    GetLogTail(usize),
+
+    /// Fetches the configuration of every attached device as a single Backup
+    GetBackup,
+
+    /// Restores a Backup onto the attached devices it holds configuration for
+    RestoreBackup(Backup),
+
+    /// Lists the Backups the daemon has taken before replacing a device's configuration
+    ListBackups,
+
+    /// Restores one of the Backups from ListBackups, by name
+    RestoreStoredBackup(String),
+
+    /// Reports whether the daemon's devices are ready to be used
+    GetHealth,
+
+    /// Starts or stops logging every USB command and response to the traffic log file
+    SetUsbTrafficLog(bool),
+
+    /// Enables or disables Live Mode, which refuses changes that could disrupt a stream (such as
+    /// loading profiles or restoring Backups) unless they're sent as Forced
+    SetLiveMode(bool),
+
+    /// Checks the release channel for a newer daemon now, rather than waiting for the next
+    /// scheduled check. The result is reported in the status.
+    CheckForUpdate,
+
+    /// Downloads and verifies the newest release, which is applied when the daemon next stops.
+    /// Progress is reported in the status.
+    InstallUpdate,
+
+    /// Fetches a shared profile from a URL, responding with what's in it without applying it
+    PreviewProfileImport(String),
+
+    /// Fetches a shared profile from a URL, and loads it onto a device
+    ImportProfile(ProfileImport),
+}
+
+/// Whether the daemon is up, and whether its devices are ready to be used. Supervisors and
+/// scripts can wait for `ready` before starting anything which expects the devices configured.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// The daemon is running and responding, always true in a report which has been received
+    pub live: bool,
+
+    /// At least one device is attached, and every attached device has finished initialising
+    pub ready: bool,
+
+    /// How many devices are Running
+    pub running: usize,
+
+    /// Devices which aren't Running, by serial (or USB location, if the serial isn't known yet)
+    pub pending: BTreeMap<String, DeviceLifecycle>,
+}
+
+/// Everything needed to put the daemon's configuration back, kept in a single document so sync
+/// tools can store it without needing to know where, or how, anything is kept.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Backup {
+    /// The format of the Backup, a daemon won't restore a newer format than it understands
+    pub version: u32,
+
+    /// When the Backup was made, in seconds since the Unix Epoch
+    pub created: u64,
+
+    /// The Profile and Mic Profile of each device, by serial
+    pub devices: BTreeMap<String, Configuration>,
+
+    /// The daemon's own settings, which aren't kept in any profile
+    #[serde(default)]
+    pub settings: BackupSettings,
+}
+
+/// Settings held by the daemon rather than a profile, Backups made before these were added
+/// restore with them left as they are.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupSettings {
+    /// Whether Live Mode was enabled, None to leave it as it is
+    pub live_mode: Option<bool>,
+
+    /// The Headphone volume limit of each device, by serial. Devices whose limit was overridden
+    /// when the Backup was made aren't included, as the limit isn't known.
+    pub headphone_limits: BTreeMap<String, Option<u8>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileImport {
+    pub serial: String,
+    pub url: String,
+
+    /// The digest from a preview, if given the import is refused if the profile at the URL has
+    /// changed since, so what's applied is always what was previewed
+    pub digest: Option<String>,
+}
+
+/// A summary of a shared profile, so it can be checked before it replaces anything
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileImportPreview {
+    pub url: String,
+
+    /// The size of the profile, in bytes
+    pub size: usize,
+
+    /// Identifies this exact profile, to be passed back with ImportProfile
+    pub digest: String,
+
+    /// Whether a Mic Profile is included, which would also be replaced
+    pub mic_profile: bool,
+
+    pub pages: usize,
+    pub mix_modes: Vec<String>,
+    pub lighting: Lighting,
+
+    /// The Routes which are enabled by the profile
+    pub routes: Vec<(InputChannels, OutputChannels)>,
+
+    /// The names of the profile's Routing Rules
+    pub routing_rules: Vec<String>,
+
+    /// Applications which, while running, will change the Microphone's routing
+    pub voice_applications: Vec<String>,
+
+    /// The buttons which step through a Button Cycle, rather than their usual behaviour
+    pub button_cycles: Vec<Buttons>,
+
+    /// The files the Sampler plays, these need to exist on this machine
+    pub samples: Vec<PathBuf>,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeviceCommand {
@@ -65,6 +281,24 @@ pub enum GoXLRCommand {
     Microphone(MicrophoneCommand),
     Channels(ChannelCommands),
     Pages(PageCommand),
+    Effects(EffectsCommand),
+    Diagnostics(DiagnosticsCommand),
+    MixModes(MixModeCommand),
+    Ducking(DuckingCommand),
+    PushToTalk(PushToTalkCommand),
+    MonitorMix(MonitorMixCommand),
+    Lighting(LightingCommand),
+    Solo(SoloCommand),
+    Scribbles(ScribbleCommand),
+    Sampler(SamplerCommand),
+    Controls(ControlCommand),
+    Automation(AutomationCommand),
+    ButtonCycles(ButtonCycleCommand),
+
+    /// Applies several commands together, with a single status update and profile save once
+    /// they're done. The whole batch is validated first, if any command would fail then none of
+    /// them are applied.
+    Batch(Vec<GoXLRCommand>),
 }
 
 /// The GoXLR Command Response will contain command specific responses, generally not much more
@@ -73,12 +307,101 @@ pub enum GoXLRCommand {
 pub enum GoXLRCommandResponse {
     Ok,
     MicLevel(f64),
+    ProfileLoaded(ProfileLoadReport),
+    LatencyMeasured(LatencyReport),
+    GainAnalysis(GainReport),
+    RoutingState(RoutingStateReport),
+    RouteConflicts(RouteConflictReport),
+    DuckingStats(DuckingStatsReport),
+    SessionStats(SessionStatsReport),
+    AuditLog(Vec<AuditEntry>),
+    StateDiff(Vec<StateDifference>),
+    Controls(Vec<Control>),
+
+    /// The names of the stored Automations
+    Automations(Vec<String>),
+
+    /// The events held by the Flight Recorder, as JSON
+    FlightRecording(String),
     Error(String),
+
+    /// The command was refused because Live Mode is enabled, it can be sent again as Forced
+    LiveModeBlocked(String),
+
+    /// What a command would have done, in response to a DryRun
+    DryRun(DryRunReport),
+
+    /// The response to each command in a Batch, in order
+    Batch(Vec<GoXLRCommandResponse>),
+}
+
+/// The changes a command would make to a device, none of which have been applied
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DryRunReport {
+    /// The commands which would be sent to the device, in the order they'd be sent
+    pub usb_commands: Vec<String>,
+
+    /// The values in the Profile or Mic Profile which would change, by their path in the
+    /// device's Configuration
+    pub changes: Vec<StateDifference>,
+}
+
+/// A physical fader being moved, as it was reported by the device
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaderMovement {
+    pub serial: String,
+    pub fader: Fader,
+
+    /// The channel assigned to the fader when it moved
+    pub channel: FaderChannels,
+
+    /// The fader's position (0 - 255) before it moved, None if this is the first movement seen
+    pub old_value: Option<u8>,
+    pub new_value: u8,
+
+    /// When the movement was seen, in milliseconds since the Unix Epoch
+    pub timestamp: u64,
+}
+
+/// Where a client was in the daemon's stream of patches
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SessionPosition {
+    pub session: u64,
+    pub sequence: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionResume {
+    pub status: DaemonStatus,
+
+    /// Whether the client's position matches the status, in which case nothing was missed
+    pub resumed: bool,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct DaemonStatus {
+    /// Identifies this run of the daemon, a new value means it's been restarted
+    pub session: u64,
+
+    /// Increases by one with every patch, so a client can tell whether it's missed any
+    pub sequence: u64,
+
     pub devices: BTreeMap<String, DeviceStatus>,
+
+    /// The most recent problems the daemon has recovered devices from
+    pub incidents: Vec<DeviceIncident>,
+
+    /// Devices which are attached, but couldn't be claimed
+    pub unclaimed: Vec<UnclaimedDevice>,
+
+    /// Where each known device is in its lifecycle, by USB location
+    pub lifecycle: BTreeMap<String, LifecycleStatus>,
+
+    /// Whether Live Mode is refusing changes which could disrupt a stream
+    pub live_mode: bool,
+
+    /// Whether a newer daemon is available, and how far it is from being installed
+    pub update: UpdateStatus,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -87,4 +410,10 @@ pub struct HttpSettings {
     pub bind_address: String,
     pub cors_enabled: bool,
     pub port: u16,
+
+    /// How many commands per second each client can send, 0 for unlimited
+    pub rate_limit: u32,
+
+    /// How many commands a client can send in a burst before being limited
+    pub rate_burst: u32,
 }
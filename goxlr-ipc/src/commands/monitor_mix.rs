@@ -0,0 +1,10 @@
+use goxlr_shared::channels::input::InputChannels;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MonitorMixCommand {
+    Enabled(bool),
+
+    /// Sets the Headphone level for an Input, from 0 to 32 (full volume)
+    Level(InputChannels, u8),
+}
@@ -1,3 +1,4 @@
+use goxlr_profile::FaderCurve;
 use goxlr_shared::channels::fader::FaderChannels;
 use goxlr_shared::faders::Fader;
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,12 @@ pub enum PageCommand {
     LoadPage(u8),
     RemovePage(u8),
     SetFader(SetFader),
+
+    /// Sets how a physical Fader's position is turned into a volume
+    FaderCurve(Fader, FaderCurve),
+
+    /// Sets the Dead Zones (0 - 255) at the bottom and top of a physical Fader
+    FaderDeadZones(Fader, u8, u8),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
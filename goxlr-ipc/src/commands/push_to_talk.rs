@@ -0,0 +1,20 @@
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::output::OutputChannels;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PushToTalkCommand {
+    Enabled(bool),
+
+    /// Sets the button used for Push to Talk, None if only a hotkey will be used
+    Button(Option<Buttons>),
+
+    /// Sets the Outputs which only receive the Microphone while talking
+    Outputs(Vec<OutputChannels>),
+
+    /// How long (in ms) the Microphone stays open after release
+    ReleaseTail(u64),
+
+    /// Allows an external hotkey to press and release Push to Talk
+    Pressed(bool),
+}
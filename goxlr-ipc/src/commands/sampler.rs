@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::sampler::{SampleBank, SampleButton};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SamplerCommand {
+    /// Sets the outputs a bank's samples are heard on, None uses the routing table
+    BankOutputs(SampleBank, Option<Vec<OutputChannels>>),
+
+    /// Sets the outputs a single sample is heard on, None uses its bank's outputs
+    ButtonOutputs(SampleBank, SampleButton, Option<Vec<OutputChannels>>),
+
+    /// Assigns a WAV file to a button, or None to clear it
+    File(SampleBank, SampleButton, Option<PathBuf>),
+
+    /// Sets the files played after a button's sample, in order
+    Playlist(SampleBank, SampleButton, Vec<PathBuf>),
+
+    /// Plays the file assigned to a button followed by its playlist, stopping anything already
+    /// playing
+    Play(SampleBank, SampleButton),
+
+    /// Stops the sample the daemon is playing, and clears the queue
+    Stop,
+
+    /// Adds a file to the end of the queue which is currently playing
+    Enqueue(PathBuf),
+
+    /// Removes a file from the queue, by its position in the status
+    Dequeue(usize),
+
+    /// Stops the current file, and moves on to the next in the queue
+    Skip,
+
+    /// Informs the daemon which sample a client is playing, or None when playback stops. This is
+    /// also a Ducking trigger, so there's no need to send Ducking's SamplePlaying as well.
+    Playing(Option<(SampleBank, SampleButton)>),
+}
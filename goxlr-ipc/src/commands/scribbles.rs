@@ -0,0 +1,12 @@
+use goxlr_shared::faders::Fader;
+use goxlr_shared::scribbles::ScribbleWidget;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ScribbleCommand {
+    /// Shows a Widget on a Fader's Scribble in place of the channel name
+    ShowWidget(Fader, ScribbleWidget),
+
+    /// Removes the Widget from a Fader's Scribble, restoring the channel name
+    ClearWidget(Fader),
+}
@@ -0,0 +1,15 @@
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::input::InputChannels;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SoloCommand {
+    /// Solos an Input in the Headphones, or None to release the Solo
+    Set(Option<InputChannels>),
+
+    /// Sets the button which toggles Solo, or None to not use a button
+    Button(Option<Buttons>),
+
+    /// Sets the Input which the Solo button toggles
+    Input(InputChannels),
+}
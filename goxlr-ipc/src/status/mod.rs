@@ -1,8 +1,19 @@
 mod device;
 mod mic;
 
+use std::path::PathBuf;
+use std::str::FromStr;
+
 use goxlr_profile::{MicProfile, Profile};
-use goxlr_shared::device::DeviceInfo;
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::colours::TwoColourTargets;
+use goxlr_shared::device::{ClaimFailure, DeviceInfo};
+use goxlr_shared::encoders::Encoders;
+use goxlr_shared::faders::Fader;
+use goxlr_shared::sampler::{SampleBank, SampleButton};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,6 +21,199 @@ pub struct DeviceStatus {
     pub hardware: DeviceInfo,
     pub serial: String,
     pub config: Configuration,
+    pub load_report: ProfileLoadReport,
+    pub runtime: RuntimeStatus,
+}
+
+/// State which is held by the daemon while running, rather than stored in the profile
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuntimeStatus {
+    /// Whether Push to Talk is currently letting the Microphone through
+    pub push_to_talk_active: bool,
+
+    /// Whether the Microphone is Hard Muted, and can't be unmuted until it's released
+    pub hard_muted: bool,
+
+    /// Routing Rules which the current routing breaks
+    pub routing_warnings: Vec<String>,
+
+    /// The voice application currently routing the Microphone to the Chat Mic, if one is running
+    pub voice_application: Option<String>,
+
+    /// The controls used while in Input Test Mode (newest last), None if it isn't enabled
+    pub input_test: Option<Vec<InputTestEvent>>,
+
+    /// The samples being played by the daemon, None if it isn't playing any
+    pub sample_queue: Option<SampleQueueStatus>,
+
+    /// The highest the Headphones volume can be set, None if there's no limit being enforced
+    pub headphone_limit: Option<u8>,
+
+    /// Whether a configured Headphone limit has been temporarily lifted
+    pub headphone_limit_overridden: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampleQueueStatus {
+    /// The button which started the queue, its outputs are used for everything in it
+    pub bank: SampleBank,
+    pub button: SampleButton,
+
+    pub playing: PathBuf,
+
+    /// The files still to play, in order
+    pub queued: Vec<PathBuf>,
+}
+
+/// A control used on the device while in Input Test Mode, where it has no other effect
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputTestEvent {
+    /// Milliseconds since the Unix Epoch
+    pub time: u64,
+    pub input: InputTestInput,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub enum InputTestInput {
+    ButtonDown(Buttons),
+    ButtonUp(Buttons),
+    Fader(Fader, u8),
+    Encoder(Encoders, i8),
+}
+
+/// Something which went wrong with a device, and which the daemon had to step in to recover from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceIncident {
+    /// The serial of the device, if it had got far enough to report one
+    pub serial: Option<String>,
+
+    /// Seconds since the Unix Epoch
+    pub time: u64,
+    pub incident: IncidentType,
+}
+
+/// A device which was found, but couldn't be claimed by the daemon. These are retried, so will
+/// disappear once the cause is resolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnclaimedDevice {
+    /// Where the device is attached, there's no serial until it's been claimed
+    pub location: String,
+    pub failure: ClaimFailure,
+    pub message: String,
+
+    /// What the user can do to resolve the failure
+    pub hint: String,
+}
+
+/// Where a device is in its lifecycle, from being found by the daemon until it's removed.
+/// Commands are only accepted while a device is Running.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DeviceLifecycle {
+    /// The device has been found, but nothing has been done with it yet
+    Discovered,
+
+    /// The device is being claimed, and its profile loaded
+    Initialising,
+
+    /// The device is ready, and accepting commands
+    Running,
+
+    /// Something has gone wrong with the device, and the daemon is attempting to recover it
+    Degraded,
+
+    /// The device has been removed, and is being shut down
+    Disconnected,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifecycleStatus {
+    /// The serial of the device, if it had got far enough to report one
+    pub serial: Option<String>,
+    pub state: DeviceLifecycle,
+
+    /// Where the device is in its reconnect cycle, None if it's been stable
+    pub reconnect: Option<ReconnectStatus>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct ReconnectStatus {
+    /// How many times the daemon has tried to reconnect since the device was last stable
+    pub attempts: u32,
+
+    /// When (in seconds since the epoch) the next attempt will be made, if one is waiting
+    pub next_attempt: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IncidentType {
+    /// The device event loop stopped making progress for the given number of seconds, and was
+    /// restarted.
+    EventLoopHung(u64),
+
+    /// The given number of USB commands failed in a row, and the device was reconnected.
+    UsbFailures(u32),
+}
+
+/// Which releases the daemon offers to update to
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ReleaseChannel {
+    /// Full releases only
+    Stable,
+
+    /// Full releases, and pre-releases
+    Beta,
+}
+
+impl FromStr for ReleaseChannel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "stable" => Ok(ReleaseChannel::Stable),
+            "beta" => Ok(ReleaseChannel::Beta),
+            _ => Err(format!("Unknown Channel {}, use stable or beta", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateStatus {
+    /// The channel being followed, None if updates are disabled
+    pub channel: Option<ReleaseChannel>,
+
+    /// The version of the running daemon
+    pub current_version: String,
+
+    /// The newest release on the channel, if it's newer than the running daemon
+    pub available: Option<ReleaseInfo>,
+    pub state: UpdateState,
+
+    /// When the last check finished, in seconds since the Unix Epoch
+    pub last_checked: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+    pub version: String,
+    pub name: String,
+
+    /// The release's page, for reading what's changed
+    pub url: String,
+    pub prerelease: bool,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum UpdateState {
+    #[default]
+    Idle,
+    Checking,
+    Downloading,
+
+    /// The update has been downloaded and verified, and will be applied when the daemon stops
+    Staged,
+
+    /// The last check or download failed, with the reason
+    Failed(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,3 +221,27 @@ pub struct Configuration {
     pub device: Profile,
     pub mic_profile: MicProfile,
 }
+
+/// Details about what happened during the last Profile load, including any parts of the profile
+/// which couldn't be applied to this device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfileLoadReport {
+    /// Channels which had their Volume, Mute State and Routing applied
+    pub channels: Vec<FaderChannels>,
+
+    /// Routes which are enabled once the profile has been applied
+    pub routes: Vec<(InputChannels, OutputChannels)>,
+
+    /// Colour Targets whose colours were changed by the load
+    pub colours_changed: Vec<TwoColourTargets>,
+
+    /// Parts of the profile that were skipped as they're unsupported by the device
+    pub skipped: Vec<String>,
+
+    /// Timings in the profile which can't be honoured exactly at the daemon's tick interval
+    pub timings: Vec<String>,
+
+    /// Set if the profile failed to load, and a minimal Safe Mode configuration was applied
+    /// instead. Contains the reason for the failure.
+    pub safe_mode: Option<String>,
+}
@@ -0,0 +1,294 @@
+//! Contract tests for the IPC wire format. Each type is serialised and compared against a golden
+//! file in tests/golden, then the golden file is deserialised back, so a change which would stop
+//! an existing client from talking to the daemon (renaming a variant, moving a field) fails here.
+//!
+//! Additions which don't break anything still need the golden files updating, run the tests with
+//! GOXLR_BLESS_GOLDEN=1 to rewrite them, and check the diff before committing.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use json_patch::{Patch, PatchOperation, ReplaceOperation};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use goxlr_ipc::commands::automation::AutomationCommand;
+use goxlr_ipc::commands::button_cycles::ButtonCycleCommand;
+use goxlr_ipc::commands::channels::{ChannelCommands, ChannelVolume};
+use goxlr_ipc::commands::configuration::{ConfigurationCommand, StateDifference};
+use goxlr_ipc::commands::controls::ControlCommand;
+use goxlr_ipc::commands::diagnostics::DiagnosticsCommand;
+use goxlr_ipc::commands::ducking::DuckingCommand;
+use goxlr_ipc::commands::effects::reverb::ReverbCommand;
+use goxlr_ipc::commands::effects::EffectsCommand;
+use goxlr_ipc::commands::lighting::LightingCommand;
+use goxlr_ipc::commands::mic::gate::GateCommand;
+use goxlr_ipc::commands::mic::MicrophoneCommand;
+use goxlr_ipc::commands::mix_modes::MixModeCommand;
+use goxlr_ipc::commands::monitor_mix::MonitorMixCommand;
+use goxlr_ipc::commands::pages::PageCommand;
+use goxlr_ipc::commands::push_to_talk::PushToTalkCommand;
+use goxlr_ipc::commands::scribbles::ScribbleCommand;
+use goxlr_ipc::commands::solo::SoloCommand;
+use goxlr_ipc::commands::{
+    Backup, DaemonCommand, DaemonRequest, DaemonResponse, DaemonStatus, DeviceCommand,
+    DryRunReport, FaderMovement, GoXLRCommand, GoXLRCommandResponse, HealthReport, ProfileImport,
+    SessionPosition, WebsocketRequest, WebsocketResponse,
+};
+use goxlr_ipc::status::DeviceLifecycle;
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::channels::fader::FaderChannels;
+use goxlr_shared::channels::input::InputChannels;
+use goxlr_shared::channels::output::OutputChannels;
+use goxlr_shared::channels::volume::VolumeChannels;
+use goxlr_shared::colours::Colour;
+use goxlr_shared::faders::Fader;
+
+const BLESS: &str = "GOXLR_BLESS_GOLDEN";
+
+fn check<T: Serialize + DeserializeOwned>(name: &str, samples: Vec<T>) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.json", name));
+    let actual = serde_json::to_value(&samples).expect("Unable to serialise samples");
+
+    if std::env::var_os(BLESS).is_some() {
+        let json = serde_json::to_string_pretty(&actual).unwrap();
+        fs::write(&path, json + "\n").expect("Unable to write golden file");
+        return;
+    }
+
+    let golden = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "No golden file for {}, run with {}=1 to create it",
+            name, BLESS
+        )
+    });
+    let expected: Value = serde_json::from_str(&golden).expect("Golden file isn't valid JSON");
+    assert_eq!(
+        actual, expected,
+        "{} no longer serialises as it used to, existing clients would break",
+        name
+    );
+
+    // Whatever an existing client sends, the current types need to still understand
+    let parsed: Vec<T> = serde_json::from_value(expected.clone())
+        .unwrap_or_else(|e| panic!("The {} golden file no longer deserialises: {}", name, e));
+    let reserialised = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(
+        reserialised, expected,
+        "{} doesn't survive a round trip",
+        name
+    );
+}
+
+fn device_command(command: GoXLRCommand) -> DaemonRequest {
+    DaemonRequest::DeviceCommand(DeviceCommand {
+        serial: String::from("S220202153DI7"),
+        command,
+    })
+}
+
+#[test]
+fn daemon_requests() {
+    check(
+        "daemon_requests",
+        vec![
+            DaemonRequest::Ping,
+            DaemonRequest::GetStatus,
+            DaemonRequest::Subscribe,
+            DaemonRequest::Unsubscribe,
+            DaemonRequest::SubscribeFaderEvents,
+            DaemonRequest::UnsubscribeFaderEvents,
+            DaemonRequest::ResumeSession(None),
+            DaemonRequest::ResumeSession(Some(SessionPosition {
+                session: 1234,
+                sequence: 56,
+            })),
+            DaemonRequest::Forced(Box::new(DaemonRequest::Daemon(DaemonCommand::ListBackups))),
+            DaemonRequest::DryRun(DeviceCommand {
+                serial: String::from("S220202153DI7"),
+                command: GoXLRCommand::Pages(PageCommand::LoadPage(1)),
+            }),
+        ],
+    );
+}
+
+#[test]
+fn daemon_commands() {
+    check(
+        "daemon_commands",
+        vec![
+            DaemonCommand::GetLogTail(50),
+            DaemonCommand::GetBackup,
+            DaemonCommand::RestoreBackup(Backup::default()),
+            DaemonCommand::ListBackups,
+            DaemonCommand::RestoreStoredBackup(String::from("backup-1700000000")),
+            DaemonCommand::GetHealth,
+            DaemonCommand::SetUsbTrafficLog(true),
+            DaemonCommand::SetLiveMode(false),
+            DaemonCommand::CheckForUpdate,
+            DaemonCommand::InstallUpdate,
+            DaemonCommand::PreviewProfileImport(String::from("https://example.com/a.json")),
+            DaemonCommand::ImportProfile(ProfileImport {
+                serial: String::from("S220202153DI7"),
+                url: String::from("https://example.com/a.json"),
+                digest: None,
+            }),
+        ],
+    );
+}
+
+#[test]
+fn device_commands() {
+    check(
+        "device_commands",
+        vec![
+            device_command(GoXLRCommand::Configuration(
+                ConfigurationCommand::ButtonHoldTime(500),
+            )),
+            device_command(GoXLRCommand::Microphone(MicrophoneCommand::Gate(
+                GateCommand::SetThreshold(-30),
+            ))),
+            device_command(GoXLRCommand::Channels(ChannelCommands::Volume(
+                ChannelVolume {
+                    channel: VolumeChannels::Music,
+                    volume: 128,
+                },
+            ))),
+            device_command(GoXLRCommand::Pages(PageCommand::LoadPage(1))),
+            device_command(GoXLRCommand::Effects(EffectsCommand::Reverb(
+                ReverbCommand::SetAmount(40),
+            ))),
+            device_command(GoXLRCommand::Diagnostics(
+                DiagnosticsCommand::GetGainAnalysis,
+            )),
+            device_command(GoXLRCommand::MixModes(MixModeCommand::Activate(
+                String::from("Gaming"),
+            ))),
+            device_command(GoXLRCommand::Ducking(DuckingCommand::AttackTime(100))),
+            device_command(GoXLRCommand::PushToTalk(PushToTalkCommand::Outputs(vec![
+                OutputChannels::StreamMix,
+            ]))),
+            device_command(GoXLRCommand::MonitorMix(MonitorMixCommand::Level(
+                InputChannels::Chat,
+                16,
+            ))),
+            device_command(GoXLRCommand::Lighting(LightingCommand::Accent(Colour {
+                red: 255,
+                green: 0,
+                blue: 128,
+            }))),
+            device_command(GoXLRCommand::Solo(SoloCommand::Set(Some(
+                InputChannels::Microphone,
+            )))),
+            device_command(GoXLRCommand::Scribbles(ScribbleCommand::ClearWidget(
+                Fader::A,
+            ))),
+            device_command(GoXLRCommand::Controls(ControlCommand::List)),
+            device_command(GoXLRCommand::Automation(AutomationCommand::Play(
+                String::from("Intro"),
+            ))),
+            device_command(GoXLRCommand::ButtonCycles(ButtonCycleCommand::Next(
+                Buttons::FaderA,
+            ))),
+            device_command(GoXLRCommand::Batch(vec![
+                GoXLRCommand::Pages(PageCommand::LoadPage(1)),
+                GoXLRCommand::Ducking(DuckingCommand::AttackTime(100)),
+            ])),
+        ],
+    );
+}
+
+#[test]
+fn daemon_responses() {
+    let mut pending = BTreeMap::new();
+    pending.insert(String::from("1-4"), DeviceLifecycle::Initialising);
+
+    check(
+        "daemon_responses",
+        vec![
+            DaemonResponse::Ok,
+            DaemonResponse::Err(String::from("Device not found")),
+            DaemonResponse::LogTail(vec![String::from("Daemon Started")]),
+            DaemonResponse::Backups(vec![String::from("backup-1700000000")]),
+            DaemonResponse::Health(HealthReport {
+                live: true,
+                ready: false,
+                running: 0,
+                pending,
+            }),
+            DaemonResponse::LiveModeBlocked(String::from("Loading a Profile")),
+            DaemonResponse::DeviceCommand(GoXLRCommandResponse::Ok),
+        ],
+    );
+}
+
+#[test]
+fn command_responses() {
+    check(
+        "command_responses",
+        vec![
+            GoXLRCommandResponse::Ok,
+            GoXLRCommandResponse::MicLevel(-42.5),
+            GoXLRCommandResponse::Automations(vec![String::from("Intro")]),
+            GoXLRCommandResponse::FlightRecording(String::from("[]")),
+            GoXLRCommandResponse::Error(String::from("Invalid Value")),
+            GoXLRCommandResponse::LiveModeBlocked(String::from("Loading a Profile")),
+            GoXLRCommandResponse::DryRun(DryRunReport {
+                usb_commands: vec![String::from("AssignFader(A, Music)")],
+                changes: vec![StateDifference {
+                    setting: String::from("/device/pages/current"),
+                    current: String::from("0"),
+                    desired: String::from("1"),
+                }],
+            }),
+            GoXLRCommandResponse::Batch(vec![GoXLRCommandResponse::Ok]),
+        ],
+    );
+}
+
+#[test]
+fn events() {
+    let patch = Patch(vec![PatchOperation::Replace(ReplaceOperation {
+        path: String::from("/live_mode"),
+        value: json!(true),
+    })]);
+
+    check(
+        "events",
+        vec![
+            DaemonResponse::Status(DaemonStatus::default()),
+            DaemonResponse::Patch(patch),
+            DaemonResponse::FaderMoved(FaderMovement {
+                serial: String::from("S220202153DI7"),
+                fader: Fader::B,
+                channel: FaderChannels::Music,
+                old_value: Some(100),
+                new_value: 120,
+                timestamp: 1700000000000,
+            }),
+        ],
+    );
+}
+
+#[test]
+fn websocket_envelopes() {
+    check(
+        "websocket_requests",
+        vec![WebsocketRequest {
+            id: 1,
+            data: DaemonRequest::GetStatus,
+        }],
+    );
+    check(
+        "websocket_responses",
+        vec![WebsocketResponse {
+            id: 1,
+            data: DaemonResponse::Ok,
+        }],
+    );
+}
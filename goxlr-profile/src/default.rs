@@ -1,5 +1,6 @@
 use enum_map::{enum_map, EnumMap};
-use goxlr_shared::channels::ducking::DuckingInput;
+use goxlr_shared::channels::category::InputCategory;
+use goxlr_shared::channels::ducking::{DuckingInput, DuckingSensitivity};
 use goxlr_shared::channels::fader::FaderChannels;
 use goxlr_shared::channels::input::InputChannels;
 use goxlr_shared::channels::output::OutputChannels;
@@ -9,16 +10,17 @@ use strum::IntoEnumIterator;
 use goxlr_shared::colours::Colour;
 use goxlr_shared::colours::FaderDisplayMode::Meter;
 use goxlr_shared::compressor::{CompressorAttackTime, CompressorRatio, CompressorReleaseTime};
+use goxlr_shared::effects::{EchoStyle, GenderStyle, PitchStyle, ReverbStyle};
 use goxlr_shared::eq_frequencies::{Frequencies, MiniFrequencies};
 
 use goxlr_shared::gate::GateTimes;
-use goxlr_shared::mute::MuteState;
+use goxlr_shared::mute::{CoughTarget, MuteState};
 
 use crate::{
     ButtonColourSet, Channels, Compressor, CoughBehaviour, CoughSettings, DuckingSettings,
-    DuckingTransition, DuckingVolume, EqualizerValue, FaderChannel, FaderColourSet, FaderDisplay,
-    FaderPage, FaderPages, Gate, InactiveButtonBehaviour, MicProfile, Microphone, MicrophoneType,
-    Profile, Screen,
+    DuckingTransition, DuckingVolume, Echo, Effects, EqualizerValue, FaderChannel, FaderColourSet,
+    FaderDisplay, FaderPage, FaderPages, Gate, Gender, HardTune, InactiveButtonBehaviour,
+    MicProfile, Microphone, MicrophoneType, Pitch, Profile, Reverb, Screen,
 };
 use crate::{Configuration, Fader};
 use crate::{MuteAction, SwearSettings};
@@ -85,34 +87,46 @@ impl Default for Profile {
         };
 
         let channel = FaderChannel {
+            name: None,
             mute_state: MuteState::Unmuted,
             mute_actions: mute_action.clone(),
             display: fader_display.clone(),
+            mute_fade_ms: 0,
         };
         let channel2 = FaderChannel {
+            name: None,
             mute_state: MuteState::Unmuted,
             mute_actions: mute_action.clone(),
             display: fader_display.clone(),
+            mute_fade_ms: 0,
         };
         let channel3 = FaderChannel {
+            name: None,
             mute_state: MuteState::Unmuted,
             mute_actions: mute_action.clone(),
             display: fader_display.clone(),
+            mute_fade_ms: 0,
         };
         let channel4 = FaderChannel {
+            name: None,
             mute_state: MuteState::Unmuted,
             mute_actions: mute_action.clone(),
             display: fader_display.clone(),
+            mute_fade_ms: 0,
         };
         let channel5 = FaderChannel {
+            name: None,
             mute_state: MuteState::Unmuted,
             mute_actions: mute_action.clone(),
             display: fader_display.clone(),
+            mute_fade_ms: 0,
         };
         let channel6 = FaderChannel {
+            name: None,
             mute_state: MuteState::Unmuted,
             mute_actions: mute_action.clone(),
             display: fader_display.clone(),
+            mute_fade_ms: 0,
         };
 
         // We're just going to clone this config out to all the channels, these would realistically
@@ -204,6 +218,9 @@ impl Default for Profile {
             volumes,
             configs: fader_config.clone(),
             sub_mix: Default::default(),
+            trims: enum_map! { _ => 32 },
+            stereo: Default::default(),
+            categories: get_default_categories(),
         };
 
         let page = FaderPage::default();
@@ -279,8 +296,18 @@ impl Default for Profile {
             input_source: Default::default(),
             transition: Default::default(),
             output_routing: Default::default(),
+            category_routing: Default::default(),
             attack_time: 0,
             release_time: 500,
+            trigger_db: get_default_trigger_db(),
+            input_trigger_db: Default::default(),
+            capture_devices: Default::default(),
+            mic_trigger_offset: 0,
+            sensitivity: get_default_sensitivity(),
+            sensitivity_button: None,
+            adaptive: Default::default(),
+            fade_button: None,
+            fast_unduck_on_mute: false,
         };
 
         ducking.enabled = true;
@@ -298,6 +325,7 @@ impl Default for Profile {
             channel_assignment: FaderChannels::System,
             mute_state: MuteState::Unmuted,
             mute_actions: mute_action,
+            target: CoughTarget::Configured,
 
             colours: ButtonColourSet {
                 active_colour: Colour {
@@ -322,6 +350,70 @@ impl Default for Profile {
             cough,
             configuration,
             ducking,
+            effects: Default::default(),
+            mix_modes: Default::default(),
+            push_to_talk: Default::default(),
+            monitor_mix: Default::default(),
+            lighting: Default::default(),
+            solo: Default::default(),
+            sampler: Default::default(),
+            routing_rules: Default::default(),
+            hard_mute: Default::default(),
+            button_cycles: Default::default(),
+            fader_response: Default::default(),
+        }
+    }
+}
+
+impl Default for Effects {
+    fn default() -> Self {
+        // These match the 'Library' and 'Quarter' presets on the official app
+        let reverb = Reverb {
+            style: ReverbStyle::Library,
+            amount: 0,
+            decay: 1000,
+            early_level: 0,
+            pre_delay: 0,
+            low_colour: 0,
+            high_colour: 0,
+            high_factor: 0,
+            diffuse: 0,
+            mod_speed: 0,
+            mod_depth: 0,
+        };
+
+        let echo = Echo {
+            style: EchoStyle::Quarter,
+            amount: 0,
+            feedback: 50,
+            tempo: 120,
+            delay_left: 500,
+            delay_right: 500,
+            feedback_left: 50,
+            feedback_right: 50,
+            feedback_xfb_l_to_r: 0,
+            feedback_xfb_r_to_l: 0,
+        };
+
+        let pitch = Pitch {
+            style: PitchStyle::Wide,
+            amount: 0,
+            character: 100,
+        };
+
+        let gender = Gender {
+            style: GenderStyle::Medium,
+            amount: 0,
+        };
+
+        let hard_tune = HardTune { enabled: false };
+
+        Self {
+            reverb,
+            echo,
+            pitch,
+            gender,
+            hard_tune,
         }
     }
 }
@@ -452,3 +544,28 @@ impl Default for MicProfile {
         }
     }
 }
+
+// Values for fields which were added to the profile after it was first written, used when
+// they're missing from an older profile and shouldn't be zero.
+
+/// No ceiling at all
+pub(crate) fn get_default_ceiling() -> u8 {
+    32
+}
+
+pub(crate) fn get_default_trigger_db() -> f64 {
+    -40.
+}
+
+pub(crate) fn get_default_sensitivity() -> Option<DuckingSensitivity> {
+    Some(DuckingSensitivity::Medium)
+}
+
+/// The Microphone and Chat are Voice, System is System, and everything else is Media
+pub(crate) fn get_default_categories() -> EnumMap<InputChannels, Option<InputCategory>> {
+    enum_map! {
+        InputChannels::Microphone | InputChannels::Chat => Some(InputCategory::Voice),
+        InputChannels::System => Some(InputCategory::System),
+        _ => Some(InputCategory::Media),
+    }
+}
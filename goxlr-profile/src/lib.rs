@@ -1,10 +1,13 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use enum_map::{enum_map, Enum, EnumMap};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use goxlr_shared::buttons::InactiveButtonBehaviour;
-use goxlr_shared::channels::ducking::DuckingInput;
+use goxlr_shared::buttons::{Buttons, InactiveButtonBehaviour};
+use goxlr_shared::channels::category::InputCategory;
+use goxlr_shared::channels::ducking::{DuckingInput, DuckingSensitivity};
 use goxlr_shared::channels::fader::FaderChannels;
 use goxlr_shared::channels::input::InputChannels;
 use goxlr_shared::channels::output::OutputChannels;
@@ -12,16 +15,22 @@ use goxlr_shared::channels::sub_mix::SubMixChannels;
 use goxlr_shared::channels::volume::VolumeChannels;
 use goxlr_shared::colours::{Colour, FaderColour, FaderDisplayMode, TwoColour};
 use goxlr_shared::compressor::{CompressorAttackTime, CompressorRatio, CompressorReleaseTime};
+use goxlr_shared::effects::{EchoStyle, GenderStyle, PitchStyle, ReverbStyle};
 use goxlr_shared::eq_frequencies::{Frequencies, MiniFrequencies};
 use goxlr_shared::faders::Fader;
 use goxlr_shared::gate::GateTimes;
 use goxlr_shared::microphone::MicrophoneType;
-use goxlr_shared::mute::MuteState;
+use goxlr_shared::mute::{CoughTarget, MuteState};
+use goxlr_shared::routing::StereoMode;
+use goxlr_shared::sampler::{SampleBank, SampleButton};
 use goxlr_shared::submix::Mix;
 
+use crate::partial::deserialize_partial_map;
+
 mod default;
+mod partial;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Profile {
     /// Fader Paging Configuration
     pub pages: FaderPages,
@@ -30,6 +39,7 @@ pub struct Profile {
     pub channels: Channels,
 
     /// Configuration for the Output Settings..
+    #[schemars(with = "BTreeMap<OutputChannels, Outputs>")]
     pub outputs: EnumMap<OutputChannels, Outputs>,
 
     /// Configuration for the Swear Button
@@ -39,6 +49,7 @@ pub struct Profile {
     pub cough: CoughSettings,
 
     /// The Routing Configuration
+    #[schemars(with = "BTreeMap<InputChannels, BTreeMap<OutputChannels, bool>>")]
     pub routing: EnumMap<InputChannels, EnumMap<OutputChannels, bool>>,
 
     /// The General 'Configuration' of the device
@@ -46,15 +57,67 @@ pub struct Profile {
 
     /// Ducking Configuration
     pub ducking: DuckingSettings,
+
+    /// Voice Effect Configuration
+    pub effects: Effects,
+
+    /// Named Mix Modes which can be switched between
+    pub mix_modes: MixModes,
+
+    /// Push to Talk Configuration
+    pub push_to_talk: PushToTalk,
+
+    /// Headphone Monitor Mix Configuration
+    pub monitor_mix: MonitorMix,
+
+    /// Lighting which isn't attached to a channel or button
+    pub lighting: Lighting,
+
+    /// Solo Button Configuration
+    pub solo: Solo,
+
+    /// Where samples are heard while they're playing
+    pub sampler: Sampler,
+
+    /// Checks for routing which is probably a mistake
+    pub routing_rules: RoutingRules,
+
+    /// The buttons which engage and release the Hard Mute
+    #[serde(default)]
+    pub hard_mute: HardMute,
+
+    /// Buttons which step through a list of states, one per press
+    #[serde(default)]
+    #[schemars(with = "BTreeMap<Buttons, Option<ButtonCycle>>")]
+    pub button_cycles: EnumMap<Buttons, Option<ButtonCycle>>,
+
+    /// How each physical Fader's position is turned into a volume
+    #[serde(default)]
+    #[schemars(with = "BTreeMap<Fader, FaderResponse>")]
+    pub fader_response: EnumMap<Fader, FaderResponse>,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Outputs {
     /// The Mix this Output is Assigned to when Sub Mixing is enabled
     pub mix_assignment: Mix,
+
+    /// The loudest any route to this Output can be, from 0 to 32 (no ceiling). Nothing which
+    /// changes the routing (such as the Ducker, Mix Modes or Monitor Mix) can go above it.
+    #[serde(default = "default::get_default_ceiling")]
+    pub ceiling: u8,
+}
+
+impl Default for Outputs {
+    fn default() -> Self {
+        Self {
+            mix_assignment: Default::default(),
+            ceiling: default::get_default_ceiling(),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FaderPages {
     /// The Currently Active Fader Page
     pub current: usize,
@@ -63,9 +126,10 @@ pub struct FaderPages {
     pub page_list: Vec<FaderPage>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FaderPage {
     /// A map of the Faders, and which Channels are assigned to them on this Page
+    #[schemars(with = "BTreeMap<Fader, FaderChannels>")]
     pub faders: EnumMap<Fader, FaderChannels>,
 }
 
@@ -82,33 +146,58 @@ impl Default for FaderPage {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Channels {
     /// Volumes for All Channels
+    #[schemars(with = "BTreeMap<VolumeChannels, u8>")]
     pub volumes: EnumMap<VolumeChannels, u8>,
 
     /// Configs for Channels which can be assigned to Faders
+    #[schemars(with = "BTreeMap<FaderChannels, FaderChannel>")]
     pub configs: EnumMap<FaderChannels, FaderChannel>,
 
     /// Sub-mix Settings for all applicable channels
+    #[schemars(with = "BTreeMap<SubMixChannels, SubMixVolumes>")]
     pub sub_mix: EnumMap<SubMixChannels, SubMixVolumes>,
+
+    /// How much each input is trimmed before it's routed, from 0 to 32 (untouched)
+    #[schemars(with = "BTreeMap<InputChannels, u8>")]
+    pub trims: EnumMap<InputChannels, u8>,
+
+    /// How the left and right sides of each input are sent to the outputs
+    #[schemars(with = "BTreeMap<InputChannels, StereoMode>")]
+    pub stereo: EnumMap<InputChannels, StereoMode>,
+
+    /// The category each input belongs to, None if it isn't part of one
+    #[serde(default = "default::get_default_categories")]
+    #[schemars(with = "BTreeMap<InputChannels, Option<InputCategory>>")]
+    pub categories: EnumMap<InputChannels, Option<InputCategory>>,
 }
 
 /// This is a Channel that can be assigned to a fader. All configuration for the channel
 /// including colours, mute states and behaviours are configured here.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FaderChannel {
+    /// A user defined name for the channel (for example, 'Spotify' rather than 'Music')
+    pub name: Option<String>,
+
     /// The current channel Mute State
     pub mute_state: MuteState,
 
     /// Defines what action is performed on Press and Hold
+    #[schemars(with = "BTreeMap<MuteAction, Vec<OutputChannels>>")]
     pub mute_actions: EnumMap<MuteAction, Vec<OutputChannels>>,
 
     /// A struct detailing how a fader is displayed on the GoXLR
     pub display: FaderDisplay,
+
+    /// How long (in ms) to fade the volume over when muting or unmuting the channel to all
+    /// outputs, 0 switches immediately.
+    #[serde(default)]
+    pub mute_fade_ms: u16,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SubMixVolumes {
     /// The Mix B Volumes
     pub volume: u8,
@@ -127,7 +216,7 @@ impl Default for SubMixVolumes {
 }
 
 /// A struct that defines top to bottom how a fader is displayed on the Device
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FaderDisplay {
     /// Which display mode features to apply to the fader
     pub fader_display_mode: Vec<FaderDisplayMode>,
@@ -143,7 +232,7 @@ pub struct FaderDisplay {
 }
 
 /// Represents the screen above the faders on the Full Sized GoXLR
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Screen {
     /// The background Colour of the Screen
     pub colour: Colour,
@@ -162,7 +251,7 @@ pub struct Screen {
 }
 
 /// This defines a Buttons colour configuration
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ButtonColourSet {
     /// The Currently Set 'Active' Colour
     pub active_colour: Colour,
@@ -184,7 +273,7 @@ impl From<ButtonColourSet> for TwoColour {
 }
 
 /// Colour's related to the Fader Slider
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FaderColourSet {
     /// The colour displayed above the fader
     pub top_colour: Colour,
@@ -203,7 +292,7 @@ impl From<FaderColourSet> for FaderColour {
 }
 
 /// These are the different methods of interacting with Mute Keys
-#[derive(Debug, Copy, Clone, Enum, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Enum, Serialize, Deserialize, JsonSchema)]
 pub enum MuteAction {
     Press,
     Hold,
@@ -219,14 +308,14 @@ impl From<MuteState> for MuteAction {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SwearSettings {
     pub volume: u8,
     pub colours: ButtonColourSet,
 }
 
 /// This is for handling the cough button and it's settings
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CoughSettings {
     /// The behaviour when pressing the button
     pub cough_behaviour: CoughBehaviour,
@@ -238,29 +327,36 @@ pub struct CoughSettings {
     pub mute_state: MuteState,
 
     /// Defines what action is performed on Press and Hold
+    #[schemars(with = "BTreeMap<MuteAction, Vec<OutputChannels>>")]
     pub mute_actions: EnumMap<MuteAction, Vec<OutputChannels>>,
 
+    /// A quick mode which mutes to only Chat or the Stream, in place of the mute actions
+    #[serde(default)]
+    pub target: CoughTarget,
+
     /// Defines the colours and styling of the button
     pub colours: ButtonColourSet,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Enum, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, Enum, Eq, PartialEq)]
 pub enum CoughBehaviour {
     Press,
     Hold,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Configuration {
     pub submix_enabled: bool,
     pub button_hold_time: u16,
     pub change_page_with_buttons: bool,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MicProfile {
     pub microphone: Microphone,
+    #[schemars(with = "BTreeMap<Frequencies, EqualizerValue>")]
     pub equalizer: EnumMap<Frequencies, EqualizerValue>,
+    #[schemars(with = "BTreeMap<MiniFrequencies, EqualizerValue>")]
     pub equalizer_mini: EnumMap<MiniFrequencies, EqualizerValue>,
     pub compressor: Compressor,
     pub deess: u8,
@@ -268,19 +364,20 @@ pub struct MicProfile {
     pub bleep_volume: i8,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Microphone {
     pub mic_type: MicrophoneType,
+    #[schemars(with = "BTreeMap<MicrophoneType, u8>")]
     pub mic_gains: EnumMap<MicrophoneType, u8>,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EqualizerValue {
     pub gain: i8,
     pub frequency: f32,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Compressor {
     pub threshold: i8,
     pub ratio: CompressorRatio,
@@ -289,7 +386,7 @@ pub struct Compressor {
     pub makeup_gain: i8,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Gate {
     pub enabled: bool,
 
@@ -299,24 +396,527 @@ pub struct Gate {
     pub attenuation: u8,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DuckingSettings {
     pub enabled: bool,
+    #[serde(deserialize_with = "deserialize_partial_map")]
+    #[schemars(with = "BTreeMap<DuckingInput, bool>")]
     pub input_source: EnumMap<DuckingInput, bool>,
     pub transition: DuckingTransition,
+    #[schemars(with = "BTreeMap<InputChannels, BTreeMap<OutputChannels, bool>>")]
     pub output_routing: EnumMap<InputChannels, EnumMap<OutputChannels, bool>>,
+
+    /// Routes lowered for every input in a category, on top of the output routing above
+    #[serde(default)]
+    #[schemars(with = "BTreeMap<InputCategory, BTreeMap<OutputChannels, bool>>")]
+    pub category_routing: EnumMap<InputCategory, EnumMap<OutputChannels, bool>>,
     pub attack_time: u64,
     pub release_time: u64,
+
+    /// The level (in dB) an Input other than the Microphone needs to reach to trigger ducking
+    #[serde(default = "default::get_default_trigger_db")]
+    pub trigger_db: f64,
+
+    /// Trigger levels (in dB) for individual Inputs, replacing the trigger level above for that
+    /// Input. These aren't used for the Microphone, which triggers from its gate threshold.
+    #[serde(default, deserialize_with = "deserialize_partial_map")]
+    #[schemars(with = "BTreeMap<DuckingInput, Option<f64>>")]
+    pub input_trigger_db: EnumMap<DuckingInput, Option<f64>>,
+
+    /// The capture devices Inputs are measured from, for Inputs the device can't meter itself
+    /// (such as the Chat Mic). These are opened again as the profile is loaded.
+    #[serde(default, deserialize_with = "deserialize_partial_map")]
+    #[schemars(with = "BTreeMap<DuckingInput, Option<String>>")]
+    pub capture_devices: EnumMap<DuckingInput, Option<String>>,
+
+    /// Added to the Microphone's gate threshold when it's a trigger, higher needs louder speech
+    #[serde(default)]
+    pub mic_trigger_offset: i8,
+
+    /// The sensitivity preset the trigger level and times came from, None once they're changed
+    #[serde(default = "default::get_default_sensitivity")]
+    pub sensitivity: Option<DuckingSensitivity>,
+
+    /// Pressing this button steps through the sensitivity presets, from Low to High
+    #[serde(default)]
+    pub sensitivity_button: Option<Buttons>,
+    pub adaptive: AdaptiveDucking,
+
+    /// While this button is held, the ducked routes are faded down through the transition as if
+    /// an input had triggered them, and faded back up once it's released. This works even when
+    /// Ducking itself is disabled.
+    #[serde(default)]
+    pub fade_button: Option<Buttons>,
+
+    /// When the Microphone is muted (to the Stream, or to everything) and it's all that's holding
+    /// the duck, unduck straight away rather than waiting out the release time
+    #[serde(default)]
+    pub fast_unduck_on_mute: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DuckingTransition {
     pub ducking: Vec<DuckingVolume>,
     pub unducking: Vec<DuckingVolume>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DuckingVolume {
     pub route_volume: u8,
     pub wait_time: u64,
 }
+
+/// When enabled, the depth of the duck follows the loudness of the Music, so quiet music is only
+/// lowered a little, while loud music is lowered further. The levels are mapped linearly between
+/// the quiet and loud points, and the transition is scaled so its deepest step hits the result.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdaptiveDucking {
+    pub enabled: bool,
+
+    /// The Music levels (in dB) considered quiet and loud
+    pub quiet_db: f64,
+    pub loud_db: f64,
+
+    /// The route volume ducked to at (or below) the quiet level, and at (or above) the loud level
+    pub quiet_volume: u8,
+    pub loud_volume: u8,
+}
+
+impl Default for AdaptiveDucking {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            quiet_db: -40.,
+            loud_db: -6.,
+            quiet_volume: 24,
+            loud_volume: 8,
+        }
+    }
+}
+
+/// When enabled, the Microphone is only sent to the listed outputs while the button (or an
+/// external hotkey) is held.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PushToTalk {
+    pub enabled: bool,
+
+    /// The button which needs to be held to talk, this replaces the button's usual behaviour
+    pub button: Option<Buttons>,
+
+    /// The Outputs the Microphone is held back from until talking
+    pub outputs: Vec<OutputChannels>,
+
+    /// How long (in ms) to keep the Microphone open after the button is released
+    pub release_tail: u64,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Lighting {
+    /// The colour of the X logo (Full Size only)
+    pub accent: Colour,
+
+    /// The colour of the light inside the device
+    pub global: Colour,
+
+    /// The brightness of all the device's lighting, as a percentage
+    pub brightness: u8,
+
+    /// Dims the lighting while the device isn't being used
+    pub idle: IdleDimming,
+
+    /// Fader colours are dimmed to follow the channel's volume, and show the mute colour while
+    /// the channel is muted
+    #[serde(default)]
+    pub channel_linked: bool,
+}
+
+impl Default for Lighting {
+    fn default() -> Self {
+        let white = Colour {
+            red: 255,
+            green: 255,
+            blue: 255,
+        };
+
+        Self {
+            accent: white,
+            global: white,
+            brightness: 100,
+            idle: Default::default(),
+            channel_linked: false,
+        }
+    }
+}
+
+/// Once nothing on the device has been pressed, turned or moved for the timeout, the lighting
+/// is dimmed, and anything only there for show is paused. It all comes back on the next input.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IdleDimming {
+    /// How long (in minutes) without any input before dimming, 0 disables it
+    pub timeout: u16,
+
+    /// The brightness to dim to, as a percentage
+    pub brightness: u8,
+}
+
+impl Default for IdleDimming {
+    fn default() -> Self {
+        Self {
+            timeout: 0,
+            brightness: 10,
+        }
+    }
+}
+
+/// Gives the Headphones their own level for each input, separate from what the other outputs
+/// receive, using route volumes rather than the device's sub mixes.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MonitorMix {
+    pub enabled: bool,
+
+    /// The Headphone route level for each input, from 0 to 32 (full volume)
+    #[schemars(with = "BTreeMap<InputChannels, u8>")]
+    pub levels: EnumMap<InputChannels, u8>,
+}
+
+impl Default for MonitorMix {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            levels: EnumMap::from_fn(|_| 32),
+        }
+    }
+}
+
+/// A button which toggles Solo for an input, so it's the only thing heard in the Headphones
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Solo {
+    /// The button which toggles Solo, this replaces the button's usual behaviour
+    pub button: Option<Buttons>,
+
+    /// The Input the button Solos
+    pub input: InputChannels,
+}
+
+impl Default for Solo {
+    fn default() -> Self {
+        Self {
+            button: None,
+            input: InputChannels::Microphone,
+        }
+    }
+}
+
+/// Samples can be heard on different outputs to the rest of the Sample channel, for example so
+/// a sound effect only reaches the Stream. While a sample plays, its outputs replace the Sample
+/// channel's routing, which is put back once it stops. A button's outputs take priority over
+/// its bank's, and if neither are set the routing table is used as normal.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Sampler {
+    /// The WAV file assigned to each button, for samples played by the daemon
+    #[schemars(with = "BTreeMap<SampleBank, BTreeMap<SampleButton, Option<PathBuf>>>")]
+    pub samples: EnumMap<SampleBank, EnumMap<SampleButton, Option<PathBuf>>>,
+
+    /// Files queued to play, in order, after a button's sample has finished
+    #[serde(default)]
+    #[schemars(with = "BTreeMap<SampleBank, BTreeMap<SampleButton, Vec<PathBuf>>>")]
+    pub playlists: EnumMap<SampleBank, EnumMap<SampleButton, Vec<PathBuf>>>,
+
+    #[schemars(with = "BTreeMap<SampleBank, Option<Vec<OutputChannels>>>")]
+    pub bank_outputs: EnumMap<SampleBank, Option<Vec<OutputChannels>>>,
+    #[schemars(with = "BTreeMap<SampleBank, BTreeMap<SampleButton, Option<Vec<OutputChannels>>>>")]
+    pub button_outputs: EnumMap<SampleBank, EnumMap<SampleButton, Option<Vec<OutputChannels>>>>,
+}
+
+/// Routing which is probably a mistake (such as the Microphone not going to the Stream) is
+/// reported as a warning, rather than stopping the configuration being used.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RoutingRules {
+    pub rules: Vec<RoutingRule>,
+
+    /// While one of these applications (by name or executable) has an audio stream open, the
+    /// Microphone is routed to the Chat Mic, and Chat to the Headphones. Routing goes back to
+    /// normal once it closes.
+    #[serde(default)]
+    pub voice_applications: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RoutingRule {
+    /// Shown as the warning when the rule is broken, and used to identify the rule
+    pub name: String,
+
+    pub input: InputChannels,
+    pub output: OutputChannels,
+
+    /// Whether the Input is expected to be routed to the Output
+    pub routed: bool,
+
+    /// Only check the rule while this Mix Mode is active, rather than all the time
+    pub mix_mode: Option<String>,
+}
+
+impl Default for RoutingRules {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                RoutingRule {
+                    name: String::from("The Microphone isn't routed to the Stream Mix"),
+                    input: InputChannels::Microphone,
+                    output: OutputChannels::StreamMix,
+                    routed: true,
+                    mix_mode: None,
+                },
+                RoutingRule {
+                    name: String::from("Chat isn't routed to the Headphones"),
+                    input: InputChannels::Chat,
+                    output: OutputChannels::Headphones,
+                    routed: true,
+                    mix_mode: None,
+                },
+            ],
+            voice_applications: vec![],
+        }
+    }
+}
+
+/// The Hard Mute silences the Microphone at the input, and while engaged nothing (including
+/// mute buttons, scripts and automations) can unmute it. It can only be released by the same
+/// gesture which engaged it.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HardMute {
+    /// Buttons which, pressed together, engage or release the Hard Mute. Empty disables this.
+    pub buttons: Vec<Buttons>,
+
+    /// How the Hard Mute was engaged if it is, kept here so it's still engaged after a restart
+    #[serde(default)]
+    pub engaged: Option<HardMuteOrigin>,
+}
+
+/// How the Hard Mute was engaged, it can only be released the same way
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum HardMuteOrigin {
+    Buttons,
+    Command,
+}
+
+/// Each press of the button moves to the next step and applies its actions, going back to the
+/// first step after the last. This replaces the button's usual behaviour.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ButtonCycle {
+    pub steps: Vec<ButtonCycleStep>,
+
+    /// The step which was last applied
+    pub current: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ButtonCycleStep {
+    /// The colour of the button while this step is active
+    pub colour: Colour,
+
+    pub actions: Vec<ButtonCycleAction>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum ButtonCycleAction {
+    /// Sets the Volume of a channel (0 - 255)
+    Volume(VolumeChannels, u8),
+
+    /// Mutes or Unmutes a channel, using its configured mute behaviour
+    Mute(FaderChannels, MuteState),
+
+    /// Enables or Disables a route
+    Route(InputChannels, OutputChannels, bool),
+}
+
+/// The fader's travel is split into a dead zone at the bottom (where the volume stays at 0), a
+/// dead zone at the top (where it stays at full volume), and the curve which runs between them.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FaderResponse {
+    pub curve: FaderCurve,
+
+    /// How far (0 - 255) the fader moves from the bottom before the volume starts to rise
+    pub bottom_dead_zone: u8,
+
+    /// How far (0 - 255) from the top the fader reaches full volume
+    pub top_dead_zone: u8,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum FaderCurve {
+    /// The volume follows the fader's position
+    #[default]
+    Linear,
+
+    /// An audio taper, the volume rises slowly at first giving finer control at lower volumes
+    Logarithmic,
+
+    /// Points of (position, volume), from 0 - 255 across the travel between the dead zones, in
+    /// order of position. The volume is interpolated between the points, and holds at the first
+    /// and last points beyond them.
+    Custom(Vec<(u8, u8)>),
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MixModes {
+    /// The name of the most recently activated Mix Mode
+    pub active: Option<String>,
+
+    /// All the available Mix Modes, by name
+    pub modes: BTreeMap<String, MixMode>,
+
+    /// How long to cross-fade routing changes over when switching modes or applying a Desired
+    /// State (0 switches immediately)
+    pub crossfade_ms: u16,
+}
+
+/// A Mix Mode bundles together the volumes, routing, ducking and lighting so they can be switched
+/// in one go, without needing to load a whole new profile.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MixMode {
+    /// The Effect Select button which activates this mode
+    pub button: Option<Buttons>,
+
+    /// Volumes for All Channels
+    #[schemars(with = "BTreeMap<VolumeChannels, u8>")]
+    pub volumes: EnumMap<VolumeChannels, u8>,
+
+    /// The Routing Configuration
+    #[schemars(with = "BTreeMap<InputChannels, BTreeMap<OutputChannels, bool>>")]
+    pub routing: EnumMap<InputChannels, EnumMap<OutputChannels, bool>>,
+
+    /// Whether Ducking is enabled
+    pub ducking_enabled: bool,
+
+    /// The Lighting for each channel
+    #[schemars(with = "BTreeMap<FaderChannels, ChannelLighting>")]
+    pub lighting: EnumMap<FaderChannels, ChannelLighting>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ChannelLighting {
+    /// The Colours assigned to the top and bottom of the fader
+    pub fader_colours: FaderColourSet,
+
+    /// The Colours assigned to the Mute button of the Fader
+    pub mute_colours: ButtonColourSet,
+
+    /// The background Colour of the Screen
+    pub screen_colour: Colour,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Effects {
+    /// The Reverb Effect Settings
+    pub reverb: Reverb,
+
+    /// The Echo Effect Settings
+    pub echo: Echo,
+
+    /// The Pitch Effect Settings
+    pub pitch: Pitch,
+
+    /// The Gender Effect Settings
+    pub gender: Gender,
+
+    /// The Hard Tune Effect Settings
+    pub hard_tune: HardTune,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Reverb {
+    /// The Style of Reverb
+    pub style: ReverbStyle,
+
+    /// The Wet Amount, as a percentage (0 - 100)
+    pub amount: u8,
+
+    /// The Decay time in ms (10 - 10000)
+    pub decay: u16,
+
+    /// The Level of Early Reflections (-25 - 0)
+    pub early_level: i8,
+
+    /// The Pre-Delay in ms (0 - 100)
+    pub pre_delay: u8,
+
+    /// Low Frequency Colouration (-50 - 50)
+    pub low_colour: i8,
+
+    /// High Frequency Colouration (-50 - 50)
+    pub high_colour: i8,
+
+    /// High Frequency Damping Factor (-25 - 25)
+    pub high_factor: i8,
+
+    /// The Diffusion of the Reverb (-50 - 50)
+    pub diffuse: i8,
+
+    /// Modulation Speed (-25 - 25)
+    pub mod_speed: i8,
+
+    /// Modulation Depth (-25 - 25)
+    pub mod_depth: i8,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Echo {
+    /// The Style of Echo
+    pub style: EchoStyle,
+
+    /// The Wet Amount, as a percentage (0 - 100)
+    pub amount: u8,
+
+    /// The Overall Feedback (0 - 100)
+    pub feedback: u8,
+
+    /// The Tempo in BPM, used for Tempo based Styles (45 - 300)
+    pub tempo: u16,
+
+    /// The Left Delay in ms (0 - 2500)
+    pub delay_left: u16,
+
+    /// The Right Delay in ms (0 - 2500)
+    pub delay_right: u16,
+
+    /// The Left Channel Feedback (0 - 100)
+    pub feedback_left: u8,
+
+    /// The Right Channel Feedback (0 - 100)
+    pub feedback_right: u8,
+
+    /// Cross Feedback from the Left to Right channel (0 - 100)
+    pub feedback_xfb_l_to_r: u8,
+
+    /// Cross Feedback from the Right to Left channel (0 - 100)
+    pub feedback_xfb_r_to_l: u8,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Pitch {
+    /// The Style of Pitch, defines the range of the amount
+    pub style: PitchStyle,
+
+    /// The Pitch amount in semitones (Narrow: -12 - 12, Wide: -24 - 24), when Hard Tune is
+    /// enabled this is instead in octaves (Narrow: -1 - 1, Wide: -2 - 2)
+    pub amount: i8,
+
+    /// The Pitch Character (0 - 100)
+    pub character: u8,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Gender {
+    /// The Style of Gender, defines the range of the amount
+    pub style: GenderStyle,
+
+    /// The Gender amount (Narrow: -12 - 12, Medium: -25 - 25, Wide: -50 - 50)
+    pub amount: i8,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HardTune {
+    /// Whether Hard Tune is enabled, this changes the behaviour of the Pitch encoder
+    pub enabled: bool,
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use enum_map::{EnumArray, EnumMap};
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+/// Reads an EnumMap which may not have every key, such as one saved before a new variant was
+/// added to its Enum. Missing keys are filled with the default value, rather than failing the
+/// whole profile.
+pub(crate) fn deserialize_partial_map<'de, D, K, V>(
+    deserializer: D,
+) -> Result<EnumMap<K, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: EnumArray<V> + Deserialize<'de>,
+    V: Default + Deserialize<'de>,
+{
+    deserializer.deserialize_map(PartialMapVisitor(PhantomData))
+}
+
+struct PartialMapVisitor<K, V>(PhantomData<(K, V)>);
+
+impl<'de, K, V> Visitor<'de> for PartialMapVisitor<K, V>
+where
+    K: EnumArray<V> + Deserialize<'de>,
+    V: Default + Deserialize<'de>,
+{
+    type Value = EnumMap<K, V>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
+        let mut map = EnumMap::default();
+        while let Some((key, value)) = access.next_entry::<K, V>()? {
+            map[key] = value;
+        }
+        Ok(map)
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::runtime::Runtime;
+use tokio::time::timeout;
+
+use goxlr_client::ipc::clients::ipc::ipc_client::{DEFAULT_NAMED_PIPE, DEFAULT_SOCKET_PATH};
+use goxlr_client::ipc::commands::channels::{ChannelCommands, ChannelVolume, MuteCommand};
+use goxlr_client::ipc::commands::configuration::{ConfigurationCommand, DesiredState};
+use goxlr_client::ipc::commands::{GoXLRCommand, GoXLRCommandResponse};
+use goxlr_client::{DaemonEvent, EventStream, GoXLRClient};
+
+create_exception!(goxlr, GoXLRError, PyException);
+
+// How often a running event loop stops waiting to check for Ctrl+C
+const SIGNAL_POLL: Duration = Duration::from_millis(250);
+
+/// Where the daemon is, kept so the event stream can open its own connection
+enum Connection {
+    Ipc(String, String),
+    Http(String),
+}
+
+#[pyclass]
+struct Client {
+    runtime: Runtime,
+    connection: Connection,
+    client: GoXLRClient,
+}
+
+#[pymethods]
+impl Client {
+    /// Connects to the daemon, over its IPC socket unless an HTTP address is given
+    #[new]
+    #[pyo3(signature = (socket_path=None, pipe_name=None, http=None))]
+    fn new(
+        py: Python<'_>,
+        socket_path: Option<String>,
+        pipe_name: Option<String>,
+        http: Option<String>,
+    ) -> PyResult<Self> {
+        let runtime = Runtime::new()?;
+        let connection = match http {
+            Some(address) => Connection::Http(address),
+            None => Connection::Ipc(
+                socket_path.unwrap_or_else(|| DEFAULT_SOCKET_PATH.to_string()),
+                pipe_name.unwrap_or_else(|| DEFAULT_NAMED_PIPE.to_string()),
+            ),
+        };
+
+        let client = py
+            .allow_threads(|| {
+                runtime.block_on(async {
+                    match &connection {
+                        Connection::Ipc(path, pipe) => GoXLRClient::connect_to(path, pipe).await,
+                        Connection::Http(address) => GoXLRClient::connect_http(address),
+                    }
+                })
+            })
+            .map_err(to_py)?;
+
+        Ok(Self {
+            runtime,
+            connection,
+            client,
+        })
+    }
+
+    /// The daemon's full status, as a dictionary
+    fn get_status(&mut self, py: Python<'_>) -> PyResult<PyObject> {
+        let (runtime, client) = (&self.runtime, &mut self.client);
+        let status = py
+            .allow_threads(|| runtime.block_on(client.get_status()))
+            .map_err(to_py)?;
+        to_python(py, &status)
+    }
+
+    /// The serials of the attached devices
+    fn devices(&mut self, py: Python<'_>) -> PyResult<Vec<String>> {
+        let (runtime, client) = (&self.runtime, &mut self.client);
+        let status = py
+            .allow_threads(|| runtime.block_on(client.get_status()))
+            .map_err(to_py)?;
+        Ok(status.devices.into_keys().collect())
+    }
+
+    /// Sets a channel's volume (0 - 255)
+    #[pyo3(signature = (channel, volume, serial=None))]
+    fn set_volume(
+        &mut self,
+        py: Python<'_>,
+        channel: &str,
+        volume: u8,
+        serial: Option<String>,
+    ) -> PyResult<()> {
+        let channel = parse(channel)?;
+        let command = ChannelCommands::Volume(ChannelVolume { channel, volume });
+        self.send(py, serial, GoXLRCommand::Channels(command))
+    }
+
+    /// Sets a fader's mute state, one of "Unmuted", "Pressed" or "Held"
+    #[pyo3(signature = (channel, state, serial=None))]
+    fn set_mute(
+        &mut self,
+        py: Python<'_>,
+        channel: &str,
+        state: &str,
+        serial: Option<String>,
+    ) -> PyResult<()> {
+        let command = MuteCommand {
+            channel: parse(channel)?,
+            state: parse(state)?,
+        };
+        let command = ChannelCommands::Mute(command);
+        self.send(py, serial, GoXLRCommand::Channels(command))
+    }
+
+    /// Enables or disables a route from an Input to an Output
+    #[pyo3(signature = (input, output, enabled, serial=None))]
+    fn set_route(
+        &mut self,
+        py: Python<'_>,
+        input: &str,
+        output: &str,
+        enabled: bool,
+        serial: Option<String>,
+    ) -> PyResult<()> {
+        let outputs = HashMap::from([(parse(output)?, enabled)]);
+        let state = DesiredState {
+            routing: Some(HashMap::from([(parse(input)?, outputs)])),
+            ..Default::default()
+        };
+        let command = ConfigurationCommand::ApplyState(state);
+        self.send(py, serial, GoXLRCommand::Configuration(command))
+    }
+
+    /// Sends any device command, in the IPC's JSON form, returning the daemon's response
+    #[pyo3(signature = (command, serial=None))]
+    fn command(
+        &mut self,
+        py: Python<'_>,
+        command: &Bound<'_, PyAny>,
+        serial: Option<String>,
+    ) -> PyResult<PyObject> {
+        let command: GoXLRCommand = from_python(py, command)?;
+        let response = self.device_command(py, serial, command)?;
+        to_python(py, &response)
+    }
+
+    /// Calls back with each status event until the callback returns False, or the connection is
+    /// closed. The callback is given the kind ("status", "patch" or "fader"), the status (None for
+    /// fader movements), and the patch or fader movement. Fader movements need `faders=True`.
+    #[pyo3(signature = (callback, faders=false))]
+    fn events(
+        &mut self,
+        py: Python<'_>,
+        callback: &Bound<'_, PyAny>,
+        faders: bool,
+    ) -> PyResult<()> {
+        let (runtime, connection) = (&self.runtime, &self.connection);
+        let mut events = py
+            .allow_threads(|| {
+                runtime.block_on(async {
+                    let mut events = match connection {
+                        Connection::Ipc(path, pipe) => EventStream::connect_to(path, pipe).await?,
+                        Connection::Http(address) => {
+                            EventStream::connect_websocket(address).await?
+                        }
+                    };
+                    if faders {
+                        events.subscribe_fader_events().await?;
+                    }
+                    Ok::<_, anyhow::Error>(events)
+                })
+            })
+            .map_err(to_py)?;
+
+        loop {
+            let event = py.allow_threads(|| {
+                runtime.block_on(async { timeout(SIGNAL_POLL, events.next()).await })
+            });
+
+            let event = match event {
+                Err(_) => {
+                    // Nothing yet, let Python raise KeyboardInterrupt if it needs to
+                    py.check_signals()?;
+                    continue;
+                }
+                Ok(None) => return Ok(()),
+                Ok(Some(event)) => event.map_err(to_py)?,
+            };
+
+            let result = match event {
+                DaemonEvent::Status(status) => {
+                    callback.call1(("status", to_python(py, &status)?, py.None()))?
+                }
+                DaemonEvent::Patched(patch, status) => {
+                    let patch = to_python(py, &patch)?;
+                    callback.call1(("patch", to_python(py, &status)?, patch))?
+                }
+                DaemonEvent::FaderMoved(movement) => {
+                    callback.call1(("fader", py.None(), to_python(py, &movement)?))?
+                }
+            };
+
+            // Returning nothing carries on, only an explicit False stops
+            if result.extract::<bool>().ok() == Some(false) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl Client {
+    fn send(
+        &mut self,
+        py: Python<'_>,
+        serial: Option<String>,
+        command: GoXLRCommand,
+    ) -> PyResult<()> {
+        self.device_command(py, serial, command).map(|_| ())
+    }
+
+    fn device_command(
+        &mut self,
+        py: Python<'_>,
+        serial: Option<String>,
+        command: GoXLRCommand,
+    ) -> PyResult<GoXLRCommandResponse> {
+        let serial = self.get_serial(py, serial)?;
+        let (runtime, client) = (&self.runtime, &mut self.client);
+        py.allow_threads(|| runtime.block_on(client.device(&serial).command(command)))
+            .map_err(to_py)
+    }
+
+    /// The serial to send to, which only needs to be given if there's more than one device
+    fn get_serial(&mut self, py: Python<'_>, serial: Option<String>) -> PyResult<String> {
+        if let Some(serial) = serial {
+            return Ok(serial);
+        }
+
+        let (runtime, client) = (&self.runtime, &mut self.client);
+        let status = py
+            .allow_threads(|| runtime.block_on(client.get_status()))
+            .map_err(to_py)?;
+        let mut serials = status.devices.into_keys();
+        match (serials.next(), serials.next()) {
+            (Some(serial), None) => Ok(serial),
+            (None, _) => Err(GoXLRError::new_err("No GoXLR Devices Detected")),
+            (Some(_), Some(_)) => Err(GoXLRError::new_err(
+                "More than one device detected, specify device with serial",
+            )),
+        }
+    }
+}
+
+fn to_py(error: anyhow::Error) -> PyErr {
+    GoXLRError::new_err(error.to_string())
+}
+
+/// Parses a name (such as "Headphones") into one of the IPC's enums
+fn parse<T: DeserializeOwned>(name: &str) -> PyResult<T> {
+    serde_json::from_value(Value::String(name.to_string()))
+        .map_err(|_| GoXLRError::new_err(format!("Unknown Value: {}", name)))
+}
+
+/// Converts the IPC's types to plain Python, through JSON
+fn to_python(py: Python<'_>, value: &impl Serialize) -> PyResult<PyObject> {
+    let text = serde_json::to_string(value).map_err(|e| GoXLRError::new_err(e.to_string()))?;
+    let json = py.import_bound("json")?;
+    Ok(json.call_method1("loads", (text,))?.unbind())
+}
+
+fn from_python<T: DeserializeOwned>(py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<T> {
+    let json = py.import_bound("json")?;
+    let text: String = json.call_method1("dumps", (value,))?.extract()?;
+    serde_json::from_str(&text).map_err(|e| GoXLRError::new_err(e.to_string()))
+}
+
+#[pymodule]
+fn goxlr(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Client>()?;
+    m.add("GoXLRError", m.py().get_type_bound::<GoXLRError>())?;
+    Ok(())
+}
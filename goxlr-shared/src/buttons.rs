@@ -1,11 +1,18 @@
 use crate::colours::TwoColourTargets;
 use crate::faders::Fader;
 use crate::interaction::InteractiveButtons;
+#[cfg(feature = "clap")]
+use clap::ValueEnum;
 use enum_map::Enum;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Enum, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum Buttons {
     // Fader Mute Buttons
     FaderA,
@@ -118,6 +125,7 @@ impl From<TwoColourTargets> for Buttons {
 
 /// Defines potential inactive button behaviours
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum InactiveButtonBehaviour {
     /// This Dimms the Active Colour.
     DimActive,
@@ -130,6 +138,7 @@ pub enum InactiveButtonBehaviour {
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum ButtonActiveState {
     Active,
     Inactive,
@@ -0,0 +1,27 @@
+use enum_map::Enum;
+use strum::{Display, EnumIter};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "clap")]
+use clap::ValueEnum;
+
+/// A broad grouping of Inputs, so settings can target a kind of input (such as anything playing
+/// media) rather than listing each channel, and pick up channels as they're moved between them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Display, Enum, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum InputCategory {
+    /// People talking, such as the Microphone and Chat
+    Voice,
+
+    /// Music, Games and other content
+    Media,
+
+    /// Alerts and other sounds from the system
+    System,
+}
@@ -1,6 +1,8 @@
 use enum_map::Enum;
 use strum::EnumIter;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +15,7 @@ use clap::ValueEnum;
 /// A list of All the GoXLR Channels
 #[derive(Debug, Copy, Clone, Hash, Enum, EnumIter, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum AllChannels {
     Microphone,
@@ -1,6 +1,8 @@
 use enum_map::Enum;
 use strum::{Display, EnumIter};
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +11,33 @@ use clap::ValueEnum;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Display, Enum, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum DuckingInput {
     Mic,
+
+    /// Ducks while a sample is playing through the Sample channel
+    Sample,
+
+    /// Ducks while the Chat (such as Discord) is talking. The device doesn't meter the Chat, so
+    /// this needs a Level Source setting before it'll trigger.
+    ChatMic,
+
+    /// Ducks on the Line In, as above this needs a Level Source setting
+    LineIn,
+}
+
+/// Quick presets for how easily the Ducking triggers fire, each sets the trigger level, and the
+/// attack and release times in one go.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Display, Enum, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum DuckingSensitivity {
+    /// Only clear, sustained sound ducks, and the duck is let go of quickly
+    Low,
+    Medium,
+
+    /// Quieter sound ducks straight away, and the duck is held for longer
+    High,
 }
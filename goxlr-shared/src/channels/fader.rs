@@ -1,6 +1,8 @@
 use enum_map::Enum;
 use strum::EnumIter;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +12,7 @@ use clap::ValueEnum;
 /// Channels which can be assigned to Faders
 #[derive(Debug, Copy, Clone, Hash, Enum, EnumIter, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum FaderChannels {
     Microphone,
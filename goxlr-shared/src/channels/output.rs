@@ -1,6 +1,8 @@
 use enum_map::Enum;
 use strum::EnumIter;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +11,7 @@ use clap::ValueEnum;
 
 #[derive(Debug, Copy, Clone, Hash, Enum, EnumIter, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum OutputChannels {
     Headphones,
@@ -20,6 +23,7 @@ pub enum OutputChannels {
 
 #[derive(Debug, Copy, Clone, Hash, Enum, EnumIter, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum RoutingOutput {
     Headphones,
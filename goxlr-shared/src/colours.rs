@@ -10,6 +10,8 @@ use crate::encoders::Encoders;
 use crate::faders::Fader;
 use crate::scribbles::Scribble;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -24,7 +26,9 @@ const SAMPLE_BUTTON_COUNT: usize = 5;
 const FX_BUTTON_COUNT: usize = 4;
 const MIC_BUTTON_COUNT: usize = 2;
 
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ColourScheme {
     pub is_legacy: bool,
 
@@ -93,6 +97,38 @@ impl ColourScheme {
     pub fn get_encoder_target(&mut self, target: Encoders) -> &mut ThreeColour {
         &mut self.encoders[target as usize]
     }
+
+    /// Returns a copy of the scheme with every colour dimmed to a brightness percentage
+    pub fn with_brightness(mut self, brightness: u8) -> Self {
+        let scale = |colour: &mut Colour| *colour = colour.scale(brightness);
+
+        let two_colours = [
+            &mut self.scribbles[..],
+            &mut self.mood[..],
+            &mut self.mutes[..],
+            &mut self.presets[..],
+            &mut self.sample_banks[..],
+            &mut self.sample_buttons[..],
+            &mut self.fx_buttons[..],
+            &mut self.mic_buttons[..],
+        ];
+        for colours in two_colours.into_iter().flatten() {
+            scale(&mut colours.colour1);
+            scale(&mut colours.colour2);
+        }
+
+        for colours in self.faders.iter_mut() {
+            scale(&mut colours.colour1);
+            scale(&mut colours.colour2);
+        }
+
+        for colours in self.encoders.iter_mut() {
+            scale(&mut colours.left);
+            scale(&mut colours.right);
+            scale(&mut colours.knob);
+        }
+        self
+    }
 }
 
 impl From<Scribble> for TwoColourTargets {
@@ -108,6 +144,7 @@ impl From<Scribble> for TwoColourTargets {
 
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Colour {
     pub red: u32,
     pub green: u32,
@@ -115,6 +152,16 @@ pub struct Colour {
 }
 
 impl Colour {
+    /// Dims the colour to a brightness percentage
+    pub fn scale(self, brightness: u8) -> Colour {
+        let brightness = brightness.min(100) as u32;
+        Colour {
+            red: self.red * brightness / 100,
+            green: self.green * brightness / 100,
+            blue: self.blue * brightness / 100,
+        }
+    }
+
     pub fn black() -> Colour {
         Colour {
             red: 0,
@@ -125,13 +172,15 @@ impl Colour {
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Default, Debug, Copy, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
 pub struct OneColour {
     pub colour1: Colour,
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct TwoColour {
     pub colour1: Colour,
     pub colour2: Colour,
@@ -144,8 +193,9 @@ impl TwoColour {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ThreeColour {
     pub left: Colour,
     pub right: Colour,
@@ -154,8 +204,9 @@ pub struct ThreeColour {
 
 /// FaderColour lives separately, as it has different behaviours depending on the firmware
 /// version. While we won't see them here, they'll be handled in the USB crate.
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct FaderColour {
     pub colour1: Colour,
     pub colour2: Colour,
@@ -170,6 +221,7 @@ impl FaderColour {
 
 #[derive(Debug, Copy, Clone, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum TwoColourTargets {
     // Scribble Bar first..
     Scribble1,
@@ -251,6 +303,7 @@ impl From<Buttons> for TwoColourTargets {
 
 /// How the colours on the fader are displayed
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum FaderDisplayMode {
     /// This will produce a meter, that matches the current audio volume
     Meter,
@@ -1,11 +1,20 @@
+use alloc::format;
+use core::fmt::{Display, Formatter};
+
 #[cfg(feature = "clap")]
 use clap::ValueEnum;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::values::InvalidValue;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum CompressorAttackTime {
     // Note: 0ms is technically 0.001ms
@@ -31,8 +40,9 @@ pub enum CompressorAttackTime {
     Attack40ms,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum CompressorReleaseTime {
     // Note: 0 is technically 15 :)
@@ -58,8 +68,9 @@ pub enum CompressorReleaseTime {
     Release3000ms,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum CompressorRatio {
     Ratio1_0,
@@ -78,3 +89,134 @@ pub enum CompressorRatio {
     Ratio32_0,
     Ratio64_0,
 }
+
+/// The Compressor's Attack time, in milliseconds
+impl From<CompressorAttackTime> for u16 {
+    fn from(value: CompressorAttackTime) -> Self {
+        match value {
+            CompressorAttackTime::Attack0ms => 0,
+            CompressorAttackTime::Attack2ms => 2,
+            CompressorAttackTime::Attack3ms => 3,
+            CompressorAttackTime::Attack4ms => 4,
+            CompressorAttackTime::Attack5ms => 5,
+            CompressorAttackTime::Attack6ms => 6,
+            CompressorAttackTime::Attack7ms => 7,
+            CompressorAttackTime::Attack8ms => 8,
+            CompressorAttackTime::Attack9ms => 9,
+            CompressorAttackTime::Attack10ms => 10,
+            CompressorAttackTime::Attack12ms => 12,
+            CompressorAttackTime::Attack14ms => 14,
+            CompressorAttackTime::Attack16ms => 16,
+            CompressorAttackTime::Attack18ms => 18,
+            CompressorAttackTime::Attack20ms => 20,
+            CompressorAttackTime::Attack23ms => 23,
+            CompressorAttackTime::Attack26ms => 26,
+            CompressorAttackTime::Attack30ms => 30,
+            CompressorAttackTime::Attack35ms => 35,
+            CompressorAttackTime::Attack40ms => 40,
+        }
+    }
+}
+
+impl TryFrom<u16> for CompressorAttackTime {
+    type Error = InvalidValue;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let mut times = CompressorAttackTime::iter();
+        let time = times.find(|time| u16::from(*time) == value);
+        time.ok_or_else(|| InvalidValue::new("Compressor Attack", format!("{}ms", value)))
+    }
+}
+
+impl Display for CompressorAttackTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}ms", u16::from(*self))
+    }
+}
+
+/// The Compressor's Release time, in milliseconds
+impl From<CompressorReleaseTime> for u16 {
+    fn from(value: CompressorReleaseTime) -> Self {
+        match value {
+            CompressorReleaseTime::Release0ms => 0,
+            CompressorReleaseTime::Release15ms => 15,
+            CompressorReleaseTime::Release25ms => 25,
+            CompressorReleaseTime::Release35ms => 35,
+            CompressorReleaseTime::Release45ms => 45,
+            CompressorReleaseTime::Release55ms => 55,
+            CompressorReleaseTime::Release65ms => 65,
+            CompressorReleaseTime::Release75ms => 75,
+            CompressorReleaseTime::Release85ms => 85,
+            CompressorReleaseTime::Release100ms => 100,
+            CompressorReleaseTime::Release115ms => 115,
+            CompressorReleaseTime::Release140ms => 140,
+            CompressorReleaseTime::Release170ms => 170,
+            CompressorReleaseTime::Release230ms => 230,
+            CompressorReleaseTime::Release340ms => 340,
+            CompressorReleaseTime::Release680ms => 680,
+            CompressorReleaseTime::Release1000ms => 1000,
+            CompressorReleaseTime::Release1500ms => 1500,
+            CompressorReleaseTime::Release2000ms => 2000,
+            CompressorReleaseTime::Release3000ms => 3000,
+        }
+    }
+}
+
+impl TryFrom<u16> for CompressorReleaseTime {
+    type Error = InvalidValue;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let mut times = CompressorReleaseTime::iter();
+        let time = times.find(|time| u16::from(*time) == value);
+        time.ok_or_else(|| InvalidValue::new("Compressor Release", format!("{}ms", value)))
+    }
+}
+
+impl Display for CompressorReleaseTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}ms", u16::from(*self))
+    }
+}
+
+/// The Compressor's Ratio, as the input level change for each 1dB of output (3.2 is 3.2:1)
+impl From<CompressorRatio> for f32 {
+    fn from(value: CompressorRatio) -> Self {
+        match value {
+            CompressorRatio::Ratio1_0 => 1.0,
+            CompressorRatio::Ratio1_1 => 1.1,
+            CompressorRatio::Ratio1_2 => 1.2,
+            CompressorRatio::Ratio1_4 => 1.4,
+            CompressorRatio::Ratio1_6 => 1.6,
+            CompressorRatio::Ratio1_8 => 1.8,
+            CompressorRatio::Ratio2_0 => 2.0,
+            CompressorRatio::Ratio2_5 => 2.5,
+            CompressorRatio::Ratio3_2 => 3.2,
+            CompressorRatio::Ratio4_0 => 4.0,
+            CompressorRatio::Ratio5_6 => 5.6,
+            CompressorRatio::Ratio8_0 => 8.0,
+            CompressorRatio::Ratio16_0 => 16.0,
+            CompressorRatio::Ratio32_0 => 32.0,
+            CompressorRatio::Ratio64_0 => 64.0,
+        }
+    }
+}
+
+impl TryFrom<f32> for CompressorRatio {
+    type Error = InvalidValue;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        // The ratios are all written to a single decimal place, so allow for rounding
+        let mut ratios = CompressorRatio::iter();
+        let ratio = ratios.find(|ratio| {
+            let difference = f32::from(*ratio) - value;
+            -0.05 < difference && difference < 0.05
+        });
+        ratio.ok_or_else(|| InvalidValue::new("Compressor Ratio", format!("{}:1", value)))
+    }
+}
+
+impl Display for CompressorRatio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:.1}:1", f32::from(*self))
+    }
+}
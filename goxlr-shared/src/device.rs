@@ -1,10 +1,16 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use crate::version::FirmwareVersions;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DeviceInfo {
     pub serial: String,
     pub manufacture_date: String,
@@ -17,6 +23,7 @@ pub struct DeviceInfo {
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum GoXLRFeature {
     Animation,
     SubMix,
@@ -25,13 +32,35 @@ pub enum GoXLRFeature {
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum DeviceType {
     Full,
     Mini,
 }
 
+/// The likely reason a device couldn't be claimed
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum ClaimFailure {
+    /// The OS refused access to the device
+    PermissionDenied,
+
+    /// Another driver or application is holding the device
+    InUse,
+
+    /// The driver required to talk to the device isn't available
+    DriverMissing,
+
+    /// The device went away while it was being claimed
+    Disconnected,
+
+    Unknown,
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum DeviceColour {
     Black,
     White,
@@ -0,0 +1,59 @@
+use enum_map::Enum;
+use strum::EnumIter;
+
+#[cfg(feature = "clap")]
+use clap::ValueEnum;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The Reverb Styles available on the GoXLR, the order here matches the value sent to the device.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Enum, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum ReverbStyle {
+    Library,
+    DarkBloom,
+    MusicClub,
+    RealPlate,
+    Chapel,
+    HockeyArena,
+}
+
+/// The Echo Styles available on the GoXLR, the order here matches the value sent to the device.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Enum, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum EchoStyle {
+    Quarter,
+    Eighth,
+    Triplet,
+    PingPong,
+    ClassicSlap,
+    MultiTap,
+}
+
+/// The Pitch Styles, these define the range of the Pitch encoder.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Enum, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum PitchStyle {
+    Narrow,
+    Wide,
+}
+
+/// The Gender Styles, these define the range of the Gender encoder.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Enum, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum GenderStyle {
+    Narrow,
+    Medium,
+    Wide,
+}
@@ -1,10 +1,16 @@
-use crate::interaction::InteractiveEncoders;
+use enum_map::Enum;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+use crate::interaction::InteractiveEncoders;
 
 /// A simple list of the 4 encoders
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Enum, EnumIter, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum Encoders {
     Pitch,
     Gender,
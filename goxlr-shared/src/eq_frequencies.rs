@@ -1,6 +1,8 @@
 #[cfg(feature = "clap")]
 use clap::ValueEnum;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +11,7 @@ use strum::EnumIter;
 
 #[derive(Debug, Copy, Clone, Enum, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum Frequencies {
     Eq31h,
@@ -25,6 +28,7 @@ pub enum Frequencies {
 
 #[derive(Debug, Copy, Clone, Enum, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum MiniFrequencies {
     Eq90h,
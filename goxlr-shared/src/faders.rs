@@ -1,6 +1,8 @@
 #[cfg(feature = "clap")]
 use clap::ValueEnum;
 use enum_map::Enum;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
@@ -11,6 +13,7 @@ use crate::interaction::InteractiveFaders;
 /// A Simple list of the faders, with A being the far left
 #[derive(Debug, Copy, Clone, Enum, EnumIter, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum Fader {
     A,
@@ -1,11 +1,20 @@
+use alloc::format;
+use core::fmt::{Display, Formatter};
+
 #[cfg(feature = "clap")]
 use clap::ValueEnum;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::values::InvalidValue;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum GateTimes {
     Time10ms,
@@ -56,9 +65,10 @@ pub enum GateTimes {
     Time2000ms,
 }
 
-impl GateTimes {
-    pub fn to_u16(self) -> u16 {
-        match self {
+/// The Gate's Attack or Release time, in milliseconds
+impl From<GateTimes> for u16 {
+    fn from(value: GateTimes) -> Self {
+        match value {
             GateTimes::Time10ms => 10,
             GateTimes::Time20ms => 20,
             GateTimes::Time30ms => 30,
@@ -92,7 +102,7 @@ impl GateTimes {
             GateTimes::Time750ms => 750,
             GateTimes::Time800ms => 800,
             GateTimes::Time850ms => 850,
-            GateTimes::Time900ms => 9000,
+            GateTimes::Time900ms => 900,
             GateTimes::Time950ms => 950,
             GateTimes::Time1000ms => 1000,
             GateTimes::Time1100ms => 1100,
@@ -108,3 +118,19 @@ impl GateTimes {
         }
     }
 }
+
+impl TryFrom<u16> for GateTimes {
+    type Error = InvalidValue;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let mut times = GateTimes::iter();
+        let time = times.find(|time| u16::from(*time) == value);
+        time.ok_or_else(|| InvalidValue::new("Gate Time", format!("{}ms", value)))
+    }
+}
+
+impl Display for GateTimes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}ms", u16::from(*self))
+    }
+}
@@ -1,17 +1,28 @@
+// Without the 'std' feature the crate is no_std, so the wire types can be shared with embedded
+// hardware, it still needs 'alloc' for the device info and conversion errors.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod buttons;
 pub mod channels;
 pub mod colours;
 pub mod compressor;
 pub mod device;
+pub mod effects;
 pub mod encoders;
 pub mod eq_frequencies;
 pub mod faders;
 pub mod gate;
 pub mod interaction;
+pub mod locale;
 pub mod microphone;
 pub mod mute;
 pub mod routing;
+pub mod sampler;
 pub mod scribbles;
 pub mod states;
 pub mod submix;
+pub mod values;
 pub mod version;
+pub mod volume;
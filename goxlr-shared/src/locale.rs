@@ -0,0 +1,136 @@
+use strum::EnumIter;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "clap")]
+use clap::ValueEnum;
+
+use crate::buttons::Buttons;
+use crate::channels::fader::FaderChannels;
+use crate::channels::input::InputChannels;
+use crate::channels::output::OutputChannels;
+
+/// The languages display names are available in. The enum identifiers are used throughout the
+/// API and never change, these only affect what's presented to a user.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+    Spanish,
+    French,
+}
+
+impl Locale {
+    /// The ISO 639-1 code for this Locale
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+            Locale::Spanish => "es",
+            Locale::French => "fr",
+        }
+    }
+}
+
+// Each table is in the same order as the Locale enum
+type Names = [&'static str; 4];
+
+fn pick(names: Names, locale: Locale) -> &'static str {
+    names[locale as usize]
+}
+
+/// A name suitable for showing to a user, including on the device's scribble displays
+pub trait DisplayName {
+    fn display_name(&self, locale: Locale) -> &'static str;
+}
+
+impl DisplayName for FaderChannels {
+    fn display_name(&self, locale: Locale) -> &'static str {
+        let names: Names = match self {
+            FaderChannels::Microphone => ["Microphone", "Mikrofon", "Micrófono", "Microphone"],
+            FaderChannels::Chat => ["Chat", "Chat", "Chat", "Chat"],
+            FaderChannels::Music => ["Music", "Musik", "Música", "Musique"],
+            FaderChannels::Game => ["Game", "Spiel", "Juego", "Jeu"],
+            FaderChannels::Console => ["Console", "Konsole", "Consola", "Console"],
+            FaderChannels::LineIn => ["Line In", "Line-In", "Entrada", "Entrée Ligne"],
+            FaderChannels::System => ["System", "System", "Sistema", "Système"],
+            FaderChannels::Sample => ["Sample", "Sample", "Muestra", "Échantillon"],
+            FaderChannels::Headphones => ["Headphones", "Kopfhörer", "Auriculares", "Casque"],
+            FaderChannels::LineOut => ["Line Out", "Line-Out", "Salida", "Sortie Ligne"],
+        };
+        pick(names, locale)
+    }
+}
+
+impl DisplayName for InputChannels {
+    fn display_name(&self, locale: Locale) -> &'static str {
+        // Every Input can sit on a Fader, so they share names
+        let channel = match self {
+            InputChannels::Microphone => FaderChannels::Microphone,
+            InputChannels::Chat => FaderChannels::Chat,
+            InputChannels::Music => FaderChannels::Music,
+            InputChannels::Game => FaderChannels::Game,
+            InputChannels::Console => FaderChannels::Console,
+            InputChannels::LineIn => FaderChannels::LineIn,
+            InputChannels::System => FaderChannels::System,
+            InputChannels::Sample => FaderChannels::Sample,
+        };
+        channel.display_name(locale)
+    }
+}
+
+impl DisplayName for OutputChannels {
+    fn display_name(&self, locale: Locale) -> &'static str {
+        let names: Names = match self {
+            OutputChannels::Headphones => ["Headphones", "Kopfhörer", "Auriculares", "Casque"],
+            OutputChannels::StreamMix => ["Stream Mix", "Stream-Mix", "Mezcla", "Mix du Stream"],
+            OutputChannels::LineOut => ["Line Out", "Line-Out", "Salida", "Sortie Ligne"],
+            OutputChannels::ChatMic => ["Chat Mic", "Chat-Mikro", "Micro Chat", "Micro Chat"],
+            OutputChannels::Sampler => ["Sampler", "Sampler", "Sampler", "Échantillonneur"],
+        };
+        pick(names, locale)
+    }
+}
+
+impl DisplayName for Buttons {
+    fn display_name(&self, locale: Locale) -> &'static str {
+        let names: Names = match self {
+            Buttons::FaderA => ["Mute A", "Stumm A", "Silencio A", "Muet A"],
+            Buttons::FaderB => ["Mute B", "Stumm B", "Silencio B", "Muet B"],
+            Buttons::FaderC => ["Mute C", "Stumm C", "Silencio C", "Muet C"],
+            Buttons::FaderD => ["Mute D", "Stumm D", "Silencio D", "Muet D"],
+            Buttons::Swear => ["Bleep", "Piepton", "Pitido", "Bip"],
+            Buttons::CoughButton => ["Cough", "Husten", "Tos", "Toux"],
+            Buttons::EffectSelect1 => ["Preset 1", "Preset 1", "Preset 1", "Préréglage 1"],
+            Buttons::EffectSelect2 => ["Preset 2", "Preset 2", "Preset 2", "Préréglage 2"],
+            Buttons::EffectSelect3 => ["Preset 3", "Preset 3", "Preset 3", "Préréglage 3"],
+            Buttons::EffectSelect4 => ["Preset 4", "Preset 4", "Preset 4", "Préréglage 4"],
+            Buttons::EffectSelect5 => ["Preset 5", "Preset 5", "Preset 5", "Préréglage 5"],
+            Buttons::EffectSelect6 => ["Preset 6", "Preset 6", "Preset 6", "Préréglage 6"],
+            Buttons::EffectFx => ["FX", "FX", "FX", "FX"],
+            Buttons::EffectMegaphone => ["Megaphone", "Megafon", "Megáfono", "Mégaphone"],
+            Buttons::EffectRobot => ["Robot", "Roboter", "Robot", "Robot"],
+            Buttons::EffectHardTune => ["Hard Tune", "Hard Tune", "Hard Tune", "Hard Tune"],
+            Buttons::SamplerSelectA => ["Bank A", "Bank A", "Banco A", "Banque A"],
+            Buttons::SamplerSelectB => ["Bank B", "Bank B", "Banco B", "Banque B"],
+            Buttons::SamplerSelectC => ["Bank C", "Bank C", "Banco C", "Banque C"],
+            Buttons::SamplerTopLeft => ["Top Left", "Oben Links", "Arriba Izq.", "Haut Gauche"],
+            Buttons::SamplerTopRight => ["Top Right", "Oben Rechts", "Arriba Der.", "Haut Droite"],
+            Buttons::SamplerBottomLeft => {
+                ["Bottom Left", "Unten Links", "Abajo Izq.", "Bas Gauche"]
+            }
+            Buttons::SamplerBottomRight => {
+                ["Bottom Right", "Unten Rechts", "Abajo Der.", "Bas Droite"]
+            }
+            Buttons::SamplerClear => ["Clear", "Leeren", "Borrar", "Effacer"],
+        };
+        pick(names, locale)
+    }
+}
@@ -1,14 +1,18 @@
 use crate::eq_frequencies::{Frequencies, MiniFrequencies};
 use enum_map::Enum;
+use strum::EnumIter;
 
 #[cfg(feature = "clap")]
 use clap::ValueEnum;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Copy, Clone, Enum)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum MicrophoneType {
     XLR,
@@ -16,6 +20,22 @@ pub enum MicrophoneType {
     Jack,
 }
 
+/// Bundled starting points for the Mic processing, applied over the current Mic Profile
+#[derive(Debug, Copy, Clone, Eq, PartialEq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum MicPreset {
+    /// A warm, controlled voice for talking
+    PodcastVoice,
+
+    /// An aggressive gate and low cut, to keep background noise out
+    NoisyRoom,
+
+    /// Extra gain and makeup for quieter dynamic mics on the XLR input
+    DynamicMicBoost,
+}
+
 /*
  As with everything else, we're going to keep the values to these keys isolated in the USB crate
  and have alternatives for direct access in goxlr-shared, where we don't have to care about
@@ -1,12 +1,15 @@
 #[cfg(feature = "clap")]
 use clap::ValueEnum;
 use enum_map::Enum;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Enum, EnumIter)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum ChannelMuteState {
     Muted,
     Unmuted,
@@ -15,9 +18,28 @@ pub enum ChannelMuteState {
 /// This represents the current state of a Channel
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[cfg_attr(feature = "clap", derive(ValueEnum))]
 pub enum MuteState {
     Unmuted,
     Pressed,
     Held,
 }
+
+/// Where the Cough button mutes its channel to, the quick modes replace the button's Press and
+/// Hold targets with a single one.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum CoughTarget {
+    /// Uses the Cough button's Press and Hold mute actions
+    #[default]
+    Configured,
+
+    /// Mutes to Chat, while staying live on the Stream
+    ChatOnly,
+
+    /// Mutes to the Stream, while staying live in Chat
+    StreamOnly,
+}
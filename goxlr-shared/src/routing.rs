@@ -1,12 +1,18 @@
 use crate::channels::input::InputChannels;
 use crate::channels::output::RoutingOutput;
+#[cfg(feature = "clap")]
+use clap::ValueEnum;
 use enum_map::{Enum, EnumMap};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 // Types to help keep things tidy..
 type Row = EnumMap<RoutingOutput, RouteValue>;
 type Table = EnumMap<InputChannels, Row>;
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct RoutingTable {
     table: Table,
 }
@@ -31,9 +37,29 @@ impl RoutingTable {
 }
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Enum)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum RouteValue {
     On,
     #[default]
     Off,
     Value(u8),
 }
+
+/// How the left and right sides of an input are sent to its outputs. The routing matrix only
+/// takes levels, so anything which needs a negative level (such as a phase invert) can't be
+/// done by the hardware.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Enum)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum StereoMode {
+    #[default]
+    Stereo,
+
+    /// Both sides are summed (at half level) and sent to both sides of the outputs
+    Mono,
+
+    /// The left side is sent to the right of the outputs, and the right to the left
+    Swapped,
+}
@@ -0,0 +1,31 @@
+#[cfg(feature = "clap")]
+use clap::ValueEnum;
+use enum_map::Enum;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+/// The three banks of samples, switched between with the bank buttons
+#[derive(Debug, Copy, Clone, Enum, EnumIter, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum SampleBank {
+    A,
+    B,
+    C,
+}
+
+/// The four sample buttons, each bank has a sample for each of them
+#[derive(Debug, Copy, Clone, Enum, EnumIter, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum SampleButton {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
@@ -1,3 +1,10 @@
+#[cfg(feature = "clap")]
+use clap::ValueEnum;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::faders::Fader;
 
 pub enum Scribble {
@@ -17,3 +24,17 @@ impl From<Fader> for Scribble {
         }
     }
 }
+
+/// Something which can be drawn on a scribble in place of the channel name, and is redrawn
+/// by the daemon as its value changes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "clap", derive(ValueEnum))]
+pub enum ScribbleWidget {
+    /// The current local time
+    Clock,
+
+    /// The time elapsed since the widget was shown, useful for tracking a stream's length
+    Timer,
+}
@@ -0,0 +1,28 @@
+use alloc::string::{String, ToString};
+use core::fmt::{Display, Formatter};
+
+/// Returned when converting a raw value (such as a time in milliseconds) into one of the
+/// device's fixed settings, and the value doesn't match any of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidValue {
+    pub setting: &'static str,
+    pub value: String,
+}
+
+impl InvalidValue {
+    pub(crate) fn new(setting: &'static str, value: impl Display) -> Self {
+        Self {
+            setting,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for InvalidValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} is not a valid {}", self.value, self.setting)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidValue {}
@@ -1,10 +1,13 @@
-use std::fmt::{Debug, Display, Formatter};
+use core::fmt::{Debug, Display, Formatter};
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct FirmwareVersions {
     pub firmware: VersionNumber,
     pub dice: VersionNumber,
@@ -13,10 +16,11 @@ pub struct FirmwareVersions {
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct VersionNumber(pub u32, pub u32, pub Option<u32>, pub Option<u32>);
 
 impl Display for VersionNumber {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         if let Some(patch) = self.2 {
             if let Some(build) = self.3 {
                 return write!(f, "{}.{}.{}.{}", self.0, self.1, patch, build);
@@ -29,7 +33,7 @@ impl Display for VersionNumber {
 }
 
 impl Debug for VersionNumber {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
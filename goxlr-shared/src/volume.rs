@@ -0,0 +1,72 @@
+use core::fmt::{Display, Formatter};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::values::InvalidValue;
+
+// Each step on the device's scale is this many steps here, so 255 lands exactly on u16::MAX
+const HARDWARE_STEP: u32 = 257;
+
+/// A channel volume held at a higher resolution than the device's 0-255 scale, so volumes built
+/// up in small steps (such as fades) don't lose precision along the way. Values are only
+/// rounded to the device's scale as they're sent.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Volume(u16);
+
+impl Volume {
+    pub const SILENT: Volume = Volume(0);
+    pub const FULL: Volume = Volume(u16::MAX);
+
+    pub fn from_hardware(value: u8) -> Self {
+        Self((value as u32 * HARDWARE_STEP) as u16)
+    }
+
+    /// The nearest value on the device's 0-255 scale
+    pub fn to_hardware(self) -> u8 {
+        ((self.0 as u32 + HARDWARE_STEP / 2) / HARDWARE_STEP) as u8
+    }
+
+    /// Creates a volume from a fraction of full volume, between 0.0 and 1.0
+    pub fn from_fraction(value: f32) -> Result<Self, InvalidValue> {
+        if !(0. ..=1.).contains(&value) {
+            return Err(InvalidValue::new("Volume", value));
+        }
+        // f32::round isn't in core, the value can't be negative so adding a half and truncating
+        // rounds the same way.
+        Ok(Self((value * u16::MAX as f32 + 0.5) as u16))
+    }
+
+    pub fn as_fraction(self) -> f32 {
+        self.0 as f32 / u16::MAX as f32
+    }
+
+    /// The volume `position` steps of `steps` along the way from `self` to `target`
+    pub fn step_towards(self, target: Volume, position: u16, steps: u16) -> Volume {
+        let (from, to) = (self.0 as i64, target.0 as i64);
+        let change = (to - from) * position.min(steps) as i64 / steps.max(1) as i64;
+        Volume((from + change) as u16)
+    }
+}
+
+impl From<u16> for Volume {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Volume> for u16 {
+    fn from(value: Volume) -> Self {
+        value.0
+    }
+}
+
+impl Display for Volume {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:.1}%", self.as_fraction() * 100.)
+    }
+}
@@ -14,7 +14,7 @@ use goxlr_shared::channels::input::InputChannels;
 use goxlr_shared::channels::output::RoutingOutput;
 use goxlr_shared::colours::{ColourScheme, FaderDisplayMode};
 use goxlr_shared::faders::Fader;
-use goxlr_shared::routing::RouteValue;
+use goxlr_shared::routing::{RouteValue, StereoMode};
 use goxlr_shared::states::ButtonDisplayStates;
 use goxlr_shared::version::{FirmwareVersions, VersionNumber};
 
@@ -23,6 +23,7 @@ use crate::goxlr::commands::{Command, HardwareInfoCommand};
 use crate::types::buttons::{CurrentButtonStates, DeviceButton};
 use crate::types::channels::{ChannelList, ChannelState, MixOutputChannel};
 use crate::types::colours::ColourStruct;
+use crate::types::encoders::DeviceEncoder;
 use crate::types::faders::DeviceFader;
 use crate::types::mic_keys::{DeviceMicEffectKeys, DeviceMicParamKeys};
 use crate::types::microphone::MicrophoneType;
@@ -163,7 +164,12 @@ pub(crate) trait GoXLRCommands: ExecutableGoXLR {
         Ok(())
     }
 
-    async fn apply_routing(&mut self, input: InputChannels, values: RoutingValues) -> Result<()> {
+    async fn apply_routing(
+        &mut self,
+        input: InputChannels,
+        values: RoutingValues,
+        stereo: StereoMode,
+    ) -> Result<()> {
         // We need to take the values map, iterate it, and create the routing structure...
         let mut l_data = [0; 22];
         let mut r_data = [0; 22];
@@ -173,19 +179,31 @@ pub(crate) trait GoXLRCommands: ExecutableGoXLR {
             let left = RoutingOutputDevice::from(output, Left).position();
             let right = RoutingOutputDevice::from(output, Right).position();
 
-            let value = values[output];
-
-            l_data[left] = match value {
+            let value = match values[output] {
                 RouteValue::On => 0x20,
                 RouteValue::Off => 0x00,
                 RouteValue::Value(value) => value,
             };
 
-            r_data[right] = match value {
-                RouteValue::On => 0x20,
-                RouteValue::Off => 0x00,
-                RouteValue::Value(value) => value,
-            };
+            // Each side of the input has its own row, saying where in the outputs it ends up
+            match stereo {
+                StereoMode::Stereo => {
+                    l_data[left] = value;
+                    r_data[right] = value;
+                }
+                StereoMode::Swapped => {
+                    l_data[right] = value;
+                    r_data[left] = value;
+                }
+                StereoMode::Mono => {
+                    // Halved, so the sum of both sides doesn't clip
+                    let value = value / 2;
+                    l_data[left] = value;
+                    l_data[right] = value;
+                    r_data[left] = value;
+                    r_data[right] = value;
+                }
+            }
         }
 
         let left = RoutingInputChannel::from(input, Left);
@@ -279,6 +297,24 @@ pub(crate) trait GoXLRCommands: ExecutableGoXLR {
         Ok(mix_array)
     }
 
+    async fn set_encoder_value(&mut self, encoder: DeviceEncoder, value: i8) -> Result<()> {
+        // Encoders can be negative, the GoXLR expects the raw byte
+        let command = Command::SetEncoderValue(encoder);
+        self.request_data(command, &[value as u8]).await?;
+        Ok(())
+    }
+
+    async fn set_encoder_mode(
+        &mut self,
+        encoder: DeviceEncoder,
+        mode: u8,
+        steps: u8,
+    ) -> Result<()> {
+        let command = Command::SetEncoderMode(encoder);
+        self.request_data(command, &[mode, steps]).await?;
+        Ok(())
+    }
+
     async fn get_microphone_level(&mut self) -> Result<f64> {
         let result = self.request_data(Command::GetMicrophoneLevel, &[]).await?;
         let value = LittleEndian::read_u16(&result);
@@ -1,4 +1,7 @@
+use std::time::Instant;
+
 use crate::goxlr::commands::Command;
+use crate::traffic::log_traffic;
 use anyhow::Result;
 use async_trait::async_trait;
 use log::warn;
@@ -6,7 +9,7 @@ use log::warn;
 #[async_trait]
 pub(crate) trait ExecutableGoXLR {
     async fn request_data(&mut self, command: Command, body: &[u8]) -> Result<Vec<u8>> {
-        match self.perform_request(command, body).await {
+        match self.logged_request(command, body).await {
             Ok(result) => return Ok(result),
             Err(error) => {
                 warn!("Error Executing Command, attempting recovery: {}", error);
@@ -17,7 +20,7 @@ pub(crate) trait ExecutableGoXLR {
                     return Err(error);
                 }
 
-                let result = self.perform_request(command, body).await;
+                let result = self.logged_request(command, body).await;
                 match result {
                     Ok(result) => Ok(result),
                     Err(error) => {
@@ -29,6 +32,13 @@ pub(crate) trait ExecutableGoXLR {
         }
     }
 
+    async fn logged_request(&mut self, command: Command, body: &[u8]) -> Result<Vec<u8>> {
+        let started = Instant::now();
+        let result = self.perform_request(command, body).await;
+        log_traffic(command, body, &result, started.elapsed());
+        result
+    }
+
     async fn perform_request(&mut self, command: Command, body: &[u8]) -> Result<Vec<u8>>;
     async fn perform_recovery(&mut self) -> Result<()>;
     async fn perform_stop(&mut self);
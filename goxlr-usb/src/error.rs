@@ -0,0 +1,33 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use goxlr_shared::device::ClaimFailure;
+
+/// Returned when a device was found, but couldn't be opened or claimed. This is kept separate
+/// from general errors so that callers can explain to the user what's wrong and how to fix it.
+#[derive(Debug, Clone)]
+pub struct DeviceClaimError {
+    pub failure: ClaimFailure,
+    pub message: String,
+}
+
+impl DeviceClaimError {
+    pub fn new(failure: ClaimFailure, message: impl Into<String>) -> Self {
+        Self {
+            failure,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for DeviceClaimError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unable to Claim Device ({:?}): {}",
+            self.failure, self.message
+        )
+    }
+}
+
+impl Error for DeviceClaimError {}
@@ -9,11 +9,12 @@ use ritelinked::LinkedHashMap;
 use tokio::sync::oneshot;
 
 use goxlr_shared::colours::{ColourScheme, FaderDisplayMode};
+use goxlr_shared::encoders::Encoders;
 use goxlr_shared::faders::Fader;
 use goxlr_shared::interaction::CurrentStates;
 use goxlr_shared::microphone::{MicEffectKeys, MicParamKeys, MicrophoneType};
 use goxlr_shared::mute::ChannelMuteState;
-use goxlr_shared::routing::RouteValue;
+use goxlr_shared::routing::{RouteValue, StereoMode};
 use goxlr_shared::states::ButtonDisplayStates;
 
 /// This is a helper enum for commands that will simply return a Result<()> with no additional
@@ -24,7 +25,11 @@ pub enum BasicResultCommand {
     SetVolume(VolumeChannels, u8),
     SetMuteState(FaderChannels, ChannelMuteState),
     AssignFader(Fader, FaderChannels),
-    ApplyRouting(InputChannels, EnumMap<RoutingOutput, RouteValue>),
+    ApplyRouting(
+        InputChannels,
+        EnumMap<RoutingOutput, RouteValue>,
+        StereoMode,
+    ),
     SetFaderStyle(Fader, Vec<FaderDisplayMode>),
     SetButtonStates(ButtonDisplayStates),
     SetScribble(Fader, [u8; 1024]),
@@ -37,6 +42,10 @@ pub enum BasicResultCommand {
     SetMicGain(MicrophoneType, u8),
     SetMicParams(LinkedHashMap<MicParamKeys, f32>),
     SetMicEffects(LinkedHashMap<MicEffectKeys, i32>),
+
+    /// Encoder Stuff
+    SetEncoderValue(Encoders, i8),
+    SetEncoderMode(Encoders, u8, u8),
 }
 
 #[derive(Debug)]
@@ -9,10 +9,13 @@ mod platform;
 
 pub(crate) mod types;
 
+pub mod error;
 pub mod events;
 pub mod handlers;
+pub mod mock;
 pub mod requests;
 pub mod runners;
+pub mod traffic;
 mod util;
 
 /// GoXLR USB Vendor ID
@@ -29,6 +32,21 @@ pub const PID_GOXLR_MINI: u16 = 0x8fe4;
 pub struct USBLocation {
     lib_usb: Option<LibUSB>,
     windows_usb: Option<WindowsUSB>,
+    mock: Option<u8>,
+}
+
+impl USBLocation {
+    pub(crate) fn mock(id: u8) -> Self {
+        Self {
+            lib_usb: None,
+            windows_usb: None,
+            mock: Some(id),
+        }
+    }
+
+    pub(crate) fn is_mock(&self) -> bool {
+        self.mock.is_some()
+    }
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -54,6 +72,9 @@ impl Display for USBLocation {
         if let Some(winusb) = &self.windows_usb {
             return write!(f, "[{}]", winusb.identifier);
         }
+        if let Some(id) = &self.mock {
+            return write!(f, "[Mock:{}]", id);
+        }
         write!(f, "[ERROR] Unknown Device identification")
     }
 }
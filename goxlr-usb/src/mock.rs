@@ -0,0 +1,136 @@
+/*
+   A Mock device, which behaves like a GoXLR without any hardware attached. Commands sent to it
+   are accepted and ignored, while the physical state (buttons, faders, encoders and the mic
+   level) can be driven from code. This is primarily useful for replaying recorded sessions.
+*/
+
+use std::sync::{Arc, Mutex};
+
+use enumset::EnumSet;
+use strum::IntoEnumIterator;
+use tokio::sync::Notify;
+
+use goxlr_shared::buttons::Buttons;
+use goxlr_shared::encoders::Encoders;
+use goxlr_shared::faders::Fader;
+use goxlr_shared::interaction::InteractiveButtons;
+
+use crate::types::buttons::DeviceButton;
+use crate::types::encoders::DeviceEncoder;
+use crate::types::faders::DeviceFader;
+use crate::USBLocation;
+
+// All the currently attached Mock Devices, these are picked up by the PnP runner
+static ATTACHED: Mutex<Vec<MockDevice>> = Mutex::new(Vec::new());
+
+#[derive(Debug)]
+pub(crate) struct MockState {
+    pub(crate) serial: String,
+    pub(crate) pressed: EnumSet<DeviceButton>,
+    pub(crate) volumes: [u8; 4],
+    pub(crate) encoders: [i8; 4],
+
+    /// The raw level, as the device would report it
+    pub(crate) mic_level: u16,
+}
+
+/// A handle to a Mock Device, cloning this will refer to the same device.
+#[derive(Debug, Clone)]
+pub struct MockDevice {
+    location: USBLocation,
+    pub(crate) state: Arc<Mutex<MockState>>,
+
+    // Notified each time the device is polled for its state
+    pub(crate) polled: Arc<Notify>,
+}
+
+impl MockDevice {
+    /// Creates a new Mock Device and attaches it, it'll be detected the same as a real device.
+    pub fn attach(id: u8, serial: &str) -> Self {
+        let state = MockState {
+            serial: serial.to_string(),
+            pressed: EnumSet::empty(),
+            volumes: [0; 4],
+            encoders: [0; 4],
+            mic_level: 0,
+        };
+
+        let device = Self {
+            location: USBLocation::mock(id),
+            state: Arc::new(Mutex::new(state)),
+            polled: Arc::new(Notify::new()),
+        };
+
+        if let Ok(mut attached) = ATTACHED.lock() {
+            attached.retain(|mock| mock.location != device.location);
+            attached.push(device.clone());
+        }
+        device
+    }
+
+    /// Removes the device, as if it had been unplugged
+    pub fn detach(&self) {
+        if let Ok(mut attached) = ATTACHED.lock() {
+            attached.retain(|mock| mock.location != self.location);
+        }
+    }
+
+    pub fn location(&self) -> USBLocation {
+        self.location.clone()
+    }
+
+    /// Waits until whatever is running the device next asks for its state
+    pub async fn wait_for_poll(&self) {
+        self.polled.notified().await
+    }
+
+    pub fn set_button(&self, button: Buttons, pressed: bool) {
+        let Some(button) = InteractiveButtons::iter().find(|b| Buttons::from(*b) == button) else {
+            return;
+        };
+
+        self.update(|state| match pressed {
+            true => state.pressed.insert(button.into()),
+            false => state.pressed.remove(button.into()),
+        });
+    }
+
+    pub fn set_volume(&self, fader: Fader, volume: u8) {
+        let fader = DeviceFader::from(fader);
+        self.update(|state| state.volumes[fader as usize] = volume);
+    }
+
+    pub fn set_encoder(&self, encoder: Encoders, value: i8) {
+        let encoder = DeviceEncoder::from(encoder);
+        self.update(|state| state.encoders[encoder as usize] = value);
+    }
+
+    /// Sets the level (in dB) the device will report for the microphone
+    pub fn set_mic_level(&self, db: f64) {
+        // This is the reverse of the conversion done when reading the level
+        let value = 10_f64.powf((db.clamp(-72.2, 0.) + 72.2) / 20.);
+        self.update(|state| state.mic_level = value as u16);
+    }
+
+    fn update<F, T>(&self, update: F)
+    where
+        F: FnOnce(&mut MockState) -> T,
+    {
+        if let Ok(mut state) = self.state.lock() {
+            update(&mut state);
+        }
+    }
+}
+
+pub(crate) fn get_attached() -> Vec<MockDevice> {
+    match ATTACHED.lock() {
+        Ok(attached) => attached.clone(),
+        Err(_) => vec![],
+    }
+}
+
+pub(crate) fn find_attached(location: &USBLocation) -> Option<MockDevice> {
+    get_attached()
+        .into_iter()
+        .find(|mock| &mock.location == location)
+}
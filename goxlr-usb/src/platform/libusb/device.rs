@@ -2,13 +2,14 @@ use std::time::Duration;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use goxlr_shared::device::DeviceType;
+use goxlr_shared::device::{ClaimFailure, DeviceType};
 use log::{debug, info};
 use rusb::{Device, DeviceDescriptor, DeviceHandle, GlobalContext};
 use tokio::task::JoinHandle;
 use tokio::{select, task, time};
 
 use crate::common::command_handler::GoXLRCommands;
+use crate::error::DeviceClaimError;
 use crate::platform::common::device::{GoXLRConfiguration, GoXLRDevice};
 use crate::platform::common::initialiser::InitialisableGoXLR;
 use crate::platform::FullGoXLRDevice;
@@ -36,7 +37,7 @@ impl GoXLRDevice for LibUSBGoXLR {
         Self: Sized,
     {
         let (device, descriptor) = LibUSBGoXLR::find_device(config.device.clone())?;
-        let handle = device.open()?;
+        let handle = device.open().map_err(claim_error)?;
         let device = handle.device();
 
         info!("Connected to possible GoXLR device at {:?}", device);
@@ -110,5 +111,17 @@ impl GoXLRDevice for LibUSBGoXLR {
     }
 }
 
+/// Works out the likely cause of libusb refusing us the device
+pub(crate) fn claim_error(error: rusb::Error) -> DeviceClaimError {
+    let failure = match error {
+        rusb::Error::Access => ClaimFailure::PermissionDenied,
+        rusb::Error::Busy => ClaimFailure::InUse,
+        rusb::Error::NoDevice | rusb::Error::NotFound => ClaimFailure::Disconnected,
+        rusb::Error::NotSupported => ClaimFailure::DriverMissing,
+        _ => ClaimFailure::Unknown,
+    };
+    DeviceClaimError::new(failure, error.to_string())
+}
+
 impl GoXLRCommands for LibUSBGoXLR {}
 impl FullGoXLRDevice for LibUSBGoXLR {}
@@ -1,7 +1,6 @@
 use crate::platform::common::initialiser::InitialisableGoXLR;
-use crate::platform::libusb::device::LibUSBGoXLR;
+use crate::platform::libusb::device::{claim_error, LibUSBGoXLR};
 use crate::platform::libusb::local::{ReadControl, WriteControl};
-use anyhow::bail;
 use log::{debug, info};
 use rusb::Error::Pipe;
 use std::time::Duration;
@@ -22,11 +21,10 @@ impl InitialisableGoXLR for LibUSBGoXLR {
         if result == Err(Pipe) {
             // The GoXLR is not initialised, we need to fix that..
             info!("Found uninitialised GoXLR, attempting initialisation..");
-            self.handle.set_auto_detach_kernel_driver(true)?;
-
-            if self.handle.claim_interface(0).is_err() {
-                bail!("Unable to Claim Device");
-            }
+            self.handle
+                .set_auto_detach_kernel_driver(true)
+                .map_err(claim_error)?;
+            self.handle.claim_interface(0).map_err(claim_error)?;
 
             debug!("Activating Vendor Interface...");
             self.read_control(ReadControl {
@@ -24,6 +24,7 @@ pub async fn get_devices() -> Vec<USBLocation> {
                             address,
                         }),
                         windows_usb: None,
+                        mock: None,
                     };
 
                     list.push(device);
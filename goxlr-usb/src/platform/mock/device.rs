@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use log::debug;
+use tokio::task::JoinHandle;
+use tokio::{select, task, time};
+
+use goxlr_shared::device::DeviceType;
+
+use crate::common::command_handler::GoXLRCommands;
+use crate::mock::{find_attached, MockDevice};
+use crate::platform::common::device::{GoXLRConfiguration, GoXLRDevice};
+use crate::platform::FullGoXLRDevice;
+use crate::runners::device::InternalDeviceMessage;
+use crate::util::stop::Stop;
+
+pub(crate) struct MockGoXLR {
+    config: GoXLRConfiguration,
+    stop: Stop,
+    task: Option<JoinHandle<()>>,
+
+    pub(crate) mock: MockDevice,
+}
+
+#[async_trait]
+impl GoXLRDevice for MockGoXLR {
+    async fn from_config(config: GoXLRConfiguration) -> Result<Box<dyn FullGoXLRDevice>>
+    where
+        Self: Sized,
+    {
+        let mock = find_attached(&config.device).context("Mock Device not Attached")?;
+        Ok(Box::new(MockGoXLR {
+            config,
+            stop: Stop::new(),
+            task: None,
+            mock,
+        }))
+    }
+
+    async fn run(&mut self) -> Result<()> {
+        let device = self.config.device.clone();
+        let events = self.config.events.clone();
+
+        // Behave like libusb, and simply poll on a timer..
+        let mut stop = self.stop.clone();
+        self.task = Some(task::spawn(async move {
+            debug!("[MOCK]{} Spawning Event Loop..", device);
+            let mut ticker = time::interval(Duration::from_millis(20));
+            loop {
+                select! {
+                    _ = ticker.tick() => {
+                        if events.capacity() > 0 {
+                            let _ = events.send(InternalDeviceMessage::Poll).await;
+                        }
+                    }
+                    _ = stop.recv() => {
+                        break;
+                    }
+                }
+            }
+            debug!("[MOCK]{} Event Loop Stopped", device);
+        }));
+
+        Ok(())
+    }
+
+    async fn stop(&mut self) {
+        self.stop.trigger();
+        if let Some(task) = self.task.take() {
+            let _ = task.await;
+        }
+    }
+
+    fn get_device_type(&self) -> DeviceType {
+        DeviceType::Full
+    }
+}
+
+impl GoXLRCommands for MockGoXLR {}
+impl FullGoXLRDevice for MockGoXLR {}
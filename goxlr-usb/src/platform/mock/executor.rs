@@ -0,0 +1,64 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::common::executor::ExecutableGoXLR;
+use crate::goxlr::commands::{Command, HardwareInfoCommand};
+use crate::platform::common::device::GoXLRDevice;
+use crate::platform::mock::device::MockGoXLR;
+
+// The firmware reported by the Mock, new enough to support all features (1.4.2.107)
+const FIRMWARE: (u32, u32) = ((1 << 12) | (4 << 8) | 2, 107);
+
+/// The Mock doesn't have anything to talk to, so responds to anything which expects data with
+/// its current state, and accepts everything else.
+#[async_trait]
+impl ExecutableGoXLR for MockGoXLR {
+    async fn perform_request(&mut self, command: Command, _body: &[u8]) -> Result<Vec<u8>> {
+        let Ok(state) = self.mock.state.lock() else {
+            bail!("Mock State Unavailable");
+        };
+
+        let mut response = vec![];
+        match command {
+            Command::GetHardwareInfo(HardwareInfoCommand::SerialNumber) => {
+                let mut serial = state.serial.clone().into_bytes();
+                serial.resize(24, 0);
+                response.extend(serial);
+
+                let mut date = b"2024-01-01".to_vec();
+                date.resize(16, 0);
+                response.extend(date);
+            }
+            Command::GetHardwareInfo(HardwareInfoCommand::FirmwareVersion) => {
+                let (packed, build) = FIRMWARE;
+                for value in [packed, build, 0, 1, 0, 0] {
+                    response.write_u32::<LittleEndian>(value)?;
+                }
+            }
+            Command::GetButtonStates => {
+                let mut buttons = 0;
+                for button in state.pressed {
+                    buttons |= 1 << button as u8;
+                }
+                response.write_u32::<LittleEndian>(buttons)?;
+                response.extend(state.encoders.map(|encoder| encoder as u8));
+                response.extend(state.volumes);
+                self.mock.polled.notify_waiters();
+            }
+            Command::GetMicrophoneLevel => {
+                response.write_u16::<LittleEndian>(state.mic_level)?;
+            }
+            _ => {}
+        }
+        Ok(response)
+    }
+
+    async fn perform_recovery(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn perform_stop(&mut self) {
+        self.stop().await
+    }
+}
@@ -6,6 +6,7 @@ use anyhow::Result;
 // This file will select which backend to use depending on platform, internally they'll all
 // behave the same way.
 pub mod common;
+mod mock;
 
 pub trait FullGoXLRDevice: GoXLRDevice + GoXLRCommands + Sync + Send {}
 
@@ -15,21 +16,38 @@ cfg_if::cfg_if! {
         use crate::platform::tusb::device;
 
         pub async fn find_devices() -> Vec<USBLocation> {
-            crate::platform::tusb::pnp::get_devices()
+            let mut devices = crate::platform::tusb::pnp::get_devices();
+            devices.extend(get_mock_devices());
+            devices
         }
 
         pub async fn from_device(config: GoXLRConfiguration) -> Result<Box<dyn FullGoXLRDevice>> {
+            if config.device.is_mock() {
+                return mock::device::MockGoXLR::from_config(config).await;
+            }
             device::TUSBAudioGoXLR::from_config(config).await
         }
     } else {
         mod libusb;
 
         pub async fn find_devices() -> Vec<USBLocation> {
-            libusb::pnp::get_devices().await
+            let mut devices = libusb::pnp::get_devices().await;
+            devices.extend(get_mock_devices());
+            devices
         }
 
         pub async fn from_device(config: GoXLRConfiguration) -> Result<Box<dyn FullGoXLRDevice>> {
+            if config.device.is_mock() {
+                return mock::device::MockGoXLR::from_config(config).await;
+            }
             libusb::device::LibUSBGoXLR::from_config(config).await
         }
     }
 }
+
+fn get_mock_devices() -> Vec<USBLocation> {
+    crate::mock::get_attached()
+        .iter()
+        .map(|mock| mock.location())
+        .collect()
+}
@@ -12,7 +12,8 @@ pub fn get_devices() -> Vec<USBLocation> {
             lib_usb: None,
             windows_usb: Some(WindowsUSB {
                 identifier: device
-            })
+            }),
+            mock: None,
         })
     }
     list
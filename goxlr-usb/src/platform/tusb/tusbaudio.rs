@@ -1,7 +1,9 @@
+use crate::error::DeviceClaimError;
 use crate::platform::common::device::GoXLRConfiguration;
 use crate::{LibUSB, USBLocation, WindowsUSB, PID_GOXLR_FULL, PID_GOXLR_MINI, VID_GOXLR};
-use anyhow::{anyhow, bail, Result};
+use anyhow::{bail, Result};
 use byteorder::{ByteOrder, LittleEndian};
+use goxlr_shared::device::ClaimFailure;
 use goxlr_shared::version::VersionNumber;
 use lazy_static::lazy_static;
 use libloading::{Library, Symbol};
@@ -378,7 +380,9 @@ impl TUSBAudio<'_> {
             .unwrap()
             .iter()
             .position(|id| identifier == id.clone())
-            .ok_or_else(|| anyhow!("Cannot Find Device"))?;
+            .ok_or_else(|| {
+                DeviceClaimError::new(ClaimFailure::Disconnected, "Cannot Find Device")
+            })?;
         self.open_device_by_index(device_index.try_into()?)
     }
 
@@ -388,7 +392,10 @@ impl TUSBAudio<'_> {
         if result == 0 {
             return Ok(handle);
         }
-        bail!("Unable to Open Device: {}", result)
+
+        // If the driver can see the device but won't open it, something else has it
+        let message = format!("Unable to Open Device: {}", self.get_error(result));
+        Err(DeviceClaimError::new(ClaimFailure::InUse, message).into())
     }
 
     pub fn close_device(&self, handle: u32) -> Result<()> {
@@ -609,6 +616,7 @@ impl TUSBAudio<'_> {
                                         address,
                                     }),
                                     windows_usb: None,
+                                    mock: None,
                                 });
                             }
                         }
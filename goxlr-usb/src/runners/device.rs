@@ -30,8 +30,9 @@ use crate::types::faders::DeviceFader;
 use crate::types::mic_keys::{DeviceMicEffectKeys, DeviceMicParamKeys};
 use crate::USBLocation;
 
-// This is an obnoxiously long type, shorten it!
-type Ready = oneshot::Sender<DeviceInfo>;
+// This is an obnoxiously long type, shorten it! If the device can't be started, the error is
+// sent back here so the caller can see why.
+type Ready = oneshot::Sender<Result<DeviceInfo>>;
 
 struct GoXLRUSBDevice {
     config: GoXLRUSBConfiguration,
@@ -62,18 +63,34 @@ impl GoXLRUSBDevice {
 
         // Ok, firstly, we need to create a GoXLR device from our Location..
         debug!("[RUNNER]{} Initialising Device..", self.config.device);
-        let mut device = from_device(config).await?;
-        device.run().await?;
+        let mut device = match from_device(config).await {
+            Ok(device) => device,
+            Err(error) => {
+                let _ = ready.send(Err(error));
+                bail!("Unable to Open Device");
+            }
+        };
+
+        if let Err(error) = device.run().await {
+            let _ = ready.send(Err(error));
+            bail!("Unable to Initialise Device");
+        }
 
         debug!(
             "[RUNNER]{} Device Initialised, starting event loop",
             self.config.device
         );
 
-        let details = self.get_device_info(&mut device).await?;
+        let details = match self.get_device_info(&mut device).await {
+            Ok(details) => details,
+            Err(error) => {
+                let _ = ready.send(Err(error));
+                bail!("Unable to Read Device Info");
+            }
+        };
 
         // Once we get here, the device has setup, send back the message sender...
-        let _ = ready.send(details);
+        let _ = ready.send(Ok(details));
 
         loop {
             select! {
@@ -130,8 +147,8 @@ impl GoXLRUSBDevice {
                 BasicResultCommand::AssignFader(fader, channel) => {
                     let _ = responder.send(device.assign_fader(fader.into(), channel.into()).await);
                 }
-                BasicResultCommand::ApplyRouting(input, table) => {
-                    let _ = responder.send(device.apply_routing(input, table).await);
+                BasicResultCommand::ApplyRouting(input, table, stereo) => {
+                    let _ = responder.send(device.apply_routing(input, table, stereo).await);
                 }
                 BasicResultCommand::SetFaderStyle(fader, style) => {
                     let _ = responder.send(device.set_fader_style(fader, style).await);
@@ -175,6 +192,14 @@ impl GoXLRUSBDevice {
                     });
                     let _ = responder.send(device.set_mic_effects(map).await);
                 }
+                BasicResultCommand::SetEncoderValue(encoder, value) => {
+                    let encoder = encoder.into();
+                    let _ = responder.send(device.set_encoder_value(encoder, value).await);
+                }
+                BasicResultCommand::SetEncoderMode(encoder, mode, steps) => {
+                    let encoder = encoder.into();
+                    let _ = responder.send(device.set_encoder_mode(encoder, mode, steps).await);
+                }
             },
             CommandSender::GetMicLevel(responder) => {
                 let _ = responder.send(device.get_microphone_level().await);
@@ -0,0 +1,110 @@
+/*
+   A log of every command sent to a device, and what came back, written to a file of its own.
+   This is for working on the protocol (and for bug reports against it), so everything is written
+   as raw hex alongside the decoded command, rather than anything friendlier.
+*/
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+
+use crate::goxlr::commands::Command;
+
+// Shared by every device, so a single file shows the order commands were really sent in
+static TRAFFIC_LOG: Mutex<Option<File>> = Mutex::new(None);
+
+// How many bytes are shown on each line of a dump
+const DUMP_WIDTH: usize = 16;
+
+/// Starts logging to the given file, appending if it already exists
+pub fn start_traffic_log(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context("Unable to create the USB Traffic Log directory")?;
+    }
+
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Unable to open USB Traffic Log: {}", path.display()))?;
+
+    info!("Logging USB Traffic to {}", path.display());
+    if let Ok(mut log) = TRAFFIC_LOG.lock() {
+        *log = Some(file);
+    }
+    Ok(())
+}
+
+pub fn stop_traffic_log() {
+    if let Ok(mut log) = TRAFFIC_LOG.lock() {
+        if log.take().is_some() {
+            info!("Stopped Logging USB Traffic");
+        }
+    }
+}
+
+pub fn is_traffic_logging() -> bool {
+    TRAFFIC_LOG.lock().map(|log| log.is_some()).unwrap_or(false)
+}
+
+pub(crate) fn log_traffic(
+    command: Command,
+    request: &[u8],
+    response: &Result<Vec<u8>>,
+    latency: Duration,
+) {
+    let Ok(mut log) = TRAFFIC_LOG.lock() else {
+        return;
+    };
+    let Some(file) = log.as_mut() else {
+        return;
+    };
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    let mut entry = format!(
+        "[{}.{:03}] {:?} (0x{:08x}) in {}us\n",
+        time.as_secs(),
+        time.subsec_millis(),
+        command,
+        command.command_id(),
+        latency.as_micros()
+    );
+    entry.push_str(&hex_dump(">", request));
+    match response {
+        Ok(data) => entry.push_str(&hex_dump("<", data)),
+        Err(error) => entry.push_str(&format!("  ! {}\n", error)),
+    }
+
+    // Written straight away, so the log is complete up to the moment something goes wrong
+    if let Err(error) = file.write_all(entry.as_bytes()) {
+        warn!("Unable to write USB Traffic Log, stopping: {}", error);
+        *log = None;
+    }
+}
+
+fn hex_dump(direction: &str, data: &[u8]) -> String {
+    if data.is_empty() {
+        return format!("  {} (empty)\n", direction);
+    }
+
+    let mut dump = String::new();
+    for (line, chunk) in data.chunks(DUMP_WIDTH).enumerate() {
+        let bytes: Vec<String> = chunk.iter().map(|byte| format!("{:02x}", byte)).collect();
+        let offset = line * DUMP_WIDTH;
+        dump.push_str(&format!(
+            "  {} {:04x}: {}\n",
+            direction,
+            offset,
+            bytes.join(" ")
+        ));
+    }
+    dump
+}